@@ -0,0 +1,145 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! Benchmarks that compare the interpreting of syntax tree with the executing of bytecode.
+//!
+//! The benchmarks are scaled scripts from the interpreter tests. These benchmarks can be run by
+//! `cargo bench --bench interp`.
+use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+use unlab_gpu::doc::DocIterator;
+use unlab_gpu::error::Pos;
+use unlab_gpu::lexer::Lexer;
+use unlab_gpu::lexer::Token;
+use unlab_gpu::parser::Parser;
+use unlab_gpu::Env;
+use unlab_gpu::Interp;
+use unlab_gpu::ModNode;
+use unlab_gpu::Result;
+use unlab_gpu::Tree;
+use unlab_gpu::Value;
+use unlab_gpu::add_std_builtin_funs;
+
+const ITER_COUNT: usize = 10;
+
+const FOR_SCRIPT: &'static str = "
+X = 1
+Y = 1
+for I in 1 to 100000
+    X = X + 1
+    Y = Y + I
+end
+";
+
+const WHILE_SCRIPT: &'static str = "
+X = 1
+Y = 2
+while X <= 100000
+    X = X + 1
+    Y = Y + 2
+end
+";
+
+const LOCAL_FOR_SCRIPT: &'static str = "
+function f()
+    X = 1
+    Y = 1
+    for I in 1 to 100000
+        X = X + 1
+        Y = Y + I
+    end
+    Y
+end
+Z = f()
+";
+
+const APPLICATION_SCRIPT: &'static str = "
+function fib(N)
+    if N < 2
+        return N
+    end
+    fib(N - 1) + fib(N - 2)
+end
+X = fib(20)
+";
+
+const LAMBDA_SCRIPT: &'static str = "
+F = function(X, Y) X * Y + 1 end
+Z = 0
+for I in 1 to 50000
+    Z = Z + F(I, 2)
+end
+";
+
+const SORT_SCRIPT: &'static str = "
+A = .[.]
+for I in 1 to 5000
+    push(A, { name: I; score: 5000 - I })
+end
+sort(A, none, function(D, X) X.score end)
+";
+
+fn parse_script(s: &str) -> Result<Tree>
+{
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("bench.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    parser.parse()
+}
+
+fn run_script(tree: &Tree, is_vm: bool) -> Result<Duration>
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new_with_vm_flag(is_vm);
+    let now = Instant::now();
+    interp.interpret(&mut env, tree)?;
+    Ok(now.elapsed())
+}
+
+fn bench_script(name: &str, s: &str) -> Result<()>
+{
+    let tree = parse_script(s)?;
+    let mut tree_duration = Duration::ZERO;
+    let mut vm_duration = Duration::ZERO;
+    for _ in 0..ITER_COUNT {
+        tree_duration += run_script(&tree, false)?;
+        vm_duration += run_script(&tree, true)?;
+    }
+    let tree_ms = tree_duration.as_secs_f64() * 1000.0 / (ITER_COUNT as f64);
+    let vm_ms = vm_duration.as_secs_f64() * 1000.0 / (ITER_COUNT as f64);
+    println!("{:<12} tree: {:>10.3} ms  vm: {:>10.3} ms  speedup: {:>6.2}x", name, tree_ms, vm_ms, tree_ms / vm_ms);
+    Ok(())
+}
+
+fn main()
+{
+    let scripts = [
+        ("for", FOR_SCRIPT),
+        ("while", WHILE_SCRIPT),
+        ("local for", LOCAL_FOR_SCRIPT),
+        ("application", APPLICATION_SCRIPT),
+        ("lambda", LAMBDA_SCRIPT),
+        ("sort", SORT_SCRIPT)
+    ];
+    for (name, s) in scripts {
+        match bench_script(name, s) {
+            Ok(()) => (),
+            Err(err) => {
+                eprintln!("{}: {}", name, err);
+                std::process::exit(1);
+            },
+        }
+    }
+}
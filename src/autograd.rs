@@ -0,0 +1,645 @@
+//
+// Copyright (c) 2025-2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of automatic differentiation.
+//!
+//! This module implements a tape-based reverse-mode automatic differentiation. Operations on
+//! tracked values are recorded on a tape and gradients are calculated by traversal of the tape in
+//! reverse order.
+use std::sync::Arc;
+use std::sync::RwLock;
+use crate::matrix::Matrix;
+use crate::env::*;
+use crate::error::*;
+use crate::interp::*;
+use crate::tree::*;
+use crate::utils::*;
+use crate::value::*;
+
+/// An enumeration of function that can be recorded on a tape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TapeFun
+{
+    /// A sigmoid function.
+    Sigmoid,
+    /// A hyperbolic tangent function.
+    Tanh,
+    /// A swish function.
+    Swish,
+    /// A softmax function.
+    Softmax,
+    /// An exponential function.
+    Exp,
+    /// A natural logarithm function.
+    Log,
+}
+
+/// An enumeration of pooling that can be recorded on a tape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TapePool
+{
+    /// A max pooling.
+    Max,
+    /// An average pooling.
+    Avg,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum TapeOp
+{
+    Leaf,
+    Neg(usize),
+    Transpose(usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    MulElems(usize, usize),
+    DivElems(usize, usize),
+    Fun(TapeFun, usize),
+    Reshape(usize),
+    Conv2d(Conv2dParams, usize, usize),
+    Pool2d(TapePool, Conv2dParams, usize),
+}
+
+/// A tape structure.
+///
+/// The tape contains nodes of recorded operations. Each node has a value that is a floating-point
+/// number, a matrix, or a tensor, and an operation that computed this value. A tracked value refers to a
+/// node of the tape.
+#[derive(Clone, Debug)]
+pub struct Tape
+{
+    nodes: Vec<(Value, TapeOp)>,
+}
+
+impl Tape
+{
+    /// Creates a tape.
+    pub fn new() -> Self
+    { Tape { nodes: Vec::new(), } }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize
+    { self.nodes.len() }
+
+    /// Returns the value of the node with the index.
+    pub fn value(&self, idx: usize) -> Option<&Value>
+    {
+        match self.nodes.get(idx) {
+            Some((value, _)) => Some(value),
+            None => None,
+        }
+    }
+
+    /// Adds a leaf node with the value and returns the index of the added node.
+    ///
+    /// An integer number is converted to a floating-point number.
+    pub fn add_leaf(&mut self, value: &Value) -> Result<usize>
+    {
+        let value2 = match value {
+            Value::Int(_) | Value::Float(_) => Value::Float(value.to_f64()),
+            Value::Object(object) => {
+                match &**object {
+                    Object::Matrix(_) | Object::Tensor(_, _) => value.clone(),
+                    _ => return Err(Error::Interp(String::from("unsupported type for tape"))),
+                }
+            },
+            _ => return Err(Error::Interp(String::from("unsupported type for tape"))),
+        };
+        Ok(self.add_node(value2, TapeOp::Leaf))
+    }
+
+    fn add_node(&mut self, value: Value, op: TapeOp) -> usize
+    {
+        self.nodes.push((value, op));
+        self.nodes.len() - 1
+    }
+
+    fn add_operand(&mut self, tape: &Arc<RwLock<Tape>>, value: &Value) -> Result<usize>
+    {
+        match tracked_tape_and_idx(value) {
+            Some((tape2, idx)) => {
+                if !Arc::ptr_eq(tape, tape2) {
+                    return Err(Error::Interp(String::from("tracked values are on different tapes")));
+                }
+                Ok(idx)
+            },
+            None => self.add_leaf(value),
+        }
+    }
+
+    fn node_value(&self, idx: usize) -> Result<Value>
+    {
+        match self.value(idx) {
+            Some(value) => Ok(value.clone()),
+            None => Err(Error::Interp(String::from("no tape node"))),
+        }
+    }
+
+    /// Calculates the gradients of the value of the node with the index for all nodes.
+    ///
+    /// If the value of the node is a matrix or a tensor, the gradients are calculated for the sum
+    /// of the elements. A gradient is `None` if the value of the node doesn't depend on the
+    /// node.
+    pub fn backward(&self, idx: usize) -> Result<Vec<Option<Value>>>
+    {
+        let mut grads: Vec<Option<Value>> = vec![None; self.nodes.len()];
+        match grads.get_mut(idx) {
+            Some(grad) => *grad = Some(ones_like(&self.node_value(idx)?)?),
+            None => return Err(Error::Interp(String::from("no tape node"))),
+        }
+        for i in (0..=idx).rev() {
+            let grad = match &grads[i] {
+                Some(tmp_grad) => tmp_grad.clone(),
+                None => continue,
+            };
+            let (value, op) = &self.nodes[i];
+            match op {
+                TapeOp::Leaf => (),
+                TapeOp::Neg(j) => add_grad(&mut grads, *j, grad.unary_op(UnaryOp::Neg)?)?,
+                TapeOp::Transpose(j) => add_grad(&mut grads, *j, grad.unary_op(UnaryOp::Transpose)?)?,
+                TapeOp::Add(j, k) => {
+                    let a = self.node_value(*j)?;
+                    let b = self.node_value(*k)?;
+                    add_grad(&mut grads, *j, reduce_grad(grad.clone(), &a)?)?;
+                    add_grad(&mut grads, *k, reduce_grad(grad, &b)?)?;
+                },
+                TapeOp::Sub(j, k) => {
+                    let a = self.node_value(*j)?;
+                    let b = self.node_value(*k)?;
+                    add_grad(&mut grads, *j, reduce_grad(grad.clone(), &a)?)?;
+                    add_grad(&mut grads, *k, reduce_grad(grad.unary_op(UnaryOp::Neg)?, &b)?)?;
+                },
+                TapeOp::Mul(j, k) => {
+                    let a = self.node_value(*j)?;
+                    let b = self.node_value(*k)?;
+                    if is_matrix(&a) && is_matrix(&b) {
+                        add_grad(&mut grads, *j, grad.bin_op(BinOp::Mul, &b.unary_op(UnaryOp::Transpose)?)?)?;
+                        add_grad(&mut grads, *k, a.unary_op(UnaryOp::Transpose)?.bin_op(BinOp::Mul, &grad)?)?;
+                    } else {
+                        add_grad(&mut grads, *j, reduce_grad(grad.bin_op(BinOp::DotMul, &b)?, &a)?)?;
+                        add_grad(&mut grads, *k, reduce_grad(grad.bin_op(BinOp::DotMul, &a)?, &b)?)?;
+                    }
+                },
+                TapeOp::MulElems(j, k) => {
+                    let a = self.node_value(*j)?;
+                    let b = self.node_value(*k)?;
+                    add_grad(&mut grads, *j, reduce_grad(grad.bin_op(BinOp::DotMul, &b)?, &a)?)?;
+                    add_grad(&mut grads, *k, reduce_grad(grad.bin_op(BinOp::DotMul, &a)?, &b)?)?;
+                },
+                TapeOp::DivElems(j, k) => {
+                    let a = self.node_value(*j)?;
+                    let b = self.node_value(*k)?;
+                    add_grad(&mut grads, *j, reduce_grad(grad.bin_op(BinOp::DotDiv, &b)?, &a)?)?;
+                    let grad_b = grad.bin_op(BinOp::DotMul, value)?.bin_op(BinOp::DotDiv, &b)?.unary_op(UnaryOp::Neg)?;
+                    add_grad(&mut grads, *k, reduce_grad(grad_b, &b)?)?;
+                },
+                TapeOp::Fun(fun, j) => {
+                    let x = self.node_value(*j)?;
+                    add_grad(&mut grads, *j, fun_grad(*fun, grad, &x, value)?)?;
+                },
+                TapeOp::Reshape(j) => {
+                    let x = self.node_value(*j)?;
+                    add_grad(&mut grads, *j, reshape_like(&grad, &x)?)?;
+                },
+                TapeOp::Conv2d(params, j, k) => {
+                    let (x_shape, a) = tensor_shape_and_matrix(&self.node_value(*j)?)?;
+                    let (y_shape, b) = tensor_shape_and_matrix(&self.node_value(*k)?)?;
+                    let (_, c) = tensor_shape_and_matrix(&grad)?;
+                    let (d, e) = matrix_conv2d_grads(&a, &b, &c, params)?;
+                    add_grad(&mut grads, *j, Value::Object(Arc::new(Object::Tensor(x_shape, d))))?;
+                    add_grad(&mut grads, *k, Value::Object(Arc::new(Object::Tensor(y_shape, e))))?;
+                },
+                TapeOp::Pool2d(pool, params, j) => {
+                    let (x_shape, a) = tensor_shape_and_matrix(&self.node_value(*j)?)?;
+                    let (_, b) = tensor_shape_and_matrix(&grad)?;
+                    let c = match pool {
+                        TapePool::Max => matrix_max_pool2d_grad(&a, &b, params)?,
+                        TapePool::Avg => matrix_avg_pool2d_grad(&a, &b, params)?,
+                    };
+                    add_grad(&mut grads, *j, Value::Object(Arc::new(Object::Tensor(x_shape, c))))?;
+                },
+            }
+        }
+        Ok(grads)
+    }
+}
+
+fn matrix_value(a: Matrix) -> Value
+{ Value::Object(Arc::new(Object::Matrix(a))) }
+
+fn is_matrix(value: &Value) -> bool
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(_) => true,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn is_matrix_or_tensor(value: &Value) -> bool
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(_) | Object::Tensor(_, _) => true,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn tensor_shape_and_matrix(value: &Value) -> Result<(Vec<usize>, Matrix)>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => Ok((vec![a.row_count(), a.col_count()], a.clone())),
+                Object::Tensor(shape, a) => Ok((shape.clone(), a.clone())),
+                _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+            }
+        },
+        _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+    }
+}
+
+fn ones_like(value: &Value) -> Result<Value>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => {
+                    let xs = vec![1.0f32; a.row_count() * a.col_count()];
+                    Ok(matrix_value(matrix_create_and_set_elems(a.row_count(), a.col_count(), xs.as_slice())?))
+                },
+                Object::Tensor(shape, a) => {
+                    let xs = vec![1.0f32; a.row_count() * a.col_count()];
+                    let b = matrix_create_and_set_elems(a.row_count(), a.col_count(), xs.as_slice())?;
+                    Ok(Value::Object(Arc::new(Object::Tensor(shape.clone(), b))))
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+            }
+        },
+        _ => Ok(Value::Float(1.0)),
+    }
+}
+
+fn zeros_like(value: &Value) -> Result<Value>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => Ok(matrix_value(matrix_create_and_set_zeros(a.row_count(), a.col_count())?)),
+                Object::Tensor(shape, a) => {
+                    let b = matrix_create_and_set_zeros(a.row_count(), a.col_count())?;
+                    Ok(Value::Object(Arc::new(Object::Tensor(shape.clone(), b))))
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+            }
+        },
+        _ => Ok(Value::Float(0.0)),
+    }
+}
+
+fn reduce_grad(grad: Value, value: &Value) -> Result<Value>
+{
+    if is_matrix_or_tensor(&grad) && !is_matrix_or_tensor(value) {
+        match &grad {
+            Value::Object(object) => {
+                match &**object {
+                    Object::Matrix(a) | Object::Tensor(_, a) => {
+                        let (xs, _) = matrix_elems_and_transpose_flag(a)?;
+                        Ok(Value::Float(xs.iter().sum::<f32>() as f64))
+                    },
+                    _ => Ok(grad.clone()),
+                }
+            },
+            _ => Ok(grad.clone()),
+        }
+    } else {
+        Ok(grad)
+    }
+}
+
+fn reshape_like(grad: &Value, value: &Value) -> Result<Value>
+{
+    let (_, a) = tensor_shape_and_matrix(grad)?;
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(b) | Object::Tensor(_, b) => {
+                    let c = if a.row_count() == b.row_count() && a.col_count() == b.col_count() {
+                        a
+                    } else {
+                        matrix_reshape(&a, b.row_count(), b.col_count())?
+                    };
+                    match &**object {
+                        Object::Tensor(shape, _) => Ok(Value::Object(Arc::new(Object::Tensor(shape.clone(), c)))),
+                        _ => Ok(matrix_value(c)),
+                    }
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+            }
+        },
+        _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+    }
+}
+
+fn add_grad(grads: &mut [Option<Value>], idx: usize, grad: Value) -> Result<()>
+{
+    match grads.get_mut(idx) {
+        Some(grad2) => {
+            *grad2 = match grad2.take() {
+                Some(tmp_grad) => Some(tmp_grad.bin_op(BinOp::DotAdd, &grad)?),
+                None => Some(grad),
+            };
+            Ok(())
+        },
+        None => Err(Error::Interp(String::from("no tape node"))),
+    }
+}
+
+fn fun_value(fun: TapeFun, value: &Value) -> Result<Value>
+{
+    match value {
+        Value::Int(_) | Value::Float(_) => {
+            let a = value.to_f64();
+            let b = match fun {
+                TapeFun::Sigmoid => 1.0 / (1.0 + (-a).exp()),
+                TapeFun::Tanh => a.tanh(),
+                TapeFun::Swish => a / (1.0 + (-a).exp()),
+                TapeFun::Softmax => a.exp() / a.exp(),
+                TapeFun::Exp => a.exp(),
+                TapeFun::Log => a.ln(),
+            };
+            Ok(Value::Float(b))
+        },
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => {
+                    let b = match fun {
+                        TapeFun::Sigmoid => matrix_sigmoid(a)?,
+                        TapeFun::Tanh => matrix_tanh(a)?,
+                        TapeFun::Swish => matrix_swish(a)?,
+                        TapeFun::Softmax => matrix_softmax(a)?,
+                        TapeFun::Exp => matrix_exp(a)?,
+                        TapeFun::Log => matrix_ln(a)?,
+                    };
+                    Ok(matrix_value(b))
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+            }
+        },
+        _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+    }
+}
+
+fn fun_grad(fun: TapeFun, grad: Value, x: &Value, y: &Value) -> Result<Value>
+{
+    let one = Value::Float(1.0);
+    match fun {
+        TapeFun::Sigmoid => grad.bin_op(BinOp::DotMul, y)?.bin_op(BinOp::DotMul, &one.bin_op(BinOp::DotSub, y)?),
+        TapeFun::Tanh => grad.bin_op(BinOp::DotMul, &one.bin_op(BinOp::DotSub, &y.bin_op(BinOp::DotMul, y)?)?),
+        TapeFun::Swish => {
+            let s = fun_value(TapeFun::Sigmoid, x)?;
+            let z = y.bin_op(BinOp::DotAdd, &s.bin_op(BinOp::DotMul, &one.bin_op(BinOp::DotSub, y)?)?)?;
+            grad.bin_op(BinOp::DotMul, &z)
+        },
+        TapeFun::Softmax => {
+            match y {
+                Value::Object(object) => {
+                    match &**object {
+                        Object::Matrix(a) => {
+                            let n = a.row_count();
+                            let ones_row = matrix_value(matrix_create_and_set_elems(1, n, vec![1.0f32; n].as_slice())?);
+                            let ones_col = matrix_value(matrix_create_and_set_elems(n, 1, vec![1.0f32; n].as_slice())?);
+                            let sums = ones_row.bin_op(BinOp::Mul, &grad.bin_op(BinOp::DotMul, y)?)?;
+                            let z = grad.bin_op(BinOp::DotSub, &ones_col.bin_op(BinOp::Mul, &sums)?)?;
+                            y.bin_op(BinOp::DotMul, &z)
+                        },
+                        _ => Err(Error::Interp(String::from("unsupported type for tape"))),
+                    }
+                },
+                _ => Ok(Value::Float(0.0)),
+            }
+        },
+        TapeFun::Exp => grad.bin_op(BinOp::DotMul, y),
+        TapeFun::Log => grad.bin_op(BinOp::DotDiv, x),
+    }
+}
+
+fn tracked_tape_and_idx(value: &Value) -> Option<(&Arc<RwLock<Tape>>, usize)>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Tracked(tape, idx) => Some((tape, *idx)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the untracked value of the value.
+///
+/// If the value is a tracked value, this function returns the value of the tape node, otherwise
+/// the cloned value.
+pub fn untracked_value(value: &Value) -> Result<Value>
+{
+    match tracked_tape_and_idx(value) {
+        Some((tape, idx)) => rw_lock_read(&**tape)?.node_value(idx),
+        None => Ok(value.clone()),
+    }
+}
+
+/// Performs an operation on the tracked value for the unary operator and records it on the
+/// tape.
+pub fn tracked_unary_op(op: UnaryOp, value: &Value) -> Result<Value>
+{
+    let (tape, idx) = match tracked_tape_and_idx(value) {
+        Some((tape, idx)) => (tape, idx),
+        None => return value.unary_op(op),
+    };
+    let tape_op = match op {
+        UnaryOp::Neg | UnaryOp::DotNeg => TapeOp::Neg(idx),
+        UnaryOp::Transpose => TapeOp::Transpose(idx),
+        UnaryOp::Not => return untracked_value(value)?.unary_op(op),
+    };
+    let mut tape_g = rw_lock_write(&**tape)?;
+    let value2 = tape_g.node_value(idx)?.unary_op(op)?;
+    let idx2 = tape_g.add_node(value2, tape_op);
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx2))))
+}
+
+/// Performs an operation on two values for the binary operator and records it on the tape if
+/// at least one value is tracked.
+///
+/// Operations that aren't arithmetic operations are performed on untracked values without
+/// recording.
+pub fn tracked_bin_op(op: BinOp, value: &Value, value2: &Value) -> Result<Value>
+{
+    let tape_op_fun: fn(usize, usize) -> TapeOp = match op {
+        BinOp::Mul => TapeOp::Mul,
+        BinOp::DotMul => TapeOp::MulElems,
+        BinOp::Div | BinOp::DotDiv => TapeOp::DivElems,
+        BinOp::Add | BinOp::DotAdd => TapeOp::Add,
+        BinOp::Sub | BinOp::DotSub => TapeOp::Sub,
+        _ => return untracked_value(value)?.bin_op(op, &untracked_value(value2)?),
+    };
+    let tape = match (tracked_tape_and_idx(value), tracked_tape_and_idx(value2)) {
+        (Some((tape, _)), _) => tape.clone(),
+        (None, Some((tape, _))) => tape.clone(),
+        (None, None) => return value.bin_op(op, value2),
+    };
+    let mut tape_g = rw_lock_write(&*tape)?;
+    let idx = tape_g.add_operand(&tape, value)?;
+    let idx2 = tape_g.add_operand(&tape, value2)?;
+    let value3 = tape_g.node_value(idx)?.bin_op(op, &tape_g.node_value(idx2)?)?;
+    let idx3 = tape_g.add_node(value3, tape_op_fun(idx, idx2));
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx3))))
+}
+
+/// Applies the function to the tracked value and records it on the tape.
+pub fn tracked_fun(fun: TapeFun, value: &Value) -> Result<Value>
+{
+    let (tape, idx) = match tracked_tape_and_idx(value) {
+        Some((tape, idx)) => (tape, idx),
+        None => return fun_value(fun, value),
+    };
+    let mut tape_g = rw_lock_write(&**tape)?;
+    let value2 = fun_value(fun, &tape_g.node_value(idx)?)?;
+    let idx2 = tape_g.add_node(value2, TapeOp::Fun(fun, idx));
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx2))))
+}
+
+/// Applies the reshaping function to the tracked value and records it on the tape.
+///
+/// The reshaping function must return a matrix or a tensor with the same elements in the
+/// row-major order as the value.
+pub fn tracked_reshape<F>(value: &Value, f: F) -> Result<Value>
+    where F: FnOnce(&Value) -> Result<Value>
+{
+    let (tape, idx) = match tracked_tape_and_idx(value) {
+        Some((tape, idx)) => (tape, idx),
+        None => return f(value),
+    };
+    let mut tape_g = rw_lock_write(&**tape)?;
+    let value2 = f(&tape_g.node_value(idx)?)?;
+    let idx2 = tape_g.add_node(value2, TapeOp::Reshape(idx));
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx2))))
+}
+
+fn conv2d_value(value: &Value, value2: &Value, params: &Conv2dParams) -> Result<Value>
+{
+    let (shape, a) = tensor_shape_and_matrix(value)?;
+    let (shape2, b) = tensor_shape_and_matrix(value2)?;
+    let c = matrix_conv2d(&a, &b, params)?;
+    Ok(Value::Object(Arc::new(Object::Tensor(vec![shape[0], shape2[0], params.out_height(), params.out_width()], c))))
+}
+
+/// Calculates the two-dimensional convolution of the image tensor with the kernel tensor and
+/// records it on the tape if at least one tensor is tracked.
+///
+/// See [`matrix_conv2d`].
+pub fn tracked_conv2d(value: &Value, value2: &Value, params: &Conv2dParams) -> Result<Value>
+{
+    let tape = match (tracked_tape_and_idx(value), tracked_tape_and_idx(value2)) {
+        (Some((tape, _)), _) => tape.clone(),
+        (None, Some((tape, _))) => tape.clone(),
+        (None, None) => return conv2d_value(value, value2, params),
+    };
+    let mut tape_g = rw_lock_write(&*tape)?;
+    let idx = tape_g.add_operand(&tape, value)?;
+    let idx2 = tape_g.add_operand(&tape, value2)?;
+    let value3 = conv2d_value(&tape_g.node_value(idx)?, &tape_g.node_value(idx2)?, params)?;
+    let idx3 = tape_g.add_node(value3, TapeOp::Conv2d(*params, idx, idx2));
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx3))))
+}
+
+fn pool2d_value(pool: TapePool, value: &Value, params: &Conv2dParams) -> Result<Value>
+{
+    let (shape, a) = tensor_shape_and_matrix(value)?;
+    let b = match pool {
+        TapePool::Max => matrix_max_pool2d(&a, params)?,
+        TapePool::Avg => matrix_avg_pool2d(&a, params)?,
+    };
+    Ok(Value::Object(Arc::new(Object::Tensor(vec![shape[0], params.channel_count, params.out_height(), params.out_width()], b))))
+}
+
+/// Calculates the two-dimensional pooling of the image tensor and records it on the tape if the
+/// tensor is tracked.
+///
+/// See [`matrix_max_pool2d`] and [`matrix_avg_pool2d`].
+pub fn tracked_pool2d(pool: TapePool, value: &Value, params: &Conv2dParams) -> Result<Value>
+{
+    let (tape, idx) = match tracked_tape_and_idx(value) {
+        Some((tape, idx)) => (tape, idx),
+        None => return pool2d_value(pool, value, params),
+    };
+    let mut tape_g = rw_lock_write(&**tape)?;
+    let value2 = pool2d_value(pool, &tape_g.node_value(idx)?, params)?;
+    let idx2 = tape_g.add_node(value2, TapeOp::Pool2d(pool, *params, idx));
+    Ok(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx2))))
+}
+
+/// Applies the function to the parameters and calculates the gradients of the function result
+/// for the parameters.
+///
+/// The parameters are passed to the function as tracked values. If the function result is a
+/// matrix or a tensor, the gradients are calculated for the sum of the elements. If the function
+/// result doesn't depend on a parameter, the gradient for this parameter is zero.
+pub fn grads(interp: &mut Interp, env: &mut Env, fun_value: &Value, param_values: &[Value]) -> Result<Vec<Value>>
+{
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let mut arg_values: Vec<Value> = Vec::new();
+    {
+        let mut tape_g = rw_lock_write(&*tape)?;
+        for param_value in param_values {
+            let idx = tape_g.add_leaf(param_value)?;
+            arg_values.push(Value::Object(Arc::new(Object::Tracked(tape.clone(), idx))));
+        }
+    }
+    let value = fun_value.apply(interp, env, arg_values.as_slice())?;
+    let tape_g = rw_lock_read(&*tape)?;
+    let mut tmp_grads = match tracked_tape_and_idx(&value) {
+        Some((tape2, idx)) => {
+            if !Arc::ptr_eq(&tape, tape2) {
+                return Err(Error::Interp(String::from("tracked values are on different tapes")));
+            }
+            tape_g.backward(idx)?
+        },
+        None => {
+            match &value {
+                Value::Int(_) | Value::Float(_) => vec![None; tape_g.len()],
+                Value::Object(object) => {
+                    match &**object {
+                        Object::Matrix(_) | Object::Tensor(_, _) => vec![None; tape_g.len()],
+                        _ => return Err(Error::Interp(String::from("unsupported type for function result"))),
+                    }
+                },
+                _ => return Err(Error::Interp(String::from("unsupported type for function result"))),
+            }
+        },
+    };
+    let mut grads: Vec<Value> = Vec::new();
+    for i in 0..param_values.len() {
+        match tmp_grads[i].take() {
+            Some(grad) => grads.push(grad),
+            None => grads.push(zeros_like(&tape_g.node_value(i)?)?),
+        }
+    }
+    Ok(grads)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,399 @@
+//
+// Copyright (c) 2025-2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io::Cursor;
+use crate::builtins::*;
+use crate::doc::*;
+use crate::lexer::*;
+use crate::mod_node::*;
+use crate::parser::*;
+use super::*;
+
+#[test]
+fn test_tape_backward_calculates_gradients_for_numbers()
+{
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let x_idx = tape.write().unwrap().add_leaf(&Value::Float(2.0)).unwrap();
+    let y_idx = tape.write().unwrap().add_leaf(&Value::Int(3)).unwrap();
+    let x = Value::Object(Arc::new(Object::Tracked(tape.clone(), x_idx)));
+    let y = Value::Object(Arc::new(Object::Tracked(tape.clone(), y_idx)));
+    let z = match tracked_bin_op(BinOp::Mul, &x, &y) {
+        Ok(tmp_z) => tmp_z,
+        Err(_) => {
+            assert!(false);
+            return;
+        },
+    };
+    let z = match tracked_bin_op(BinOp::Sub, &z, &x) {
+        Ok(tmp_z) => tmp_z,
+        Err(_) => {
+            assert!(false);
+            return;
+        },
+    };
+    let z = match tracked_bin_op(BinOp::Div, &z, &Value::Int(2)) {
+        Ok(tmp_z) => tmp_z,
+        Err(_) => {
+            assert!(false);
+            return;
+        },
+    };
+    match untracked_value(&z) {
+        Ok(Value::Float(n)) => assert!((2.0 - n).abs() < 0.001),
+        _ => assert!(false),
+    }
+    let z_idx = match &z {
+        Value::Object(object) => {
+            match &**object {
+                Object::Tracked(_, idx) => *idx,
+                _ => {
+                    assert!(false);
+                    return;
+                },
+            }
+        },
+        _ => {
+            assert!(false);
+            return;
+        },
+    };
+    let tape_g = tape.read().unwrap();
+    match tape_g.backward(z_idx) {
+        Ok(grads) => {
+            match &grads[x_idx] {
+                Some(Value::Float(n)) => assert!((1.0 - n).abs() < 0.001),
+                _ => assert!(false),
+            }
+            match &grads[y_idx] {
+                Some(Value::Float(n)) => assert!((1.0 - n).abs() < 0.001),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_tape_backward_calculates_gradients_for_matrix_multiplication()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ];
+    let ys = vec![
+        5.0, 6.0, 7.0,
+        8.0, 9.0, 10.0
+    ];
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let a_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(2, 2, xs.as_slice()))));
+    let b_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(2, 3, ys.as_slice()))));
+    let a_idx = tape.write().unwrap().add_leaf(&a_value).unwrap();
+    let b_idx = tape.write().unwrap().add_leaf(&b_value).unwrap();
+    let a = Value::Object(Arc::new(Object::Tracked(tape.clone(), a_idx)));
+    let b = Value::Object(Arc::new(Object::Tracked(tape.clone(), b_idx)));
+    let c = match tracked_bin_op(BinOp::Mul, &a, &b) {
+        Ok(Value::Object(object)) => object,
+        _ => {
+            assert!(false);
+            return;
+        },
+    };
+    let c_idx = match &*c {
+        Object::Tracked(_, idx) => *idx,
+        _ => {
+            assert!(false);
+            return;
+        },
+    };
+    let tape_g = tape.read().unwrap();
+    match tape_g.backward(c_idx) {
+        Ok(grads) => {
+            match &grads[a_idx] {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(2, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            let zs = matrix.elems();
+                            for i in 0..2 {
+                                for j in 0..2 {
+                                    let mut sum = 0.0f32;
+                                    for k in 0..3 {
+                                        sum += ys[j * 3 + k];
+                                    }
+                                    let k = if matrix.is_transposed() { j * 2 + i } else { i * 2 + j };
+                                    assert!((sum - zs[k]).abs() < 0.001);
+                                }
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            match &grads[b_idx] {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(2, matrix.row_count());
+                            assert_eq!(3, matrix.col_count());
+                            let zs = matrix.elems();
+                            for i in 0..2 {
+                                for j in 0..3 {
+                                    let mut sum = 0.0f32;
+                                    for k in 0..2 {
+                                        sum += xs[k * 2 + i];
+                                    }
+                                    let k = if matrix.is_transposed() { j * 2 + i } else { i * 3 + j };
+                                    assert!((sum - zs[k]).abs() < 0.001);
+                                }
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_tape_backward_calculates_gradient_for_softmax()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    let ws = vec![
+        1.0, -1.0,
+        2.0, 0.5,
+        -3.0, 1.5
+    ];
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let x_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+    let w_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, ws.as_slice()))));
+    let x_idx = tape.write().unwrap().add_leaf(&x_value).unwrap();
+    let x = Value::Object(Arc::new(Object::Tracked(tape.clone(), x_idx)));
+    let y = match tracked_fun(TapeFun::Softmax, &x) {
+        Ok(tmp_y) => tmp_y,
+        Err(_) => {
+            assert!(false);
+            return;
+        },
+    };
+    let z = match tracked_bin_op(BinOp::DotMul, &y, &w_value) {
+        Ok(Value::Object(object)) => object,
+        _ => {
+            assert!(false);
+            return;
+        },
+    };
+    let z_idx = match &*z {
+        Object::Tracked(_, idx) => *idx,
+        _ => {
+            assert!(false);
+            return;
+        },
+    };
+    let tape_g = tape.read().unwrap();
+    match tape_g.backward(z_idx) {
+        Ok(grads) => {
+            match &grads[x_idx] {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let zs = matrix.elems();
+                            for j in 0..2 {
+                                let mut sum = 0.0f32;
+                                for k in 0..3 {
+                                    sum += xs[k * 2 + j].exp();
+                                }
+                                let mut dot = 0.0f32;
+                                for k in 0..3 {
+                                    dot += ws[k * 2 + j] * xs[k * 2 + j].exp() / sum;
+                                }
+                                for i in 0..3 {
+                                    let s = xs[i * 2 + j].exp() / sum;
+                                    assert!((s * (ws[i * 2 + j] - dot) - zs[i * 2 + j]).abs() < 0.001);
+                                }
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_tracked_bin_op_complains_on_tracked_values_on_different_tapes()
+{
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let tape2 = Arc::new(RwLock::new(Tape::new()));
+    let x_idx = tape.write().unwrap().add_leaf(&Value::Float(1.0)).unwrap();
+    let y_idx = tape2.write().unwrap().add_leaf(&Value::Float(2.0)).unwrap();
+    let x = Value::Object(Arc::new(Object::Tracked(tape.clone(), x_idx)));
+    let y = Value::Object(Arc::new(Object::Tracked(tape2.clone(), y_idx)));
+    match tracked_bin_op(BinOp::Add, &x, &y) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("tracked values are on different tapes"), msg),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_typ_returns_type_of_tracked_value()
+{
+    let tape = Arc::new(RwLock::new(Tape::new()));
+    let x_idx = tape.write().unwrap().add_leaf(&Value::Float(1.0)).unwrap();
+    let x = Value::Object(Arc::new(Object::Tracked(tape.clone(), x_idx)));
+    let root_mod: ModNode<Value, ()> = ModNode::new(());
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    match typ(&mut interp, &mut env, &[x]) {
+        Ok(Value::Object(object)) => {
+            match &*object {
+                Object::String(s) => assert_eq!(String::from("float"), *s),
+                _ => assert!(false),
+            }
+        },
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_grads_calculates_gradients_for_anonymous_function()
+{
+    let s = "
+f = function(x, y)
+    return tanh(x .* y) + exp(x) - log(y)
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+            add_std_builtin_funs(&mut root_mod);
+            let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => {
+                    let fun_value = {
+                        let root_mod = env.root_mod().clone();
+                        let root_mod_g = root_mod.read().unwrap();
+                        match root_mod_g.var(&String::from("f")) {
+                            Some(tmp_fun_value) => tmp_fun_value.clone(),
+                            None => {
+                                assert!(false);
+                                return;
+                            },
+                        }
+                    };
+                    match grads(&mut interp, &mut env, &fun_value, &[Value::Float(0.5), Value::Float(2.0)]) {
+                        Ok(grad_values) => {
+                            assert_eq!(2, grad_values.len());
+                            let t = (1.0f64).tanh();
+                            match &grad_values[0] {
+                                Value::Float(n) => assert!(((1.0 - t * t) * 2.0 + (0.5f64).exp() - n).abs() < 0.001),
+                                _ => assert!(false),
+                            }
+                            match &grad_values[1] {
+                                Value::Float(n) => assert!(((1.0 - t * t) * 0.5 - 0.5 - n).abs() < 0.001),
+                                _ => assert!(false),
+                            }
+                        },
+                        Err(_) => assert!(false),
+                    }
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_grads_calculates_gradients_for_conv2d_and_maxpool2d()
+{
+    let s = "
+f = function(x, k)
+    return matrix(maxpool2d(conv2d(x, k), 2))
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+            add_std_builtin_funs(&mut root_mod);
+            let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => {
+                    let fun_value = {
+                        let root_mod = env.root_mod().clone();
+                        let root_mod_g = root_mod.read().unwrap();
+                        match root_mod_g.var(&String::from("f")) {
+                            Some(tmp_fun_value) => tmp_fun_value.clone(),
+                            None => {
+                                assert!(false);
+                                return;
+                            },
+                        }
+                    };
+                    let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+                    let ys = vec![1.0, 0.0, 0.0, 1.0];
+                    let x = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 3, 3], Matrix::new_with_elems(1, 9, xs.as_slice()))));
+                    let k = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 2, 2], Matrix::new_with_elems(1, 4, ys.as_slice()))));
+                    match grads(&mut interp, &mut env, &fun_value, &[x, k]) {
+                        Ok(grad_values) => {
+                            assert_eq!(2, grad_values.len());
+                            match tensor_shape_and_matrix(&grad_values[0]) {
+                                Ok((shape, c)) => {
+                                    assert_eq!(vec![1, 1, 3, 3], shape);
+                                    let xs = vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+                                    let matrix_array = Arc::new(Object::MatrixArray(1, 9, TransposeFlag::NoTranspose, xs));
+                                    assert_eq!(Value::Object(matrix_array), matrix_value(c).to_matrix_array().unwrap());
+                                },
+                                Err(_) => assert!(false),
+                            }
+                            match tensor_shape_and_matrix(&grad_values[1]) {
+                                Ok((shape, c)) => {
+                                    assert_eq!(vec![1, 1, 2, 2], shape);
+                                    let xs = vec![5.0, 6.0, 8.0, 9.0];
+                                    let matrix_array = Arc::new(Object::MatrixArray(1, 4, TransposeFlag::NoTranspose, xs));
+                                    assert_eq!(Value::Object(matrix_array), matrix_value(c).to_matrix_array().unwrap());
+                                },
+                                Err(_) => assert!(false),
+                            }
+                        },
+                        Err(_) => assert!(false),
+                    }
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
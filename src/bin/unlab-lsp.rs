@@ -0,0 +1,72 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::process::exit;
+use clap::Parser;
+use unlab_gpu::pkg_cmds::lsp;
+use unlab_gpu::Home;
+
+#[derive(Parser, Debug)]
+#[command(version)]
+struct Args
+{
+    /// Unlab-gpu home directory
+    #[arg(short = 'H', long)]
+    home_dir: Option<String>,
+    /// Binary path
+    #[arg(short = 'B', long)]
+    bin_path: Option<String>,
+    /// Library path
+    #[arg(short = 'L', long)]
+    lib_path: Option<String>,
+    /// Documentation path
+    #[arg(short = 'D', long)]
+    doc_path: Option<String>,
+    /// Add directory to binary path
+    #[arg(short, long)]
+    bin_dir: Vec<String>,
+    /// Add directory to library path
+    #[arg(short, long)]
+    lib_dir: Vec<String>,
+    /// Add directory to documentation path
+    #[arg(short, long)]
+    doc_dir: Vec<String>,
+}
+
+fn main()
+{
+    let args = Args::parse();
+    let add_dirs = |home: &mut Home| {
+        match home.add_dirs_to_bin_path(args.bin_dir.as_slice()) {
+            Ok(()) => (),
+            Err(err) => {
+                eprintln!("{}", err);
+                return false;
+            },
+        }
+        match home.add_dirs_to_lib_path(args.lib_dir.as_slice()) {
+            Ok(()) => (),
+            Err(err) => {
+                eprintln!("{}", err);
+                return false;
+            },
+        }
+        match home.add_dirs_to_doc_path(args.doc_dir.as_slice()) {
+            Ok(()) => (),
+            Err(err) => {
+                eprintln!("{}", err);
+                return false;
+            },
+        }
+        true
+    };
+    let exit_code = lsp(&args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs);
+    match exit_code {
+        Some(exit_code) => exit(exit_code),
+        None => (),
+    }
+}
@@ -36,6 +36,10 @@ use unlab_gpu::pkg_cmds::init;
 use unlab_gpu::pkg_cmds::new;
 use unlab_gpu::pkg_cmds::run;
 use unlab_gpu::pkg_cmds::console;
+use unlab_gpu::pkg_cmds::dap;
+use unlab_gpu::pkg_cmds::lsp;
+use unlab_gpu::pkg_cmds::fmt;
+use unlab_gpu::pkg_cmds::lint;
 use unlab_gpu::pkg_cmds::doc;
 use unlab_gpu::pkg_cmds::test;
 use unlab_gpu::pkg_cmds::clean_test;
@@ -242,6 +246,14 @@ struct TestArgs
     name: Option<String>,
 }
 
+#[derive(Parser, Debug)]
+struct FmtArgs
+{
+    /// Only check whether scripts are formatted
+    #[arg(short, long)]
+    check: bool,
+}
+
 #[derive(Subcommand, Debug)]
 enum Subcmd
 {
@@ -299,6 +311,14 @@ enum Subcmd
     Run(RunArgs),
     /// Run interpreter for current package
     Console(ConsoleArgs),
+    /// Run debug adapter for current package
+    Dap,
+    /// Run language server for current package
+    Lsp,
+    /// Format scripts of current package
+    Fmt(FmtArgs),
+    /// Lint scripts of current package
+    Lint,
     /// Generate documentation for current package
     Doc,
     /// Execute tests for current package
@@ -450,6 +470,18 @@ fn main()
         Subcmd::Console(args2) => {
             console(!args2.no_ctrl_c, !args2.no_plotter_windows, &args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs, add_std_builtin_funs)
         },
+        Subcmd::Dap => {
+            dap(&args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs, add_std_builtin_funs)
+        },
+        Subcmd::Lsp => {
+            lsp(&args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs)
+        },
+        Subcmd::Fmt(args2) => {
+            fmt(args2.check, &args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs)
+        },
+        Subcmd::Lint => {
+            lint(&args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, add_dirs, add_std_builtin_funs)
+        },
         Subcmd::Doc => {
             doc(&args.home_dir, &args.bin_path, &args.lib_path, &args.doc_path, src_factories, add_dirs)
         },
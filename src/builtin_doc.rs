@@ -122,7 +122,7 @@ An $e$ number.
     doc_root_mod.add_var(String::from("e"), String::from(&doc[1..]));
 
     let doc = r#"
-A machine epsilon number.
+A machine epsilon number for single-precision floating-point elements of matrices.
 "#;
     sig_root_mod.add_var(String::from("eps"), Sig::Var);
     doc_root_mod.add_var(String::from("eps"), String::from(&doc[1..]));
@@ -141,19 +141,28 @@ The stings corresponding to the value types and the object types are:
 - `"none"` - none value
 - `"bool"` - boolean value
 - `"int"` - integer number
-- `"float"` - floating-point number
+- `"float"` - double-precision floating-point number
 - `"string"` - string
 - `"intrange"` - integer range
 - `"floatrange"` - floating-point range
 - `"matrix"` - matrix
+- `"tensor"` - tensor
+- `"doublematrix"` - matrix with double-precision floating-point elements
+- `"intmatrix"` - matrix with integer elements
 - `"function"` - function
 - `"matrixarray"` matrix array
 - `"matrixrowslice"` - matrix row slice
 - `"error"` - error
+- `"regex"` - compiled regular expression
+- `"valuereader"` - value reader
+- `"valuewriter"` - value writer
 - `"windowid"` - window identifier
 - `"array"` - array
 - `"struct"` - structure
+- `"dict"` - dictionary
 - `"weak"` - weak reference
+
+A tracked value has the type of its value.
 "#;
     sig_root_mod.add_var(String::from("type"), Sig::BuiltinFun(vec![
         BuiltinFunArg::Arg(String::from("X"))
@@ -284,7 +293,10 @@ $$ \begin{bmatrix} f(\mathbf{D}, 1) & 0 & \ldots & 0 \\ 0  & f(\mathbf{D}, 2) &
 Creates a matrix from the `X` iterable object that contains the iterable objects which contains
 the numbers.
 
-If the `X` object is a matrix, this function returns the `X` object. The created matrix is:
+If the `X` object is a matrix, this function returns the `X` object. If the `X` object is a
+tensor, this function returns the matrix of tensor elements that has the first dimension as the
+number of rows. If the `X` object is a double matrix or an integer matrix, this function converts
+the `X` object to a matrix with single-precision floating-point elements. The created matrix is:
 
 $$ \begin{bmatrix} x_{1 1} & x_{1 2} & \ldots & x_{1M} \\ x_{2 1} & x_{2 2} & \ldots & x_{2M} \\ \vdots & \vdots & \ddots & \vdots \\ x_{N1} & x_{N2} & \ldots & x_{NM} \end{bmatrix} $$
 
@@ -329,6 +341,55 @@ If the `X` object is a matrix array, this function returns the `X` object.
         BuiltinFunArg::Arg(String::from("X"))
     ]));
     doc_root_mod.add_var(String::from("matrixarray"), String::from(&doc[1..]));
+
+    let doc = r#"
+Creates a double matrix from the `X` iterable object that contains the iterable objects which
+contains the numbers.
+
+A double matrix has double-precision floating-point elements and is stored in the host memory
+instead of the GPU memory, so operations on a double matrix are performed by the CPU. The `X`
+object can also be a matrix, a matrix array, or an integer matrix that are converted to a double
+matrix. Binary operations on a double matrix and an integer matrix or a number return a double
+matrix. A matrix with single-precision floating-point elements can't be mixed with a double
+matrix without conversion.
+"#;
+    sig_root_mod.add_var(String::from("doublematrix"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("doublematrix"), String::from(&doc[1..]));
+
+    let doc = r#"
+Creates an integer matrix from the `X` iterable object that contains the iterable objects which
+contains the numbers.
+
+An integer matrix has 32-bit integer elements and is stored in the host memory instead of the GPU
+memory, so operations on an integer matrix are performed by the CPU. The `X` object can also be a
+matrix, a matrix array, or a double matrix that are converted to an integer matrix. Floating-point
+numbers are truncated towards zero. Binary operations on an integer matrix and an integer number
+return an integer matrix and report an overflow as an error. Binary operations on an integer
+matrix and a floating-point number return a double matrix.
+"#;
+    sig_root_mod.add_var(String::from("intmatrix"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("intmatrix"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns the element type of the `X` matrix as a string.
+
+The element types are:
+
+- `"float"` - single-precision floating-point number
+- `"double"` - double-precision floating-point number
+- `"int"` - 32-bit integer number
+
+The `X` object can be a matrix, a tensor, a matrix array, a matrix row slice, a double matrix, or
+an integer matrix.
+"#;
+    sig_root_mod.add_var(String::from("elemtype"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("elemtype"), String::from(&doc[1..]));
     
     let doc = r#"
 Creates an error with the `kind` error kind and the `msg` message which are strings.
@@ -382,7 +443,7 @@ The `X` object can be a string, a matrix array, a matrix row slice, or an array.
     let doc = r#"
 Returns the number of elements in the`X` object.
 
-The `X` object can be a string, a matrix array, a matrix row slice, or an array. This function
+The `X` object can be a string, a matrix array, a matrix row slice, an array, or a dictionary. This function
 returns the number of UTF-8 characters for a string, the number of rows for a matrix array, or the
 number of columns for a matrix row slice. 
 "#;
@@ -420,7 +481,8 @@ with the `i` row index and the `j`  column index in the `X` matrix array. This f
 the string with one UTF-8 character for a string, the matrix row slice for a matrix array, or the
 element of matrix for a matrix row slice if the `j` index isn't passed. The field with the `i` 
 identifier in the `X` structure is returned if the `j` index isn't passed and the `X` object is
-structure.
+structure. The value with the `i` key in the `X` dictionary is returned if the `j` index isn't
+passed and the `X` object is dictionary.
 "#;
     sig_root_mod.add_var(String::from("get"), Sig::BuiltinFun(vec![
         BuiltinFunArg::Arg(String::from("X")),
@@ -521,6 +583,94 @@ Returns a lowercase string corresponding the `s` string.
     ]));
     doc_root_mod.add_var(String::from("lower"), String::from(&doc[1..]));
 
+    let doc = r#"
+Compiles the `pattern` regular expression to a regex object.
+
+The regex object can be passed instead of a pattern to the `rematch`, `refind`, `refindall`,
+`rereplace`, and `resplit` functions, so the regular expression isn't compiled again in loops. If
+the `pattern` regular expression is invalid, this function returns an error with the `"regex"`
+error kind.
+"#;
+    sig_root_mod.add_var(String::from("regex"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("pattern"))
+    ]));
+    doc_root_mod.add_var(String::from("regex"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns `true` if the `re` regular expression matches any substring of the `s` string, otherwise
+`false`.
+
+The `re` regular expression is a regex object or a pattern string. If the pattern is invalid, this
+function returns an error with the `"regex"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("rematch"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("s")),
+        BuiltinFunArg::Arg(String::from("re"))
+    ]));
+    doc_root_mod.add_var(String::from("rematch"), String::from(&doc[1..]));
+
+    let doc = r#"
+Finds the first match of the `re` regular expression in the `s` string.
+
+This function returns a structure of the match or `none` if the `re` regular expression doesn't
+match. The structure of the match has the following fields:
+
+- `text` - the matched substring
+- `start` - the index of the first character of the matched substring
+- `end` - the index of the last character of the matched substring
+- `groups` - an array of the capture groups
+- `names` - a structure of the named capture groups
+
+The indices of characters are counted from one. A capture group that doesn't participate in the
+match is `none`. The `re` regular expression is a regex object or a pattern string. If the
+pattern is invalid, this function returns an error with the `"regex"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("refind"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("s")),
+        BuiltinFunArg::Arg(String::from("re"))
+    ]));
+    doc_root_mod.add_var(String::from("refind"), String::from(&doc[1..]));
+
+    let doc = r#"
+Finds all non-overlapping matches of the `re` regular expression in the `s` string.
+
+This function returns an array of structures of the matches. The structures of the matches are
+described in the documentation of the `refind` function. If the pattern is invalid, this function
+returns an error with the `"regex"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("refindall"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("s")),
+        BuiltinFunArg::Arg(String::from("re"))
+    ]));
+    doc_root_mod.add_var(String::from("refindall"), String::from(&doc[1..]));
+
+    let doc = r#"
+Replaces the matches of the `re` regular expression in the `s` string with the `u` string.
+
+The `u` string can refer to the capture groups by `$1` or `${name}`. If the `n` number is passed,
+this function replaces at most `n` first matches, otherwise this function replaces all matches.
+If the pattern is invalid, this function returns an error with the `"regex"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("rereplace"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("s")),
+        BuiltinFunArg::Arg(String::from("re")),
+        BuiltinFunArg::Arg(String::from("u")),
+        BuiltinFunArg::OptArg(String::from("n"))
+    ]));
+    doc_root_mod.add_var(String::from("rereplace"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns the substrings of the `s` string which are separated by the matches of the `re` regular
+expression.
+
+If the pattern is invalid, this function returns an error with the `"regex"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("resplit"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("s")),
+        BuiltinFunArg::Arg(String::from("re"))
+    ]));
+    doc_root_mod.add_var(String::from("resplit"), String::from(&doc[1..]));
+
     let doc = r#"
 Sorts boolean values, numbers, or strings in the `x` array.
 
@@ -529,12 +679,56 @@ sorting value type that can be the boolean type, the number type, or the string
 more elements in the `x` array have the different sorting value types, an error occurs. The
 integer numbers and the the floating-point numbers have same sorting value type. An error occurs
 if any element in the `x` array is `nan`.
+
+If the `f` function is passed, the `f` function is a key function or a comparator according to
+the `kind` kind. The kinds are:
+
+- `"key"` - the `f` function takes the `data` data and an element, and returns a key of the
+  element
+- `"cmp"` - the `f` function takes the `data` data and two elements, and returns a negative
+  number if the first element is less than the second element, zero if the elements are equal,
+  or a positive number if the first element is greater than the second element
+
+If the `kind` kind isn't passed, the `f` function is a key function. The keys must satisfy the
+same rules as the elements which are sorted without the `f` function. This sort is stable, so the
+equal elements keep their order.
 "#;
     sig_root_mod.add_var(String::from("sort"), Sig::BuiltinFun(vec![
-        BuiltinFunArg::Arg(String::from("x"))
+        BuiltinFunArg::Arg(String::from("x")),
+        BuiltinFunArg::OptArg(String::from("data")),
+        BuiltinFunArg::OptArg(String::from("f")),
+        BuiltinFunArg::OptArg(String::from("kind"))
     ]));
     doc_root_mod.add_var(String::from("sort"), String::from(&doc[1..]));
 
+    let doc = r#"
+Returns a new array with the sorted elements of the `X` iterable value.
+
+This function sorts the elements as the `sort` function but doesn't modify the `X` value.
+"#;
+    sig_root_mod.add_var(String::from("sorted"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::OptArg(String::from("data")),
+        BuiltinFunArg::OptArg(String::from("f")),
+        BuiltinFunArg::OptArg(String::from("kind"))
+    ]));
+    doc_root_mod.add_var(String::from("sorted"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns the indices of the elements of the `X` iterable value in the sorted order.
+
+This function sorts the elements as the `sort` function and returns an array of indices which are
+counted from one. If the `X` value is a matrix, this function sorts each row of the `X` matrix
+and returns a matrix with the indices of the sorted row elements.
+"#;
+    sig_root_mod.add_var(String::from("argsort"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::OptArg(String::from("data")),
+        BuiltinFunArg::OptArg(String::from("f")),
+        BuiltinFunArg::OptArg(String::from("kind"))
+    ]));
+    doc_root_mod.add_var(String::from("argsort"), String::from(&doc[1..]));
+
     let doc = r#"
 Reverses the order of elements in the `x` array.
 "#;
@@ -669,9 +863,10 @@ If the `X` array is empty, this function returns `none`.
     let doc = r#"
 Appends the `Y` mutable object to the `X` mutable object.
 
-The `X` mutable object and the `Y` mutable object must be arrays or structures. If two fields in
-two structures have same field identifier, the field in the first structure is overwritten by a
-value from the field in the second structure.
+The `X` mutable object and the `Y` mutable object must be arrays, structures, or dictionaries. If
+two fields in two structures have same field identifier, the field in the first structure is
+overwritten by a value from the field in the second structure. Values in two dictionaries are
+overwritten in the same way.
 "#;
     sig_root_mod.add_var(String::from("append"), Sig::BuiltinFun(vec![
         BuiltinFunArg::Arg(String::from("X")),
@@ -686,7 +881,9 @@ If the `X` mutable object is an array, this function inserts the `y` value as an
 `i` index to the `X` array, moves all elements after the inserted element to right, and returns
 `none`.  If the `X` mutable object is a structure, this function inserts the `X` value as a field
 with the `i` identifier to the `X` structure and then returns the replaced field. This function
-returns `none` if the `X` structure doesn't contain the field with the `i` identifier. 
+returns `none` if the `X` structure doesn't contain the field with the `i` identifier. If the `X`
+mutable object is a dictionary, this function inserts the `y` value with the `i` key in the same
+way as for a structure.
 "#;
     sig_root_mod.add_var(String::from("insert"), Sig::BuiltinFun(vec![
         BuiltinFunArg::Arg(String::from("X")),
@@ -700,7 +897,9 @@ Removes the element from the `X` mutable object.
 
 If the `X` mutable object is an array, this function removes an element with the `i` index from
 the `X` array and moves all elements after the removed element to left. If the `X` mutable object
-is a structure, this function removes a field with the `i` identifier from the `X` structure.
+is a structure, this function removes a field with the `i` identifier from the `X` structure. If
+the `X` mutable object is a dictionary, this function removes a value with the `i` key from the
+`X` dictionary.
 This finction returns the removed element or the removed field if the `X` mutable object contains
 the element with the `i` index or the field with the `i` identifier, otherwise `none`.
 "#;
@@ -710,6 +909,49 @@ the element with the `i` index or the field with the `i` identifier, otherwise `
     ]));
     doc_root_mod.add_var(String::from("remove"), String::from(&doc[1..]));
 
+    let doc = r#"
+Returns an array of keys in the `X` structure or the `X` dictionary.
+
+Keys are returned in the sorted order. Field identifiers of structure are returned as strings.
+"#;
+    sig_root_mod.add_var(String::from("keys"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("keys"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns an array of values in the `X` structure or the `X` dictionary.
+
+Values are returned in the order of their keys.
+"#;
+    sig_root_mod.add_var(String::from("values"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("values"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns `true` if the `X` structure or the `X` dictionary contains the `k` key, otherwise `false`.
+
+A key of dictionary can be a boolean value, a number, or a string. A floating-point number that is
+equal to an integer number is the same key as this integer number.
+"#;
+    sig_root_mod.add_var(String::from("haskey"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("k"))
+    ]));
+    doc_root_mod.add_var(String::from("haskey"), String::from(&doc[1..]));
+
+    let doc = r#"
+Deletes the `k` key from the `X` structure or the `X` dictionary.
+
+This function returns `true` if the `k` key is deleted, otherwise `false`.
+"#;
+    sig_root_mod.add_var(String::from("delete"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("k"))
+    ]));
+    doc_root_mod.add_var(String::from("delete"), String::from(&doc[1..]));
+
     let doc = r#"
 Returns the error kind for the `e` error.
 "#;
@@ -840,6 +1082,25 @@ This function is a mathematical function that takes a number, a matrix, or a mut
     ]));
     doc_root_mod.add_var(String::from("softmax"), String::from(&doc[1..]));
 
+    let doc = r#"
+Calculates gradients of the `F` function for the `Params` parameters.
+
+The `F` function is applied to the parameters as tracked values. Operations on tracked values are
+recorded on a tape and the gradients are calculated by reverse-mode automatic differentiation.
+The recorded operations are negation, transpose, addition, subtraction, multiplication, division,
+and the `sigmoid`, `tanh`, `swish`, `softmax`, `exp`, `log`, `matrix`, `tensor`, `reshape`,
+`conv2d`, `maxpool2d`, and `avgpool2d` functions. If the `Params` value is an array, the `F`
+function takes the array elements as arguments and this function returns an array of the
+gradients. Otherwise, the `F` function takes one argument and this function returns one gradient.
+If the function result is a matrix or a tensor, the gradients are calculated for the sum of the
+elements.
+"#;
+    sig_root_mod.add_var(String::from("grad"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("F")),
+        BuiltinFunArg::Arg(String::from("Params"))
+    ]));
+    doc_root_mod.add_var(String::from("grad"), String::from(&doc[1..]));
+
     let doc = r#"
 Calculates square root of the `X` value ($\sqrt{x}$ or $\sqrt{x_{ij}}$).
 
@@ -874,6 +1135,211 @@ Repeats the `x` vector as column or row.
     ]));
     doc_root_mod.add_var(String::from("repeat"), String::from(&doc[1..]));
 
+    let doc = r#"
+Concatenates the `X` matrix and the other matrices horizontally.
+
+The matrices must have the same number of rows. A number is treated as a one-element matrix. The
+elements of the matrices are copied to the host memory and the result is copied back to the GPU
+memory, so this function isn't performed on the GPU.
+"#;
+    sig_root_mod.add_var(String::from("hcat"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("hcat"), String::from(&doc[1..]));
+
+    let doc = r#"
+Concatenates the `X` matrix and the other matrices vertically.
+
+The matrices must have the same number of columns. A number is treated as a one-element matrix.
+The elements of the matrices are copied to the host memory and the result is copied back to the
+GPU memory, so this function isn't performed on the GPU.
+"#;
+    sig_root_mod.add_var(String::from("vcat"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("vcat"), String::from(&doc[1..]));
+
+    let doc = r#"
+Reshapes the `X` matrix to a matrix with the `n` rows and the `m` columns.
+
+The elements of the `X` matrix are taken in the row-major order. The number of elements of the
+`X` matrix must be equal to $nm$. If the `X` object is a tensor, this function takes the
+dimensions of new tensor instead the `n` number and the `m` number. The elements of the `X` matrix
+are copied through the host memory.
+"#;
+    sig_root_mod.add_var(String::from("reshape"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("n")),
+        BuiltinFunArg::Arg(String::from("m"))
+    ]));
+    doc_root_mod.add_var(String::from("reshape"), String::from(&doc[1..]));
+
+    let doc = r#"
+Sums the elements of the `X` matrix ($\sum_{i,j} x_{ij}$).
+
+The sum is computed on the GPU and returned as a floating-point number. This function returns
+the `X` value for a number.
+"#;
+    sig_root_mod.add_var(String::from("sum"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("sum"), String::from(&doc[1..]));
+
+    let doc = r#"
+Calculates the arithmetic mean of the elements of the `X` matrix.
+
+This function returns a floating-point number. The `X` matrix mustn't be empty.
+"#;
+    sig_root_mod.add_var(String::from("mean"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("mean"), String::from(&doc[1..]));
+
+    let doc = r#"
+Sums the rows of the `X` matrix ($\sum_j x_{ij}$) and returns a column vector.
+"#;
+    sig_root_mod.add_var(String::from("rowsum"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("rowsum"), String::from(&doc[1..]));
+
+    let doc = r#"
+Sums the columns of the `X` matrix ($\sum_i x_{ij}$) and returns a row vector.
+"#;
+    sig_root_mod.add_var(String::from("colsum"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("colsum"), String::from(&doc[1..]));
+
+    let doc = r#"
+Calculates the Frobenius norm of the `X` matrix ($\sqrt{\sum_{i,j} x_{ij}^2}$).
+
+The norm of a vector is the Euclidean norm. This function returns the absolute value for a
+number.
+"#;
+    sig_root_mod.add_var(String::from("norm"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("norm"), String::from(&doc[1..]));
+
+    let doc = r#"
+Finds the indices of the maximum elements in the rows of the `X` matrix and returns a column
+vector of these indices.
+
+The indices start from one. If a row contains several maximum elements, the index of first
+maximum element is returned. The elements of the `X` matrix are copied to the host memory and the
+indices are copied back to the GPU memory, so this function isn't performed on the GPU.
+"#;
+    sig_root_mod.add_var(String::from("argmax"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("argmax"), String::from(&doc[1..]));
+
+    let doc = r#"
+Creates a tensor with the `n` dimension and the other dimensions from the `X` matrix or the `X`
+tensor.
+
+The elements of the `X` object are taken in the row-major order. The product of dimensions must be
+equal to the number of elements of the `X` object. If the dimensions aren't passed, this function
+creates a tensor with the shape of the `X` matrix or returns the `X` tensor.
+"#;
+    sig_root_mod.add_var(String::from("tensor"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::OptArg(String::from("n")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("tensor"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns the dimensions of the `X` matrix or the `X` tensor as an array.
+"#;
+    sig_root_mod.add_var(String::from("shape"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("shape"), String::from(&doc[1..]));
+
+    let doc = r#"
+Permutes the dimensions of the `X` tensor.
+
+The `i` index and the other indices are the indices of the dimensions of the `X` tensor in the
+new order. The indices start from one. The elements of the `X` tensor are copied to the host
+memory.
+"#;
+    sig_root_mod.add_var(String::from("permute"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("i")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("permute"), String::from(&doc[1..]));
+
+    let doc = r#"
+Rearranges the windows of the `X` image tensor to the rows of a matrix.
+
+The `X` tensor has the dimensions of images, channels, rows, and columns. The window has the `kh`
+height and the `kw` width, and it slides with the `stride` step over the images with the
+`padding` zeros on each side. Each row of the result matrix contains the elements of one window in
+the row-major order of channels, rows, and columns. The rows are ordered by images and then by
+window positions. The elements of the `X` tensor are copied to the host memory.
+"#;
+    sig_root_mod.add_var(String::from("im2col"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("kh")),
+        BuiltinFunArg::Arg(String::from("kw")),
+        BuiltinFunArg::OptArg(String::from("stride")),
+        BuiltinFunArg::OptArg(String::from("padding"))
+    ]));
+    doc_root_mod.add_var(String::from("im2col"), String::from(&doc[1..]));
+
+    let doc = r#"
+Calculates the two-dimensional convolution of the `X` image tensor with the `K` kernel tensor.
+
+The `X` tensor has the dimensions of images, channels, rows, and columns. The `K` tensor has the
+dimensions of output channels, channels, rows, and columns. The kernels slide with the `stride`
+step over the images with the `padding` zeros on each side. This function returns a tensor with
+the dimensions of images, output channels, rows, and columns. The windows are rearranged as by
+the `im2col` function and are multiplied by the kernels on the backend. Like other deep learning
+libraries, this function calculates the cross-correlation without flipping the kernels.
+"#;
+    sig_root_mod.add_var(String::from("conv2d"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("K")),
+        BuiltinFunArg::OptArg(String::from("stride")),
+        BuiltinFunArg::OptArg(String::from("padding"))
+    ]));
+    doc_root_mod.add_var(String::from("conv2d"), String::from(&doc[1..]));
+
+    let doc = r#"
+Calculates the two-dimensional max pooling of the `X` image tensor.
+
+The `X` tensor has the dimensions of images, channels, rows, and columns. The window has the `k`
+size and it slides over each channel with the `stride` step. The default step is equal to the `k`
+size. The `padding` elements on each side are skipped. The elements of the `X` tensor are copied
+to the host memory.
+"#;
+    sig_root_mod.add_var(String::from("maxpool2d"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("k")),
+        BuiltinFunArg::OptArg(String::from("stride")),
+        BuiltinFunArg::OptArg(String::from("padding"))
+    ]));
+    doc_root_mod.add_var(String::from("maxpool2d"), String::from(&doc[1..]));
+
+    let doc = r#"
+Calculates the two-dimensional average pooling of the `X` image tensor.
+
+This function is similar to the `maxpool2d` function, but the `padding` elements are zeros and
+the sum of the window elements is always divided by the number of window elements.
+"#;
+    sig_root_mod.add_var(String::from("avgpool2d"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::Arg(String::from("k")),
+        BuiltinFunArg::OptArg(String::from("stride")),
+        BuiltinFunArg::OptArg(String::from("padding"))
+    ]));
+    doc_root_mod.add_var(String::from("avgpool2d"), String::from(&doc[1..]));
+
     let doc = r#"
 Calculates remainder of division the `x` value by the `y` value ($\operatorname{mod}(x, y)$).
 
@@ -1255,6 +1721,35 @@ kind.
 
     let doc = r#"
 Formats the values and then returns the formatted values as a string.
+
+If the `X` value is a string with braces, the `X` string is a format string that has placeholders
+which are replaced with the next arguments. The placeholders have the following forms:
+
+- `{}` - the next argument
+- `{n}` - the argument with the `n` index that is counted from one
+- `{name}` - the `name` field of the first structure or the first dictionary with this field
+
+The placeholder can have a format specification after the `:` character, for example `{:.4}` or
+`{name:>8}`. The format specification has the following form:
+
+    [[fill]align][+][0][width][.precision][type]
+
+The alignments are `<` for the left alignment, `^` for the center alignment, and `>` for the right
+alignment. The numbers are aligned to the right by default and other values are aligned to the
+left by default. The `+` flag prints the plus sign for non-negative numbers and the `0` flag pads
+numbers with zeros after the sign. The precision is a number of digits after the decimal point for
+floating-point numbers or a maximal number of characters for other values. The types are:
+
+- `x` - hexadecimal integer number with lowercase digits
+- `X` - hexadecimal integer number with uppercase digits
+- `o` - octal integer number
+- `b` - binary integer number
+- `e` - number in scientific notation with the lowercase exponent
+- `E` - number in scientific notation with the uppercase exponent
+
+The `{{` and `}}` sequences are replaced with the `{` and `}` characters. An interpolated string
+literal, for example `$"loss: {loss:.4}"`, is expanded to a call of this function. If the format
+string is invalid, this function returns an error with the `"format"` error kind.
 "#;
     sig_root_mod.add_var(String::from("format"), Sig::BuiltinFun(vec![
         BuiltinFunArg::OptArg(String::from("X")),
@@ -1468,6 +1963,9 @@ Terminates the current process with the `exitcode` exit code.
     let doc = r#"
 Loads values from the `path` file in the binary format.
 
+This function reads compressed files and files in the older `unlab1` format. A checksum of values
+is verified, so a truncated file or a corrupted file is reported as an error.
+
 This function returns the loaded values if an I/O error doesn't occur while this operation, 
 otherwise an error with the `"io"` error kind.
 "#;
@@ -1489,6 +1987,115 @@ error with the `"io"` error kind.
     ]));
     doc_root_mod.add_var(String::from("save"), String::from(&doc[1..]));
 
+    let doc = r#"
+Saves the values to the `path` file in the binary format with the `codec` compression.
+
+The compression codec can be `"none"`, `"gzip"`, `"bzip2"`, or `"xz"`. The saved values can be
+loaded by the `load` function.
+
+This function returns `true` if an I/O error doesn't occur while this operation, otherwise an
+error with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("savecompressed"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("codec")),
+        BuiltinFunArg::OptArg(String::from("X")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("savecompressed"), String::from(&doc[1..]));
+
+    let doc = r#"
+Saves the values to the `path` file in the indexed binary format with the `codec` compression.
+
+The compression codec can be `"none"`, `"gzip"`, `"bzip2"`, or `"xz"`. The values are saved as
+separate blocks with an index, so the saved values can be loaded by the `load` function or read
+lazily by the `openvalues` function. Each value is compressed separately. Objects that are shared
+by different values aren't shared after loading.
+
+This function returns `true` if an I/O error doesn't occur while this operation, otherwise an
+error with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("saveindexed"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("codec")),
+        BuiltinFunArg::OptArg(String::from("X")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("saveindexed"), String::from(&doc[1..]));
+
+    let doc = r#"
+Opens the `path` file with values in the binary format.
+
+This function returns a value reader if an I/O error doesn't occur while this operation,
+otherwise an error with the `"io"` error kind. The value reader reads values lazily from a file
+that is saved by the `saveindexed` function or is written by a value writer. Files in the other
+formats can't be opened because they don't have indices of values.
+"#;
+    sig_root_mod.add_var(String::from("openvalues"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path"))
+    ]));
+    doc_root_mod.add_var(String::from("openvalues"), String::from(&doc[1..]));
+
+    let doc = r#"
+Reads the value with the `i` index from the `r` value reader.
+
+This function returns the read value if an I/O error doesn't occur while this operation,
+otherwise an error with the `"io"` error kind. The index must be between 1 and the number of
+values.
+"#;
+    sig_root_mod.add_var(String::from("readvalue"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("r")),
+        BuiltinFunArg::Arg(String::from("i"))
+    ]));
+    doc_root_mod.add_var(String::from("readvalue"), String::from(&doc[1..]));
+
+    let doc = r#"
+Returns the number of values of the `r` value reader.
+"#;
+    sig_root_mod.add_var(String::from("valuecount"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("r"))
+    ]));
+    doc_root_mod.add_var(String::from("valuecount"), String::from(&doc[1..]));
+
+    let doc = r#"
+Creates the `path` file for values in the indexed binary format with the `codec` compression.
+
+The compression codec can be `"none"`, `"gzip"`, `"bzip2"`, or `"xz"`. This function returns a
+value writer if an I/O error doesn't occur while this operation, otherwise an error with the
+`"io"` error kind. The value writer appends values to the file one by one, so the values don't
+have to be in the memory at once. The file can be read by the `openvalues` function after the
+value writer is closed.
+"#;
+    sig_root_mod.add_var(String::from("createvalues"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("codec"))
+    ]));
+    doc_root_mod.add_var(String::from("createvalues"), String::from(&doc[1..]));
+
+    let doc = r#"
+Appends the `X` value to the file of the `w` value writer.
+
+This function returns `true` if an I/O error doesn't occur while this operation, otherwise an
+error with the `"io"` error kind. After an I/O error, the value writer is closed without an
+index of values.
+"#;
+    sig_root_mod.add_var(String::from("writevalue"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("w")),
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("writevalue"), String::from(&doc[1..]));
+
+    let doc = r#"
+Writes an index of values and closes the `w` value writer.
+
+This function returns `true` if an I/O error doesn't occur while this operation, otherwise an
+error with the `"io"` error kind. A value writer is also closed when it isn't referred.
+"#;
+    sig_root_mod.add_var(String::from("closevalues"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("w"))
+    ]));
+    doc_root_mod.add_var(String::from("closevalues"), String::from(&doc[1..]));
+
     let doc = r#"
 Loads a string from the `path` text file.
 
@@ -1558,6 +2165,177 @@ with the `"io"` error kind or the `"json"` error kind.
     ]));
     doc_root_mod.add_var(String::from("savejson"), String::from(&doc[1..]));
 
+    let doc = r#"
+Loads rows from the `path` file in the [CSV](https://en.wikipedia.org/wiki/Comma-separated_values)
+format.
+
+The `opts` structure can have the following fields:
+
+- `delimiter` - the field delimiter (default: `","`, `"\t"` for TSV)
+- `quote` - the quote character (default: `"\""`)
+- `header` - if this field is `true`, the first row is the header (default: `true`)
+- `matrix` - if this field is `true`, the rows are loaded to a matrix (default: `false`)
+- `types` - the column types as a string for all columns, an array of strings or a structure of
+  strings keyed by header names (default: `"auto"`)
+
+The column types are `"auto"`, `"bool"`, `"int"`, `"float"`, and `"string"`. The `"auto"` type
+chooses the integer type, the floating-point type, the boolean type, or the string type for each
+field. An empty field is `none` for each column type except the string type.
+
+If the `matrix` field is `true`, this function returns a matrix of the numeric fields without the
+header. Otherwise, this function returns an array of structures keyed by header names if the file
+has the header, or an array of arrays if the file doesn't have the header. Fields can be quoted
+in accordance with [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+
+This function returns an error with the `"io"` error kind or the `"csv"` error kind if an error
+occurs. The message of the `"csv"` error contains the line number of the malformed row.
+"#;
+    sig_root_mod.add_var(String::from("loadcsv"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::OptArg(String::from("opts"))
+    ]));
+    doc_root_mod.add_var(String::from("loadcsv"), String::from(&doc[1..]));
+
+    let doc = r#"
+Saves the `X` rows to the `path` file in the
+[CSV](https://en.wikipedia.org/wiki/Comma-separated_values) format.
+
+The `X` value can be a matrix, an array of structures, or an array of arrays. The `opts`
+structure can have the following fields:
+
+- `delimiter` - the field delimiter (default: `","`, `"\t"` for TSV)
+- `quote` - the quote character (default: `"\""`)
+- `header` - if this field is `true`, the header is saved (default: `true`)
+- `columns` - the column names (default: the field names of the first structure)
+
+The header is only saved if the column names are passed or `X` is an array of structures. Fields
+that contain the delimiter, the quote character, or a newline are quoted. `none` is saved as an
+empty field.
+
+This function returns `true` if an error doesn't occur while this operation, otherwise an error
+with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("savecsv"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::OptArg(String::from("opts"))
+    ]));
+    doc_root_mod.add_var(String::from("savecsv"), String::from(&doc[1..]));
+
+    let doc = r#"
+Loads a matrix from the `path` file in the
+[NPY](https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html) format.
+
+A one-dimensional array is loaded as a column vector and a two-dimensional array is loaded as a
+matrix. An array in Fortran order is loaded as a transposed matrix. Elements of booleans,
+integers, unsigned integers, and 64-bit floating-point numbers are cast to 32-bit floating-point
+numbers.
+
+This function returns the loaded matrix if an error doesn't occur while this operation, otherwise
+an error with the `"io"` error kind. An unsupported data type or an unsupported number of
+dimensions also is the error with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("loadnpy"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path"))
+    ]));
+    doc_root_mod.add_var(String::from("loadnpy"), String::from(&doc[1..]));
+
+    let doc = r#"
+Saves the `X` matrix to the `path` file in the
+[NPY](https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html) format.
+
+The matrix is saved as a two-dimensional array of 32-bit floating-point numbers. A transposed
+matrix is saved in Fortran order. `X` also can be a host matrix.
+
+This function returns `true` if an error doesn't occur while this operation, otherwise an error
+with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("savenpy"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("X"))
+    ]));
+    doc_root_mod.add_var(String::from("savenpy"), String::from(&doc[1..]));
+
+    let doc = r#"
+Loads matrices from the `path` file in the NPZ format.
+
+This function returns a structure of matrices keyed by array names if an error doesn't occur
+while this operation, otherwise an error with the `"io"` error kind or the `"zip"` error kind.
+The arrays are converted as for the `loadnpy` function.
+"#;
+    sig_root_mod.add_var(String::from("loadnpz"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path"))
+    ]));
+    doc_root_mod.add_var(String::from("loadnpz"), String::from(&doc[1..]));
+
+    let doc = r#"
+Saves matrices to the `path` file in the NPZ format.
+
+If only the `X` structure is passed, the matrices of its fields are saved as arrays that are named
+by field names. Otherwise, the passed matrices are saved as the `arr_0`, `arr_1`, ... arrays.
+The arrays aren't compressed.
+
+This function returns `true` if an error doesn't occur while this operation, otherwise an error
+with the `"io"` error kind or the `"zip"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("savenpz"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::OptArg(String::from("X")),
+        BuiltinFunArg::DotDotDot
+    ]));
+    doc_root_mod.add_var(String::from("savenpz"), String::from(&doc[1..]));
+
+    let doc = r#"
+Loads tensors from the `path` file in the [safetensors](https://github.com/huggingface/safetensors)
+format.
+
+This function returns a structure of matrices keyed by tensor names if an error doesn't occur
+while this operation. Dots in tensor names separate names of nested structures, so the
+`l1.weight` tensor is loaded to the `weight` field of the `l1` field. A two-dimensional tensor is
+loaded as a matrix and the other tensors, including scalars and one-dimensional tensors, are loaded
+as tensors that keep their shapes. Elements of other data types than `F32` are cast to 32-bit
+floating-point numbers.
+
+This function returns an error with the `"io"` error kind if an I/O error occurs or the file is
+malformed, or an error with the `"safetensors"` error kind if tensor names conflict with
+structure names.
+"#;
+    sig_root_mod.add_var(String::from("loadsafetensors"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path"))
+    ]));
+    doc_root_mod.add_var(String::from("loadsafetensors"), String::from(&doc[1..]));
+
+    let doc = r#"
+Loads metadata from the `path` file in the
+[safetensors](https://github.com/huggingface/safetensors) format.
+
+This function returns a structure of metadata strings if an error doesn't occur while this
+operation, otherwise an error with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("loadsafetensorsmeta"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path"))
+    ]));
+    doc_root_mod.add_var(String::from("loadsafetensorsmeta"), String::from(&doc[1..]));
+
+    let doc = r#"
+Saves the `X` structure of matrices to the `path` file in the
+[safetensors](https://github.com/huggingface/safetensors) format.
+
+Fields of nested structures are saved as tensors that have names joined by dots, so the `weight`
+field of the `l1` field is saved as the `l1.weight` tensor. Matrices are saved as two-dimensional
+tensors of the `F32` data type and tensors keep their shapes. The `meta` structure of strings is
+saved as metadata.
+
+This function returns `true` if an error doesn't occur while this operation, otherwise an error
+with the `"io"` error kind.
+"#;
+    sig_root_mod.add_var(String::from("savesafetensors"), Sig::BuiltinFun(vec![
+        BuiltinFunArg::Arg(String::from("path")),
+        BuiltinFunArg::Arg(String::from("X")),
+        BuiltinFunArg::OptArg(String::from("meta"))
+    ]));
+    doc_root_mod.add_var(String::from("savesafetensors"), String::from(&doc[1..]));
+
     let doc = r#"
 Returns the arguments which are passed for this script.
 "#;
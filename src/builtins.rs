@@ -7,7 +7,10 @@
 //
 //! A module of built-in functions.
 use std::cmp;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::f32;
+use std::f64;
 use std::ffi::OsString;
 use std::fs;
 use std::fs::File;
@@ -15,6 +18,7 @@ use std::fs::create_dir;
 use std::fs::read_dir;
 use std::fs::remove_dir;
 use std::fs::remove_file;
+use std::io;
 use std::io::BufWriter;
 use std::io::ErrorKind;
 use std::io::Read;
@@ -22,6 +26,7 @@ use std::io::Write;
 use std::io::stdin;
 use std::io::stdout;
 use std::io::stderr;
+use std::mem;
 use std::mem::size_of;
 use std::path;
 use std::path::PathBuf;
@@ -33,17 +38,22 @@ use opener::open_browser;
 use rand::random;
 use rand::random_range;
 use crate::matrix::Matrix;
+use crate::regex::Captures;
+use crate::regex::Regex;
 use crate::serde_json;
 use crate::toml;
+use crate::autograd::*;
 use crate::env::*;
 use crate::error::*;
 use crate::getopts::*;
 use crate::interp::*;
 use crate::io::*;
 use crate::mod_node::*;
+use crate::npy::*;
 use crate::parser::*;
 #[cfg(feature = "plot")]
 use crate::plot::*;
+use crate::safetensors::*;
 use crate::utils::*;
 use crate::value::*;
 use crate::version::*;
@@ -72,33 +82,44 @@ fn fun2<F>(arg_values: &[Value], f: F) -> Result<Value>
     }
 }
 
-fn fun1_for_f32_and_matrix_with_fun_refs<F, G>(arg_values: &[Value], err_msg: &str, f: &mut F, g: &mut G) -> Result<Value>
-    where F: FnMut(f32) -> f32,
+fn fun1_for_f64_and_matrix_with_fun_refs<F, G>(arg_values: &[Value], err_msg: &str, f: &mut F, g: &mut G) -> Result<Value>
+    where F: FnMut(f64) -> f64,
         G: FnMut(&Matrix) -> Result<Matrix>
 {
     if arg_values.len() != 1 {
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match arg_values.get(0) {
-        Some(value @ (Value::Int(_) | Value::Float(_))) => Ok(Value::Float(f(value.to_f32()))),
+        Some(value @ (Value::Int(_) | Value::Float(_))) => Ok(Value::Float(f(value.to_f64()))),
         Some(Value::Object(object)) => {
             match &**object {
                 Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(g(a)?)))),
+                Object::Tensor(_, _) => arg_values[0].dot1(err_msg, |a| fun1_for_f64_and_matrix_with_fun_refs(&[a.clone()], err_msg, f, g)),
                 _ => Err(Error::Interp(String::from(err_msg))),
             }
         },
-        Some(value) => value.dot1(err_msg, |a| fun1_for_f32_and_matrix_with_fun_refs(&[a.clone()], err_msg, f, g)),
+        Some(value) => value.dot1(err_msg, |a| fun1_for_f64_and_matrix_with_fun_refs(&[a.clone()], err_msg, f, g)),
         None => Err(Error::Interp(String::from("no argument"))),
     }
 }
 
-fn fun1_for_f32_and_matrix<F, G>(arg_values: &[Value], err_msg: &str, mut f: F, mut g: G) -> Result<Value>
-    where F: FnMut(f32) -> f32,
+fn fun1_for_f64_and_matrix<F, G>(arg_values: &[Value], err_msg: &str, mut f: F, mut g: G) -> Result<Value>
+    where F: FnMut(f64) -> f64,
         G: FnMut(&Matrix) -> Result<Matrix>
-{ fun1_for_f32_and_matrix_with_fun_refs(arg_values, err_msg, &mut f, &mut g) }
+{ fun1_for_f64_and_matrix_with_fun_refs(arg_values, err_msg, &mut f, &mut g) }
 
-fn fun2_for_f32_and_matrix_with_fun_refs<F, G, RG, H>(arg_values: &[Value], err_msg: &str, f: &mut F, g: &mut G, rg: &mut RG, h: &mut H) -> Result<Value>
-    where F: FnMut(f32, f32) -> f32,
+fn fun1_for_f64_and_matrix_and_tape_fun<F, G>(arg_values: &[Value], err_msg: &str, tape_fun: TapeFun, f: F, g: G) -> Result<Value>
+    where F: FnMut(f64) -> f64,
+        G: FnMut(&Matrix) -> Result<Matrix>
+{
+    match arg_values.get(0) {
+        Some(value) if arg_values.len() == 1 && value.is_tracked() => tracked_fun(tape_fun, value),
+        _ => fun1_for_f64_and_matrix(arg_values, err_msg, f, g),
+    }
+}
+
+fn fun2_for_f64_and_matrix_with_fun_refs<F, G, RG, H>(arg_values: &[Value], err_msg: &str, f: &mut F, g: &mut G, rg: &mut RG, h: &mut H) -> Result<Value>
+    where F: FnMut(f64, f64) -> f64,
         G: FnMut(&Matrix, f32) -> Result<Matrix>,
         RG: FnMut(&Matrix, f32) -> Result<Matrix>,
         H: FnMut(&Matrix, &Matrix) -> Result<Matrix>
@@ -107,38 +128,41 @@ fn fun2_for_f32_and_matrix_with_fun_refs<F, G, RG, H>(arg_values: &[Value], err_
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match (arg_values.get(0), arg_values.get(1)) {
-        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(f(value.to_f32(), value2.to_f32()))),
+        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(f(value.to_f64(), value2.to_f64()))),
         (Some(Value::Object(object)), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => {
             match &**object {
                 Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(g(a, value2.to_f32())?)))),
+                Object::Tensor(_, _) => arg_values[0].dot2(value2, err_msg, |a, b| fun2_for_f64_and_matrix_with_fun_refs(&[a.clone(), b.clone()], err_msg, f, g, rg, h)),
                 _ => Err(Error::Interp(String::from(err_msg))),
             }
         },
         (Some(value @ (Value::Int(_) | Value::Float(_))), Some(Value::Object(object2))) => {
             match &**object2 {
                 Object::Matrix(b) => Ok(Value::Object(Arc::new(Object::Matrix(rg(b, value.to_f32())?)))),
+                Object::Tensor(_, _) => value.dot2(&arg_values[1], err_msg, |a, b| fun2_for_f64_and_matrix_with_fun_refs(&[a.clone(), b.clone()], err_msg, f, g, rg, h)),
                 _ => Err(Error::Interp(String::from(err_msg))),
             }
         },
         (Some(Value::Object(object)), Some(Value::Object(object2))) => {
             match (&**object, &**object2) {
                 (Object::Matrix(a), Object::Matrix(b)) => Ok(Value::Object(Arc::new(Object::Matrix(h(a, b)?)))),
+                (Object::Tensor(_, _), Object::Tensor(_, _)) => arg_values[0].dot2(&arg_values[1], err_msg, |a, b| fun2_for_f64_and_matrix_with_fun_refs(&[a.clone(), b.clone()], err_msg, f, g, rg, h)),
                 _ => Err(Error::Interp(String::from(err_msg))),
             }
         },
-        (Some(value @ Value::Ref(_)), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => value.dot1(err_msg, |a| fun2_for_f32_and_matrix_with_fun_refs(&[a.clone(), value2.clone()], err_msg, f, g, rg, h)),
-        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ Value::Ref(_))) => value2.dot1(err_msg, |b| fun2_for_f32_and_matrix_with_fun_refs(&[value.clone(), b.clone()], err_msg, f, g, rg, h)),
-        (Some(value), Some(value2)) => value.dot2(value2, err_msg, |a, b| fun2_for_f32_and_matrix_with_fun_refs(&[a.clone(), b.clone()], err_msg, f, g, rg, h)),
+        (Some(value @ Value::Ref(_)), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => value.dot1(err_msg, |a| fun2_for_f64_and_matrix_with_fun_refs(&[a.clone(), value2.clone()], err_msg, f, g, rg, h)),
+        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ Value::Ref(_))) => value2.dot1(err_msg, |b| fun2_for_f64_and_matrix_with_fun_refs(&[value.clone(), b.clone()], err_msg, f, g, rg, h)),
+        (Some(value), Some(value2)) => value.dot2(value2, err_msg, |a, b| fun2_for_f64_and_matrix_with_fun_refs(&[a.clone(), b.clone()], err_msg, f, g, rg, h)),
         (_, _) => Err(Error::Interp(String::from("no argument"))),
     }
 }
 
-fn fun2_for_f32_and_matrix<F, G, RG, H>(arg_values: &[Value], err_msg: &str, mut f: F, mut g: G, mut rg: RG, mut h: H) -> Result<Value>
-    where F: FnMut(f32, f32) -> f32,
+fn fun2_for_f64_and_matrix<F, G, RG, H>(arg_values: &[Value], err_msg: &str, mut f: F, mut g: G, mut rg: RG, mut h: H) -> Result<Value>
+    where F: FnMut(f64, f64) -> f64,
         G: FnMut(&Matrix, f32) -> Result<Matrix>,
         RG: FnMut(&Matrix, f32) -> Result<Matrix>,
         H: FnMut(&Matrix, &Matrix) -> Result<Matrix>
-{ fun2_for_f32_and_matrix_with_fun_refs(arg_values, err_msg, &mut f, &mut g, &mut rg, &mut h) }
+{ fun2_for_f64_and_matrix_with_fun_refs(arg_values, err_msg, &mut f, &mut g, &mut rg, &mut h) }
 
 fn get_first_arg_string(arg_values: &[Value], err_msg: &str) -> Result<String>
 {
@@ -154,7 +178,7 @@ fn get_first_arg_string(arg_values: &[Value], err_msg: &str) -> Result<String>
 }
 
 /// A `type` built-in function.
-pub fn typ(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+pub fn typ(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
     if arg_values.len() != 1 {
         return Err(Error::Interp(String::from("invalid number of arguments")));
@@ -170,11 +194,18 @@ pub fn typ(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
                 Object::IntRange(_, _, _) => Ok(Value::Object(Arc::new(Object::String(String::from("intrange"))))),
                 Object::FloatRange(_, _, _) => Ok(Value::Object(Arc::new(Object::String(String::from("floatrange"))))),
                 Object::Matrix(_) => Ok(Value::Object(Arc::new(Object::String(String::from("matrix"))))),
-                Object::Fun(_, _, _) | Object::BuiltinFun(_, _) => Ok(Value::Object(Arc::new(Object::String(String::from("function"))))),
+                Object::Tensor(_, _) => Ok(Value::Object(Arc::new(Object::String(String::from("tensor"))))),
+                Object::HostMatrix(_, _, HostElems::Double(_)) => Ok(Value::Object(Arc::new(Object::String(String::from("doublematrix"))))),
+                Object::HostMatrix(_, _, HostElems::Int(_)) => Ok(Value::Object(Arc::new(Object::String(String::from("intmatrix"))))),
+                Object::Fun(_, _, _) | Object::BuiltinFun(_, _) | Object::Lambda(_, _, _) => Ok(Value::Object(Arc::new(Object::String(String::from("function"))))),
                 Object::MatrixArray(_, _, _, _) => Ok(Value::Object(Arc::new(Object::String(String::from("matrixarray"))))),
                 Object::MatrixRowSlice(_, _) => Ok(Value::Object(Arc::new(Object::String(String::from("matrixrowslice"))))),
                 Object::Error(_, _) => Ok(Value::Object(Arc::new(Object::String(String::from("error"))))),
+                Object::Regex(_) => Ok(Value::Object(Arc::new(Object::String(String::from("regex"))))),
+                Object::ValueReader(_) => Ok(Value::Object(Arc::new(Object::String(String::from("valuereader"))))),
+                Object::ValueWriter(_) => Ok(Value::Object(Arc::new(Object::String(String::from("valuewriter"))))),
                 Object::WindowId(_) => Ok(Value::Object(Arc::new(Object::String(String::from("windowid"))))),
+                Object::Tracked(_, _) => typ(interp, env, &[untracked_value(&arg_values[0])?]),
             }
         },
         Some(Value::Ref(object)) => {
@@ -182,6 +213,7 @@ pub fn typ(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
             match &*object_g {
                 MutObject::Array(_) => Ok(Value::Object(Arc::new(Object::String(String::from("array"))))),
                 MutObject::Struct(_) => Ok(Value::Object(Arc::new(Object::String(String::from("struct"))))),
+                MutObject::Dict(_) => Ok(Value::Object(Arc::new(Object::String(String::from("dict"))))),
             }
         },
         Some(Value::Weak(_)) => Ok(Value::Object(Arc::new(Object::String(String::from("weak"))))),
@@ -215,7 +247,7 @@ pub fn int(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
 
 /// A `float` built-in function.
 pub fn float(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1(arg_values, |a| Ok(Value::Float(a.to_f32()))) }
+{ fun1(arg_values, |a| Ok(Value::Float(a.to_f64()))) }
 
 /// A `string` built-in function.
 pub fn string(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
@@ -382,15 +414,18 @@ fn to_row_or_column(value: &Value) -> Result<Vec<f32>>
 }
 
 /// A `matrix` built-in function.
-pub fn matrix(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+pub fn matrix(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
     if arg_values.len() != 1 {
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     let value = match arg_values.get(0) {
+        Some(tmp_value) if tmp_value.is_tracked() => return tracked_reshape(tmp_value, |v| matrix(interp, env, &[v.clone()])),
         Some(tmp_value @ Value::Object(object)) => {
             match &**object {
                 Object::Matrix(_) => return Ok(tmp_value.clone()),
+                Object::Tensor(_, a) => return Ok(Value::Object(Arc::new(Object::Matrix(a.clone())))),
+                Object::HostMatrix(row_count, col_count, elems) => return Ok(Value::Object(Arc::new(Object::Matrix(matrix_create_and_set_elems(*row_count, *col_count, elems.to_f32s().as_slice())?)))),
                 _ => tmp_value,
             }
         },
@@ -483,6 +518,124 @@ pub fn colvector(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) ->
 pub fn matrixarray(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 { fun1(arg_values, Value::to_matrix_array) }
 
+fn to_row_values(value: &Value) -> Result<Vec<Value>>
+{
+    match value.iter()? {
+        Some(mut iter) => {
+            let mut elems: Vec<Value> = Vec::new();
+            loop {
+                match iter.next() {
+                    Some(Ok(elem)) => elems.push(elem),
+                    Some(Err(err)) => return Err(err),
+                    None => break,
+                }
+            }
+            Ok(elems)
+        },
+        None => Err(Error::Interp(String::from("value isn't iterable"))),
+    }
+}
+
+fn host_matrix_from_matrix_array(matrix_array: &Object, elem_type: ElemType) -> Result<Value>
+{
+    match matrix_array {
+        Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+            let mut ys: Vec<f32> = Vec::with_capacity(xs.len());
+            for i in 0..(*row_count) {
+                for j in 0..(*col_count) {
+                    let k = match transpose_flag {
+                        TransposeFlag::NoTranspose => i * (*col_count) + j,
+                        TransposeFlag::Transpose => j * (*row_count) + i,
+                    };
+                    ys.push(xs[k]);
+                }
+            }
+            Ok(Value::Object(Arc::new(Object::HostMatrix(*row_count, *col_count, HostElems::from_f32s(ys.as_slice(), elem_type)?))))
+        },
+        _ => Err(Error::Interp(String::from("invalid matrix array type"))),
+    }
+}
+
+fn host_matrix(arg_values: &[Value], elem_type: ElemType) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let value = match arg_values.get(0) {
+        Some(tmp_value @ Value::Object(object)) => {
+            match &**object {
+                Object::HostMatrix(row_count, col_count, elems) => return Ok(Value::Object(Arc::new(Object::HostMatrix(*row_count, *col_count, elems.to_elem_type(elem_type)?)))),
+                Object::Matrix(_) => {
+                    match tmp_value.to_matrix_array()? {
+                        Value::Object(matrix_array) => return host_matrix_from_matrix_array(&*matrix_array, elem_type),
+                        _ => return Err(Error::Interp(String::from("invalid matrix array type"))),
+                    }
+                },
+                Object::MatrixArray(_, _, _, _) => return host_matrix_from_matrix_array(&**object, elem_type),
+                _ => tmp_value,
+            }
+        },
+        Some(tmp_value) => tmp_value,
+        None => return Err(Error::Interp(String::from("no argument"))),
+    };
+    match value.iter()? {
+        Some(mut iter) => {
+            let mut elems: Vec<Value> = Vec::new();
+            let mut row_count = 0usize;
+            let mut col_count: Option<usize> = None;
+            loop {
+                match iter.next() {
+                    Some(Ok(row_value)) => {
+                        let row_elems = to_row_values(&row_value)?;
+                        if col_count.map(|n| n == row_elems.len()).unwrap_or(true) {
+                            col_count = Some(row_elems.len());
+                            elems.extend(row_elems);
+                        } else {
+                            return Err(Error::Interp(String::from("numbers of columns of matrix rows aren't equal")));
+                        }
+                        match row_count.checked_add(1) {
+                            Some(new_row_count) => row_count = new_row_count,
+                            None => return Err(Error::Interp(String::from("too many matrix rows"))),
+                        }
+                    },
+                    Some(Err(err)) => return Err(err),
+                    None => break,
+                }
+            }
+            let host_elems = HostElems::from_values(elems.as_slice(), elem_type)?;
+            Ok(Value::Object(Arc::new(Object::HostMatrix(row_count, col_count.unwrap_or(0), host_elems))))
+        },
+        None => Err(Error::Interp(String::from("value isn't iterable"))),
+    }
+}
+
+/// A `doublematrix` built-in function.
+pub fn doublematrix(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ host_matrix(arg_values, ElemType::Double) }
+
+/// An `intmatrix` built-in function.
+pub fn intmatrix(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ host_matrix(arg_values, ElemType::Int) }
+
+/// An `elemtype` built-in function.
+pub fn elemtype(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::Matrix(_) | Object::Tensor(_, _) | Object::MatrixArray(_, _, _, _) | Object::MatrixRowSlice(_, _) => Ok(Value::Object(Arc::new(Object::String(String::from(ElemType::Float.name()))))),
+                Object::HostMatrix(_, _, elems) => Ok(Value::Object(Arc::new(Object::String(String::from(elems.elem_type().name()))))),
+                _ => Err(Error::Interp(String::from("unsupported type for function elemtype"))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function elemtype"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
 /// An `error` built-in function.
 pub fn error(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -623,6 +776,7 @@ pub fn length(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
             let object_g = rw_lock_read(object)?;
             match &*object_g {
                 MutObject::Array(elems) => Ok(Value::Int(elems.len() as i64)),
+                MutObject::Dict(pairs) => Ok(Value::Int(pairs.len() as i64)),
                 _ => Err(Error::Interp(String::from("unsupported type for function length"))),
             }
         },
@@ -642,6 +796,7 @@ pub fn rows(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Resul
             match &**object {
                 Object::Matrix(a) => Ok(Value::Int(a.row_count() as i64)),
                 Object::MatrixArray(row_count, _, _, _) => Ok(Value::Int(*row_count as i64)),
+                Object::HostMatrix(row_count, _, _) => Ok(Value::Int(*row_count as i64)),
                 _ => Err(Error::Interp(String::from("unsupported type for function rows"))),
             }
         },
@@ -661,6 +816,7 @@ pub fn columns(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Re
             match &**object {
                 Object::Matrix(a) => Ok(Value::Int(a.col_count() as i64)),
                 Object::MatrixArray(_, col_count, _, _) => Ok(Value::Int(*col_count as i64)),
+                Object::HostMatrix(_, col_count, _) => Ok(Value::Int(*col_count as i64)),
                 Object::MatrixRowSlice(matrix_array, _) => {
                     match &**matrix_array {
                         Object::MatrixArray(_, col_count, _, _) => Ok(Value::Int(*col_count as i64)),
@@ -716,7 +872,7 @@ pub fn get(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
                                 TransposeFlag::NoTranspose => i * (*col_count) + ((j - 1) as usize),
                                 TransposeFlag::Transpose => ((j - 1) as usize) * (*row_count) + i,
                             };
-                            Ok(xs.get(k).map(|x| Value::Float(*x)).unwrap_or(Value::None))
+                            Ok(xs.get(k).map(|x| Value::Float(*x as f64)).unwrap_or(Value::None))
                         },
                         _ => Err(Error::Interp(String::from("invalid matrix array type"))),
                     }
@@ -724,7 +880,7 @@ pub fn get(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
                 _ => Err(Error::Interp(String::from("unsupported types for function get"))),
             }
         },
-        (Some(Value::Ref(object)), Some(i_value @ (Value::Int(_) | Value::Float(_) | Value::Object(_))), None)  => {
+        (Some(Value::Ref(object)), Some(i_value @ (Value::Bool(_) | Value::Int(_) | Value::Float(_) | Value::Object(_))), None)  => {
             let object_g = rw_lock_read(&**object)?;
             match &*object_g {
                 MutObject::Array(elems) => {
@@ -750,6 +906,7 @@ pub fn get(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
                         _ => Err(Error::Interp(String::from("unsupported types for function get"))),
                     }
                 },
+                MutObject::Dict(pairs) => Ok(pairs.get(&DictKey::from_value(i_value)?).map(|x| x.clone()).unwrap_or(Value::None)),
             }
         },
         (Some(Value::Object(object)), Some(i_value @ (Value::Int(_) | Value::Float(_))), Some(j_value @ (Value::Int(_) | Value::Float(_))))  => {
@@ -767,7 +924,7 @@ pub fn get(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result
                         TransposeFlag::NoTranspose => ((i - 1) as usize) * (*col_count) + ((j - 1) as usize),
                         TransposeFlag::Transpose => ((j - 1) as usize) * (*row_count) + ((i - 1) as usize),
                     };
-                    Ok(xs.get(k).map(|x| Value::Float(*x)).unwrap_or(Value::None))
+                    Ok(xs.get(k).map(|x| Value::Float(*x as f64)).unwrap_or(Value::None))
                 },
                 _ => Err(Error::Interp(String::from("unsupported types for function get"))),
             }
@@ -795,7 +952,7 @@ pub fn getdiag(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Re
                         return Ok(Value::None);
                     }
                     let k = ((i - 1) as usize) * (*col_count) + ((i - 1) as usize);
-                    Ok(xs.get(k).map(|x| Value::Float(*x)).unwrap_or(Value::None))
+                    Ok(xs.get(k).map(|x| Value::Float(*x as f64)).unwrap_or(Value::None))
                 },
                 _ => Err(Error::Interp(String::from("unsupported type for function getdiag"))),
             }
@@ -964,6 +1121,201 @@ pub fn lower(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Resu
     }
 }
 
+/// A `regex` built-in function.
+pub fn regex(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(value @ Value::Object(object)) => {
+            match &**object {
+                Object::String(_) | Object::Regex(_) => {
+                    match regex_for_value(value) {
+                        Some(Ok(regex)) => Ok(Value::Object(Arc::new(Object::Regex(regex)))),
+                        Some(Err(msg)) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                        None => Err(Error::Interp(String::from("unsupported type for function regex"))),
+                    }
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for function regex"))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function regex"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+fn regex_for_value(value: &Value) -> Option<std::result::Result<Regex, String>>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Regex(regex) => Some(Ok(regex.clone())),
+                Object::String(pattern) => {
+                    match Regex::new(pattern.as_str()) {
+                        Ok(regex) => Some(Ok(regex)),
+                        Err(err) => Some(Err(format!("{}", err))),
+                    }
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn regex_captures_to_value(s: &str, regex: &Regex, caps: &Captures<'_>) -> Value
+{
+    let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+    match caps.get(0) {
+        Some(m) => {
+            fields.insert(String::from("text"), Value::Object(Arc::new(Object::String(String::from(m.as_str())))));
+            fields.insert(String::from("start"), Value::Int((s[..m.start()].chars().count() + 1) as i64));
+            fields.insert(String::from("end"), Value::Int(s[..m.end()].chars().count() as i64));
+        },
+        None => (),
+    }
+    let groups: Vec<Value> = caps.iter().skip(1).map(|m| {
+            match m {
+                Some(m) => Value::Object(Arc::new(Object::String(String::from(m.as_str())))),
+                None => Value::None,
+            }
+    }).collect();
+    fields.insert(String::from("groups"), Value::Ref(Arc::new(RwLock::new(MutObject::Array(groups)))));
+    let mut named_groups: BTreeMap<String, Value> = BTreeMap::new();
+    for name in regex.capture_names() {
+        match name {
+            Some(name) => {
+                let value = match caps.name(name) {
+                    Some(m) => Value::Object(Arc::new(Object::String(String::from(m.as_str())))),
+                    None => Value::None,
+                };
+                named_groups.insert(String::from(name), value);
+            },
+            None => (),
+        }
+    }
+    fields.insert(String::from("names"), Value::Ref(Arc::new(RwLock::new(MutObject::Struct(named_groups)))));
+    Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))))
+}
+
+/// A `rematch` built-in function.
+pub fn rematch(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Object(object)), Some(regex_value)) => {
+            match (&**object, regex_for_value(regex_value)) {
+                (Object::String(s), Some(Ok(regex))) => Ok(Value::Bool(regex.is_match(s.as_str()))),
+                (Object::String(_), Some(Err(msg))) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                (_, _) => Err(Error::Interp(String::from("unsupported types for function rematch"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function rematch"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `refind` built-in function.
+pub fn refind(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Object(object)), Some(regex_value)) => {
+            match (&**object, regex_for_value(regex_value)) {
+                (Object::String(s), Some(Ok(regex))) => {
+                    match regex.captures(s.as_str()) {
+                        Some(caps) => Ok(regex_captures_to_value(s.as_str(), &regex, &caps)),
+                        None => Ok(Value::None),
+                    }
+                },
+                (Object::String(_), Some(Err(msg))) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                (_, _) => Err(Error::Interp(String::from("unsupported types for function refind"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function refind"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `refindall` built-in function.
+pub fn refindall(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Object(object)), Some(regex_value)) => {
+            match (&**object, regex_for_value(regex_value)) {
+                (Object::String(s), Some(Ok(regex))) => {
+                    let elems: Vec<Value> = regex.captures_iter(s.as_str()).map(|caps| regex_captures_to_value(s.as_str(), &regex, &caps)).collect();
+                    Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))))
+                },
+                (Object::String(_), Some(Err(msg))) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                (_, _) => Err(Error::Interp(String::from("unsupported types for function refindall"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function refindall"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `rereplace` built-in function.
+pub fn rereplace(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 3 || arg_values.len() > 4 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let limit = match arg_values.get(3) {
+        Some(n_value @ (Value::Int(_) | Value::Float(_))) => {
+            let n = n_value.to_i64();
+            if n < 0 {
+                return Err(Error::Interp(String::from("number of replacements is negative")));
+            }
+            n as usize
+        },
+        Some(_) => return Err(Error::Interp(String::from("unsupported types for function rereplace"))),
+        None => 0,
+    };
+    match (arg_values.get(0), arg_values.get(1), arg_values.get(2)) {
+        (Some(Value::Object(object)), Some(regex_value), Some(Value::Object(object3))) => {
+            match (&**object, regex_for_value(regex_value), &**object3) {
+                (Object::String(s), Some(Ok(regex)), Object::String(u)) => Ok(Value::Object(Arc::new(Object::String(regex.replacen(s.as_str(), limit, u.as_str()).into_owned())))),
+                (Object::String(_), Some(Err(msg)), Object::String(_)) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                (_, _, _) => Err(Error::Interp(String::from("unsupported types for function rereplace"))),
+            }
+        },
+        (Some(_), Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function rereplace"))),
+        (_, _, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `resplit` built-in function.
+pub fn resplit(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Object(object)), Some(regex_value)) => {
+            match (&**object, regex_for_value(regex_value)) {
+                (Object::String(s), Some(Ok(regex))) => {
+                    let elems: Vec<Value> = regex.split(s.as_str()).map(|t| Value::Object(Arc::new(Object::String(String::from(t))))).collect();
+                    Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))))
+                },
+                (Object::String(_), Some(Err(msg))) => Ok(Value::Object(Arc::new(Object::Error(String::from("regex"), msg)))),
+                (_, _) => Err(Error::Interp(String::from("unsupported types for function resplit"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function resplit"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 enum SortType
 {
@@ -973,42 +1325,138 @@ enum SortType
     Incomparable,
 }
 
+fn check_sort_values(values: &[Value]) -> Result<()>
+{
+    let mut sort_type: Option<SortType> = None;
+    for value in values {
+        let new_sort_type = match value {
+            Value::Bool(_) => SortType::Bool,
+            Value::Int(_) => SortType::Number,
+            Value::Float(n) => if !n.is_nan() { SortType::Number } else { SortType::Incomparable },
+            Value::Object(object) => {
+                match &**object {
+                    Object::String(_) => SortType::String,
+                    _ => SortType::Incomparable,
+                }
+            },
+            _ => SortType::Incomparable,
+        };
+        if sort_type.map(|t| t == new_sort_type).unwrap_or(true) {
+            sort_type = Some(new_sort_type);
+        } else {
+            return Err(Error::Interp(String::from("array has elements which can't be compared")));
+        }
+    }
+    match sort_type {
+        Some(SortType::Incomparable) => Err(Error::Interp(String::from("array has incomparable elements"))),
+        _ => Ok(()),
+    }
+}
+
+fn stable_sort_by<T: Clone, F>(xs: &mut Vec<T>, mut f: F) -> Result<()>
+    where F: FnMut(&T, &T) -> Result<Ordering>
+{
+    let len = xs.len();
+    let mut ys: Vec<T> = Vec::with_capacity(len);
+    let mut width = 1usize;
+    while width < len {
+        ys.clear();
+        let mut start = 0usize;
+        while start < len {
+            let middle = cmp::min(start + width, len);
+            let end = cmp::min(start + width * 2, len);
+            let mut i = start;
+            let mut j = middle;
+            while i < middle && j < end {
+                if f(&xs[j], &xs[i])? == Ordering::Less {
+                    ys.push(xs[j].clone());
+                    j += 1;
+                } else {
+                    ys.push(xs[i].clone());
+                    i += 1;
+                }
+            }
+            ys.extend_from_slice(&xs[i..middle]);
+            ys.extend_from_slice(&xs[j..end]);
+            start = end;
+        }
+        mem::swap(xs, &mut ys);
+        width *= 2;
+    }
+    Ok(())
+}
+
+fn sorted_idxs(interp: &mut Interp, env: &mut Env, elems: &[Value], arg_values: &[Value], fun_name: &str) -> Result<Vec<usize>>
+{
+    let mut idxs: Vec<usize> = (0..elems.len()).collect();
+    match (arg_values.get(0), arg_values.get(1), arg_values.get(2)) {
+        (None, None, None) => {
+            check_sort_values(elems)?;
+            stable_sort_by(&mut idxs, |i, j| Ok(elems[*i].partial_cmp(&elems[*j]).unwrap_or(Ordering::Equal)))?;
+        },
+        (Some(data_value), Some(fun_value), kind_value) => {
+            let is_cmp = match kind_value {
+                Some(Value::Object(kind_object)) => {
+                    match &**kind_object {
+                        Object::String(kind) if kind == &String::from("key") => false,
+                        Object::String(kind) if kind == &String::from("cmp") => true,
+                        Object::String(_) => return Err(Error::Interp(String::from("invalid sort kind"))),
+                        _ => return Err(Error::Interp(format!("unsupported types for function {}", fun_name))),
+                    }
+                },
+                Some(_) => return Err(Error::Interp(format!("unsupported types for function {}", fun_name))),
+                None => false,
+            };
+            if is_cmp {
+                stable_sort_by(&mut idxs, |i, j| {
+                        match fun_value.apply(interp, env, &[data_value.clone(), elems[*i].clone(), elems[*j].clone()])? {
+                            value @ (Value::Int(_) | Value::Float(_)) => {
+                                let x = value.to_f64();
+                                if x < 0.0 {
+                                    Ok(Ordering::Less)
+                                } else if x > 0.0 {
+                                    Ok(Ordering::Greater)
+                                } else {
+                                    Ok(Ordering::Equal)
+                                }
+                            },
+                            _ => Err(Error::Interp(String::from("comparator doesn't return number"))),
+                        }
+                })?;
+            } else {
+                let mut keys: Vec<Value> = Vec::with_capacity(elems.len());
+                for elem in elems {
+                    keys.push(fun_value.apply(interp, env, &[data_value.clone(), elem.clone()])?);
+                }
+                check_sort_values(keys.as_slice())?;
+                stable_sort_by(&mut idxs, |i, j| Ok(keys[*i].partial_cmp(&keys[*j]).unwrap_or(Ordering::Equal)))?;
+            }
+        },
+        (_, _, _) => return Err(Error::Interp(String::from("invalid number of arguments"))),
+    }
+    Ok(idxs)
+}
+
 /// A `sort` built-in function.
-pub fn sort(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+pub fn sort(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
-    if arg_values.len() != 1 {
+    if arg_values.len() != 1 && arg_values.len() != 3 && arg_values.len() != 4 {
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match arg_values.get(0) {
         Some(Value::Ref(object)) => {
+            let elems = {
+                let object_g = rw_lock_read(object)?;
+                match &*object_g {
+                    MutObject::Array(elems) => elems.clone(),
+                    _ => return Err(Error::Interp(String::from("unsupported type for function sort"))),
+                }
+            };
+            let idxs = sorted_idxs(interp, env, elems.as_slice(), &arg_values[1..], "sort")?;
             let mut object_g = rw_lock_write(object)?;
             match &mut *object_g {
-                MutObject::Array(elems) => {
-                    let mut sort_type: Option<SortType> = None;
-                    for elem in &*elems {
-                        let new_sort_type = match elem {
-                            Value::Bool(_) => SortType::Bool,
-                            Value::Int(_) => SortType::Number,
-                            Value::Float(n) => if !n.is_nan() { SortType::Number } else { SortType::Incomparable },
-                            Value::Object(elem_object) => {
-                                match &**elem_object {
-                                    Object::String(_) => SortType::String,
-                                    _ => SortType::Incomparable,
-                                }
-                            },
-                            _ => SortType::Incomparable,
-                       };
-                       if sort_type.map(|t| t == new_sort_type).unwrap_or(true) {
-                           sort_type = Some(new_sort_type);
-                       } else {
-                           return Err(Error::Interp(String::from("array has elements which can't be compared")));
-                       }
-                    }
-                    match sort_type {
-                        Some(SortType::Incomparable) => return Err(Error::Interp(String::from("array has incomparable elements"))),
-                        _ => (),
-                    }
-                    elems.sort_by(|x, y| x.partial_cmp(y).unwrap());
+                MutObject::Array(elems2) => {
+                    *elems2 = idxs.iter().map(|i| elems[*i].clone()).collect();
                     Ok(Value::None)
                 },
                 _ => Err(Error::Interp(String::from("unsupported type for function sort"))),
@@ -1019,6 +1467,76 @@ pub fn sort(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Resul
     }
 }
 
+/// A `sorted` built-in function.
+pub fn sorted(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 && arg_values.len() != 3 && arg_values.len() != 4 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(value) => {
+            let elems = to_row_values(value)?;
+            let idxs = sorted_idxs(interp, env, elems.as_slice(), &arg_values[1..], "sorted")?;
+            let new_elems: Vec<Value> = idxs.iter().map(|i| elems[*i].clone()).collect();
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(new_elems)))))
+        },
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// An `argsort` built-in function.
+pub fn argsort(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 && arg_values.len() != 3 && arg_values.len() != 4 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(value @ Value::Object(object)) => {
+            match &**object {
+                Object::Matrix(_) | Object::MatrixArray(_, _, _, _) => {
+                    match value.to_matrix_array()? {
+                        Value::Object(matrix_array) => {
+                            match &*matrix_array {
+                                Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+                                    let mut ys: Vec<f32> = Vec::with_capacity(xs.len());
+                                    for i in 0..(*row_count) {
+                                        let mut row_elems: Vec<Value> = Vec::with_capacity(*col_count);
+                                        for j in 0..(*col_count) {
+                                            let k = match transpose_flag {
+                                                TransposeFlag::NoTranspose => i * (*col_count) + j,
+                                                TransposeFlag::Transpose => j * (*row_count) + i,
+                                            };
+                                            row_elems.push(Value::Float(xs[k] as f64));
+                                        }
+                                        let idxs = sorted_idxs(interp, env, row_elems.as_slice(), &arg_values[1..], "argsort")?;
+                                        ys.extend(idxs.iter().map(|k| (k + 1) as f32));
+                                    }
+                                    Ok(Value::Object(Arc::new(Object::Matrix(matrix_create_and_set_elems(*row_count, *col_count, ys.as_slice())?))))
+                                },
+                                _ => Err(Error::Interp(String::from("invalid matrix array type"))),
+                            }
+                        },
+                        _ => Err(Error::Interp(String::from("invalid matrix array type"))),
+                    }
+                },
+                _ => {
+                    let elems = to_row_values(value)?;
+                    let idxs = sorted_idxs(interp, env, elems.as_slice(), &arg_values[1..], "argsort")?;
+                    let new_elems: Vec<Value> = idxs.iter().map(|i| Value::Int((i + 1) as i64)).collect();
+                    Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(new_elems)))))
+                },
+            }
+        },
+        Some(value) => {
+            let elems = to_row_values(value)?;
+            let idxs = sorted_idxs(interp, env, elems.as_slice(), &arg_values[1..], "argsort")?;
+            let new_elems: Vec<Value> = idxs.iter().map(|i| Value::Int((i + 1) as i64)).collect();
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(new_elems)))))
+        },
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
 /// A `reverse` built-in function.
 pub fn reverse(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -1205,7 +1723,7 @@ pub fn max(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<V
             }
         },
         (Some(Value::Int(a)), Some(Value::Int(b))) => Ok(Value::Int((*a).max(*b))),
-        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f32().max(value2.to_f32()))),
+        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f64().max(value2.to_f64()))),
         (Some(Value::Object(object)), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => {
             match &**object {
                 Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_max_for_scalar(a, value2.to_f32())?)))),
@@ -1264,7 +1782,7 @@ pub fn min(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<V
             }
         },
         (Some(Value::Int(a)), Some(Value::Int(b))) => Ok(Value::Int((*a).min(*b))),
-        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f32().min(value2.to_f32()))),
+        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f64().min(value2.to_f64()))),
         (Some(Value::Object(object)), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => {
             match &**object {
                 Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_min_for_scalar(a, value2.to_f32())?)))),
@@ -1450,6 +1968,12 @@ pub fn append(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
                         }
                         Ok(Value::None)
                     },
+                    (MutObject::Dict(pairs), MutObject::Dict(pairs2)) => {
+                        for (key2, value2) in pairs2 {
+                            pairs.insert(key2.clone(), value2.clone());
+                        }
+                        Ok(Value::None)
+                    },
                     (_, _) => Err(Error::Interp(String::from("unsupported types for function append"))),
                 }
             } else {
@@ -1460,7 +1984,7 @@ pub fn append(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
                         elems.extend_from_slice(elems2.as_slice());
                         Ok(Value::None)
                     },
-                    MutObject::Struct(_) => Ok(Value::None),
+                    MutObject::Struct(_) | MutObject::Dict(_) => Ok(Value::None),
                 }
             }
         },
@@ -1476,7 +2000,7 @@ pub fn insert(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match (arg_values.get(0), arg_values.get(1), arg_values.get(2)) {
-        (Some(Value::Ref(a_object)), Some(i_value @ (Value::Int(_) | Value::Float(_) | Value::Object(_))), Some(value)) => {
+        (Some(Value::Ref(a_object)), Some(i_value @ (Value::Bool(_) | Value::Int(_) | Value::Float(_) | Value::Object(_))), Some(value)) => {
             let mut a_object_g = rw_lock_write(a_object)?;
             match &mut *a_object_g {
                 MutObject::Array(elems) => {
@@ -1503,6 +2027,7 @@ pub fn insert(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
                         _ => Err(Error::Interp(String::from("unsupported types for function insert"))),
                     }
                 },
+                MutObject::Dict(pairs) => Ok(pairs.insert(DictKey::from_value(i_value)?, value.clone()).unwrap_or(Value::None)),
             }
         },
         (Some(_), Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function insert"))),
@@ -1517,7 +2042,7 @@ pub fn remove(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match (arg_values.get(0), arg_values.get(1)) {
-        (Some(Value::Ref(a_object)), Some(i_value @ (Value::Int(_) | Value::Float(_) | Value::Object(_)))) => {
+        (Some(Value::Ref(a_object)), Some(i_value @ (Value::Bool(_) | Value::Int(_) | Value::Float(_) | Value::Object(_)))) => {
             let mut a_object_g = rw_lock_write(a_object)?;
             match &mut *a_object_g {
                 MutObject::Array(elems) => {
@@ -1543,6 +2068,7 @@ pub fn remove(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
                         _ => Err(Error::Interp(String::from("unsupported types for function remove"))),
                     }
                 },
+                MutObject::Dict(pairs) => Ok(pairs.remove(&DictKey::from_value(i_value)?).unwrap_or(Value::None)),
             }
         },
         (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function remove"))),
@@ -1550,6 +2076,108 @@ pub fn remove(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
     }
 }
 
+/// A `keys` built-in function.
+pub fn keys(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(object)?;
+            let elems = match &*object_g {
+                MutObject::Struct(fields) => fields.keys().map(|ident| Value::Object(Arc::new(Object::String(ident.clone())))).collect::<Vec<Value>>(),
+                MutObject::Dict(pairs) => pairs.keys().map(|key| key.to_value()).collect::<Vec<Value>>(),
+                _ => return Err(Error::Interp(String::from("unsupported type for function keys"))),
+            };
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))))
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function keys"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `values` built-in function.
+pub fn values(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(object)?;
+            let elems = match &*object_g {
+                MutObject::Struct(fields) => fields.values().map(|field| field.clone()).collect::<Vec<Value>>(),
+                MutObject::Dict(pairs) => pairs.values().map(|value| value.clone()).collect::<Vec<Value>>(),
+                _ => return Err(Error::Interp(String::from("unsupported type for function values"))),
+            };
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))))
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function values"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `haskey` built-in function.
+pub fn haskey(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Ref(object)), Some(key_value)) => {
+            let object_g = rw_lock_read(object)?;
+            match &*object_g {
+                MutObject::Struct(fields) => {
+                    match key_value {
+                        Value::Object(key_object) => {
+                            match &**key_object {
+                                Object::String(ident) => Ok(Value::Bool(fields.contains_key(ident))),
+                                _ => Ok(Value::Bool(false)),
+                            }
+                        },
+                        _ => Ok(Value::Bool(false)),
+                    }
+                },
+                MutObject::Dict(pairs) => Ok(Value::Bool(pairs.contains_key(&DictKey::from_value(key_value)?))),
+                _ => Err(Error::Interp(String::from("unsupported types for function haskey"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function haskey"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `delete` built-in function.
+pub fn delete(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(Value::Ref(object)), Some(key_value)) => {
+            let mut object_g = rw_lock_write(object)?;
+            match &mut *object_g {
+                MutObject::Struct(fields) => {
+                    match key_value {
+                        Value::Object(key_object) => {
+                            match &**key_object {
+                                Object::String(ident) => Ok(Value::Bool(fields.remove(ident).is_some())),
+                                _ => Ok(Value::Bool(false)),
+                            }
+                        },
+                        _ => Ok(Value::Bool(false)),
+                    }
+                },
+                MutObject::Dict(pairs) => Ok(Value::Bool(pairs.remove(&DictKey::from_value(key_value)?).is_some())),
+                _ => Err(Error::Interp(String::from("unsupported types for function delete"))),
+            }
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function delete"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
 /// An `errorkind` built-in function.
 pub fn errorkind(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -1612,15 +2240,15 @@ pub fn islessequal(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -
 
 /// A `sigmoid` built-in function.
 pub fn sigmoid(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function sigmoid", |a| 1.0 / (1.0 + (-a).exp()), matrix_sigmoid) }
+{ fun1_for_f64_and_matrix_and_tape_fun(arg_values, "unsupported type for function sigmoid", TapeFun::Sigmoid, |a| 1.0 / (1.0 + (-a).exp()), matrix_sigmoid) }
 
 /// A `tanh` built-in function.
 pub fn tanh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function tanh", f32::tanh, matrix_tanh) }
+{ fun1_for_f64_and_matrix_and_tape_fun(arg_values, "unsupported type for function tanh", TapeFun::Tanh, f64::tanh, matrix_tanh) }
 
 /// A `swish` built-in function.
 pub fn swish(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function swish", |a| a / (1.0 + (-a).exp()), matrix_swish) }
+{ fun1_for_f64_and_matrix_and_tape_fun(arg_values, "unsupported type for function swish", TapeFun::Swish, |a| a / (1.0 + (-a).exp()), matrix_swish) }
 
 /// A `softmax` built-in function.
 pub fn softmax(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
@@ -1629,7 +2257,8 @@ pub fn softmax(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Re
         return Err(Error::Interp(String::from("invalid number of arguments")));
     }
     match arg_values.get(0) {
-        Some(value @ (Value::Int(_) | Value::Float(_))) => Ok(Value::Float(value.to_f32().exp() / value.to_f32().exp())),
+        Some(value) if value.is_tracked() => tracked_fun(TapeFun::Softmax, value),
+        Some(value @ (Value::Int(_) | Value::Float(_))) => Ok(Value::Float(value.to_f64().exp() / value.to_f64().exp())),
         Some(Value::Object(object)) => {
             match &**object {
                 Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_softmax(a)?)))),
@@ -1641,9 +2270,39 @@ pub fn softmax(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Re
     }
 }
 
+/// A `grad` built-in function.
+pub fn grad(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1)) {
+        (Some(fun_value), Some(param_value @ (Value::Int(_) | Value::Float(_) | Value::Object(_)))) => {
+            let mut grad_values = grads(interp, env, fun_value, &[param_value.clone()])?;
+            match grad_values.pop() {
+                Some(grad_value) => Ok(grad_value),
+                None => Err(Error::Interp(String::from("no gradient"))),
+            }
+        },
+        (Some(fun_value), Some(Value::Ref(object))) => {
+            let param_values = {
+                let object_g = rw_lock_read(&**object)?;
+                match &*object_g {
+                    MutObject::Array(elems) => elems.clone(),
+                    _ => return Err(Error::Interp(String::from("unsupported types for function grad"))),
+                }
+            };
+            let grad_values = grads(interp, env, fun_value, param_values.as_slice())?;
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(grad_values)))))
+        },
+        (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function grad"))),
+        (_, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
 /// A `sqrt` built-in function.
 pub fn sqrt(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function sqrt", f32::sqrt, matrix_sqrt) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function sqrt", f64::sqrt, matrix_sqrt) }
 
 /// A `reallytranspose` built-in function.
 pub fn reallytranspose(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
@@ -1696,6 +2355,403 @@ pub fn repeat(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
     }
 }
 
+fn matrices_for_cat(arg_values: &[Value], err_msg: &str) -> Result<Vec<Matrix>>
+{
+    if arg_values.is_empty() {
+        return Err(Error::Interp(String::from("no argument")));
+    }
+    let mut as1: Vec<Matrix> = Vec::new();
+    for arg_value in arg_values {
+        match arg_value {
+            Value::Int(_) | Value::Float(_) => as1.push(matrix_create_and_set_elems(1, 1, &[arg_value.to_f32()])?),
+            Value::Object(object) => {
+                match &**object {
+                    Object::Matrix(a) => as1.push(a.clone()),
+                    _ => return Err(Error::Interp(String::from(err_msg))),
+                }
+            },
+            _ => return Err(Error::Interp(String::from(err_msg))),
+        }
+    }
+    Ok(as1)
+}
+
+/// A `hcat` built-in function.
+pub fn hcat(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    let as1 = matrices_for_cat(arg_values, "unsupported types for function hcat")?;
+    if as1.iter().any(|a| a.row_count() != as1[0].row_count()) {
+        return Err(Error::Interp(String::from("numbers of rows of matrices aren't equal")));
+    }
+    let col_count = as1.iter().fold(0, |n, a| n + a.col_count());
+    checked_mul_row_count_and_col_count(as1[0].row_count() as i64, col_count as i64)?;
+    match matrix_hcat(as1.as_slice())? {
+        Some(b) => Ok(Value::Object(Arc::new(Object::Matrix(b)))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `vcat` built-in function.
+pub fn vcat(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    let as1 = matrices_for_cat(arg_values, "unsupported types for function vcat")?;
+    if as1.iter().any(|a| a.col_count() != as1[0].col_count()) {
+        return Err(Error::Interp(String::from("numbers of columns of matrices aren't equal")));
+    }
+    let row_count = as1.iter().fold(0, |n, a| n + a.row_count());
+    checked_mul_row_count_and_col_count(row_count as i64, as1[0].col_count() as i64)?;
+    match matrix_vcat(as1.as_slice())? {
+        Some(b) => Ok(Value::Object(Arc::new(Object::Matrix(b)))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `reshape` built-in function.
+pub fn reshape(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    match arg_values.get(0) {
+        Some(value) if value.is_tracked() => {
+            let mut arg_values2 = arg_values.to_vec();
+            return tracked_reshape(value, |v| {
+                    arg_values2[0] = v.clone();
+                    reshape(interp, env, arg_values2.as_slice())
+            });
+        },
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::Tensor(_, a) => return tensor_value_for_matrix(a, tensor_shape(&arg_values[1..])?),
+                _ => (),
+            }
+        },
+        _ => (),
+    }
+    if arg_values.len() != 3 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match (arg_values.get(0), arg_values.get(1), arg_values.get(2)) {
+        (Some(Value::Object(object)), Some(n_value @ (Value::Int(_) | Value::Float(_))), Some(m_value @ (Value::Int(_) | Value::Float(_)))) => {
+            match &**object {
+                Object::Matrix(a) => {
+                    let n = n_value.to_i64();
+                    let m = m_value.to_i64();
+                    let len = checked_mul_row_count_and_col_count(n, m)?;
+                    if len != a.row_count() * a.col_count() {
+                        return Err(Error::Interp(String::from("number of elements of matrix isn't equal to product of numbers of rows and columns")));
+                    }
+                    Ok(Value::Object(Arc::new(Object::Matrix(matrix_reshape(a, n as usize, m as usize)?))))
+                },
+                _ => Err(Error::Interp(String::from("unsupported types for function reshape"))),
+            }
+        },
+        (Some(_), Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function reshape"))),
+        (_, _, _) => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+fn tensor_shape(dim_values: &[Value]) -> Result<Vec<usize>>
+{
+    if dim_values.is_empty() {
+        return Err(Error::Interp(String::from("no tensor dimensions")));
+    }
+    let mut shape: Vec<usize> = Vec::new();
+    let mut len = 1i64;
+    for dim_value in dim_values {
+        match dim_value {
+            Value::Int(_) | Value::Float(_) => {
+                let n = dim_value.to_i64();
+                if n < 1 {
+                    return Err(Error::Interp(String::from("tensor dimension isn't positive")));
+                }
+                len = match len.checked_mul(n) {
+                    Some(new_len) => new_len,
+                    None => return Err(Error::Interp(String::from("too large number of tensor elements"))),
+                };
+                shape.push(n as usize);
+            },
+            _ => return Err(Error::Interp(String::from("unsupported type for tensor dimension"))),
+        }
+    }
+    checked_mul_row_count_and_col_count(shape[0] as i64, len / (shape[0] as i64))?;
+    Ok(shape)
+}
+
+fn tensor_value_for_matrix(a: &Matrix, shape: Vec<usize>) -> Result<Value>
+{
+    let col_count = shape[1..].iter().product::<usize>();
+    if shape[0] * col_count != a.row_count() * a.col_count() {
+        return Err(Error::Interp(String::from("number of elements isn't equal to product of tensor dimensions")));
+    }
+    if shape[0] == a.row_count() {
+        Ok(Value::Object(Arc::new(Object::Tensor(shape, a.clone()))))
+    } else {
+        let b = matrix_reshape(a, shape[0], col_count)?;
+        Ok(Value::Object(Arc::new(Object::Tensor(shape, b))))
+    }
+}
+
+/// A `tensor` built-in function.
+pub fn tensor(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    match arg_values.get(0) {
+        Some(value) if value.is_tracked() => {
+            let mut arg_values2 = arg_values.to_vec();
+            tracked_reshape(value, |v| {
+                    arg_values2[0] = v.clone();
+                    tensor(interp, env, arg_values2.as_slice())
+            })
+        },
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::Matrix(a) => {
+                    if arg_values.len() == 1 {
+                        Ok(Value::Object(Arc::new(Object::Tensor(vec![a.row_count(), a.col_count()], a.clone()))))
+                    } else {
+                        tensor_value_for_matrix(a, tensor_shape(&arg_values[1..])?)
+                    }
+                },
+                Object::Tensor(_, a) => {
+                    if arg_values.len() == 1 {
+                        Ok(arg_values[0].clone())
+                    } else {
+                        tensor_value_for_matrix(a, tensor_shape(&arg_values[1..])?)
+                    }
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for function tensor"))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function tensor"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `shape` built-in function.
+pub fn shape(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            let shape = match &**object {
+                Object::Matrix(a) => vec![a.row_count(), a.col_count()],
+                Object::Tensor(shape, _) => shape.clone(),
+                _ => return Err(Error::Interp(String::from("unsupported type for function shape"))),
+            };
+            let elems = shape.iter().map(|n| Value::Int(*n as i64)).collect::<Vec<Value>>();
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))))
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for function shape"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `permute` built-in function.
+pub fn permute(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::Tensor(shape, a) => {
+                    if arg_values.len() != shape.len() + 1 {
+                        return Err(Error::Interp(String::from("invalid number of arguments")));
+                    }
+                    let mut perm: Vec<usize> = Vec::new();
+                    for idx_value in &arg_values[1..] {
+                        match idx_value {
+                            Value::Int(_) | Value::Float(_) => {
+                                let i = idx_value.to_i64();
+                                if i < 1 || i > (shape.len() as i64) || perm.contains(&((i - 1) as usize)) {
+                                    return Err(Error::Interp(String::from("invalid permutation")));
+                                }
+                                perm.push((i - 1) as usize);
+                            },
+                            _ => return Err(Error::Interp(String::from("unsupported types for function permute"))),
+                        }
+                    }
+                    let new_shape = perm.iter().map(|i| shape[*i]).collect::<Vec<usize>>();
+                    let b = matrix_permute(a, shape.as_slice(), perm.as_slice())?;
+                    Ok(Value::Object(Arc::new(Object::Tensor(new_shape, b))))
+                },
+                _ => Err(Error::Interp(String::from("unsupported types for function permute"))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported types for function permute"))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+fn fun1_for_matrix_reduction<F, G>(arg_values: &[Value], err_msg: &str, f: F, g: G) -> Result<Value>
+    where F: FnOnce(&Value) -> Value,
+        G: FnOnce(&Matrix) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    match arg_values.get(0) {
+        Some(value @ (Value::Int(_) | Value::Float(_))) => Ok(f(value)),
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::Matrix(a) => g(a),
+                _ => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from(err_msg))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `sum` built-in function.
+pub fn sum(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ fun1_for_matrix_reduction(arg_values, "unsupported type for function sum", |value| value.clone(), |a| Ok(Value::Float(matrix_sum(a)? as f64))) }
+
+/// A `mean` built-in function.
+pub fn mean(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    fun1_for_matrix_reduction(arg_values, "unsupported type for function mean", |value| Value::Float(value.to_f64()), |a| {
+        if a.row_count() == 0 || a.col_count() == 0 {
+            return Err(Error::Interp(String::from("matrix is empty")));
+        }
+        Ok(Value::Float((matrix_sum(a)? / ((a.row_count() * a.col_count()) as f32)) as f64))
+    })
+}
+
+/// A `rowsum` built-in function.
+pub fn rowsum(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ fun1_for_matrix_reduction(arg_values, "unsupported type for function rowsum", |value| value.clone(), |a| Ok(Value::Object(Arc::new(Object::Matrix(matrix_row_sum(a)?))))) }
+
+/// A `colsum` built-in function.
+pub fn colsum(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ fun1_for_matrix_reduction(arg_values, "unsupported type for function colsum", |value| value.clone(), |a| Ok(Value::Object(Arc::new(Object::Matrix(matrix_col_sum(a)?))))) }
+
+/// A `norm` built-in function.
+pub fn norm(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ fun1_for_matrix_reduction(arg_values, "unsupported type for function norm", |value| Value::Float(value.to_f64().abs()), |a| Ok(Value::Float(matrix_norm(a)? as f64))) }
+
+/// An `argmax` built-in function.
+pub fn argmax(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ fun1_for_matrix_reduction(arg_values, "unsupported type for function argmax", |_| Value::Int(1), |a| Ok(Value::Object(Arc::new(Object::Matrix(matrix_row_argmax(a)?))))) }
+
+fn image_tensor_shape(value: &Value, err_msg: &str) -> Result<Vec<usize>>
+{
+    match untracked_value(value)? {
+        Value::Object(object) => {
+            match &*object {
+                Object::Tensor(shape, _) if shape.len() == 4 => Ok(shape.clone()),
+                Object::Tensor(_, _) => Err(Error::Interp(String::from("tensor doesn't have four dimensions"))),
+                _ => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        _ => Err(Error::Interp(String::from(err_msg))),
+    }
+}
+
+fn usize_arg(arg_value: Option<&Value>, default: usize, min: i64, err_msg: &str) -> Result<usize>
+{
+    match arg_value {
+        Some(value @ (Value::Int(_) | Value::Float(_))) => {
+            let n = value.to_i64();
+            if n < min {
+                return Err(Error::Interp(format!("argument isn't greater than or equal to {}", min)));
+            }
+            Ok(n as usize)
+        },
+        Some(_) => Err(Error::Interp(String::from(err_msg))),
+        None => Ok(default),
+    }
+}
+
+fn conv2d_params(shape: &[usize], kernel_height: usize, kernel_width: usize, stride: usize, padding: usize) -> Result<Conv2dParams>
+{
+    let params = Conv2dParams {
+        channel_count: shape[1],
+        height: shape[2],
+        width: shape[3],
+        kernel_height,
+        kernel_width,
+        stride,
+        padding,
+    };
+    let height = padding.checked_mul(2).and_then(|n| n.checked_add(shape[2]));
+    let width = padding.checked_mul(2).and_then(|n| n.checked_add(shape[3]));
+    match (height, width) {
+        (Some(height), Some(width)) if kernel_height <= height && kernel_width <= width => (),
+        (Some(_), Some(_)) => return Err(Error::Interp(String::from("kernel is larger than image"))),
+        (_, _) => return Err(Error::Interp(String::from("too large padding"))),
+    }
+    Ok(params)
+}
+
+/// An `im2col` built-in function.
+pub fn im2col(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 3 || arg_values.len() > 5 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let err_msg = "unsupported types for function im2col";
+    let kernel_height = usize_arg(arg_values.get(1), 1, 1, err_msg)?;
+    let kernel_width = usize_arg(arg_values.get(2), 1, 1, err_msg)?;
+    let stride = usize_arg(arg_values.get(3), 1, 1, err_msg)?;
+    let padding = usize_arg(arg_values.get(4), 0, 0, err_msg)?;
+    match &arg_values[0] {
+        Value::Object(object) => {
+            match &**object {
+                Object::Tensor(shape, a) if shape.len() == 4 => {
+                    let params = conv2d_params(shape.as_slice(), kernel_height, kernel_width, stride, padding)?;
+                    let row_count = (shape[0] as i64).checked_mul((params.out_height() * params.out_width()) as i64);
+                    let col_count = (shape[1] as i64).checked_mul((kernel_height * kernel_width) as i64);
+                    match (row_count, col_count) {
+                        (Some(row_count), Some(col_count)) => checked_mul_row_count_and_col_count(row_count, col_count)?,
+                        (_, _) => return Err(Error::Interp(String::from("too large matrix"))),
+                    };
+                    Ok(Value::Object(Arc::new(Object::Matrix(matrix_im2col(a, &params)?))))
+                },
+                Object::Tensor(_, _) => Err(Error::Interp(String::from("tensor doesn't have four dimensions"))),
+                _ => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        _ => Err(Error::Interp(String::from(err_msg))),
+    }
+}
+
+/// A `conv2d` built-in function.
+pub fn conv2d(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 2 || arg_values.len() > 4 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let err_msg = "unsupported types for function conv2d";
+    let shape = image_tensor_shape(&arg_values[0], err_msg)?;
+    let kernel_shape = image_tensor_shape(&arg_values[1], err_msg)?;
+    if shape[1] != kernel_shape[1] {
+        return Err(Error::Interp(String::from("numbers of channels of image and kernel aren't equal")));
+    }
+    let stride = usize_arg(arg_values.get(2), 1, 1, err_msg)?;
+    let padding = usize_arg(arg_values.get(3), 0, 0, err_msg)?;
+    let params = conv2d_params(shape.as_slice(), kernel_shape[2], kernel_shape[3], stride, padding)?;
+    tracked_conv2d(&arg_values[0], &arg_values[1], &params)
+}
+
+fn pool2d(arg_values: &[Value], err_msg: &str, pool: TapePool) -> Result<Value>
+{
+    if arg_values.len() < 2 || arg_values.len() > 4 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let shape = image_tensor_shape(&arg_values[0], err_msg)?;
+    let kernel_size = usize_arg(arg_values.get(1), 1, 1, err_msg)?;
+    let stride = usize_arg(arg_values.get(2), kernel_size, 1, err_msg)?;
+    let padding = usize_arg(arg_values.get(3), 0, 0, err_msg)?;
+    let params = conv2d_params(shape.as_slice(), kernel_size, kernel_size, stride, padding)?;
+    tracked_pool2d(pool, &arg_values[0], &params)
+}
+
+/// A `maxpool2d` built-in function.
+pub fn maxpool2d(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ pool2d(arg_values, "unsupported types for function maxpool2d", TapePool::Max) }
+
+/// An `avgpool2d` built-in function.
+pub fn avgpool2d(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{ pool2d(arg_values, "unsupported types for function avgpool2d", TapePool::Avg) }
+
 /// A `mod` built-in function.
 pub fn modulo(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -1715,7 +2771,7 @@ pub fn modulo(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Res
                 },
             }
         },
-        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f32() % value2.to_f32())),
+        (Some(value @ (Value::Int(_) | Value::Float(_))), Some(value2 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Float(value.to_f64() % value2.to_f64())),
         (Some(_), Some(_)) => Err(Error::Interp(String::from("unsupported types for function mod"))),
         (_, _) => Err(Error::Interp(String::from("no argument"))),
     }
@@ -1743,91 +2799,91 @@ pub fn abs(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<V
 
 /// A `pow` built-in function.
 pub fn pow(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun2_for_f32_and_matrix(arg_values, "unsupported types for function pow", f32::powf, matrix_pow_for_scalar, matrix_rpow_for_scalar, matrix_pow) }
+{ fun2_for_f64_and_matrix(arg_values, "unsupported types for function pow", f64::powf, matrix_pow_for_scalar, matrix_rpow_for_scalar, matrix_pow) }
 
 /// An `exp` built-in function.
 pub fn exp(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function exp", f32::exp, matrix_exp) }
+{ fun1_for_f64_and_matrix_and_tape_fun(arg_values, "unsupported type for function exp", TapeFun::Exp, f64::exp, matrix_exp) }
 
 /// A `log` built-in function.
 pub fn log(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function log", f32::ln, matrix_ln) }
+{ fun1_for_f64_and_matrix_and_tape_fun(arg_values, "unsupported type for function log", TapeFun::Log, f64::ln, matrix_ln) }
 
 /// A `log2` built-in function.
 pub fn log2(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function log2", f32::log2, matrix_log2) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function log2", f64::log2, matrix_log2) }
 
 /// A `log10` built-in function.
 pub fn log10(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function log10", f32::log10, matrix_log10) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function log10", f64::log10, matrix_log10) }
 
 /// A `sin` built-in function.
 pub fn sin(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function sin", f32::sin, matrix_sin) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function sin", f64::sin, matrix_sin) }
 
 /// A `cos` built-in function.
 pub fn cos(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function cos", f32::cos, matrix_cos) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function cos", f64::cos, matrix_cos) }
 
 /// A `tan` built-in function.
 pub fn tan(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function tan", f32::tan, matrix_tan) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function tan", f64::tan, matrix_tan) }
 
 /// An `asin` built-in function.
 pub fn asin(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function asin", f32::asin, matrix_asin) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function asin", f64::asin, matrix_asin) }
 
 /// An `acos` built-in function.
 pub fn acos(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function acos", f32::acos, matrix_acos) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function acos", f64::acos, matrix_acos) }
 
 /// An `atan` built-in function.
 pub fn atan(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function atan", f32::atan, matrix_atan) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function atan", f64::atan, matrix_atan) }
 
 /// An `atan2` built-in function.
 pub fn atan2(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun2_for_f32_and_matrix(arg_values, "unsupported types for function atan2", f32::atan2, matrix_atan2_for_scalar, matrix_ratan2_for_scalar, matrix_atan2) }
+{ fun2_for_f64_and_matrix(arg_values, "unsupported types for function atan2", f64::atan2, matrix_atan2_for_scalar, matrix_ratan2_for_scalar, matrix_atan2) }
 
 /// A `sinh` built-in function.
 pub fn sinh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function sinh", f32::sinh, matrix_sinh) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function sinh", f64::sinh, matrix_sinh) }
 
 /// A `cosh` built-in function.
 pub fn cosh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function cosh", f32::cosh, matrix_cosh) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function cosh", f64::cosh, matrix_cosh) }
 
 /// An `asinh` built-in function.
 pub fn asinh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function asinh", f32::asinh, matrix_asinh) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function asinh", f64::asinh, matrix_asinh) }
 
 /// An `acosh` built-in function.
 pub fn acosh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function acosh", f32::acosh, matrix_acosh) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function acosh", f64::acosh, matrix_acosh) }
 
 /// An `atanh` built-in function.
 pub fn atanh(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function atanh", f32::atanh, matrix_atanh) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function atanh", f64::atanh, matrix_atanh) }
 
 /// A `sign` built-in function.
 pub fn sign(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function sign", f32::signum, matrix_signum) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function sign", f64::signum, matrix_signum) }
 
 /// A `ceil` built-in function.
 pub fn ceil(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function ceil", f32::ceil, matrix_ceil) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function ceil", f64::ceil, matrix_ceil) }
 
 /// A `floor` built-in function.
 pub fn floor(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function floor", f32::floor, matrix_floor) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function floor", f64::floor, matrix_floor) }
 
 /// A `round` built-in function.
 pub fn round(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function round", f32::round, matrix_round) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function round", f64::round, matrix_round) }
 
 /// A `trunc` built-in function.
 pub fn trunc(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
-{ fun1_for_f32_and_matrix(arg_values, "unsupported type for function trunc", f32::trunc, matrix_trunc) }
+{ fun1_for_f64_and_matrix(arg_values, "unsupported type for function trunc", f64::trunc, matrix_trunc) }
 
 /// A `rand` built-in function.
 pub fn rand(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
@@ -1893,7 +2949,7 @@ pub fn str2float(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) ->
         Some(Value::Object(object)) => {
             match &**object {
                 Object::String(s) => {
-                    match s.parse::<f32>() {
+                    match s.parse::<f64>() {
                         Ok(n) => Ok(Value::Float(n)),
                         Err(err) => Ok(Value::Object(Arc::new(Object::Error(String::from("parsefloat"), format!("{}", err))))),
                     }
@@ -2098,17 +3154,292 @@ pub fn readline(_interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Re
     }
 }
 
-/// A `format` built-in function.
-pub fn format(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+struct FormatSpec
 {
-    let mut s = String::new();
-    for arg_value in arg_values {
-        s.push_str(format!("{}", arg_value).as_str());
-    }
-    Ok(Value::Object(Arc::new(Object::String(s))))
+    fill: char,
+    align: Option<char>,
+    is_plus: bool,
+    is_zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    typ: Option<char>,
 }
 
-/// A `print` built-in function.
+fn parse_format_spec(spec: &str) -> std::result::Result<FormatSpec, String>
+{
+    let cs: Vec<char> = spec.chars().collect();
+    let mut format_spec = FormatSpec {
+        fill: ' ',
+        align: None,
+        is_plus: false,
+        is_zero: false,
+        width: 0,
+        precision: None,
+        typ: None,
+    };
+    let mut i = 0usize;
+    match (cs.get(0), cs.get(1)) {
+        (Some(c), Some(c2 @ ('<' | '^' | '>'))) => {
+            format_spec.fill = *c;
+            format_spec.align = Some(*c2);
+            i = 2;
+        },
+        (Some(c @ ('<' | '^' | '>')), _) => {
+            format_spec.align = Some(*c);
+            i = 1;
+        },
+        (_, _) => (),
+    }
+    match cs.get(i) {
+        Some('+') => {
+            format_spec.is_plus = true;
+            i += 1;
+        },
+        _ => (),
+    }
+    match cs.get(i) {
+        Some('0') => {
+            format_spec.is_zero = true;
+            i += 1;
+        },
+        _ => (),
+    }
+    let width_start = i;
+    while i < cs.len() && cs[i].is_ascii_digit() {
+        i += 1;
+    }
+    if width_start < i {
+        let width_s: String = cs[width_start..i].iter().collect();
+        match width_s.parse::<usize>() {
+            Ok(width) if width <= (isize::MAX as usize) => format_spec.width = width,
+            _ => return Err(String::from("too large width")),
+        }
+    }
+    match cs.get(i) {
+        Some('.') => {
+            i += 1;
+            let precision_start = i;
+            while i < cs.len() && cs[i].is_ascii_digit() {
+                i += 1;
+            }
+            if precision_start == i {
+                return Err(String::from("no precision"));
+            }
+            let precision_s: String = cs[precision_start..i].iter().collect();
+            match precision_s.parse::<usize>() {
+                Ok(precision) if precision <= (u16::MAX as usize) => format_spec.precision = Some(precision),
+                _ => return Err(String::from("too large precision")),
+            }
+        },
+        _ => (),
+    }
+    match cs.get(i) {
+        Some(c @ ('x' | 'X' | 'o' | 'b' | 'e' | 'E')) => {
+            format_spec.typ = Some(*c);
+            i += 1;
+        },
+        _ => (),
+    }
+    if i < cs.len() {
+        return Err(format!("invalid format specification {}", spec));
+    }
+    Ok(format_spec)
+}
+
+fn format_value_with_spec(value: &Value, spec: &FormatSpec) -> std::result::Result<String, String>
+{
+    let (mut t, is_number) = match (value, spec.typ) {
+        (Value::Int(n), Some(typ @ ('x' | 'X' | 'o' | 'b'))) => {
+            let sign = if *n < 0 { "-" } else { "" };
+            let m = n.unsigned_abs();
+            let digits = match typ {
+                'x' => format!("{:x}", m),
+                'X' => format!("{:X}", m),
+                'o' => format!("{:o}", m),
+                _ => format!("{:b}", m),
+            };
+            (format!("{}{}", sign, digits), true)
+        },
+        (Value::Int(_) | Value::Float(_), Some(typ @ ('e' | 'E'))) => {
+            let t = match spec.precision {
+                Some(precision) => format!("{:.*e}", precision, value.to_f64()),
+                None => format!("{:e}", value.to_f64()),
+            };
+            if typ == 'E' {
+                (t.to_uppercase(), true)
+            } else {
+                (t, true)
+            }
+        },
+        (Value::Int(_), None) => (format!("{}", value), true),
+        (Value::Float(x), None) => {
+            match spec.precision {
+                Some(precision) => (format!("{:.*}", precision, x), true),
+                None => (format!("{}", value), true),
+            }
+        },
+        (_, Some(typ)) => return Err(format!("unsupported type for format type {}", typ)),
+        (_, None) => {
+            let t = format!("{}", value);
+            match spec.precision {
+                Some(precision) => (t.chars().take(precision).collect(), false),
+                None => (t, false),
+            }
+        },
+    };
+    if is_number && spec.is_plus && !t.starts_with('-') {
+        t.insert(0, '+');
+    }
+    let count = t.chars().count();
+    if count >= spec.width {
+        return Ok(t);
+    }
+    let padding_count = spec.width - count;
+    if is_number && spec.is_zero && spec.align.is_none() {
+        let sign_len = if t.starts_with('-') || t.starts_with('+') { 1 } else { 0 };
+        t.insert_str(sign_len, "0".repeat(padding_count).as_str());
+        return Ok(t);
+    }
+    let fill = if spec.is_zero && spec.align.is_none() { '0' } else { spec.fill };
+    let align = match spec.align {
+        Some(align) => align,
+        None if is_number || spec.is_zero => '>',
+        None => '<',
+    };
+    let (left_count, right_count) = match align {
+        '<' => (0, padding_count),
+        '^' => (padding_count / 2, padding_count - padding_count / 2),
+        _ => (padding_count, 0),
+    };
+    let mut u = String::new();
+    u.extend((0..left_count).map(|_| fill));
+    u.push_str(t.as_str());
+    u.extend((0..right_count).map(|_| fill));
+    Ok(u)
+}
+
+fn format_arg_value_for_name(arg_values: &[Value], name: &str) -> Result<Option<Value>>
+{
+    for arg_value in arg_values {
+        match arg_value {
+            Value::Ref(object) => {
+                let object_g = rw_lock_read(&**object)?;
+                match &*object_g {
+                    MutObject::Struct(fields) => {
+                        match fields.get(&String::from(name)) {
+                            Some(value) => return Ok(Some(value.clone())),
+                            None => (),
+                        }
+                    },
+                    MutObject::Dict(pairs) => {
+                        match pairs.get(&DictKey::String(String::from(name))) {
+                            Some(value) => return Ok(Some(value.clone())),
+                            None => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            _ => (),
+        }
+    }
+    Ok(None)
+}
+
+fn format_with_fmt(fmt: &str, arg_values: &[Value]) -> Result<std::result::Result<String, String>>
+{
+    let mut s = String::new();
+    let mut cs = fmt.chars().peekable();
+    let mut arg_idx = 0usize;
+    loop {
+        match cs.next() {
+            Some('{') => {
+                match cs.peek() {
+                    Some('{') => {
+                        cs.next();
+                        s.push('{');
+                        continue;
+                    },
+                    _ => (),
+                }
+                let mut placeholder = String::new();
+                loop {
+                    match cs.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Ok(Err(String::from("unclosed placeholder"))),
+                    }
+                }
+                let (arg, spec) = match placeholder.split_once(':') {
+                    Some((arg, spec)) => (arg, spec),
+                    None => (placeholder.as_str(), ""),
+                };
+                let format_spec = match parse_format_spec(spec) {
+                    Ok(format_spec) => format_spec,
+                    Err(msg) => return Ok(Err(msg)),
+                };
+                let value = if arg.is_empty() {
+                    match arg_values.get(arg_idx) {
+                        Some(value) => {
+                            arg_idx += 1;
+                            value.clone()
+                        },
+                        None => return Ok(Err(String::from("too few arguments"))),
+                    }
+                } else if arg.chars().all(|c| c.is_ascii_digit()) {
+                    match arg.parse::<usize>() {
+                        Ok(idx) if idx >= 1 && idx <= arg_values.len() => arg_values[idx - 1].clone(),
+                        _ => return Ok(Err(format!("invalid argument index {}", arg))),
+                    }
+                } else {
+                    match format_arg_value_for_name(arg_values, arg)? {
+                        Some(value) => value,
+                        None => return Ok(Err(format!("no field {}", arg))),
+                    }
+                };
+                match format_value_with_spec(&value, &format_spec) {
+                    Ok(t) => s.push_str(t.as_str()),
+                    Err(msg) => return Ok(Err(msg)),
+                }
+            },
+            Some('}') => {
+                match cs.next() {
+                    Some('}') => s.push('}'),
+                    _ => return Ok(Err(String::from("unmatched closing brace"))),
+                }
+            },
+            Some(c) => s.push(c),
+            None => break,
+        }
+    }
+    Ok(Ok(s))
+}
+
+/// A `format` built-in function.
+pub fn format(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::String(fmt) if fmt.contains('{') || fmt.contains('}') => {
+                    match format_with_fmt(fmt.as_str(), &arg_values[1..])? {
+                        Ok(s) => return Ok(Value::Object(Arc::new(Object::String(s)))),
+                        Err(msg) => return Ok(Value::Object(Arc::new(Object::Error(String::from("format"), msg)))),
+                    }
+                },
+                _ => (),
+            }
+        },
+        _ => (),
+    }
+    let mut s = String::new();
+    for arg_value in arg_values {
+        s.push_str(format!("{}", arg_value).as_str());
+    }
+    Ok(Value::Object(Arc::new(Object::String(s))))
+}
+
+/// A `print` built-in function.
 pub fn print(_interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
     match env.stdout() {
@@ -2459,6 +3790,177 @@ pub fn save(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Resul
     }
 }
 
+fn get_second_arg_compression(arg_values: &[Value], err_msg: &str) -> Result<Compression>
+{
+    match arg_values.get(1) {
+        Some(compression_value) => {
+            match compression_value.to_opt_string() {
+                Some(compression_name) => {
+                    match Compression::from_name(compression_name.as_str()) {
+                        Some(compression) => Ok(compression),
+                        None => Err(Error::Interp(String::from("invalid compression"))),
+                    }
+                },
+                None => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `savecompressed` built-in function.
+pub fn savecompressed(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function savecompressed")?;
+    let compression = get_second_arg_compression(arg_values, "unsupported type for function savecompressed")?;
+    match save_values_with_compression(file_name.as_str(), &arg_values[2..], compression) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `saveindexed` built-in function.
+pub fn saveindexed(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function saveindexed")?;
+    let compression = get_second_arg_compression(arg_values, "unsupported type for function saveindexed")?;
+    match save_indexed_values(file_name.as_str(), &arg_values[2..], compression) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// An `openvalues` built-in function.
+pub fn openvalues(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function openvalues")?;
+    match ValueReader::open(file_name.as_str()) {
+        Ok(reader) => Ok(Value::Object(Arc::new(Object::ValueReader(Arc::new(RwLock::new(reader)))))),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+fn get_first_arg_value_reader(arg_values: &[Value], err_msg: &str) -> Result<Arc<RwLock<ValueReader>>>
+{
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::ValueReader(reader) => Ok(reader.clone()),
+                _ => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from(err_msg))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `readvalue` built-in function.
+pub fn readvalue(_interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let reader = get_first_arg_value_reader(arg_values, "unsupported types for function readvalue")?;
+    let i = match arg_values.get(1) {
+        Some(Value::Int(i)) => *i,
+        Some(Value::Float(n)) => *n as i64,
+        Some(_) => return Err(Error::Interp(String::from("unsupported types for function readvalue"))),
+        None => return Err(Error::Interp(String::from("no argument"))),
+    };
+    if i < 1 {
+        return Err(Error::Interp(String::from("index out of bounds")));
+    }
+    let mut reader_g = rw_lock_write(&*reader)?;
+    match reader_g.read_value((i - 1) as usize, env) {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => Err(Error::Interp(String::from("index out of bounds"))),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `valuecount` built-in function.
+pub fn valuecount(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let reader = get_first_arg_value_reader(arg_values, "unsupported type for function valuecount")?;
+    let reader_g = rw_lock_read(&*reader)?;
+    Ok(Value::Int(reader_g.len() as i64))
+}
+
+/// A `createvalues` built-in function.
+pub fn createvalues(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported types for function createvalues")?;
+    let compression = get_second_arg_compression(arg_values, "unsupported types for function createvalues")?;
+    match ValueWriter::create(file_name.as_str(), compression) {
+        Ok(writer) => Ok(Value::Object(Arc::new(Object::ValueWriter(Arc::new(RwLock::new(writer)))))),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+fn get_first_arg_value_writer(arg_values: &[Value], err_msg: &str) -> Result<Arc<RwLock<ValueWriter>>>
+{
+    match arg_values.get(0) {
+        Some(Value::Object(object)) => {
+            match &**object {
+                Object::ValueWriter(writer) => Ok(writer.clone()),
+                _ => Err(Error::Interp(String::from(err_msg))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from(err_msg))),
+        None => Err(Error::Interp(String::from("no argument"))),
+    }
+}
+
+/// A `writevalue` built-in function.
+pub fn writevalue(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let writer = get_first_arg_value_writer(arg_values, "unsupported types for function writevalue")?;
+    let mut writer_g = rw_lock_write(&*writer)?;
+    match writer_g.write_value(&arg_values[1]) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `closevalues` built-in function.
+pub fn closevalues(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let writer = get_first_arg_value_writer(arg_values, "unsupported type for function closevalues")?;
+    let mut writer_g = rw_lock_write(&*writer)?;
+    match writer_g.finish() {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
 /// A `loadstr` built-in function.
 pub fn loadstr(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -2617,6 +4119,865 @@ pub fn savejson(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> R
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CsvColType
+{
+    Auto,
+    Bool,
+    Int,
+    Float,
+    String,
+}
+
+#[derive(Clone, Debug)]
+enum CsvColTypes
+{
+    All(CsvColType),
+    Array(Vec<CsvColType>),
+    Struct(BTreeMap<String, CsvColType>),
+}
+
+#[derive(Clone, Debug)]
+struct CsvOpts
+{
+    delimiter: char,
+    quote: char,
+    has_header: bool,
+    is_matrix: bool,
+    col_types: CsvColTypes,
+    col_names: Option<Vec<String>>,
+}
+
+fn csv_char_opt(field: Option<&Value>, default_c: char, name: &str) -> Result<char>
+{
+    match field {
+        Some(Value::None) | None => Ok(default_c),
+        Some(value) => {
+            match value.to_opt_string() {
+                Some(s) => {
+                    let mut cs = s.chars();
+                    match (cs.next(), cs.next()) {
+                        (Some('\n'), None) | (Some('\r'), None) => Err(Error::Interp(format!("invalid {}", name))),
+                        (Some(c), None) => Ok(c),
+                        (_, _) => Err(Error::Interp(format!("invalid {}", name))),
+                    }
+                },
+                None => Err(Error::Interp(format!("unsupported type for {}", name))),
+            }
+        },
+    }
+}
+
+fn csv_col_type(value: &Value) -> Result<CsvColType>
+{
+    match value.to_opt_string() {
+        Some(s) => {
+            match s.as_str() {
+                "auto" => Ok(CsvColType::Auto),
+                "bool" => Ok(CsvColType::Bool),
+                "int" => Ok(CsvColType::Int),
+                "float" => Ok(CsvColType::Float),
+                "string" => Ok(CsvColType::String),
+                _ => Err(Error::Interp(String::from("invalid column type"))),
+            }
+        },
+        None => Err(Error::Interp(String::from("unsupported type for column type"))),
+    }
+}
+
+fn csv_col_types_opt(field: Option<&Value>) -> Result<CsvColTypes>
+{
+    match field {
+        Some(Value::None) | None => Ok(CsvColTypes::All(CsvColType::Auto)),
+        Some(value @ Value::Object(_)) => Ok(CsvColTypes::All(csv_col_type(value)?)),
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Array(elems) => {
+                    let mut col_types: Vec<CsvColType> = Vec::new();
+                    for elem in elems {
+                        col_types.push(csv_col_type(elem)?);
+                    }
+                    Ok(CsvColTypes::Array(col_types))
+                },
+                MutObject::Struct(fields) => {
+                    let mut col_types: BTreeMap<String, CsvColType> = BTreeMap::new();
+                    for (name, field) in fields {
+                        col_types.insert(name.clone(), csv_col_type(field)?);
+                    }
+                    Ok(CsvColTypes::Struct(col_types))
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for column types"))),
+            }
+        },
+        Some(_) => Err(Error::Interp(String::from("unsupported type for column types"))),
+    }
+}
+
+fn csv_col_names_opt(field: Option<&Value>) -> Result<Option<Vec<String>>>
+{
+    match field {
+        Some(Value::None) | None => Ok(None),
+        Some(value) => {
+            let mut col_names: Vec<String> = Vec::new();
+            for elem in to_row_values(value)? {
+                match elem.to_opt_string() {
+                    Some(s) => col_names.push(s),
+                    None => return Err(Error::Interp(String::from("unsupported type for column name"))),
+                }
+            }
+            Ok(Some(col_names))
+        },
+    }
+}
+
+fn csv_opts(value: Option<&Value>, fun_name: &str) -> Result<CsvOpts>
+{
+    let opts = match value {
+        None => {
+            CsvOpts {
+                delimiter: ',',
+                quote: '"',
+                has_header: true,
+                is_matrix: false,
+                col_types: CsvColTypes::All(CsvColType::Auto),
+                col_names: None,
+            }
+        },
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Struct(fields) => {
+                    let delimiter = csv_char_opt(fields.get(&String::from("delimiter")), ',', "delimiter")?;
+                    let quote = csv_char_opt(fields.get(&String::from("quote")), '"', "quote")?;
+                    let has_header = match fields.get(&String::from("header")) {
+                        Some(Value::None) | None => true,
+                        Some(field) => field.to_bool(),
+                    };
+                    let is_matrix = match fields.get(&String::from("matrix")) {
+                        Some(Value::None) | None => false,
+                        Some(field) => field.to_bool(),
+                    };
+                    let col_types = csv_col_types_opt(fields.get(&String::from("types")))?;
+                    let col_names = csv_col_names_opt(fields.get(&String::from("columns")))?;
+                    CsvOpts { delimiter, quote, has_header, is_matrix, col_types, col_names, }
+                },
+                _ => return Err(Error::Interp(format!("unsupported type for function {}", fun_name))),
+            }
+        },
+        Some(_) => return Err(Error::Interp(format!("unsupported type for function {}", fun_name))),
+    };
+    if opts.delimiter == opts.quote {
+        return Err(Error::Interp(String::from("delimiter and quote are same")));
+    }
+    Ok(opts)
+}
+
+fn parse_csv(s: &str, delimiter: char, quote: char) -> std::result::Result<Vec<(u64, Vec<String>)>, String>
+{
+    let mut records: Vec<(u64, Vec<String>)> = Vec::new();
+    let mut cs = s.chars().peekable();
+    let mut line = 1u64;
+    loop {
+        match cs.peek() {
+            Some(_) => (),
+            None => break,
+        }
+        let record_line = line;
+        let mut fields: Vec<String> = Vec::new();
+        let mut is_blank = true;
+        loop {
+            let mut field = String::new();
+            match cs.peek() {
+                Some(c) if *c == quote => {
+                    cs.next();
+                    is_blank = false;
+                    loop {
+                        match cs.next() {
+                            Some(c) if c == quote => {
+                                match cs.peek() {
+                                    Some(c2) if *c2 == quote => {
+                                        cs.next();
+                                        field.push(quote);
+                                    },
+                                    _ => break,
+                                }
+                            },
+                            Some(c) => {
+                                if c == '\n' {
+                                    line += 1;
+                                }
+                                field.push(c);
+                            },
+                            None => return Err(format!("line {}: unclosed quote", record_line)),
+                        }
+                    }
+                    match cs.peek() {
+                        Some(c) if *c == delimiter || *c == '\n' || *c == '\r' => (),
+                        Some(_) => return Err(format!("line {}: unexpected character after closing quote", line)),
+                        None => (),
+                    }
+                },
+                _ => {
+                    loop {
+                        match cs.peek() {
+                            Some(c) if *c == delimiter || *c == '\n' || *c == '\r' => break,
+                            Some(c) if *c == quote => return Err(format!("line {}: unexpected quote in unquoted field", line)),
+                            Some(c) => {
+                                field.push(*c);
+                                cs.next();
+                            },
+                            None => break,
+                        }
+                    }
+                },
+            }
+            if !field.is_empty() {
+                is_blank = false;
+            }
+            fields.push(field);
+            match cs.next() {
+                Some(c) if c == delimiter => is_blank = false,
+                Some('\r') => {
+                    match cs.peek() {
+                        Some('\n') => {
+                            cs.next();
+                        },
+                        _ => (),
+                    }
+                    line += 1;
+                    break;
+                },
+                Some(_) => {
+                    line += 1;
+                    break;
+                },
+                None => break,
+            }
+        }
+        if !is_blank {
+            records.push((record_line, fields));
+        }
+    }
+    Ok(records)
+}
+
+fn csv_field_value(field: &str, col_type: CsvColType) -> Option<Value>
+{
+    if field.is_empty() && col_type != CsvColType::String {
+        return Some(Value::None);
+    }
+    match col_type {
+        CsvColType::Auto => {
+            match field.parse::<i64>() {
+                Ok(a) => Some(Value::Int(a)),
+                Err(_) => {
+                    match field.parse::<f64>() {
+                        Ok(a) => Some(Value::Float(a)),
+                        Err(_) => {
+                            match field {
+                                "false" => Some(Value::Bool(false)),
+                                "true" => Some(Value::Bool(true)),
+                                _ => Some(Value::Object(Arc::new(Object::String(String::from(field))))),
+                            }
+                        },
+                    }
+                },
+            }
+        },
+        CsvColType::Bool => {
+            match field {
+                "false" => Some(Value::Bool(false)),
+                "true" => Some(Value::Bool(true)),
+                _ => None,
+            }
+        },
+        CsvColType::Int => field.parse::<i64>().ok().map(Value::Int),
+        CsvColType::Float => field.parse::<f64>().ok().map(Value::Float),
+        CsvColType::String => Some(Value::Object(Arc::new(Object::String(String::from(field))))),
+    }
+}
+
+fn csv_col_type_for_col(col_types: &CsvColTypes, i: usize, col_name: Option<&String>) -> CsvColType
+{
+    match col_types {
+        CsvColTypes::All(col_type) => *col_type,
+        CsvColTypes::Array(tmp_col_types) => tmp_col_types.get(i).map(|t| *t).unwrap_or(CsvColType::Auto),
+        CsvColTypes::Struct(tmp_col_types) => {
+            match col_name {
+                Some(col_name) => tmp_col_types.get(col_name).map(|t| *t).unwrap_or(CsvColType::Auto),
+                None => CsvColType::Auto,
+            }
+        },
+    }
+}
+
+fn csv_records_to_value(records: &[(u64, Vec<String>)], opts: &CsvOpts) -> Result<std::result::Result<Value, String>>
+{
+    let (header, rows) = if opts.has_header {
+        match records.split_first() {
+            Some(((_, header), rows)) => (Some(header), rows),
+            None => (None, records),
+        }
+    } else {
+        (None, records)
+    };
+    let col_count = match (header, records.first()) {
+        (Some(header), _) => header.len(),
+        (None, Some((_, fields))) => fields.len(),
+        (None, None) => 0,
+    };
+    for (line, fields) in rows {
+        if fields.len() != col_count {
+            return Ok(Err(format!("line {}: invalid number of fields", line)));
+        }
+    }
+    if opts.is_matrix {
+        if rows.is_empty() {
+            return Ok(Err(String::from("no rows for matrix")));
+        }
+        let mut xs: Vec<f32> = Vec::with_capacity(rows.len() * col_count);
+        for (line, fields) in rows {
+            for (j, field) in fields.iter().enumerate() {
+                match field.trim().parse::<f32>() {
+                    Ok(x) => xs.push(x),
+                    Err(_) => return Ok(Err(format!("line {}: invalid number in column {}", line, j + 1))),
+                }
+            }
+        }
+        return Ok(Ok(Value::Object(Arc::new(Object::Matrix(matrix_create_and_set_elems(rows.len(), col_count, xs.as_slice())?)))));
+    }
+    let mut elems: Vec<Value> = Vec::with_capacity(rows.len());
+    for (line, fields) in rows {
+        let mut values: Vec<Value> = Vec::with_capacity(col_count);
+        for (j, field) in fields.iter().enumerate() {
+            let col_type = csv_col_type_for_col(&opts.col_types, j, header.and_then(|h| h.get(j)));
+            match csv_field_value(field.as_str(), col_type) {
+                Some(value) => values.push(value),
+                None => return Ok(Err(format!("line {}: invalid value in column {}", line, j + 1))),
+            }
+        }
+        match header {
+            Some(header) => {
+                let mut struct_fields: BTreeMap<String, Value> = BTreeMap::new();
+                for (name, value) in header.iter().zip(values.into_iter()) {
+                    struct_fields.insert(name.clone(), value);
+                }
+                elems.push(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(struct_fields)))));
+            },
+            None => elems.push(Value::Ref(Arc::new(RwLock::new(MutObject::Array(values))))),
+        }
+    }
+    Ok(Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems))))))
+}
+
+/// A `loadcsv` built-in function.
+pub fn loadcsv(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 1 || arg_values.len() > 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function loadcsv")?;
+    let opts = csv_opts(arg_values.get(1), "loadcsv")?;
+    match File::open(file_name.as_str()) {
+        Ok(mut file) => {
+            let mut s = String::new();
+            match file.read_to_string(&mut s) {
+                Ok(_) => {
+                    let res = match parse_csv(s.as_str(), opts.delimiter, opts.quote) {
+                        Ok(records) => csv_records_to_value(records.as_slice(), &opts)?,
+                        Err(msg) => Err(msg),
+                    };
+                    match res {
+                        Ok(value) => Ok(value),
+                        Err(msg) => Ok(Value::Object(Arc::new(Object::Error(String::from("csv"), msg)))),
+                    }
+                },
+                Err(err) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+            }
+        },
+        Err(err) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+    }
+}
+
+fn csv_field_string(value: &Value) -> String
+{
+    match value {
+        Value::None => String::new(),
+        Value::Float(a) => format!("{}", a),
+        _ => format!("{}", value),
+    }
+}
+
+fn write_csv_record(w: &mut dyn Write, fields: &[String], opts: &CsvOpts) -> io::Result<()>
+{
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            write!(w, "{}", opts.delimiter)?;
+        }
+        if field.contains(|c: char| c == opts.delimiter || c == opts.quote || c == '\n' || c == '\r') {
+            let mut tmp_field = String::new();
+            for c in field.chars() {
+                if c == opts.quote {
+                    tmp_field.push(opts.quote);
+                }
+                tmp_field.push(c);
+            }
+            write!(w, "{}{}{}", opts.quote, tmp_field, opts.quote)?;
+        } else {
+            write!(w, "{}", field)?;
+        }
+    }
+    writeln!(w)
+}
+
+fn csv_records_from_value(value: &Value, opts: &CsvOpts) -> Result<Vec<Vec<String>>>
+{
+    let mut records: Vec<Vec<String>> = Vec::new();
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(_) | Object::MatrixArray(_, _, _, _) => {
+                    let matrix_array = value.to_matrix_array()?;
+                    match &matrix_array {
+                        Value::Object(object) => {
+                            match &**object {
+                                Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+                                    match &opts.col_names {
+                                        Some(col_names) if opts.has_header => records.push(col_names.clone()),
+                                        _ => (),
+                                    }
+                                    for i in 0..(*row_count) {
+                                        let mut fields: Vec<String> = Vec::with_capacity(*col_count);
+                                        for j in 0..(*col_count) {
+                                            let k = match transpose_flag {
+                                                TransposeFlag::NoTranspose => i * (*col_count) + j,
+                                                TransposeFlag::Transpose => j * (*row_count) + i,
+                                            };
+                                            fields.push(format!("{}", xs[k]));
+                                        }
+                                        records.push(fields);
+                                    }
+                                },
+                                _ => return Err(Error::Interp(String::from("invalid matrix array type"))),
+                            }
+                        },
+                        _ => return Err(Error::Interp(String::from("invalid matrix array type"))),
+                    }
+                },
+                Object::HostMatrix(row_count, col_count, elems) => {
+                    match &opts.col_names {
+                        Some(col_names) if opts.has_header => records.push(col_names.clone()),
+                        _ => (),
+                    }
+                    for i in 0..(*row_count) {
+                        let mut fields: Vec<String> = Vec::with_capacity(*col_count);
+                        for j in 0..(*col_count) {
+                            match elems.elem(i * (*col_count) + j) {
+                                Some(elem) => fields.push(csv_field_string(&elem)),
+                                None => return Err(Error::Interp(String::from("no element"))),
+                            }
+                        }
+                        records.push(fields);
+                    }
+                },
+                _ => return Err(Error::Interp(String::from("unsupported type for function savecsv"))),
+            }
+        },
+        Value::Ref(_) => {
+            let rows = to_row_values(value)?;
+            let mut col_names = opts.col_names.clone();
+            match col_names {
+                None => {
+                    match rows.first() {
+                        Some(Value::Ref(object)) => {
+                            let object_g = rw_lock_read(&**object)?;
+                            match &*object_g {
+                                MutObject::Struct(fields) => col_names = Some(fields.keys().map(|k| k.clone()).collect()),
+                                _ => (),
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Some(_) => (),
+            }
+            match &col_names {
+                Some(col_names) if opts.has_header => records.push(col_names.clone()),
+                _ => (),
+            }
+            for row in &rows {
+                let mut fields: Vec<String> = Vec::new();
+                match row {
+                    Value::Ref(object) => {
+                        let object_g = rw_lock_read(&**object)?;
+                        match &*object_g {
+                            MutObject::Struct(struct_fields) => {
+                                match &col_names {
+                                    Some(col_names) => {
+                                        for col_name in col_names {
+                                            match struct_fields.get(col_name) {
+                                                Some(field) => fields.push(csv_field_string(field)),
+                                                None => fields.push(String::new()),
+                                            }
+                                        }
+                                    },
+                                    None => return Err(Error::Interp(String::from("no column names"))),
+                                }
+                            },
+                            MutObject::Array(elems) => {
+                                for elem in elems {
+                                    fields.push(csv_field_string(elem));
+                                }
+                            },
+                            _ => return Err(Error::Interp(String::from("unsupported type for function savecsv"))),
+                        }
+                    },
+                    _ => return Err(Error::Interp(String::from("unsupported type for function savecsv"))),
+                }
+                records.push(fields);
+            }
+        },
+        _ => return Err(Error::Interp(String::from("unsupported type for function savecsv"))),
+    }
+    Ok(records)
+}
+
+/// A `savecsv` built-in function.
+pub fn savecsv(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 2 || arg_values.len() > 3 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let (file_name, value) = match (arg_values.get(0), arg_values.get(1)) {
+        (Some(file_name_value), Some(value)) => {
+            match file_name_value.to_opt_string() {
+                Some(tmp_file_name) => (tmp_file_name, value),
+                None => return Err(Error::Interp(String::from("unsupported type for function savecsv"))),
+            }
+        },
+        (_, _) => return Err(Error::Interp(String::from("no argument"))),
+    };
+    let opts = csv_opts(arg_values.get(2), "savecsv")?;
+    let records = csv_records_from_value(value, &opts)?;
+    match File::create(file_name.as_str()) {
+        Ok(file) => {
+            let mut w = BufWriter::new(file);
+            for record in &records {
+                match write_csv_record(&mut w, record.as_slice(), &opts) {
+                    Ok(()) => (),
+                    Err(err) => return Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+                }
+            }
+            match w.flush() {
+                Ok(()) => Ok(Value::Bool(true)),
+                Err(err) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+            }
+        },
+        Err(err) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+    }
+}
+
+fn npy_array_for_value(value: &Value, fun_name: &str) -> Result<NpyArray>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => NpyArray::from_matrix(a),
+                Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+                    let is_transposed = match transpose_flag {
+                        TransposeFlag::NoTranspose => false,
+                        TransposeFlag::Transpose => true,
+                    };
+                    Ok(NpyArray::new(*row_count, *col_count, is_transposed, xs.clone()))
+                },
+                Object::HostMatrix(row_count, col_count, elems) => Ok(NpyArray::new(*row_count, *col_count, false, elems.to_f32s())),
+                _ => Err(Error::Interp(format!("unsupported type for function {}", fun_name))),
+            }
+        },
+        _ => Err(Error::Interp(format!("unsupported type for function {}", fun_name))),
+    }
+}
+
+/// A `loadnpy` built-in function.
+pub fn loadnpy(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function loadnpy")?;
+    match load_npy(file_name.as_str()) {
+        Ok(a) => Ok(Value::Object(Arc::new(Object::Matrix(a.to_matrix()?)))),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `savenpy` built-in function.
+pub fn savenpy(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 2 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function savenpy")?;
+    let a = match arg_values.get(1) {
+        Some(value) => npy_array_for_value(value, "savenpy")?,
+        None => return Err(Error::Interp(String::from("no argument"))),
+    };
+    match save_npy(file_name.as_str(), &a) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `loadnpz` built-in function.
+pub fn loadnpz(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function loadnpz")?;
+    match load_npz(file_name.as_str()) {
+        Ok(arrays) => {
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            for (name, a) in &arrays {
+                fields.insert(name.clone(), Value::Object(Arc::new(Object::Matrix(a.to_matrix()?))));
+            }
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))))
+        },
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(Error::Zip(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("zip"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `savenpz` built-in function.
+pub fn savenpz(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function savenpz")?;
+    let mut arrays: Vec<(String, NpyArray)> = Vec::new();
+    match &arg_values[1..] {
+        [Value::Ref(object)] => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Struct(fields) => {
+                    for (name, field) in fields {
+                        arrays.push((name.clone(), npy_array_for_value(field, "savenpz")?));
+                    }
+                },
+                _ => return Err(Error::Interp(String::from("unsupported type for function savenpz"))),
+            }
+        },
+        values => {
+            for (i, value) in values.iter().enumerate() {
+                arrays.push((format!("arr_{}", i), npy_array_for_value(value, "savenpz")?));
+            }
+        },
+    }
+    match save_npz(file_name.as_str(), arrays.as_slice()) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(Error::Zip(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("zip"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+fn safe_tensor_for_value(value: &Value) -> Option<Result<SafeTensor>>
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => Some(SafeTensor::from_matrix(a)),
+                Object::Tensor(shape, a) => {
+                    match SafeTensor::from_matrix(a) {
+                        Ok(t) => Some(Ok(SafeTensor::new(shape.clone(), t.elems().to_vec()))),
+                        Err(err) => Some(Err(err)),
+                    }
+                },
+                Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+                    let mut ys: Vec<f32> = Vec::with_capacity(xs.len());
+                    for i in 0..(*row_count) {
+                        for j in 0..(*col_count) {
+                            let k = match transpose_flag {
+                                TransposeFlag::NoTranspose => i * (*col_count) + j,
+                                TransposeFlag::Transpose => j * (*row_count) + i,
+                            };
+                            ys.push(xs[k]);
+                        }
+                    }
+                    Some(Ok(SafeTensor::new(vec![*row_count, *col_count], ys)))
+                },
+                Object::HostMatrix(row_count, col_count, elems) => Some(Ok(SafeTensor::new(vec![*row_count, *col_count], elems.to_f32s()))),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn add_safe_tensors_for_fields(prefix: &str, fields: &BTreeMap<String, Value>, tensors: &mut Vec<(String, SafeTensor)>) -> Result<()>
+{
+    for (name, field) in fields {
+        let tensor_name = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match field {
+            Value::Ref(object) => {
+                let object_g = rw_lock_read(&**object)?;
+                match &*object_g {
+                    MutObject::Struct(fields2) => add_safe_tensors_for_fields(tensor_name.as_str(), fields2, tensors)?,
+                    _ => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+                }
+            },
+            _ => {
+                match safe_tensor_for_value(field) {
+                    Some(res) => tensors.push((tensor_name, res?)),
+                    None => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+fn value_for_safe_tensor(t: &SafeTensor) -> Result<Value>
+{
+    if t.shape().len() == 2 {
+        Ok(Value::Object(Arc::new(Object::Matrix(t.to_matrix()?))))
+    } else {
+        Ok(Value::Object(Arc::new(Object::Tensor(t.shape().to_vec(), t.to_matrix()?))))
+    }
+}
+
+fn insert_safe_tensor_value(fields: &mut BTreeMap<String, Value>, names: &[&str], value: Value) -> Result<bool>
+{
+    match names {
+        [] => Ok(false),
+        [name] => {
+            match fields.get(*name) {
+                Some(_) => Ok(false),
+                None => {
+                    fields.insert(String::from(*name), value);
+                    Ok(true)
+                },
+            }
+        },
+        [name, names2 @ ..] => {
+            let field = match fields.get(*name) {
+                Some(tmp_field) => tmp_field.clone(),
+                None => {
+                    let tmp_field = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(BTreeMap::new()))));
+                    fields.insert(String::from(*name), tmp_field.clone());
+                    tmp_field
+                },
+            };
+            match &field {
+                Value::Ref(object) => {
+                    let mut object_g = rw_lock_write(&**object)?;
+                    match &mut *object_g {
+                        MutObject::Struct(fields2) => insert_safe_tensor_value(fields2, names2, value),
+                        _ => Ok(false),
+                    }
+                },
+                _ => Ok(false),
+            }
+        },
+    }
+}
+
+/// A `loadsafetensors` built-in function.
+pub fn loadsafetensors(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function loadsafetensors")?;
+    match load_safetensors(file_name.as_str()) {
+        Ok((tensors, _)) => {
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            for (name, t) in &tensors {
+                let names: Vec<&str> = name.split('.').collect();
+                if !insert_safe_tensor_value(&mut fields, names.as_slice(), value_for_safe_tensor(t)?)? {
+                    return Ok(Value::Object(Arc::new(Object::Error(String::from("safetensors"), format!("conflicting tensor name {}", name)))));
+                }
+            }
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))))
+        },
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `loadsafetensorsmeta` built-in function.
+pub fn loadsafetensorsmeta(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() != 1 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function loadsafetensorsmeta")?;
+    match load_safetensors(file_name.as_str()) {
+        Ok((_, metadata)) => {
+            let fields: BTreeMap<String, Value> = metadata.into_iter().map(|(k, v)| (k, Value::Object(Arc::new(Object::String(v))))).collect();
+            Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))))
+        },
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
+/// A `savesafetensors` built-in function.
+pub fn savesafetensors(_interp: &mut Interp, _env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    if arg_values.len() < 2 || arg_values.len() > 3 {
+        return Err(Error::Interp(String::from("invalid number of arguments")));
+    }
+    let file_name = get_first_arg_string(arg_values, "unsupported type for function savesafetensors")?;
+    let mut tensors: Vec<(String, SafeTensor)> = Vec::new();
+    match arg_values.get(1) {
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Struct(fields) => add_safe_tensors_for_fields("", fields, &mut tensors)?,
+                _ => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+            }
+        },
+        Some(_) => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+        None => return Err(Error::Interp(String::from("no argument"))),
+    }
+    let mut metadata: BTreeMap<String, String> = BTreeMap::new();
+    match arg_values.get(2) {
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Struct(fields) => {
+                    for (key, field) in fields {
+                        match field.to_opt_string() {
+                            Some(s) => {
+                                metadata.insert(key.clone(), s);
+                            },
+                            None => return Err(Error::Interp(String::from("unsupported type for metadata"))),
+                        }
+                    }
+                },
+                _ => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+            }
+        },
+        Some(Value::None) | None => (),
+        Some(_) => return Err(Error::Interp(String::from("unsupported type for function savesafetensors"))),
+    }
+    match save_safetensors(file_name.as_str(), tensors.as_slice(), &metadata) {
+        Ok(()) => Ok(Value::Bool(true)),
+        Err(Error::Io(err)) => Ok(Value::Object(Arc::new(Object::Error(String::from("io"), format!("{}", err))))),
+        Err(err) => Err(err),
+    }
+}
+
 /// An `args` built-in function.
 pub fn args(_interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
 {
@@ -3319,9 +5680,9 @@ pub fn add_alias(root_mod: &mut ModNode<Value, ()>, new_ident: String, old_ident
 /// Adds standard built-in functions to the root module.
 pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
 {
-    root_mod.add_var(String::from("pi"), Value::Float(f32::consts::PI));
-    root_mod.add_var(String::from("e"), Value::Float(f32::consts::E));
-    root_mod.add_var(String::from("eps"), Value::Float(f32::EPSILON));
+    root_mod.add_var(String::from("pi"), Value::Float(f64::consts::PI));
+    root_mod.add_var(String::from("e"), Value::Float(f64::consts::E));
+    root_mod.add_var(String::from("eps"), Value::Float(f32::EPSILON as f64));
     root_mod.add_var(String::from("pathsep"), Value::Object(Arc::new(Object::String(format!("{}", path::MAIN_SEPARATOR)))));
     add_builtin_fun(root_mod, String::from("type"), typ);
     add_builtin_fun(root_mod, String::from("clone"), clone);
@@ -3338,6 +5699,9 @@ pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
     add_builtin_fun(root_mod, String::from("rowvector"), rowvector);
     add_builtin_fun(root_mod, String::from("colvector"), colvector);
     add_builtin_fun(root_mod, String::from("matrixarray"), matrixarray);
+    add_builtin_fun(root_mod, String::from("doublematrix"), doublematrix);
+    add_builtin_fun(root_mod, String::from("intmatrix"), intmatrix);
+    add_builtin_fun(root_mod, String::from("elemtype"), elemtype);
     add_builtin_fun(root_mod, String::from("error"), error);
     add_builtin_fun(root_mod, String::from("array"), array);
     add_builtin_fun(root_mod, String::from("strong"), strong);
@@ -3356,7 +5720,15 @@ pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
     add_builtin_fun(root_mod, String::from("replace"), replace);
     add_builtin_fun(root_mod, String::from("upper"), upper);
     add_builtin_fun(root_mod, String::from("lower"), lower);
+    add_builtin_fun(root_mod, String::from("regex"), regex);
+    add_builtin_fun(root_mod, String::from("rematch"), rematch);
+    add_builtin_fun(root_mod, String::from("refind"), refind);
+    add_builtin_fun(root_mod, String::from("refindall"), refindall);
+    add_builtin_fun(root_mod, String::from("rereplace"), rereplace);
+    add_builtin_fun(root_mod, String::from("resplit"), resplit);
     add_builtin_fun(root_mod, String::from("sort"), sort);
+    add_builtin_fun(root_mod, String::from("sorted"), sorted);
+    add_builtin_fun(root_mod, String::from("argsort"), argsort);
     add_builtin_fun(root_mod, String::from("reverse"), reverse);
     add_builtin_fun(root_mod, String::from("any"), any);
     add_builtin_fun(root_mod, String::from("all"), all);
@@ -3371,6 +5743,10 @@ pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
     add_builtin_fun(root_mod, String::from("append"), append);
     add_builtin_fun(root_mod, String::from("insert"), insert);
     add_builtin_fun(root_mod, String::from("remove"), remove);
+    add_builtin_fun(root_mod, String::from("keys"), keys);
+    add_builtin_fun(root_mod, String::from("values"), values);
+    add_builtin_fun(root_mod, String::from("haskey"), haskey);
+    add_builtin_fun(root_mod, String::from("delete"), delete);
     add_builtin_fun(root_mod, String::from("errorkind"), errorkind);
     add_builtin_fun(root_mod, String::from("errormsg"), errormsg);
     add_builtin_fun(root_mod, String::from("isequal"), isequal);
@@ -3383,10 +5759,27 @@ pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
     add_builtin_fun(root_mod, String::from("tanh"), tanh);
     add_builtin_fun(root_mod, String::from("swish"), swish);
     add_builtin_fun(root_mod, String::from("softmax"), softmax);
+    add_builtin_fun(root_mod, String::from("grad"), grad);
     add_builtin_fun(root_mod, String::from("sqrt"), sqrt);
     add_builtin_fun(root_mod, String::from("reallytranspose"), reallytranspose);
     add_alias(root_mod, String::from("rt"), &String::from("reallytranspose"));
     add_builtin_fun(root_mod, String::from("repeat"), repeat);
+    add_builtin_fun(root_mod, String::from("hcat"), hcat);
+    add_builtin_fun(root_mod, String::from("vcat"), vcat);
+    add_builtin_fun(root_mod, String::from("reshape"), reshape);
+    add_builtin_fun(root_mod, String::from("sum"), sum);
+    add_builtin_fun(root_mod, String::from("mean"), mean);
+    add_builtin_fun(root_mod, String::from("rowsum"), rowsum);
+    add_builtin_fun(root_mod, String::from("colsum"), colsum);
+    add_builtin_fun(root_mod, String::from("norm"), norm);
+    add_builtin_fun(root_mod, String::from("argmax"), argmax);
+    add_builtin_fun(root_mod, String::from("tensor"), tensor);
+    add_builtin_fun(root_mod, String::from("shape"), shape);
+    add_builtin_fun(root_mod, String::from("permute"), permute);
+    add_builtin_fun(root_mod, String::from("im2col"), im2col);
+    add_builtin_fun(root_mod, String::from("conv2d"), conv2d);
+    add_builtin_fun(root_mod, String::from("maxpool2d"), maxpool2d);
+    add_builtin_fun(root_mod, String::from("avgpool2d"), avgpool2d);
     add_builtin_fun(root_mod, String::from("mod"), modulo);
     add_builtin_fun(root_mod, String::from("abs"), abs);
     add_builtin_fun(root_mod, String::from("pow"), pow);
@@ -3444,12 +5837,29 @@ pub fn add_std_builtin_funs(root_mod: &mut ModNode<Value, ()>)
     add_builtin_fun(root_mod, String::from("exit"), exit);
     add_builtin_fun(root_mod, String::from("load"), load);
     add_builtin_fun(root_mod, String::from("save"), save);
+    add_builtin_fun(root_mod, String::from("savecompressed"), savecompressed);
+    add_builtin_fun(root_mod, String::from("saveindexed"), saveindexed);
+    add_builtin_fun(root_mod, String::from("openvalues"), openvalues);
+    add_builtin_fun(root_mod, String::from("readvalue"), readvalue);
+    add_builtin_fun(root_mod, String::from("valuecount"), valuecount);
+    add_builtin_fun(root_mod, String::from("createvalues"), createvalues);
+    add_builtin_fun(root_mod, String::from("writevalue"), writevalue);
+    add_builtin_fun(root_mod, String::from("closevalues"), closevalues);
     add_builtin_fun(root_mod, String::from("loadstr"), loadstr);
     add_builtin_fun(root_mod, String::from("savestr"), savestr);
     add_builtin_fun(root_mod, String::from("loadtoml"), loadtoml);
     add_builtin_fun(root_mod, String::from("savetoml"), savetoml);
     add_builtin_fun(root_mod, String::from("loadjson"), loadjson);
     add_builtin_fun(root_mod, String::from("savejson"), savejson);
+    add_builtin_fun(root_mod, String::from("loadcsv"), loadcsv);
+    add_builtin_fun(root_mod, String::from("savecsv"), savecsv);
+    add_builtin_fun(root_mod, String::from("loadnpy"), loadnpy);
+    add_builtin_fun(root_mod, String::from("savenpy"), savenpy);
+    add_builtin_fun(root_mod, String::from("loadnpz"), loadnpz);
+    add_builtin_fun(root_mod, String::from("savenpz"), savenpz);
+    add_builtin_fun(root_mod, String::from("loadsafetensors"), loadsafetensors);
+    add_builtin_fun(root_mod, String::from("loadsafetensorsmeta"), loadsafetensorsmeta);
+    add_builtin_fun(root_mod, String::from("savesafetensors"), savesafetensors);
     add_builtin_fun(root_mod, String::from("args"), args);
     add_builtin_fun(root_mod, String::from("env"), env);
     add_builtin_fun(root_mod, String::from("scriptdir"), scriptdir);
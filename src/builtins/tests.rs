@@ -14,13 +14,19 @@ use super::*;
 fn f(_interp: &mut Interp, _env: &mut Env, _arg_values: &[Value]) -> Result<Value>
 { Ok(Value::None) }
 
+fn g(interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
+{
+    let value = arg_values[0].bin_op(BinOp::DotMul, &arg_values[0])?;
+    sigmoid(interp, env, &[value])
+}
+
 #[test]
 fn test_pi_is_pi_constant()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
     match root_mod.var(&String::from("pi")) {
-        Some(value) => assert_eq!(Value::Float(f32::consts::PI), *value),
+        Some(value) => assert_eq!(Value::Float(f64::consts::PI), *value),
         None => assert!(false),
     }
 }
@@ -31,7 +37,7 @@ fn test_e_is_e_constant()
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
     match root_mod.var(&String::from("e")) {
-        Some(value) => assert_eq!(Value::Float(f32::consts::E), *value),
+        Some(value) => assert_eq!(Value::Float(f64::consts::E), *value),
         None => assert!(false),
     }
 }
@@ -662,6 +668,93 @@ fn test_matrixarray_is_applied_with_success()
     }
 }
 
+#[test]
+fn test_doublematrix_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("doublematrix")) {
+        Some(fun_value) => {
+            let row_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5)]))));
+            let row_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(-3), Value::Float(4.0)]))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![row_value, row_value2]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![1.0, 2.5, -3.0, 4.0])))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::MatrixArray(2, 2, TransposeFlag::Transpose, vec![1.0, 2.0, 3.0, 4.0])));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![1.0, 3.0, 2.0, 4.0])))), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_intmatrix_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("intmatrix")) {
+        Some(fun_value) => {
+            let row_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5)]))));
+            let row_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(-3), Value::Float(4.0)]))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![row_value, row_value2]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 2, -3, 4])))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::HostMatrix(1, 2, HostElems::Double(vec![1.75, -2.5]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(1, 2, HostElems::Int(vec![1, -2])))), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_elemtype_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("elemtype")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::MatrixArray(1, 1, TransposeFlag::NoTranspose, vec![1.0])));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("float")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::HostMatrix(1, 1, HostElems::Double(vec![1.0]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("double")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::HostMatrix(1, 1, HostElems::Int(vec![1]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("int")))), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
 #[test]
 fn test_error_is_applied_with_success()
 {
@@ -1309,7 +1402,7 @@ fn test_lower_is_applied_with_success()
 }
 
 #[test]
-fn test_sort_is_applied_with_success()
+fn test_regex_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1317,44 +1410,27 @@ fn test_sort_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("sort")) {
+    match root_mod_g.var(&String::from("regex")) {
         Some(fun_value) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(true), Value::Bool(false), Value::Bool(true)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::None, value);
-                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(false), Value::Bool(true), Value::Bool(true)]))));
-                    assert_eq!(expected_arg_value, arg_value);
-                },
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(3), Value::Int(1), Value::Float(2.5)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::None, value);
-                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5), Value::Int(3)]))));
-                    assert_eq!(expected_arg_value, arg_value);
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("a+"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Regex(regex) => assert_eq!("a+", regex.as_str()),
+                        _ => assert!(false),
+                    }
                 },
-                Err(_) => assert!(false),
+                _ => assert!(false),
             }
-            let elems = vec![
-                Value::Object(Arc::new(Object::String(String::from("ghi")))),
-                Value::Object(Arc::new(Object::String(String::from("abc")))),
-                Value::Object(Arc::new(Object::String(String::from("def"))))
-            ];
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::None, value);
-                    let expected_elems = vec![
-                        Value::Object(Arc::new(Object::String(String::from("abc")))),
-                        Value::Object(Arc::new(Object::String(String::from("def")))),
-                        Value::Object(Arc::new(Object::String(String::from("ghi"))))
-                    ];
-                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(expected_elems))));
-                    assert_eq!(expected_arg_value, arg_value);
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("(a"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("regex"), *err_kind),
+                        _ => assert!(false),
+                    }
                 },
-                Err(_) => assert!(false),
+                _ => assert!(false),
             }
         },
         None => assert!(false),
@@ -1362,7 +1438,7 @@ fn test_sort_is_applied_with_success()
 }
 
 #[test]
-fn test_reverse_is_applied_with_success()
+fn test_rematch_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1370,15 +1446,17 @@ fn test_reverse_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("reverse")) {
+    match root_mod_g.var(&String::from("rematch")) {
         Some(fun_value) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::None, value);
-                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(false), Value::Float(2.0), Value::Int(1)]))));
-                    assert_eq!(expected_arg_value, arg_value);
-                },
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("abc123"))));
+            let arg_value2 = Value::Object(Arc::new(Object::Regex(Regex::new("[0-9]+").unwrap())));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("^[0-9]+$"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
                 Err(_) => assert!(false),
             }
         },
@@ -1387,7 +1465,7 @@ fn test_reverse_is_applied_with_success()
 }
 
 #[test]
-fn test_any_is_applied_with_success()
+fn test_refind_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1395,25 +1473,40 @@ fn test_any_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match (root_mod_g.var(&String::from("any")), root_mod_g.var(&String::from("islessequal"))) {
-        (Some(fun_value), Some(islessequal_value)) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
+    match root_mod_g.var(&String::from("refind")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("żółw: 12 kg"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("(?P<n>[0-9]+) (kg|g)( x)?"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2]) {
+                Ok(value) => {
+                    let mut expected_names: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_names.insert(String::from("n"), Value::Object(Arc::new(Object::String(String::from("12")))));
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("text"), Value::Object(Arc::new(Object::String(String::from("12 kg")))));
+                    expected_fields.insert(String::from("start"), Value::Int(7));
+                    expected_fields.insert(String::from("end"), Value::Int(11));
+                    expected_fields.insert(String::from("groups"), Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                                    Value::Object(Arc::new(Object::String(String::from("12")))),
+                                    Value::Object(Arc::new(Object::String(String::from("kg")))),
+                                    Value::None
+                    ])))));
+                    expected_fields.insert(String::from("names"), Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_names)))));
+                    assert_eq!(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields)))), value);
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Bool(false), value),
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("[A-Z]"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => assert_eq!(Value::None, value),
                 Err(_) => assert!(false),
             }
         },
-        (_, _) => assert!(false),
+        None => assert!(false),
     }
 }
 
 #[test]
-fn test_all_is_applied_with_success()
+fn test_refindall_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1421,25 +1514,44 @@ fn test_all_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match (root_mod_g.var(&String::from("all")), root_mod_g.var(&String::from("islessequal"))) {
-        (Some(fun_value), Some(islessequal_value)) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(3), Value::Int(3), Value::Int(4), Value::Int(4)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Bool(false), value),
-                Err(_) => assert!(false),
+    match root_mod_g.var(&String::from("refindall")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("a1 b22"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("[a-z]([0-9]+)"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[1] {
+                                Value::Ref(object2) => {
+                                    let object2_g = object2.read().unwrap();
+                                    match &*object2_g {
+                                        MutObject::Struct(fields) => {
+                                            assert_eq!(Some(&Value::Object(Arc::new(Object::String(String::from("b22"))))), fields.get(&String::from("text")));
+                                            assert_eq!(Some(&Value::Int(4)), fields.get(&String::from("start")));
+                                            assert_eq!(Some(&Value::Int(6)), fields.get(&String::from("end")));
+                                            assert_eq!(Some(&Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Object(Arc::new(Object::String(String::from("22"))))]))))), fields.get(&String::from("groups")));
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
             }
         },
-        (_, _) => assert!(false),
+        None => assert!(false),
     }
 }
 
 #[test]
-fn test_find_is_applied_with_success()
+fn test_rereplace_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1447,25 +1559,26 @@ fn test_find_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match (root_mod_g.var(&String::from("find")), root_mod_g.var(&String::from("islessequal"))) {
-        (Some(fun_value), Some(islessequal_value)) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Int(2), value),
+    match root_mod_g.var(&String::from("rereplace")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("x=1, y=22"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("([a-z])=([0-9]+)"))));
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("$2:$1"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2.clone(), arg_value3.clone()]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1:x, 22:y")))), value),
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => assert_eq!(Value::None, value),
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2, arg_value3, Value::Int(1)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1:x, y=22")))), value),
                 Err(_) => assert!(false),
             }
         },
-        (_, _) => assert!(false),
+        None => assert!(false),
     }
 }
 
 #[test]
-fn test_filter_is_applied_with_success()
+fn test_resplit_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1473,31 +1586,28 @@ fn test_filter_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match (root_mod_g.var(&String::from("filter")), root_mod_g.var(&String::from("islessequal"))) {
-        (Some(fun_value), Some(islessequal_value)) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
-                Ok(value) => {
-                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(4)]))));
-                    assert_eq!(expected_value, value);
-                },
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+    match root_mod_g.var(&String::from("resplit")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("a, b;c"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("[,;] *"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(value) => {
-                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(Vec::new()))));
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                                    Value::Object(Arc::new(Object::String(String::from("a")))),
+                                    Value::Object(Arc::new(Object::String(String::from("b")))),
+                                    Value::Object(Arc::new(Object::String(String::from("c"))))
+                    ]))));
                     assert_eq!(expected_value, value);
                 },
                 Err(_) => assert!(false),
             }
         },
-        (_, _) => assert!(false),
+        None => assert!(false),
     }
 }
 
 #[test]
-fn test_max_is_applied_with_success()
+fn test_sort_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -1505,24 +1615,281 @@ fn test_max_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("max")) {
+    match root_mod_g.var(&String::from("sort")) {
         Some(fun_value) => {
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2)]))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(true), Value::Bool(false), Value::Bool(true)]))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => assert_eq!(Value::Int(3), value),
+                Ok(value) => {
+                    assert_eq!(Value::None, value);
+                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(false), Value::Bool(true), Value::Bool(true)]))));
+                    assert_eq!(expected_arg_value, arg_value);
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(Vec::new()))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(3), Value::Int(1), Value::Float(2.5)]))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
-                Ok(value) => assert_eq!(Value::None, value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1), Value::Int(2)]) {
-                Ok(value) => assert_eq!(Value::Int(2), value),
+                Ok(value) => {
+                    assert_eq!(Value::None, value);
+                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5), Value::Int(3)]))));
+                    assert_eq!(expected_arg_value, arg_value);
+                },
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(2), Value::Int(1)]) {
-                Ok(value) => assert_eq!(Value::Int(2), value),
+            let elems = vec![
+                Value::Object(Arc::new(Object::String(String::from("ghi")))),
+                Value::Object(Arc::new(Object::String(String::from("abc")))),
+                Value::Object(Arc::new(Object::String(String::from("def"))))
+            ];
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => {
+                    assert_eq!(Value::None, value);
+                    let expected_elems = vec![
+                        Value::Object(Arc::new(Object::String(String::from("abc")))),
+                        Value::Object(Arc::new(Object::String(String::from("def")))),
+                        Value::Object(Arc::new(Object::String(String::from("ghi"))))
+                    ];
+                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(expected_elems))));
+                    assert_eq!(expected_arg_value, arg_value);
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_sorted_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("sorted")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(4), Value::Float(1.5), Value::Int(3), Value::Int(2)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(1.5), Value::Int(2), Value::Int(3), Value::Int(4)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(1.5), Value::Int(2), Value::Int(4), Value::Int(3)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(4), Value::Float(1.5), Value::Int(3), Value::Int(2)]))));
+            assert_eq!(expected_value, arg_value);
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("xyz"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone(), arg_value2]) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("invalid sort kind"), msg),
+                _ => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_argsort_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("argsort")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(4), Value::Float(1.5), Value::Int(3), Value::Int(2)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(4), Value::Int(3), Value::Int(1)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone(), Value::Object(Arc::new(Object::String(String::from("key"))))]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(4), Value::Int(1), Value::Int(3)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_reverse_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("reverse")) {
+        Some(fun_value) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => {
+                    assert_eq!(Value::None, value);
+                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Bool(false), Value::Float(2.0), Value::Int(1)]))));
+                    assert_eq!(expected_arg_value, arg_value);
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_any_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("any")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_all_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("all")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(3), Value::Int(3), Value::Int(4), Value::Int(4)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_find_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("find")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => assert_eq!(Value::None, value),
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_filter_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match (root_mod_g.var(&String::from("filter")), root_mod_g.var(&String::from("islessequal"))) {
+        (Some(fun_value), Some(islessequal_value)) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(4)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(2), Value::Int(1)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), islessequal_value.clone()]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(Vec::new()))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[test]
+fn test_max_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("max")) {
+        Some(fun_value) => {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => assert_eq!(Value::Int(3), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(Vec::new()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(value) => assert_eq!(Value::None, value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1), Value::Int(2)]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(2), Value::Int(1)]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
                 Err(_) => assert!(false),
             }
             let xs = vec![
@@ -2232,7 +2599,7 @@ fn test_remove_is_applied_with_success()
 }
 
 #[test]
-fn test_errorkind_is_applied_with_success()
+fn test_keys_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2240,11 +2607,17 @@ fn test_errorkind_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("errorkind")) {
+    match root_mod_g.var(&String::from("keys")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::Error(String::from("abc"), String::from("def"))));
+            let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+            pairs.insert(DictKey::String(String::from("a")), Value::Int(1));
+            pairs.insert(DictKey::Int(2), Value::Float(2.0));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("abc")))), value),
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Object(Arc::new(Object::String(String::from("a"))))]))));
+                    assert_eq!(expected_value, value);
+                },
                 Err(_) => assert!(false),
             }
         },
@@ -2253,7 +2626,7 @@ fn test_errorkind_is_applied_with_success()
 }
 
 #[test]
-fn test_errormsg_is_applied_with_success()
+fn test_values_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2261,11 +2634,17 @@ fn test_errormsg_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("errormsg")) {
+    match root_mod_g.var(&String::from("values")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::Error(String::from("abc"), String::from("def"))));
+            let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+            pairs.insert(DictKey::String(String::from("a")), Value::Int(1));
+            pairs.insert(DictKey::Int(2), Value::Float(2.0));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("def")))), value),
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(2.0), Value::Int(1)]))));
+                    assert_eq!(expected_value, value);
+                },
                 Err(_) => assert!(false),
             }
         },
@@ -2274,7 +2653,7 @@ fn test_errormsg_is_applied_with_success()
 }
 
 #[test]
-fn test_isequal_is_applied_with_success()
+fn test_haskey_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2282,13 +2661,17 @@ fn test_isequal_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("isequal")) {
+    match root_mod_g.var(&String::from("haskey")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(1234)]) {
+            let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+            pairs.insert(DictKey::String(String::from("a")), Value::Int(1));
+            pairs.insert(DictKey::Int(2), Value::Float(2.0));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Float(2.0)]) {
                 Ok(value) => assert_eq!(Value::Bool(true), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(4567)]) {
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Object(Arc::new(Object::String(String::from("b"))))]) {
                 Ok(value) => assert_eq!(Value::Bool(false), value),
                 Err(_) => assert!(false),
             }
@@ -2298,7 +2681,7 @@ fn test_isequal_is_applied_with_success()
 }
 
 #[test]
-fn test_isnotequal_is_applied_with_success()
+fn test_delete_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2306,14 +2689,24 @@ fn test_isnotequal_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("isnotequal")) {
+    match root_mod_g.var(&String::from("delete")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(1234)]) {
-                Ok(value) => assert_eq!(Value::Bool(false), value),
+            let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+            pairs.insert(DictKey::String(String::from("a")), Value::Int(1));
+            pairs.insert(DictKey::Int(2), Value::Float(2.0));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Object(Arc::new(Object::String(String::from("a"))))]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    let mut expected_pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+                    expected_pairs.insert(DictKey::Int(2), Value::Float(2.0));
+                    let expected_arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(expected_pairs))));
+                    assert_eq!(expected_arg_value, arg_value);
+                },
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(4567)]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Int(3)]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
                 Err(_) => assert!(false),
             }
         },
@@ -2322,7 +2715,7 @@ fn test_isnotequal_is_applied_with_success()
 }
 
 #[test]
-fn test_isless_is_applied_with_success()
+fn test_errorkind_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2330,10 +2723,100 @@ fn test_isless_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("isless")) {
+    match root_mod_g.var(&String::from("errorkind")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(2), Value::Int(3)]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
+            let arg_value = Value::Object(Arc::new(Object::Error(String::from("abc"), String::from("def"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("abc")))), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_errormsg_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("errormsg")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::Error(String::from("abc"), String::from("def"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("def")))), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_isequal_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("isequal")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(1234)]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(4567)]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_isnotequal_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("isnotequal")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(1234)]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(4567)]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_isless_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("isless")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(2), Value::Int(3)]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
                 Err(_) => assert!(false),
             }
             match fun_value.apply(&mut interp, &mut env, &[Value::Int(3), Value::Int(3)]) {
@@ -2444,8 +2927,8 @@ fn shared_test_fun1_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
     let root_mod_g = root_mod.read().unwrap();
     match root_mod_g.var(&String::from(fun_name)) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a)]) {
-                Ok(Value::Float(b)) => assert!((f(a) - b).abs() < 0.001),
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a as f64)]) {
+                Ok(Value::Float(b)) => assert!((f(a) as f64 - b).abs() < 0.001),
                 _ => assert!(false),
             }
             let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))));
@@ -2466,7 +2949,7 @@ fn shared_test_fun1_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                 },
                 _ => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a as f64), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Ref(object)) => {
                     let object_g = object.read().unwrap();
@@ -2474,7 +2957,7 @@ fn shared_test_fun1_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                         MutObject::Array(elems) => {
                             assert_eq!(2, elems.len());
                             match &elems[0] {
-                                Value::Float(b) => assert!((f(a) - b).abs() < 0.001),
+                                Value::Float(b) => assert!((f(a) as f64 - b).abs() < 0.001),
                                 _ => assert!(false),
                             }
                             match &elems[1] {
@@ -2516,12 +2999,12 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
     let root_mod_g = root_mod.read().unwrap();
     match root_mod_g.var(&String::from(fun_name)) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a), Value::Float(b)]) {
-                Ok(Value::Float(c)) => assert!((f(a, b) - c).abs() < 0.001),
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a as f64), Value::Float(b as f64)]) {
+                Ok(Value::Float(c)) => assert!((f(a, b) as f64 - c).abs() < 0.001),
                 _ => assert!(false),
             }
             let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Float(b)]) {
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Float(b as f64)]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Matrix(matrix) => {
@@ -2539,7 +3022,7 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                 _ => assert!(false),
             }
             let arg_value2 = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, ys))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a), arg_value2]) {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a as f64), arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Matrix(matrix) => {
@@ -2575,15 +3058,15 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                 },
                 _ => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Float(b)]) {
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a as f64), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Float(b as f64)]) {
                 Ok(Value::Ref(object)) => {
                     let object_g = object.read().unwrap();
                     match &*object_g {
                         MutObject::Array(elems) => {
                             assert_eq!(2, elems.len());
                             match &elems[0] {
-                                Value::Float(c) => assert!((f(a, b) - c).abs() < 0.001),
+                                Value::Float(c) => assert!((f(a, b) as f64 - c).abs() < 0.001),
                                 _ => assert!(false),
                             }
                             match &elems[1] {
@@ -2609,15 +3092,15 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                 },
                 _ => assert!(false),
             }
-            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(b), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, ys))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a), arg_value2]) {
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(b as f64), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, ys))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(a as f64), arg_value2]) {
                 Ok(Value::Ref(object)) => {
                     let object_g = object.read().unwrap();
                     match &*object_g {
                         MutObject::Array(elems) => {
                             assert_eq!(2, elems.len());
                             match &elems[0] {
-                                Value::Float(c) => assert!((f(a, b) - c).abs() < 0.001),
+                                Value::Float(c) => assert!((f(a, b) as f64 - c).abs() < 0.001),
                                 _ => assert!(false),
                             }
                             match &elems[1] {
@@ -2643,8 +3126,8 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                 },
                 _ => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
-            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(b), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, ys))))]))));
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(a as f64), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, xs))))]))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(b as f64), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(row_count, col_count, ys))))]))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Ref(object)) => {
                     let object_g = object.read().unwrap();
@@ -2652,7 +3135,7 @@ fn shared_test_fun2_is_applied_with_success_for_f32_and_matrix<F>(fun_name: &str
                         MutObject::Array(elems) => {
                             assert_eq!(2, elems.len());
                             match &elems[0] {
-                                Value::Float(c) => assert!((f(a, b) - c).abs() < 0.001),
+                                Value::Float(c) => assert!((f(a, b) as f64 - c).abs() < 0.001),
                                 _ => assert!(false),
                             }
                             match &elems[1] {
@@ -2728,7 +3211,7 @@ fn test_softmax_is_applied_with_success()
     match root_mod_g.var(&String::from("softmax")) {
         Some(fun_value) => {
             match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.5)]) {
-                Ok(Value::Float(b)) => assert!((((0.5f32).exp() / (0.5f32).exp()) - b).abs() < 0.001),
+                Ok(Value::Float(b)) => assert!((((0.5f64).exp() / (0.5f64).exp()) - b).abs() < 0.001),
                 _ => assert!(false),
             }
             let xs: Vec<f32> = vec![
@@ -2765,6 +3248,65 @@ fn test_softmax_is_applied_with_success()
     }
 }
 
+#[test]
+fn test_grad_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("grad")) {
+        Some(fun_value) => {
+            let g_value = Value::Object(Arc::new(Object::BuiltinFun(String::from("g"), g)));
+            let s = 1.0 / (1.0 + (-2.25f32).exp());
+            match fun_value.apply(&mut interp, &mut env, &[g_value.clone(), Value::Float(1.5)]) {
+                Ok(Value::Float(b)) => assert!(((s * (1.0 - s) * 3.0) as f64 - b).abs() < 0.001),
+                _ => assert!(false),
+            }
+            let xs: Vec<f32> = vec![
+                -1.0, -0.5,
+                0.0, 0.5,
+                1.0, 1.5
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            let params_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![arg_value]))));
+            match fun_value.apply(&mut interp, &mut env, &[g_value, params_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(1, elems.len());
+                            match &elems[0] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..6 {
+                                                let s2 = 1.0 / (1.0 + (-(xs[i] * xs[i])).exp());
+                                                assert!((s2 * (1.0 - s2) * 2.0 * xs[i] - ys[i]).abs() < 0.001);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
 #[test]
 fn test_sqrt_is_applied_with_success()
 {
@@ -2875,7 +3417,7 @@ fn test_repeat_is_applied_with_success()
 }
 
 #[test]
-fn test_mod_is_applied_with_success()
+fn test_hcat_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2883,14 +3425,27 @@ fn test_mod_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("mod")) {
+    match root_mod_g.var(&String::from("hcat")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(10), Value::Int(4)]) {
-                Ok(value) => assert_eq!(Value::Int(2), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(10.5), Value::Float(3.5)]) {
-                Ok(value) => assert_eq!(Value::Float(10.5 % 3.5), value),
+            let a = matrix![
+                [1.0, 2.0],
+                [3.0, 4.0]
+            ];
+            let b = matrix![
+                [5.0],
+                [6.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            let arg_value2 = Value::Object(Arc::new(Object::Matrix(b)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    let b = vec![
+                    1.0, 2.0, 5.0,
+                    3.0, 4.0, 6.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 3, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
                 Err(_) => assert!(false),
             }
         },
@@ -2899,7 +3454,7 @@ fn test_mod_is_applied_with_success()
 }
 
 #[test]
-fn test_abs_is_applied_with_success()
+fn test_hcat_is_applied_with_success_for_non_finite_elements()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -2907,80 +3462,20 @@ fn test_abs_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("abs")) {
+    match root_mod_g.var(&String::from("hcat")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(3)]) {
-                Ok(value) => assert_eq!(Value::Int(3), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(0)]) {
-                Ok(value) => assert_eq!(Value::Int(0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(-2)]) {
-                Ok(value) => assert_eq!(Value::Int(2), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(3.5)]) {
-                Ok(value) => assert_eq!(Value::Float(3.5), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(0.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(2.5), value),
-                Err(_) => assert!(false),
-            }
-            let xs = vec![
-                -2.0, -1.0,
-                0.0, 1.0,
-                2.0, 3.0
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].abs(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!(2.5, *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].abs(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
+            let arg_value = Value::Object(Arc::new(Object::Matrix(matrix![[f32::INFINITY], [f32::NAN]])));
+            let arg_value2 = Value::Object(Arc::new(Object::Matrix(matrix![[1.0], [2.0]])));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    match value.to_matrix_array().unwrap() {
+                        Value::Object(object) => {
+                            match &*object {
+                                Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, xs) => {
+                                    assert_eq!(f32::INFINITY, xs[0]);
+                                    assert_eq!(1.0, xs[1]);
+                                    assert_eq!(true, xs[2].is_nan());
+                                    assert_eq!(2.0, xs[3]);
                                 },
                                 _ => assert!(false),
                             }
@@ -2988,7 +3483,7 @@ fn test_abs_is_applied_with_success()
                         _ => assert!(false),
                     }
                 },
-                _ => assert!(false),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
@@ -2996,204 +3491,1478 @@ fn test_abs_is_applied_with_success()
 }
 
 #[test]
-fn test_pow_is_applied_with_success()
+fn test_vcat_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    let ys = vec![
-        3.0, 4.0,
-        5.0, 6.0,
-        4.0, 3.0
-    ];
-    shared_test_fun2_is_applied_with_success_for_f32_and_matrix("pow", 3.5, 2.5, 3, 2, xs.as_slice(), ys.as_slice(), f32::powf);
-}
-
-#[test]
-fn test_exp_is_applied_with_success()
-{ 
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("exp", 2.53, 3, 2, xs.as_slice(), f32::exp);
-}
-
-#[test]
-fn test_log_is_applied_with_success()
-{
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log", 10.5, 3, 2, xs.as_slice(), f32::ln);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("vcat")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0],
+                [3.0, 4.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Object(Arc::new(Object::Matrix(matrix![[5.0, 6.0]])))]) {
+                Ok(value) => {
+                    let b = vec![
+                    1.0, 2.0,
+                    3.0, 4.0,
+                    5.0, 6.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(3, 2, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_log2_is_applied_with_success()
+fn test_vcat_is_applied_with_success_for_non_finite_elements()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log2", 10.5, 3, 2, xs.as_slice(), f32::log2);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("vcat")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::Matrix(matrix![[f32::INFINITY, f32::NAN]])));
+            let arg_value2 = Value::Object(Arc::new(Object::Matrix(matrix![[1.0, 2.0]])));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    match value.to_matrix_array().unwrap() {
+                        Value::Object(object) => {
+                            match &*object {
+                                Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, xs) => {
+                                    assert_eq!(f32::INFINITY, xs[0]);
+                                    assert_eq!(true, xs[1].is_nan());
+                                    assert_eq!(1.0, xs[2]);
+                                    assert_eq!(2.0, xs[3]);
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_log10_is_applied_with_success()
+fn test_reshape_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log10", 10.5, 3, 2, xs.as_slice(), f32::log10);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("reshape")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), Value::Int(2)]) {
+                Ok(value) => {
+                    let b = vec![
+                    1.0, 2.0,
+                    3.0, 4.0,
+                    6.0, 5.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(3, 2, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_sin_is_applied_with_success()
+fn test_sum_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("sin", 0.5, 3, 2, xs.as_slice(), f32::sin);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("sum")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Float(21.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(2)]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_cos_is_applied_with_success()
+fn test_mean_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("cos", 0.5, 3, 2, xs.as_slice(), f32::cos);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("mean")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Float(3.5), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_tan_is_applied_with_success()
+fn test_mean_complains_on_empty_matrix()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("tan", 0.5, 3, 2, xs.as_slice(), f32::tan);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("mean")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::Matrix(matrix_create_and_set_elems(0, 2, &[]).unwrap())));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(_) => assert!(false),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("matrix is empty"), msg),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_asin_is_applied_with_success()
+fn test_rowsum_is_applied_with_success()
 {
-    let xs = vec![
-        -0.5, 0.25,
-        0.0, 0.25,
-        0.5, 0.75
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("asin", 0.5, 3, 2, xs.as_slice(), f32::asin);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("rowsum")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let b = vec![
+                    6.0,
+                    15.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 1, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_acos_is_applied_with_success()
+fn test_colsum_is_applied_with_success()
 {
-    let xs = vec![
-        -0.5, 0.25,
-        0.0, 0.25,
-        0.5, 0.75
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("acos", 0.5, 3, 2, xs.as_slice(), f32::acos);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("colsum")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let b = vec![
+                    5.0, 8.0, 8.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(1, 3, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_atan_is_applied_with_success()
+fn test_norm_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("atan", 0.5, 3, 2, xs.as_slice(), f32::atan);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("norm")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [3.0, 0.0],
+                [0.0, 4.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Float(5.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(2.5), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_atan2_is_applied_with_success()
+fn test_argmax_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    let ys = vec![
-        5.0, 6.0,
-        7.0, 8.0,
-        9.0, 10.0
-    ];
-    shared_test_fun2_is_applied_with_success_for_f32_and_matrix("atan2", 0.25, 0.5, 3, 2, xs.as_slice(), ys.as_slice(), f32::atan2);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("argmax")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0],
+                [4.0, 6.0, 5.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let b = vec![
+                    3.0,
+                    2.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 1, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_tensor_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("tensor")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(4), Value::Int(1), Value::Int(2)]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Tensor(shape, b) => {
+                            assert_eq!(vec![4, 1, 2], *shape);
+                            let c = vec![
+                                1.0, 2.0,
+                                3.0, 4.0,
+                                5.0, 6.0,
+                                7.0, 8.0
+                            ];
+                            let matrix_array = Arc::new(Object::MatrixArray(4, 2, TransposeFlag::NoTranspose, c));
+                            assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(b.clone()))).to_matrix_array().unwrap());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_permute_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("permute")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                [7.0, 8.0, 9.0, 10.0, 11.0, 12.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Tensor(vec![2, 3, 2], a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(3), Value::Int(1), Value::Int(2)]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Tensor(shape, b) => {
+                            assert_eq!(vec![2, 2, 3], *shape);
+                            let c = vec![
+                                1.0, 3.0, 5.0, 7.0, 9.0, 11.0,
+                                2.0, 4.0, 6.0, 8.0, 10.0, 12.0
+                            ];
+                            let matrix_array = Arc::new(Object::MatrixArray(2, 6, TransposeFlag::NoTranspose, c));
+                            assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(b.clone()))).to_matrix_array().unwrap());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_im2col_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("im2col")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 3, 3], a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(2), Value::Int(2)]) {
+                Ok(value) => {
+                    let b = vec![
+                        1.0, 2.0, 4.0, 5.0,
+                        2.0, 3.0, 5.0, 6.0,
+                        4.0, 5.0, 7.0, 8.0,
+                        5.0, 6.0, 8.0, 9.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(4, 4, TransposeFlag::NoTranspose, b));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_conv2d_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("conv2d")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 3, 3], a)));
+            let b = matrix![
+                [1.0, 0.0, 0.0, 1.0],
+                [1.0, 1.0, 1.0, 1.0]
+            ];
+            let arg_value2 = Value::Object(Arc::new(Object::Tensor(vec![2, 1, 2, 2], b)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Tensor(shape, b) => {
+                            assert_eq!(vec![1, 2, 2, 2], *shape);
+                            let c = vec![
+                                6.0, 8.0, 12.0, 14.0, 12.0, 16.0, 24.0, 28.0
+                            ];
+                            let matrix_array = Arc::new(Object::MatrixArray(1, 8, TransposeFlag::NoTranspose, c));
+                            assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(b.clone()))).to_matrix_array().unwrap());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_maxpool2d_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("maxpool2d")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 4, 4], a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(2)]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Tensor(shape, b) => {
+                            assert_eq!(vec![1, 1, 2, 2], *shape);
+                            let c = vec![
+                                6.0, 8.0, 14.0, 16.0
+                            ];
+                            let matrix_array = Arc::new(Object::MatrixArray(1, 4, TransposeFlag::NoTranspose, c));
+                            assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(b.clone()))).to_matrix_array().unwrap());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_avgpool2d_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("avgpool2d")) {
+        Some(fun_value) => {
+            let a = matrix![
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Tensor(vec![1, 1, 4, 4], a)));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(2)]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Tensor(shape, b) => {
+                            assert_eq!(vec![1, 1, 2, 2], *shape);
+                            let c = vec![
+                                3.5, 5.5, 11.5, 13.5
+                            ];
+                            let matrix_array = Arc::new(Object::MatrixArray(1, 4, TransposeFlag::NoTranspose, c));
+                            assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(b.clone()))).to_matrix_array().unwrap());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_mod_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("mod")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(10), Value::Int(4)]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(10.5), Value::Float(3.5)]) {
+                Ok(value) => assert_eq!(Value::Float(10.5 % 3.5), value),
+                Err(_) => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_abs_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("abs")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(3)]) {
+                Ok(value) => assert_eq!(Value::Int(3), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(0)]) {
+                Ok(value) => assert_eq!(Value::Int(0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(-2)]) {
+                Ok(value) => assert_eq!(Value::Int(2), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(3.5)]) {
+                Ok(value) => assert_eq!(Value::Float(3.5), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(0.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(2.5), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.0, -1.0,
+                0.0, 1.0,
+                2.0, 3.0
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].abs(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!(2.5, *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].abs(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_pow_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    let ys = vec![
+        3.0, 4.0,
+        5.0, 6.0,
+        4.0, 3.0
+    ];
+    shared_test_fun2_is_applied_with_success_for_f32_and_matrix("pow", 3.5, 2.5, 3, 2, xs.as_slice(), ys.as_slice(), f32::powf);
+}
+
+#[test]
+fn test_exp_is_applied_with_success()
+{ 
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("exp", 2.53, 3, 2, xs.as_slice(), f32::exp);
+}
+
+#[test]
+fn test_log_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log", 10.5, 3, 2, xs.as_slice(), f32::ln);
+}
+
+#[test]
+fn test_log2_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log2", 10.5, 3, 2, xs.as_slice(), f32::log2);
+}
+
+#[test]
+fn test_log10_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("log10", 10.5, 3, 2, xs.as_slice(), f32::log10);
+}
+
+#[test]
+fn test_sin_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("sin", 0.5, 3, 2, xs.as_slice(), f32::sin);
+}
+
+#[test]
+fn test_cos_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("cos", 0.5, 3, 2, xs.as_slice(), f32::cos);
+}
+
+#[test]
+fn test_tan_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("tan", 0.5, 3, 2, xs.as_slice(), f32::tan);
+}
+
+#[test]
+fn test_asin_is_applied_with_success()
+{
+    let xs = vec![
+        -0.5, 0.25,
+        0.0, 0.25,
+        0.5, 0.75
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("asin", 0.5, 3, 2, xs.as_slice(), f32::asin);
+}
+
+#[test]
+fn test_acos_is_applied_with_success()
+{
+    let xs = vec![
+        -0.5, 0.25,
+        0.0, 0.25,
+        0.5, 0.75
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("acos", 0.5, 3, 2, xs.as_slice(), f32::acos);
+}
+
+#[test]
+fn test_atan_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("atan", 0.5, 3, 2, xs.as_slice(), f32::atan);
+}
+
+#[test]
+fn test_atan2_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    let ys = vec![
+        5.0, 6.0,
+        7.0, 8.0,
+        9.0, 10.0
+    ];
+    shared_test_fun2_is_applied_with_success_for_f32_and_matrix("atan2", 0.25, 0.5, 3, 2, xs.as_slice(), ys.as_slice(), f32::atan2);
+}
+
+#[test]
+fn test_sinh_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("sinh", 0.5, 3, 2, xs.as_slice(), f32::sinh);
+}
+
+#[test]
+fn test_cosh_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("cosh", 0.5, 3, 2, xs.as_slice(), f32::cosh);
+}
+
+#[test]
+fn test_asinh_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("asinh", 0.5, 3, 2, xs.as_slice(), f32::asinh);
+}
+
+#[test]
+fn test_acosh_is_applied_with_success()
+{
+    let xs = vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("acosh", 1.5, 3, 2, xs.as_slice(), f32::acosh);
+}
+
+#[test]
+fn test_atanh_is_applied_with_success()
+{
+    let xs = vec![
+        -0.5, 0.25,
+        0.0, 0.25,
+        0.5, 0.75
+    ];
+    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("atanh", 0.5, 3, 2, xs.as_slice(), f32::atanh);
+}
+
+#[test]
+fn test_sign_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("sign")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(1.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(1.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-3.5)]) {
+                Ok(value) => assert_eq!(Value::Float(-1.0), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.0, -1.0,
+                0.0, 1.0,
+                2.0, 3.0
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].signum(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!(-1.0, *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].signum(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_ceil_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("ceil")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(3.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(0.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(-2.0), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.6, -1.3,
+                0.0, 1.3,
+                2.6, 3.7
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].ceil(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!((-2.0), *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].ceil(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_floor_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("floor")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(2.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(0.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(-3.0), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.6, -1.3,
+                0.0, 1.3,
+                2.6, 3.7
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].floor(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!((-3.0), *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].floor(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_round_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("round")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(3.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(0.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(-3.0), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.6, -1.3,
+                0.0, 1.3,
+                2.6, 3.7
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].round(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!((-3.0), *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].round(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_trunc_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("trunc")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(2.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
+                Ok(value) => assert_eq!(Value::Float(0.0), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
+                Ok(value) => assert_eq!(Value::Float(-2.0), value),
+                Err(_) => assert!(false),
+            }
+            let xs = vec![
+                -2.6, -1.3,
+                0.0, 1.3,
+                2.6, 3.7
+            ];
+            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Matrix(matrix) => {
+                            assert_eq!(3, matrix.row_count());
+                            assert_eq!(2, matrix.col_count());
+                            assert_eq!(false, matrix.is_transposed());
+                            let ys = matrix.elems();
+                            for i in 0..(3usize * 2usize) {
+                                assert_eq!(xs[i].trunc(), ys[i]);
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match &elems[0] {
+                                Value::Float(b) => assert_eq!((-2.0), *b),
+                                _ => assert!(false),
+                            }
+                            match &elems[1] {
+                                Value::Object(object2) => {
+                                    match &**object2 {
+                                        Object::Matrix(matrix) => {
+                                            assert_eq!(3, matrix.row_count());
+                                            assert_eq!(2, matrix.col_count());
+                                            assert_eq!(false, matrix.is_transposed());
+                                            let ys = matrix.elems();
+                                            for i in 0..(3usize * 2usize) {
+                                                assert_eq!(xs[i].trunc(), ys[i]);
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_sinh_is_applied_with_success()
+fn test_rand_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("sinh", 0.5, 3, 2, xs.as_slice(), f32::sinh);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("rand")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[]) {
+                Ok(Value::Float(n)) => assert!(n >= 0.0 && n < 1.0),
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_cosh_is_applied_with_success()
+fn test_randi_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("cosh", 0.5, 3, 2, xs.as_slice(), f32::cosh);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("randi")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(10)]) {
+                Ok(Value::Int(n)) => assert!(n >= 1 && n <= 10),
+                _ => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(3), Value::Int(12)]) {
+                Ok(Value::Int(n)) => assert!(n >= 3 && n <= 12),
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_asinh_is_applied_with_success()
+fn test_str2int_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("asinh", 0.5, 3, 2, xs.as_slice(), f32::asinh);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("str2int")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(1234), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234xxx"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parseint"), String::from("invalid digit found in string"))));
+                    assert_eq!(expected_value, value);
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_acosh_is_applied_with_success()
+fn test_str2float_is_applied_with_success()
 {
-    let xs = vec![
-        1.0, 2.0,
-        3.0, 4.0,
-        5.0, 6.0
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("acosh", 1.5, 3, 2, xs.as_slice(), f32::acosh);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("str2float")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("12.34"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Float(12.34), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("12.34xxx"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parsefloat"), String::from("invalid float literal"))));
+                    assert_eq!(expected_value, value);
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_atanh_is_applied_with_success()
+fn test_hex2dec_is_applied_with_success()
 {
-    let xs = vec![
-        -0.5, 0.25,
-        0.0, 0.25,
-        0.5, 0.75
-    ];
-    shared_test_fun1_is_applied_with_success_for_f32_and_matrix("atanh", 0.5, 3, 2, xs.as_slice(), f32::atanh);
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("hex2dec")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("123abc"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("0x123abc"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("0X123ABC"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("123abcxxx"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parseint"), String::from("invalid digit found in string"))));
+                    assert_eq!(expected_value, value);
+                },
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_char2code_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("char2code")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("a"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(97), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("ą"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(261), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Int(49), value),
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::new())));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::None, value),
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
+}
+
+#[test]
+fn test_code2char_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("code2char")) {
+        Some(fun_value) => {
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(97)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("a")))), value),
+                _ => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(261)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("ą")))), value),
+                _ => assert!(false),
+            }
+        },
+        None => assert!(false),
+    }
 }
 
 #[test]
-fn test_sign_is_applied_with_success()
+fn test_formatmillis_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3201,76 +4970,27 @@ fn test_sign_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("sign")) {
+    match root_mod_g.var(&String::from("formatmillis")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(1.0), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("s"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1234 * 1000 + 567)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234.567s")))), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(1.0), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("ms"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(123 * 60 * 1000 + 4 * 1000 + 567)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("123m4.567s")))), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-3.5)]) {
-                Ok(value) => assert_eq!(Value::Float(-1.0), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("hms"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(12 * 60 * 60 * 1000 + 3 * 60 * 1000 + 4 * 1000 + 567)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("12h3m4.567s")))), value),
                 Err(_) => assert!(false),
             }
-            let xs = vec![
-                -2.0, -1.0,
-                0.0, 1.0,
-                2.0, 3.0
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].signum(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!(-1.0, *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].signum(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
-                                },
-                                _ => assert!(false),
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("xxx"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1234 * 1000 + 567)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("invalid format")))), value),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
@@ -3278,7 +4998,7 @@ fn test_sign_is_applied_with_success()
 }
 
 #[test]
-fn test_ceil_is_applied_with_success()
+fn test_withwidth_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3286,76 +5006,55 @@ fn test_ceil_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("ceil")) {
+    match root_mod_g.var(&String::from("withwidth")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(3.0), value),
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(0.0), value),
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("left"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(-2.0), value),
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("l"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
                 Err(_) => assert!(false),
             }
-            let xs = vec![
-                -2.6, -1.3,
-                0.0, 1.3,
-                2.6, 3.7
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].ceil(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("center"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("   1234   ")))), value),
+                Err(_) => assert!(false),
             }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!((-2.0), *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].ceil(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
-                                },
-                                _ => assert!(false),
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("c"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("   1234   ")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("right"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("      1234")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("r"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("      1234")))), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("left"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("xxx"))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("invalid alignment")))), value),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
@@ -3363,7 +5062,7 @@ fn test_ceil_is_applied_with_success()
 }
 
 #[test]
-fn test_floor_is_applied_with_success()
+fn test_withzeros_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3371,84 +5070,40 @@ fn test_floor_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("floor")) {
+    match root_mod_g.var(&String::from("withzeros")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(2.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(0.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(-3.0), value),
-                Err(_) => assert!(false),
-            }
-            let xs = vec![
-                -2.6, -1.3,
-                0.0, 1.3,
-                2.6, 3.7
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].floor(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!((-3.0), *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].floor(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
-                                },
-                                _ => assert!(false),
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("0000001234")))), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
     }
 }
 
+fn shared_test_fun_is_existent(fun_name: &str, f: fn(&mut Interp, &mut Env, &[Value]) -> Result<Value>)
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    match root_mod.var(&String::from(fun_name)) {
+        Some(fun_value) => {
+            let expected_fun_value = Value::Object(Arc::new(Object::BuiltinFun(String::from(fun_name), f)));
+            assert_eq!(expected_fun_value, *fun_value);
+        },
+        None => assert!(false),
+    }
+}
+
 #[test]
-fn test_round_is_applied_with_success()
+fn test_readline_is_existent()
+{ shared_test_fun_is_existent("readline", readline); }
+
+#[test]
+fn test_format_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3456,84 +5111,20 @@ fn test_round_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("round")) {
+    match root_mod_g.var(&String::from("format")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(3.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(0.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(-3.0), value),
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from(" abc "))));
+            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), arg_value2, Value::Float(12.34)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234 abc 12.3400")))), value),
                 Err(_) => assert!(false),
             }
-            let xs = vec![
-                -2.6, -1.3,
-                0.0, 1.3,
-                2.6, 3.7
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].round(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!((-3.0), *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].round(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
-                                },
-                                _ => assert!(false),
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
         },
         None => assert!(false),
     }
 }
 
 #[test]
-fn test_trunc_is_applied_with_success()
+fn test_format_is_applied_with_success_for_format_specifications()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3541,71 +5132,31 @@ fn test_trunc_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("trunc")) {
+    match root_mod_g.var(&String::from("format")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(2.0), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(0.0)]) {
-                Ok(value) => assert_eq!(Value::Float(0.0), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("{:.4} {:x} [{:>6}] [{:<4}] [{:*^7}] [{:08.3}] [{:+}] {{}}"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("ab"))));
+            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("mid"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Float(0.123456789), Value::Int(255), arg_value2, Value::Int(12), arg_value3, Value::Float(-3.14159), Value::Int(5)]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("0.1235 ff [    ab] [12  ] [**mid**] [-003.142] [+5] {}")))), value),
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Float(-2.5)]) {
-                Ok(value) => assert_eq!(Value::Float(-2.0), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("{name}: {n:X} {2:.1e} {3:.2}"))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("name"), Value::Object(Arc::new(Object::String(String::from("abc")))));
+            fields.insert(String::from("n"), Value::Int(-255));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2, Value::Float(1234.5), Value::Object(Arc::new(Object::String(String::from("defg"))))]) {
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("abc: -FF 1.2e3 de")))), value),
                 Err(_) => assert!(false),
             }
-            let xs = vec![
-                -2.6, -1.3,
-                0.0, 1.3,
-                2.6, 3.7
-            ];
-            let arg_value = Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("{} {}"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1)]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
-                        Object::Matrix(matrix) => {
-                            assert_eq!(3, matrix.row_count());
-                            assert_eq!(2, matrix.col_count());
-                            assert_eq!(false, matrix.is_transposed());
-                            let ys = matrix.elems();
-                            for i in 0..(3usize * 2usize) {
-                                assert_eq!(xs[i].trunc(), ys[i]);
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(-2.5), Value::Object(Arc::new(Object::Matrix(Matrix::new_with_elems(3, 2, xs.as_slice()))))]))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(2, elems.len());
-                            match &elems[0] {
-                                Value::Float(b) => assert_eq!((-2.0), *b),
-                                _ => assert!(false),
-                            }
-                            match &elems[1] {
-                                Value::Object(object2) => {
-                                    match &**object2 {
-                                        Object::Matrix(matrix) => {
-                                            assert_eq!(3, matrix.row_count());
-                                            assert_eq!(2, matrix.col_count());
-                                            assert_eq!(false, matrix.is_transposed());
-                                            let ys = matrix.elems();
-                                            for i in 0..(3usize * 2usize) {
-                                                assert_eq!(xs[i].trunc(), ys[i]);
-                                            }
-                                        },
-                                        _ => assert!(false),
-                                    }
-                                },
-                                _ => assert!(false),
-                            }
+                        Object::Error(err_kind, msg) => {
+                            assert_eq!(String::from("format"), *err_kind);
+                            assert_eq!(String::from("too few arguments"), *msg);
                         },
                         _ => assert!(false),
                     }
@@ -3618,27 +5169,31 @@ fn test_trunc_is_applied_with_success()
 }
 
 #[test]
-fn test_rand_is_applied_with_success()
-{
-    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
-    add_std_builtin_funs(&mut root_mod);
-    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
-    let mut interp = Interp::new();
-    let root_mod = env.root_mod().clone();
-    let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("rand")) {
-        Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[]) {
-                Ok(Value::Float(n)) => assert!(n >= 0.0 && n < 1.0),
-                _ => assert!(false),
-            }
-        },
-        None => assert!(false),
-    }
-}
+fn test_print_is_existent()
+{ shared_test_fun_is_existent("print", print); }
 
 #[test]
-fn test_randi_is_applied_with_success()
+fn test_println_is_existent()
+{ shared_test_fun_is_existent("println", println); }
+
+#[test]
+fn test_eprint_is_existent()
+{ shared_test_fun_is_existent("eprint", eprint); }
+
+#[test]
+fn test_eprintln_is_existent()
+{ shared_test_fun_is_existent("eprintln", eprintln); }
+
+#[test]
+fn test_flush_is_existent()
+{ shared_test_fun_is_existent("flush", flush); }
+
+#[test]
+fn test_eflush_is_existent()
+{ shared_test_fun_is_existent("eflush", eflush); }
+
+#[sealed_test]
+fn test_cd_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3646,23 +5201,37 @@ fn test_randi_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("randi")) {
+    match root_mod_g.var(&String::from("cd")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(10)]) {
-                Ok(Value::Int(n)) => assert!(n >= 1 && n <= 10),
-                _ => assert!(false),
+            let old_curerent_dir = std::env::current_dir().unwrap();
+            fs::create_dir("test").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    assert_eq!(Value::Object(Arc::new(Object::String(old_curerent_dir.to_string_lossy().into_owned()))), value);
+                    let new_current_dir = std::env::current_dir().unwrap();
+                    assert_eq!(true, new_current_dir.ends_with("test"));
+                },
+                Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(3), Value::Int(12)]) {
-                Ok(Value::Int(n)) => assert!(n >= 3 && n <= 12),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
                 _ => assert!(false),
             }
+            std::env::set_current_dir(old_curerent_dir.as_path()).unwrap();
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_str2int_is_applied_with_success()
+#[sealed_test]
+fn test_pwd_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3670,28 +5239,28 @@ fn test_str2int_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("str2int")) {
+    match root_mod_g.var(&String::from("pwd")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(1234), value),
-                _ => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234xxx"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => {
-                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parseint"), String::from("invalid digit found in string"))));
-                    assert_eq!(expected_value, value);
+            let old_curerent_dir = std::env::current_dir().unwrap();
+            fs::create_dir("test").unwrap();
+            std::env::set_current_dir("test").unwrap();
+            match fun_value.apply(&mut interp, &mut env, &[]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::String(s) => assert_eq!(true, PathBuf::from(s).ends_with("test")),
+                        _ => assert!(false),
+                    }
                 },
                 _ => assert!(false),
             }
+            std::env::set_current_dir(old_curerent_dir.as_path()).unwrap();
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_str2float_is_applied_with_success()
+#[sealed_test]
+fn test_exist_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3699,28 +5268,32 @@ fn test_str2float_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("str2float")) {
+    match root_mod_g.var(&String::from("exist")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("12.34"))));
+            fs::write("test.txt", "some text").unwrap();
+            fs::create_dir("test").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Float(12.34), value),
-                _ => assert!(false),
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("12.34xxx"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => {
-                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parsefloat"), String::from("invalid float literal"))));
-                    assert_eq!(expected_value, value);
-                },
-                _ => assert!(false),
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(Value::Bool(false), value),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_hex2dec_is_applied_with_success()
+#[sealed_test]
+fn test_filetype_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3728,28 +5301,27 @@ fn test_hex2dec_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("hex2dec")) {
+    match root_mod_g.var(&String::from("filetype")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("123abc"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
-                _ => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("0x123abc"))));
+            fs::write("test.txt", "some text").unwrap();
+            fs::create_dir("test").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
-                _ => assert!(false),
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("file")))), value),
+                Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("0X123ABC"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(0x123abc), value),
-                _ => assert!(false),
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("dir")))), value),
+                Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("123abcxxx"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => {
-                    let expected_value = Value::Object(Arc::new(Object::Error(String::from("parseint"), String::from("invalid digit found in string"))));
-                    assert_eq!(expected_value, value);
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
                 },
                 _ => assert!(false),
             }
@@ -3758,8 +5330,8 @@ fn test_hex2dec_is_applied_with_success()
     }
 }
 
-#[test]
-fn test_char2code_is_applied_with_success()
+#[sealed_test]
+fn test_dir_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3767,26 +5339,52 @@ fn test_char2code_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("char2code")) {
+    match root_mod_g.var(&String::from("dir")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("a"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(97), value),
-                _ => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("ą"))));
+            fs::write("test.txt", "some text").unwrap();
+            fs::create_dir("test").unwrap();
+            fs::create_dir("test2").unwrap();
+            let mut path_buf = PathBuf::from("test2");
+            path_buf.push("test.txt");
+            fs::write(path_buf.as_path(), "second some text").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("."))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(261), value),
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(3, elems.len());
+                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test.txt"))))));
+                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test"))))));
+                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test2"))))));
+                        },
+                        _ => assert!(false),
+                    }
+                },
                 _ => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("1234"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Int(49), value),
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(1, elems.len());
+                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test.txt"))))));
+                        },
+                        _ => assert!(false),
+                    }
+                },
                 _ => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::new())));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::None, value),
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
                 _ => assert!(false),
             }
         },
@@ -3795,31 +5393,18 @@ fn test_char2code_is_applied_with_success()
 }
 
 #[test]
-fn test_code2char_is_applied_with_success()
+fn test_ls_is_alias_to_dir()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
-    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
-    let mut interp = Interp::new();
-    let root_mod = env.root_mod().clone();
-    let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("code2char")) {
-        Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(97)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("a")))), value),
-                _ => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(261)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("ą")))), value),
-                _ => assert!(false),
-            }
-        },
-        None => assert!(false),
+    match (root_mod.var(&String::from("ls")), root_mod.var(&String::from("dir"))) {
+        (Some(alias_value), Some(fun_value)) => assert_eq!(fun_value, alias_value),
+        (_, _) => assert!(false),
     }
 }
 
-#[test]
-fn test_formatmillis_is_applied_with_success()
+#[sealed_test]
+fn test_mkdir_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3827,35 +5412,38 @@ fn test_formatmillis_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("formatmillis")) {
+    match root_mod_g.var(&String::from("mkdir")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("s"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1234 * 1000 + 567)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234.567s")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("ms"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(123 * 60 * 1000 + 4 * 1000 + 567)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("123m4.567s")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("hms"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(12 * 60 * 60 * 1000 + 3 * 60 * 1000 + 4 * 1000 + 567)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("12h3m4.567s")))), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::metadata("test") {
+                        Ok(metadata) => assert_eq!(true, metadata.is_dir()),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("xxx"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1234 * 1000 + 567)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("invalid format")))), value),
-                Err(_) => assert!(false),
+            let mut path_buf = PathBuf::from("test2");
+            path_buf.push("test");
+            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
             }
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_withwidth_is_applied_with_success()
+#[sealed_test]
+fn test_rmdir_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3863,63 +5451,42 @@ fn test_withwidth_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("withwidth")) {
+    match root_mod_g.var(&String::from("rmdir")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("left"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("l"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234      ")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("center"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("   1234   ")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("c"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("   1234   ")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("right"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("      1234")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("r"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("      1234")))), value),
-                Err(_) => assert!(false),
-            }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("left"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
+            fs::create_dir("test").unwrap();
+            fs::create_dir("test2").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::metadata("test") {
+                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
+                        Ok(_) => assert!(false), 
+                    }
+                    match fs::metadata("test2") {
+                        Ok(metadata) => assert_eq!(true, metadata.is_dir()),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value3 = Value::Object(Arc::new(Object::String(String::from("xxx"))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10), arg_value3]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("invalid alignment")))), value),
-                Err(_) => assert!(false),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
             }
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_withzeros_is_applied_with_success()
+#[sealed_test]
+fn test_rmfile_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3927,40 +5494,42 @@ fn test_withzeros_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("withzeros")) {
+    match root_mod_g.var(&String::from("rmfile")) {
         Some(fun_value) => {
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(10)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("0000001234")))), value),
+            fs::write("test.txt", "some text").unwrap();
+            fs::write("test2.txt", "second some text").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::metadata("test.txt") {
+                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
+                        Ok(_) => assert!(false), 
+                    }
+                    match fs::metadata("test2.txt") {
+                        Ok(metadata) => assert_eq!(true, metadata.is_file()),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), Value::Int(-5)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::Error(String::from("format"), String::from("width is negative")))), value),
-                Err(_) => assert!(false),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
             }
         },
         None => assert!(false),
     }
 }
 
-fn shared_test_fun_is_existent(fun_name: &str, f: fn(&mut Interp, &mut Env, &[Value]) -> Result<Value>)
-{
-    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
-    add_std_builtin_funs(&mut root_mod);
-    match root_mod.var(&String::from(fun_name)) {
-        Some(fun_value) => {
-            let expected_fun_value = Value::Object(Arc::new(Object::BuiltinFun(String::from(fun_name), f)));
-            assert_eq!(expected_fun_value, *fun_value);
-        },
-        None => assert!(false),
-    }
-}
-
-#[test]
-fn test_readline_is_existent()
-{ shared_test_fun_is_existent("readline", readline); }
-
-#[test]
-fn test_format_is_applied_with_success()
+#[sealed_test]
+fn test_copy_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -3968,44 +5537,43 @@ fn test_format_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("format")) {
+    match root_mod_g.var(&String::from("copy")) {
         Some(fun_value) => {
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from(" abc "))));
-            match fun_value.apply(&mut interp, &mut env, &[Value::Int(1234), arg_value2, Value::Float(12.34)]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("1234 abc 12.3400")))), value),
+            fs::write("test.txt", "some text").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::read_to_string("test.txt") {
+                        Ok(s) => assert_eq!(String::from("some text"), s),
+                        Err(_) => assert!(false),
+                    }
+                    match fs::read_to_string("test2.txt") {
+                        Ok(s) => assert_eq!(String::from("some text"), s),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test4.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
         },
         None => assert!(false),
     }
 }
 
-#[test]
-fn test_print_is_existent()
-{ shared_test_fun_is_existent("print", print); }
-
-#[test]
-fn test_println_is_existent()
-{ shared_test_fun_is_existent("println", println); }
-
-#[test]
-fn test_eprint_is_existent()
-{ shared_test_fun_is_existent("eprint", eprint); }
-
-#[test]
-fn test_eprintln_is_existent()
-{ shared_test_fun_is_existent("eprintln", eprintln); }
-
-#[test]
-fn test_flush_is_existent()
-{ shared_test_fun_is_existent("flush", flush); }
-
-#[test]
-fn test_eflush_is_existent()
-{ shared_test_fun_is_existent("eflush", eflush); }
-
 #[sealed_test]
-fn test_cd_is_applied_with_success()
+fn test_rename_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4013,21 +5581,28 @@ fn test_cd_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("cd")) {
+    match root_mod_g.var(&String::from("rename")) {
         Some(fun_value) => {
-            let old_curerent_dir = std::env::current_dir().unwrap();
-            fs::create_dir("test").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            fs::write("test.txt", "some text").unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(value) => {
-                    assert_eq!(Value::Object(Arc::new(Object::String(old_curerent_dir.to_string_lossy().into_owned()))), value);
-                    let new_current_dir = std::env::current_dir().unwrap();
-                    assert_eq!(true, new_current_dir.ends_with("test"));
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::metadata("test.txt") {
+                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
+                        Ok(_) => assert!(false),
+                    }
+                    match fs::read_to_string("test2.txt") {
+                        Ok(s) => assert_eq!(String::from("some text"), s),
+                        Err(_) => assert!(false),
+                    }
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test4.txt"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4036,14 +5611,21 @@ fn test_cd_is_applied_with_success()
                 },
                 _ => assert!(false),
             }
-            std::env::set_current_dir(old_curerent_dir.as_path()).unwrap();
         },
         None => assert!(false),
     }
 }
 
+#[test]
+fn test_spawn_is_existent()
+{ shared_test_fun_is_existent("spawn", spawn); }
+
+#[test]
+fn test_exit_is_existent()
+{ shared_test_fun_is_existent("exit", exit); }
+
 #[sealed_test]
-fn test_pwd_is_applied_with_success()
+fn test_load_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4051,28 +5633,34 @@ fn test_pwd_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("pwd")) {
+    match root_mod_g.var(&String::from("load")) {
         Some(fun_value) => {
-            let old_curerent_dir = std::env::current_dir().unwrap();
-            fs::create_dir("test").unwrap();
-            std::env::set_current_dir("test").unwrap();
-            match fun_value.apply(&mut interp, &mut env, &[]) {
+            save_values("test.bin", &[Value::Int(1), Value::Float(2.0), Value::Bool(false)]).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)]))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.bin"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
-                        Object::String(s) => assert_eq!(true, PathBuf::from(s).ends_with("test")),
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
                         _ => assert!(false),
                     }
                 },
                 _ => assert!(false),
             }
-            std::env::set_current_dir(old_curerent_dir.as_path()).unwrap();
         },
         None => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_exist_is_applied_with_success()
+fn test_save_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4080,24 +5668,30 @@ fn test_exist_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("exist")) {
+    match root_mod_g.var(&String::from("save")) {
         Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            fs::create_dir("test").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Bool(true), value),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1), Value::Float(2.0), Value::Bool(false)]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match load_values("test.bin", &env) {
+                        Ok(values) => assert_eq!(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)], values),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
+            let mut path_buf = PathBuf::from("test");
+            path_buf.push("test.bin");
+            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Bool(false), value),
-                Err(_) => assert!(false),
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
             }
         },
         None => assert!(false),
@@ -4105,7 +5699,7 @@ fn test_exist_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_filetype_is_applied_with_success()
+fn test_loadstr_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4113,21 +5707,15 @@ fn test_filetype_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("filetype")) {
+    match root_mod_g.var(&String::from("loadstr")) {
         Some(fun_value) => {
             fs::write("test.txt", "some text").unwrap();
-            fs::create_dir("test").unwrap();
             let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("file")))), value),
-                Err(_) => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("dir")))), value),
+                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("some text")))), value),
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
@@ -4143,54 +5731,33 @@ fn test_filetype_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_dir_is_applied_with_success()
+fn test_savestr_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
     let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
-    let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("dir")) {
-        Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            fs::create_dir("test").unwrap();
-            fs::create_dir("test2").unwrap();
-            let mut path_buf = PathBuf::from("test2");
-            path_buf.push("test.txt");
-            fs::write(path_buf.as_path(), "second some text").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("."))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(3, elems.len());
-                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test.txt"))))));
-                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test"))))));
-                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test2"))))));
-                        },
-                        _ => assert!(false),
-                    }
-                },
-                _ => assert!(false),
-            }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Ref(object)) => {
-                    let object_g = object.read().unwrap();
-                    match &*object_g {
-                        MutObject::Array(elems) => {
-                            assert_eq!(1, elems.len());
-                            assert_eq!(true, elems.contains(&Value::Object(Arc::new(Object::String(String::from("test.txt"))))));
-                        },
-                        _ => assert!(false),
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("savestr")) {
+        Some(fun_value) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("some text"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::read_to_string("test.txt") {
+                        Ok(s) => assert_eq!(String::from("some text"), s),
+                        Err(_) => assert!(false),
                     }
                 },
-                _ => assert!(false),
+                Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let mut path_buf = PathBuf::from("test");
+            path_buf.push("test.txt");
+            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("some text"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4204,19 +5771,8 @@ fn test_dir_is_applied_with_success()
     }
 }
 
-#[test]
-fn test_ls_is_alias_to_dir()
-{
-    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
-    add_std_builtin_funs(&mut root_mod);
-    match (root_mod.var(&String::from("ls")), root_mod.var(&String::from("dir"))) {
-        (Some(alias_value), Some(fun_value)) => assert_eq!(fun_value, alias_value),
-        (_, _) => assert!(false),
-    }
-}
-
 #[sealed_test]
-fn test_mkdir_is_applied_with_success()
+fn test_loadtoml_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4224,22 +5780,28 @@ fn test_mkdir_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("mkdir")) {
+    match root_mod_g.var(&String::from("loadtoml")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
+            let s = "
+a = 1
+b = 2.0
+c = false
+";
+            let s2 = &s[1..];
+            fs::write("test.toml", s2).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.toml"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::metadata("test") {
-                        Ok(metadata) => assert_eq!(true, metadata.is_dir()),
-                        Err(_) => assert!(false),
-                    }
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("a"), Value::Int(1));
+                    expected_fields.insert(String::from("b"), Value::Float(2.0));
+                    expected_fields.insert(String::from("c"), Value::Bool(false));
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields))));
+                    assert_eq!(expected_value, value);
                 },
                 Err(_) => assert!(false),
             }
-            let mut path_buf = PathBuf::from("test2");
-            path_buf.push("test");
-            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.toml"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
@@ -4255,7 +5817,7 @@ fn test_mkdir_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_rmdir_is_applied_with_success()
+fn test_savetoml_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4263,27 +5825,36 @@ fn test_rmdir_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("rmdir")) {
+    match root_mod_g.var(&String::from("savetoml")) {
         Some(fun_value) => {
-            fs::create_dir("test").unwrap();
-            fs::create_dir("test2").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.toml"))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("a"), Value::Int(1));
+            fields.insert(String::from("b"), Value::Float(2.0));
+            fields.insert(String::from("c"), Value::Bool(false));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
                 Ok(value) => {
                     assert_eq!(Value::Bool(true), value);
-                    match fs::metadata("test") {
-                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
-                        Ok(_) => assert!(false), 
-                    }
-                    match fs::metadata("test2") {
-                        Ok(metadata) => assert_eq!(true, metadata.is_dir()),
+                    match fs::read_to_string("test.toml") {
+                        Ok(s) => {
+                            let expected_s = "
+a = 1
+b = 2.0
+c = false
+";
+                            let expected_s2 = &expected_s[1..];
+                            assert_eq!(String::from(expected_s2), s);
+                        },
                         Err(_) => assert!(false),
                     }
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let mut path_buf = PathBuf::from("test");
+            path_buf.push("test.toml");
+            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4298,7 +5869,7 @@ fn test_rmdir_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_rmfile_is_applied_with_success()
+fn test_loadjson_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4306,26 +5877,30 @@ fn test_rmfile_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("rmfile")) {
+    match root_mod_g.var(&String::from("loadjson")) {
         Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            fs::write("test2.txt", "second some text").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
+            let s = "
+{
+    \"a\": 1,
+    \"b\": 2.0,
+    \"c\": false
+}
+";
+            let s2 = &s[1..];
+            fs::write("test.json", s2).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.json"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::metadata("test.txt") {
-                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
-                        Ok(_) => assert!(false), 
-                    }
-                    match fs::metadata("test2.txt") {
-                        Ok(metadata) => assert_eq!(true, metadata.is_file()),
-                        Err(_) => assert!(false),
-                    }
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("a"), Value::Int(1));
+                    expected_fields.insert(String::from("b"), Value::Float(2.0));
+                    expected_fields.insert(String::from("c"), Value::Bool(false));
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields))));
+                    assert_eq!(expected_value, value);
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.json"))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
@@ -4341,7 +5916,7 @@ fn test_rmfile_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_copy_is_applied_with_success()
+fn test_savejson_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4349,27 +5924,30 @@ fn test_copy_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("copy")) {
+    match root_mod_g.var(&String::from("savejson")) {
         Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.json"))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("a"), Value::Int(1));
+            fields.insert(String::from("b"), Value::Float(2.0));
+            fields.insert(String::from("c"), Value::Bool(false));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
                 Ok(value) => {
                     assert_eq!(Value::Bool(true), value);
-                    match fs::read_to_string("test.txt") {
-                        Ok(s) => assert_eq!(String::from("some text"), s),
-                        Err(_) => assert!(false),
-                    }
-                    match fs::read_to_string("test2.txt") {
-                        Ok(s) => assert_eq!(String::from("some text"), s),
+                    match fs::read_to_string("test.json") {
+                        Ok(s) => {
+                            let expected_s = "{\"a\":1,\"b\":2.0,\"c\":false}";
+                            assert_eq!(String::from(expected_s), s);
+                        },
                         Err(_) => assert!(false),
                     }
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test4.txt"))));
+            let mut path_buf = PathBuf::from("test");
+            path_buf.push("test.json");
+            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
             match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
@@ -4385,7 +5963,7 @@ fn test_copy_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_rename_is_applied_with_success()
+fn test_savejson_and_loadjson_preserve_double_precision()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4393,28 +5971,118 @@ fn test_rename_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("rename")) {
+    match (root_mod_g.var(&String::from("savejson")), root_mod_g.var(&String::from("loadjson"))) {
+        (Some(fun_value), Some(fun_value2)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.json"))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("a"), Value::Float(0.1));
+            fields.insert(String::from("b"), Value::Float(16777217.125));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2.clone()]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(arg_value2, value),
+                Err(_) => assert!(false),
+            }
+        },
+        (_, _) => assert!(false),
+    }
+}
+
+#[sealed_test]
+fn test_loadcsv_is_applied_with_success()
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+    let mut interp = Interp::new();
+    let root_mod = env.root_mod().clone();
+    let root_mod_g = root_mod.read().unwrap();
+    match root_mod_g.var(&String::from("loadcsv")) {
         Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+            let s = "
+a,b,c,d
+1,2.5,\"x, \"\"y\"\"\",true
+2,,z,false
+";
+            let s2 = &s[1..];
+            fs::write("test.csv", s2).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.csv"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
                 Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::metadata("test.txt") {
-                        Err(err) => assert_eq!(ErrorKind::NotFound, err.kind()),
-                        Ok(_) => assert!(false),
-                    }
-                    match fs::read_to_string("test2.txt") {
-                        Ok(s) => assert_eq!(String::from("some text"), s),
-                        Err(_) => assert!(false),
-                    }
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("a"), Value::Int(1));
+                    expected_fields.insert(String::from("b"), Value::Float(2.5));
+                    expected_fields.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("x, \"y\"")))));
+                    expected_fields.insert(String::from("d"), Value::Bool(true));
+                    let mut expected_fields2: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields2.insert(String::from("a"), Value::Int(2));
+                    expected_fields2.insert(String::from("b"), Value::None);
+                    expected_fields2.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("z")))));
+                    expected_fields2.insert(String::from("d"), Value::Bool(false));
+                    let expected_elems = vec![
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields)))),
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields2))))
+                    ];
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(expected_elems))));
+                    assert_eq!(expected_value, value);
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test3.txt"))));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("test4.txt"))));
+            let mut opt_fields: BTreeMap<String, Value> = BTreeMap::new();
+            opt_fields.insert(String::from("types"), Value::Object(Arc::new(Object::String(String::from("string")))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(opt_fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2]) {
+                Ok(value) => {
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("a"), Value::Object(Arc::new(Object::String(String::from("1")))));
+                    expected_fields.insert(String::from("b"), Value::Object(Arc::new(Object::String(String::from("2.5")))));
+                    expected_fields.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("x, \"y\"")))));
+                    expected_fields.insert(String::from("d"), Value::Object(Arc::new(Object::String(String::from("true")))));
+                    let mut expected_fields2: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields2.insert(String::from("a"), Value::Object(Arc::new(Object::String(String::from("2")))));
+                    expected_fields2.insert(String::from("b"), Value::Object(Arc::new(Object::String(String::new()))));
+                    expected_fields2.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("z")))));
+                    expected_fields2.insert(String::from("d"), Value::Object(Arc::new(Object::String(String::from("false")))));
+                    let expected_elems = vec![
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields)))),
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields2))))
+                    ];
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(expected_elems))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            let s = "
+1\t2
+3\t4
+";
+            let s2 = &s[1..];
+            fs::write("test.tsv", s2).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.tsv"))));
+            let mut opt_fields: BTreeMap<String, Value> = BTreeMap::new();
+            opt_fields.insert(String::from("delimiter"), Value::Object(Arc::new(Object::String(String::from("\t")))));
+            opt_fields.insert(String::from("header"), Value::Bool(false));
+            opt_fields.insert(String::from("types"), Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                Value::Object(Arc::new(Object::String(String::from("float")))),
+                Value::Object(Arc::new(Object::String(String::from("int"))))
+            ])))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(opt_fields))));
             match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+                Ok(value) => {
+                    let expected_elems = vec![
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(1.0), Value::Int(2)])))),
+                        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(3.0), Value::Int(4)]))))
+                    ];
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(expected_elems))));
+                    assert_eq!(expected_value, value);
+                },
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.csv"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4428,16 +6096,8 @@ fn test_rename_is_applied_with_success()
     }
 }
 
-#[test]
-fn test_spawn_is_existent()
-{ shared_test_fun_is_existent("spawn", spawn); }
-
-#[test]
-fn test_exit_is_existent()
-{ shared_test_fun_is_existent("exit", exit); }
-
 #[sealed_test]
-fn test_load_is_applied_with_success()
+fn test_loadcsv_complains_on_malformed_rows()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4445,22 +6105,63 @@ fn test_load_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("load")) {
+    match root_mod_g.var(&String::from("loadcsv")) {
         Some(fun_value) => {
-            save_values("test.bin", &[Value::Int(1), Value::Float(2.0), Value::Bool(false)]).unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => {
-                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)]))));
-                    assert_eq!(expected_value, value);
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.csv"))));
+            let s = "
+a,b
+1,2
+3
+";
+            let s2 = &s[1..];
+            fs::write("test.csv", s2).unwrap();
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, msg) => {
+                            assert_eq!(String::from("csv"), *err_kind);
+                            assert_eq!(String::from("line 3: invalid number of fields"), *msg);
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let s = "
+a,b
+1,\"2
+";
+            let s2 = &s[1..];
+            fs::write("test.csv", s2).unwrap();
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone()]) {
+                Ok(Value::Object(object)) => {
+                    match &*object {
+                        Object::Error(err_kind, msg) => {
+                            assert_eq!(String::from("csv"), *err_kind);
+                            assert_eq!(String::from("line 2: unclosed quote"), *msg);
+                        },
+                        _ => assert!(false),
+                    }
                 },
-                Err(_) => assert!(false),
+                _ => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.bin"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let s = "
+a,b
+1,2
+x,4
+";
+            let s2 = &s[1..];
+            fs::write("test.csv", s2).unwrap();
+            let mut opt_fields: BTreeMap<String, Value> = BTreeMap::new();
+            opt_fields.insert(String::from("types"), Value::Object(Arc::new(Object::String(String::from("int")))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(opt_fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
-                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        Object::Error(err_kind, msg) => {
+                            assert_eq!(String::from("csv"), *err_kind);
+                            assert_eq!(String::from("line 3: invalid value in column 1"), *msg);
+                        },
                         _ => assert!(false),
                     }
                 },
@@ -4472,7 +6173,7 @@ fn test_load_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_save_is_applied_with_success()
+fn test_savecsv_is_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4480,30 +6181,59 @@ fn test_save_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("save")) {
+    match root_mod_g.var(&String::from("savecsv")) {
         Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, Value::Int(1), Value::Float(2.0), Value::Bool(false)]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.csv"))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("a"), Value::Int(1));
+            fields.insert(String::from("b"), Value::Float(2.5));
+            fields.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("x, \"y\"")))));
+            let mut fields2: BTreeMap<String, Value> = BTreeMap::new();
+            fields2.insert(String::from("a"), Value::Int(2));
+            fields2.insert(String::from("b"), Value::None);
+            fields2.insert(String::from("c"), Value::Object(Arc::new(Object::String(String::from("z")))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))),
+                Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields2))))
+            ]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
                 Ok(value) => {
                     assert_eq!(Value::Bool(true), value);
-                    match load_values("test.bin", &env) {
-                        Ok(values) => assert_eq!(vec![Value::Int(1), Value::Float(2.0), Value::Bool(false)], values),
+                    match fs::read_to_string("test.csv") {
+                        Ok(s) => {
+                            let expected_s = "
+a,b,c
+1,2.5,\"x, \"\"y\"\"\"
+2,,z
+";
+                            assert_eq!(String::from(&expected_s[1..]), s);
+                        },
                         Err(_) => assert!(false),
                     }
                 },
                 Err(_) => assert!(false),
             }
-            let mut path_buf = PathBuf::from("test");
-            path_buf.push("test.bin");
-            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
-                        _ => assert!(false),
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.tsv"))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.0)])))),
+                Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(3), Value::Bool(true)]))))
+            ]))));
+            let mut opt_fields: BTreeMap<String, Value> = BTreeMap::new();
+            opt_fields.insert(String::from("delimiter"), Value::Object(Arc::new(Object::String(String::from("\t")))));
+            opt_fields.insert(String::from("columns"), Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                Value::Object(Arc::new(Object::String(String::from("x")))),
+                Value::Object(Arc::new(Object::String(String::from("y"))))
+            ])))));
+            let arg_value3 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(opt_fields))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2, arg_value3]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match fs::read_to_string("test.tsv") {
+                        Ok(s) => assert_eq!(String::from("x\ty\n1\t2\n3\ttrue\n"), s),
+                        Err(_) => assert!(false),
                     }
                 },
-                _ => assert!(false),
+                Err(_) => assert!(false),
             }
         },
         None => assert!(false),
@@ -4511,7 +6241,7 @@ fn test_save_is_applied_with_success()
 }
 
 #[sealed_test]
-fn test_loadstr_is_applied_with_success()
+fn test_savenpy_and_savenpz_are_applied_with_success_for_host_matrices()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4519,16 +6249,40 @@ fn test_loadstr_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("loadstr")) {
-        Some(fun_value) => {
-            fs::write("test.txt", "some text").unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("some text")))), value),
+    match (root_mod_g.var(&String::from("savenpy")), root_mod_g.var(&String::from("savenpz")), root_mod_g.var(&String::from("loadnpy"))) {
+        (Some(fun_value), Some(fun_value2), Some(fun_value3)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.npy"))));
+            let arg_value2 = Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 2, 3, 4]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match load_npy("test.npy") {
+                        Ok(a) => assert_eq!(NpyArray::new(2, 2, false, vec![1.0, 2.0, 3.0, 4.0]), a),
+                        Err(_) => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.txt"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.npz"))));
+            let arg_value3 = Value::Object(Arc::new(Object::HostMatrix(1, 2, HostElems::Double(vec![5.0, 6.5]))));
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value, arg_value2, arg_value3]) {
+                Ok(value) => {
+                    assert_eq!(Value::Bool(true), value);
+                    match load_npz("test.npz") {
+                        Ok(arrays) => {
+                            let expected_arrays = vec![
+                                (String::from("arr_0"), NpyArray::new(2, 2, false, vec![1.0, 2.0, 3.0, 4.0])),
+                                (String::from("arr_1"), NpyArray::new(1, 2, false, vec![5.0, 6.5]))
+                            ];
+                            assert_eq!(expected_arrays, arrays);
+                        },
+                        Err(_) => assert!(false),
+                    }
+                },
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.npy"))));
+            match fun_value3.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4538,12 +6292,12 @@ fn test_loadstr_is_applied_with_success()
                 _ => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _, _) => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_savestr_is_applied_with_success()
+fn test_loadsafetensors_and_savesafetensors_are_applied_with_success_for_one_dimensional_tensors()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4551,40 +6305,44 @@ fn test_savestr_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("savestr")) {
-        Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.txt"))));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("some text"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+    match (root_mod_g.var(&String::from("loadsafetensors")), root_mod_g.var(&String::from("savesafetensors"))) {
+        (Some(fun_value), Some(fun_value2)) => {
+            let tensors = vec![
+                (String::from("l1.bias"), SafeTensor::new(vec![3], vec![1.0, 2.0, 3.0])),
+                (String::from("l1.weight"), SafeTensor::new(vec![3, 2], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]))
+            ];
+            save_safetensors("test.safetensors", tensors.as_slice(), &BTreeMap::new()).unwrap();
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.safetensors"))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::read_to_string("test.txt") {
-                        Ok(s) => assert_eq!(String::from("some text"), s),
+                    match value.field(&String::from("l1")).unwrap().field(&String::from("bias")).unwrap() {
+                        Value::Object(object) => {
+                            match &*object {
+                                Object::Tensor(shape, _) => assert_eq!(vec![3], *shape),
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                    let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.safetensors"))));
+                    match fun_value2.apply(&mut interp, &mut env, &[arg_value, value]) {
+                        Ok(value2) => assert_eq!(Value::Bool(true), value2),
                         Err(_) => assert!(false),
                     }
-                },
-                Err(_) => assert!(false),
-            }
-            let mut path_buf = PathBuf::from("test");
-            path_buf.push("test.txt");
-            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
-            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("some text"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
-                        _ => assert!(false),
+                    match load_safetensors("test2.safetensors") {
+                        Ok((tensors2, _)) => assert_eq!(tensors, tensors2),
+                        Err(_) => assert!(false),
                     }
                 },
-                _ => assert!(false),
+                Err(_) => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _) => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_loadtoml_is_applied_with_success()
+fn test_savesafetensors_and_loadsafetensorsmeta_are_applied_with_success_for_host_matrices()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4592,29 +6350,40 @@ fn test_loadtoml_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("loadtoml")) {
-        Some(fun_value) => {
-            let s = "
-a = 1
-b = 2.0
-c = false
-";
-            let s2 = &s[1..];
-            fs::write("test.toml", s2).unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.toml"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+    match (root_mod_g.var(&String::from("savesafetensors")), root_mod_g.var(&String::from("loadsafetensorsmeta"))) {
+        (Some(fun_value), Some(fun_value2)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.safetensors"))));
+            let mut layer_fields: BTreeMap<String, Value> = BTreeMap::new();
+            layer_fields.insert(String::from("b"), Value::Object(Arc::new(Object::HostMatrix(2, 1, HostElems::Double(vec![0.5, -0.5])))));
+            layer_fields.insert(String::from("w"), Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 2, 3, 4])))));
+            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+            fields.insert(String::from("l1"), Value::Ref(Arc::new(RwLock::new(MutObject::Struct(layer_fields)))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
+            let mut metadata_fields: BTreeMap<String, Value> = BTreeMap::new();
+            metadata_fields.insert(String::from("format"), Value::Object(Arc::new(Object::String(String::from("pt")))));
+            let arg_value3 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(metadata_fields.clone()))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2, arg_value3.clone()]) {
                 Ok(value) => {
-                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
-                    expected_fields.insert(String::from("a"), Value::Int(1));
-                    expected_fields.insert(String::from("b"), Value::Float(2.0));
-                    expected_fields.insert(String::from("c"), Value::Bool(false));
-                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields))));
-                    assert_eq!(expected_value, value);
+                    assert_eq!(Value::Bool(true), value);
+                    match load_safetensors("test.safetensors") {
+                        Ok((tensors, _)) => {
+                            let expected_tensors = vec![
+                                (String::from("l1.b"), SafeTensor::new(vec![2, 1], vec![0.5, -0.5])),
+                                (String::from("l1.w"), SafeTensor::new(vec![2, 2], vec![1.0, 2.0, 3.0, 4.0]))
+                            ];
+                            assert_eq!(expected_tensors, tensors);
+                        },
+                        Err(_) => assert!(false),
+                    }
                 },
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.toml"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(value) => assert_eq!(arg_value3, value),
+                Err(_) => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.safetensors"))));
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4624,12 +6393,12 @@ c = false
                 _ => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _) => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_savetoml_is_applied_with_success()
+fn test_save_and_load_preserve_shared_objects()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4637,51 +6406,37 @@ fn test_savetoml_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("savetoml")) {
-        Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.toml"))));
-            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
-            fields.insert(String::from("a"), Value::Int(1));
-            fields.insert(String::from("b"), Value::Float(2.0));
-            fields.insert(String::from("c"), Value::Bool(false));
-            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::read_to_string("test.toml") {
-                        Ok(s) => {
-                            let expected_s = "
-a = 1
-b = 2.0
-c = false
-";
-                            let expected_s2 = &expected_s[1..];
-                            assert_eq!(String::from(expected_s2), s);
-                        },
-                        Err(_) => assert!(false),
-                    }
-                },
+    match (root_mod_g.var(&String::from("save")), root_mod_g.var(&String::from("load"))) {
+        (Some(fun_value), Some(fun_value2)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
+            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2)]))));
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2.clone(), arg_value2]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
                 Err(_) => assert!(false),
             }
-            let mut path_buf = PathBuf::from("test");
-            path_buf.push("test.toml");
-            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
-                Ok(Value::Object(object)) => {
-                    match &*object {
-                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(Value::Ref(object)) => {
+                    let object_g = object.read().unwrap();
+                    match &*object_g {
+                        MutObject::Array(elems) => {
+                            assert_eq!(2, elems.len());
+                            match (&elems[0], &elems[1]) {
+                                (Value::Ref(elem), Value::Ref(elem2)) => assert!(Arc::ptr_eq(elem, elem2)),
+                                (_, _) => assert!(false),
+                            }
+                        },
                         _ => assert!(false),
                     }
                 },
                 _ => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _) => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_loadjson_is_applied_with_success()
+fn test_openvalues_and_readvalue_and_valuecount_are_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4689,31 +6444,44 @@ fn test_loadjson_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("loadjson")) {
-        Some(fun_value) => {
-            let s = "
-{
-    \"a\": 1,
-    \"b\": 2.0,
-    \"c\": false
-}
-";
-            let s2 = &s[1..];
-            fs::write("test.json", s2).unwrap();
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.json"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
-                Ok(value) => {
-                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
-                    expected_fields.insert(String::from("a"), Value::Int(1));
-                    expected_fields.insert(String::from("b"), Value::Float(2.0));
-                    expected_fields.insert(String::from("c"), Value::Bool(false));
-                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields))));
-                    assert_eq!(expected_value, value);
-                },
+    match (root_mod_g.var(&String::from("saveindexed")), root_mod_g.var(&String::from("openvalues")), root_mod_g.var(&String::from("readvalue")), root_mod_g.var(&String::from("valuecount"))) {
+        (Some(fun_value), Some(fun_value2), Some(fun_value3), Some(fun_value4)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("gzip"))));
+            let values = vec![
+                Value::Int(1),
+                Value::Float(2.5),
+                Value::Object(Arc::new(Object::String(String::from("abc"))))
+            ];
+            let mut arg_values = vec![arg_value.clone(), arg_value2];
+            arg_values.extend_from_slice(values.as_slice());
+            match fun_value.apply(&mut interp, &mut env, arg_values.as_slice()) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
                 Err(_) => assert!(false),
             }
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.json"))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value]) {
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
+                Ok(reader_value @ Value::Object(_)) => {
+                    match fun_value4.apply(&mut interp, &mut env, &[reader_value.clone()]) {
+                        Ok(value) => assert_eq!(Value::Int(3), value),
+                        Err(_) => assert!(false),
+                    }
+                    match fun_value3.apply(&mut interp, &mut env, &[reader_value.clone(), Value::Int(3)]) {
+                        Ok(value) => assert_eq!(values[2], value),
+                        Err(_) => assert!(false),
+                    }
+                    match fun_value3.apply(&mut interp, &mut env, &[reader_value.clone(), Value::Int(1)]) {
+                        Ok(value) => assert_eq!(values[0], value),
+                        Err(_) => assert!(false),
+                    }
+                    match fun_value3.apply(&mut interp, &mut env, &[reader_value, Value::Int(4)]) {
+                        Err(Error::Interp(msg)) => assert_eq!(String::from("index out of bounds"), msg),
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.bin"))));
+            match fun_value2.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
                         Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
@@ -4723,12 +6491,12 @@ fn test_loadjson_is_applied_with_success()
                 _ => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _, _, _) => assert!(false),
     }
 }
 
 #[sealed_test]
-fn test_savejson_is_applied_with_success()
+fn test_createvalues_and_writevalue_and_closevalues_are_applied_with_success()
 {
     let mut root_mod: ModNode<Value, ()> = ModNode::new(());
     add_std_builtin_funs(&mut root_mod);
@@ -4736,41 +6504,58 @@ fn test_savejson_is_applied_with_success()
     let mut interp = Interp::new();
     let root_mod = env.root_mod().clone();
     let root_mod_g = root_mod.read().unwrap();
-    match root_mod_g.var(&String::from("savejson")) {
-        Some(fun_value) => {
-            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.json"))));
-            let mut fields: BTreeMap<String, Value> = BTreeMap::new();
-            fields.insert(String::from("a"), Value::Int(1));
-            fields.insert(String::from("b"), Value::Float(2.0));
-            fields.insert(String::from("c"), Value::Bool(false));
-            let arg_value2 = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields))));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2.clone()]) {
-                Ok(value) => {
-                    assert_eq!(Value::Bool(true), value);
-                    match fs::read_to_string("test.json") {
-                        Ok(s) => {
-                            let expected_s = "{\"a\":1,\"b\":2.0,\"c\":false}";
-                            assert_eq!(String::from(expected_s), s);
-                        },
+    match (root_mod_g.var(&String::from("createvalues")), root_mod_g.var(&String::from("writevalue")), root_mod_g.var(&String::from("closevalues")), root_mod_g.var(&String::from("openvalues")), root_mod_g.var(&String::from("save"))) {
+        (Some(fun_value), Some(fun_value2), Some(fun_value3), Some(fun_value4), Some(fun_value5)) => {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test.bin"))));
+            let arg_value2 = Value::Object(Arc::new(Object::String(String::from("bzip2"))));
+            let values = vec![
+                Value::Int(1),
+                Value::Object(Arc::new(Object::String(String::from("abc"))))
+            ];
+            match fun_value.apply(&mut interp, &mut env, &[arg_value.clone(), arg_value2]) {
+                Ok(writer_value @ Value::Object(_)) => {
+                    for value in &values {
+                        match fun_value2.apply(&mut interp, &mut env, &[writer_value.clone(), value.clone()]) {
+                            Ok(value2) => assert_eq!(Value::Bool(true), value2),
+                            Err(_) => assert!(false),
+                        }
+                    }
+                    match fun_value3.apply(&mut interp, &mut env, &[writer_value]) {
+                        Ok(value) => assert_eq!(Value::Bool(true), value),
                         Err(_) => assert!(false),
                     }
                 },
+                _ => assert!(false),
+            }
+            match ValueReader::open("test.bin") {
+                Ok(mut reader) => {
+                    assert_eq!(2, reader.len());
+                    match reader.read_value(1, &env) {
+                        Ok(Some(value)) => assert_eq!(values[1], value),
+                        _ => assert!(false),
+                    }
+                },
                 Err(_) => assert!(false),
             }
-            let mut path_buf = PathBuf::from("test");
-            path_buf.push("test.json");
-            let arg_value = Value::Object(Arc::new(Object::String(path_buf.to_string_lossy().into_owned())));
-            match fun_value.apply(&mut interp, &mut env, &[arg_value, arg_value2]) {
+            let arg_value = Value::Object(Arc::new(Object::String(String::from("test2.bin"))));
+            match fun_value5.apply(&mut interp, &mut env, &[arg_value.clone(), Value::Int(1)]) {
+                Ok(value) => assert_eq!(Value::Bool(true), value),
+                Err(_) => assert!(false),
+            }
+            match fun_value4.apply(&mut interp, &mut env, &[arg_value]) {
                 Ok(Value::Object(object)) => {
                     match &*object {
-                        Object::Error(err_kind, _) => assert_eq!(String::from("io"), *err_kind),
+                        Object::Error(err_kind, msg) => {
+                            assert_eq!(String::from("io"), *err_kind);
+                            assert_eq!(String::from("values aren't indexed"), *msg);
+                        },
                         _ => assert!(false),
                     }
                 },
                 _ => assert!(false),
             }
         },
-        None => assert!(false),
+        (_, _, _, _, _) => assert!(false),
     }
 }
 
@@ -5942,8 +7727,8 @@ fn test_removelocalvar_is_applied_with_success()
         }
     };
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(1), Value::Float(2.5)];
     env.push_fun_mod_and_local_vars(&[], args.as_slice(), arg_values.as_slice()).unwrap();
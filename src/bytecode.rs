@@ -0,0 +1,979 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A bytecode module.
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use crate::error::*;
+use crate::tree::*;
+use crate::value::*;
+
+/// An enumeration of instruction.
+///
+/// The instructions operate on an operand stack of a virtual machine. The instruction fields are
+/// indices to tables of a code, indices of local variables, or targets of jumps. The file
+/// positions are indices to a table of file positions.
+#[derive(Copy, Clone, Debug)]
+pub enum Instr
+{
+    /// Pushes the constant.
+    Const(usize),
+    /// Pushes the local variable value or the variable value if the local variable isn't set.
+    LoadLocal(usize, usize),
+    /// Pushes the variable value for the name.
+    LoadVar(usize, usize),
+    /// Pops a value and sets the local variable.
+    StoreLocal(usize),
+    /// Pops a value and sets the variable for the name.
+    StoreVar(usize, usize),
+    /// Pops the named argument values, the argument values, and the function and then pushes a
+    /// result of application.
+    ///
+    /// The fields are the number of the argument values, the index of identifier list of named
+    /// arguments, and the file position.
+    App(usize, usize, usize),
+    /// Applies the unary operator to a popped value.
+    UnaryOp(UnaryOp, usize),
+    /// Applies the binary operator to two popped values.
+    BinOp(BinOp, usize),
+    /// Pushes the field of a popped value.
+    Field(usize, usize),
+    /// Pushes the element or the sub-matrix of a popped value for the optional popped row index
+    /// and the optional popped column index.
+    Elem2(bool, bool, usize),
+    /// Pushes a range with an optional step.
+    Range(bool, usize),
+    /// Pushes an anonymous function with captured local variables.
+    Lambda(usize),
+    /// Propagates an error if a top value is the `none` value or the error.
+    PropagateError(usize),
+    /// Pushes an array of popped values.
+    Array(usize),
+    /// Pushes a structure of popped values with the field identifiers from the identifier list.
+    Struct(usize),
+    /// Begins a matrix.
+    MatrixBegin,
+    /// Begins a matrix row.
+    MatrixRowBegin,
+    /// Adds a popped value to a matrix row.
+    MatrixElem(usize),
+    /// Ends the matrix row and adds it to the matrix.
+    MatrixRowEnd(usize),
+    /// Ends the matrix and pushes it.
+    MatrixEnd(usize),
+    /// Begins an array.
+    ArrayBegin,
+    /// Adds a popped value to the array.
+    ArrayElem,
+    /// Ends the array and pushes it.
+    ArrayEnd,
+    /// Begins a dictionary.
+    DictBegin,
+    /// Adds a popped key and a popped value to the dictionary.
+    DictPair(usize),
+    /// Ends the dictionary and pushes it.
+    DictEnd,
+    /// Pops a value and begins a counter for it.
+    CountBegin(usize),
+    /// Decrements a counter or removes the counter and jumps if the counter is zero.
+    CountNext(usize),
+    /// Jumps to the target.
+    Jump(usize),
+    /// Pops a value and jumps if the value is false.
+    JumpIfFalse(usize),
+    /// Jumps if a top value is false, otherwise this instruction pops the top value.
+    JumpIfFalseOrPop(usize),
+    /// Jumps if a top value is true, otherwise this instruction pops the top value.
+    JumpIfTrueOrPop(usize),
+    /// Pops a value and sets it as the return value.
+    SetRetValue,
+    /// Sets the `none` value as the return value.
+    ClearRetValue,
+    /// Pops an index, a value, and an element value and then sets the element.
+    SetElem(usize),
+    /// Pops the optional column index, the optional row index, a value, and an element value and
+    /// then pushes the value with the set element or the set sub-matrix.
+    SetElem2(bool, bool, usize),
+    /// Pops a value and a field value and then sets the field.
+    SetField(usize, usize),
+    /// Pops a value and begins an iteration over it.
+    IterBegin(usize),
+    /// Pushes a next element of iteration or jumps if the iteration is finished.
+    IterNext(usize, usize),
+    /// Ends the iteration.
+    IterEnd,
+    /// Begins a loop with targets for `break` and `continue`.
+    LoopBegin(usize, usize),
+    /// Ends the loop.
+    LoopEnd,
+    /// Begins a `try` statement with optional targets of `catch` and `finally`.
+    TryBegin(Option<usize>, Option<usize>),
+    /// Ends a body of `try` statement.
+    TryEnd,
+    /// Ends a `catch` clause.
+    CatchEnd,
+    /// Begins a `finally` clause.
+    FinallyBegin,
+    /// Ends the `finally` clause.
+    FinallyEnd,
+    /// Pops a value and returns it.
+    Return(usize),
+    /// Stops by a `break` statement, a `continue` statement, or a `quit` statement.
+    Stop(Stop, usize),
+    /// Returns an interpreter error with the message.
+    Error(usize, usize),
+    /// Jumps if an argument value is passed to the argument with the default value.
+    DefaultArg(usize, usize),
+    /// Defines the function.
+    Fun(usize, usize, usize),
+    /// Adds a module and sets it as current.
+    ModBegin(usize, usize),
+    /// Sets the parent module as current.
+    ModEnd(usize, usize),
+}
+
+/// A code structure.
+///
+/// The code contains instructions and tables that are referred by the instructions. Local
+/// variables of the code are identified by indices that are resolved while compilation.
+#[derive(Clone, Debug)]
+pub struct Code
+{
+    instrs: Vec<Instr>,
+    consts: Vec<Value>,
+    names: Vec<Name>,
+    strings: Vec<String>,
+    ident_lists: Vec<Vec<String>>,
+    funs: Vec<Arc<Fun>>,
+    poss: Vec<Pos>,
+    local_idents: Vec<String>,
+}
+
+impl Code
+{
+    fn new() -> Self
+    {
+        Code {
+            instrs: Vec::new(),
+            consts: Vec::new(),
+            names: Vec::new(),
+            strings: Vec::new(),
+            ident_lists: Vec::new(),
+            funs: Vec::new(),
+            poss: Vec::new(),
+            local_idents: Vec::new(),
+        }
+    }
+
+    /// Returns the instructions.
+    pub fn instrs(&self) -> &[Instr]
+    { self.instrs.as_slice() }
+
+    /// Returns the constants.
+    pub fn consts(&self) -> &[Value]
+    { self.consts.as_slice() }
+
+    /// Returns the variable names.
+    pub fn names(&self) -> &[Name]
+    { self.names.as_slice() }
+
+    /// Returns the strings which are identifiers or messages.
+    pub fn strings(&self) -> &[String]
+    { self.strings.as_slice() }
+
+    /// Returns the identifier lists.
+    pub fn ident_lists(&self) -> &[Vec<String>]
+    { self.ident_lists.as_slice() }
+
+    /// Returns the functions.
+    pub fn funs(&self) -> &[Arc<Fun>]
+    { self.funs.as_slice() }
+
+    /// Returns the file positions.
+    pub fn poss(&self) -> &[Pos]
+    { self.poss.as_slice() }
+
+    /// Returns the identifiers of local variables.
+    pub fn local_idents(&self) -> &[String]
+    { self.local_idents.as_slice() }
+}
+
+struct Loop
+{
+    break_jumps: Vec<usize>,
+    continue_target: usize,
+    try_depth: usize,
+}
+
+/// A compiler structure.
+///
+/// The compiler compiles a syntax tree or a function to a code. The variables with the `Name::Var`
+/// names which are set in the function are compiled to local variables.
+pub struct Compiler
+{
+    code: Code,
+    local_vars: BTreeMap<String, usize>,
+    loops: Vec<Loop>,
+    try_depth: usize,
+}
+
+impl Compiler
+{
+    fn new() -> Self
+    { Compiler { code: Code::new(), local_vars: BTreeMap::new(), loops: Vec::new(), try_depth: 0, } }
+
+    /// Compiles the syntax tree to a code.
+    pub fn compile_tree(tree: &Tree) -> Code
+    {
+        let mut compiler = Self::new();
+        match tree {
+            Tree(nodes) => compiler.compile_nodes(nodes.as_slice()),
+        }
+        compiler.code
+    }
+
+    /// Compiles the function to a code.
+    ///
+    /// The code of function evaluates the default values of arguments which don't have passed
+    /// values and then executes the statements of function.
+    pub fn compile_fun(fun: &Fun) -> Code
+    {
+        let mut compiler = Self::new();
+        match fun {
+            Fun(args, stats) => {
+                for arg in args {
+                    compiler.add_local_var(&arg.0);
+                }
+                compiler.add_local_vars_for_stats(stats.as_slice());
+                for (i, arg) in args.iter().enumerate() {
+                    match arg {
+                        Arg(ident, ArgKind::Default(expr), _) => {
+                            let jump_idx = compiler.emit(Instr::DefaultArg(i, 0));
+                            compiler.compile_expr(&**expr);
+                            let local_idx = compiler.local_vars[ident];
+                            compiler.emit(Instr::StoreLocal(local_idx));
+                            compiler.patch(jump_idx);
+                        },
+                        _ => (),
+                    }
+                }
+                compiler.compile_stats(stats.as_slice());
+            },
+        }
+        compiler.code
+    }
+
+    fn add_local_var(&mut self, ident: &String)
+    {
+        if !self.local_vars.contains_key(ident) {
+            self.local_vars.insert(ident.clone(), self.code.local_idents.len());
+            self.code.local_idents.push(ident.clone());
+        }
+    }
+
+    fn add_local_var_for_expr(&mut self, expr: &Expr)
+    {
+        match expr {
+            Expr::Var(Name::Var(ident), _) => self.add_local_var(ident),
+            Expr::Index2(expr2, _, _, _) => self.add_local_var_for_expr(&**expr2),
+            _ => (),
+        }
+    }
+
+    fn add_local_vars_for_stats(&mut self, stats: &[Box<Stat>])
+    {
+        for stat in stats {
+            match &**stat {
+                Stat::Assign(expr, _, _) => self.add_local_var_for_expr(&**expr),
+                Stat::If(_, stats2, else_if_pairs, else_stats, _) => {
+                    self.add_local_vars_for_stats(stats2.as_slice());
+                    for (_, else_if_stats) in else_if_pairs {
+                        self.add_local_vars_for_stats(else_if_stats.as_slice());
+                    }
+                    match else_stats {
+                        Some(else_stats) => self.add_local_vars_for_stats(else_stats.as_slice()),
+                        None => (),
+                    }
+                },
+                Stat::For(ident, _, stats2, _) => {
+                    self.add_local_var(ident);
+                    self.add_local_vars_for_stats(stats2.as_slice());
+                },
+                Stat::While(_, stats2, _) => self.add_local_vars_for_stats(stats2.as_slice()),
+                Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                    self.add_local_vars_for_stats(try_stats.as_slice());
+                    match catch_pair {
+                        Some((ident, catch_stats)) => {
+                            self.add_local_var(ident);
+                            self.add_local_vars_for_stats(catch_stats.as_slice());
+                        },
+                        None => (),
+                    }
+                    match finally_stats {
+                        Some(finally_stats) => self.add_local_vars_for_stats(finally_stats.as_slice()),
+                        None => (),
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn emit(&mut self, instr: Instr) -> usize
+    {
+        self.code.instrs.push(instr);
+        self.code.instrs.len() - 1
+    }
+
+    fn patch(&mut self, idx: usize)
+    {
+        let target = self.code.instrs.len();
+        self.patch_with_target(idx, target);
+    }
+
+    fn patch_with_target(&mut self, idx: usize, target: usize)
+    {
+        match &mut self.code.instrs[idx] {
+            Instr::CountNext(target2) => *target2 = target,
+            Instr::Jump(target2) => *target2 = target,
+            Instr::JumpIfFalse(target2) => *target2 = target,
+            Instr::JumpIfFalseOrPop(target2) => *target2 = target,
+            Instr::JumpIfTrueOrPop(target2) => *target2 = target,
+            Instr::IterNext(target2, _) => *target2 = target,
+            Instr::DefaultArg(_, target2) => *target2 = target,
+            _ => (),
+        }
+    }
+
+    fn add_const(&mut self, value: Value) -> usize
+    {
+        self.code.consts.push(value);
+        self.code.consts.len() - 1
+    }
+
+    fn add_name(&mut self, name: &Name) -> usize
+    {
+        self.code.names.push(name.clone());
+        self.code.names.len() - 1
+    }
+
+    fn add_string(&mut self, s: String) -> usize
+    {
+        self.code.strings.push(s);
+        self.code.strings.len() - 1
+    }
+
+    fn add_ident_list(&mut self, idents: Vec<String>) -> usize
+    {
+        self.code.ident_lists.push(idents);
+        self.code.ident_lists.len() - 1
+    }
+
+    fn add_fun(&mut self, fun: &Arc<Fun>) -> usize
+    {
+        self.code.funs.push(fun.clone());
+        self.code.funs.len() - 1
+    }
+
+    fn add_pos(&mut self, pos: &Pos) -> usize
+    {
+        self.code.poss.push(pos.clone());
+        self.code.poss.len() - 1
+    }
+
+    fn emit_error(&mut self, msg: String, pos: &Pos)
+    {
+        let msg_idx = self.add_string(msg);
+        let pos_idx = self.add_pos(pos);
+        self.emit(Instr::Error(msg_idx, pos_idx));
+    }
+
+    fn emit_store(&mut self, name: &Name, pos: &Pos)
+    {
+        let local_idx = match name {
+            Name::Var(ident) => self.local_vars.get(ident).map(|i| *i),
+            _ => None,
+        };
+        match local_idx {
+            Some(local_idx) => {
+                self.emit(Instr::StoreLocal(local_idx));
+            },
+            None => {
+                let name_idx = self.add_name(name);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::StoreVar(name_idx, pos_idx));
+            },
+        }
+    }
+
+    fn compile_repeated_arg_error(&mut self, fun: &Fun) -> bool
+    {
+        match fun {
+            Fun(args, _) => {
+                let mut idents: BTreeSet<&String> = BTreeSet::new();
+                for arg in args {
+                    match arg {
+                        Arg(ident, _, pos) => {
+                            if idents.contains(&ident) {
+                                self.emit_error(format!("already defined argument {}", ident), pos);
+                                return true;
+                            }
+                            idents.insert(ident);
+                        },
+                    }
+                }
+                false
+            },
+        }
+    }
+
+    fn compile_nodes(&mut self, nodes: &[Node])
+    {
+        self.emit(Instr::ClearRetValue);
+        for node in nodes {
+            match node {
+                Node::Def(def) => self.compile_def(&**def),
+                Node::Stat(stat) => self.compile_stat(&**stat),
+            }
+            self.emit(Instr::ClearRetValue);
+        }
+    }
+
+    fn compile_def(&mut self, def: &Def)
+    {
+        match def {
+            Def::Mod(ident, mod1, pos) => {
+                match &**mod1 {
+                    Mod(nodes) => {
+                        let ident_idx = self.add_string(ident.clone());
+                        let pos_idx = self.add_pos(pos);
+                        self.emit(Instr::ModBegin(ident_idx, pos_idx));
+                        self.compile_nodes(nodes.as_slice());
+                        self.emit(Instr::ModEnd(ident_idx, pos_idx));
+                    },
+                }
+            },
+            Def::Fun(ident, fun, pos) => {
+                if !self.compile_repeated_arg_error(&**fun) {
+                    let ident_idx = self.add_string(ident.clone());
+                    let fun_idx = self.add_fun(fun);
+                    let pos_idx = self.add_pos(pos);
+                    self.emit(Instr::Fun(ident_idx, fun_idx, pos_idx));
+                }
+            },
+        }
+    }
+
+    fn compile_stats(&mut self, stats: &[Box<Stat>])
+    {
+        self.emit(Instr::ClearRetValue);
+        for stat in stats {
+            self.compile_stat(&**stat);
+        }
+    }
+
+    fn compile_loop_body(&mut self, stats: &[Box<Stat>], continue_target: usize) -> Vec<usize>
+    {
+        self.loops.push(Loop { break_jumps: Vec::new(), continue_target, try_depth: self.try_depth, });
+        self.compile_stats(stats);
+        match self.loops.pop() {
+            Some(loop1) => loop1.break_jumps,
+            None => Vec::new(),
+        }
+    }
+
+    fn compile_assign(&mut self, expr: &Expr, pos: &Pos)
+    {
+        match expr {
+            Expr::Var(name, _) => self.emit_store(name, pos),
+            Expr::BinOp(BinOp::Index, expr2, expr3, _) => {
+                self.compile_expr(&**expr2);
+                self.compile_expr(&**expr3);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::SetElem(pos_idx));
+            },
+            Expr::Field(expr2, ident, _) => {
+                self.compile_expr(&**expr2);
+                let ident_idx = self.add_string(ident.clone());
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::SetField(ident_idx, pos_idx));
+            },
+            Expr::Index2(expr2, expr3, expr4, _) => {
+                self.compile_expr(&**expr2);
+                self.compile_index2(expr3, expr4);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::SetElem2(expr3.is_some(), expr4.is_some(), pos_idx));
+                self.compile_assign(&**expr2, pos);
+            },
+            _ => self.emit_error(String::from("expression isn't assignable"), expr.pos()),
+        }
+    }
+
+    fn compile_index2(&mut self, expr: &Option<Box<Expr>>, expr2: &Option<Box<Expr>>)
+    {
+        match expr {
+            Some(expr) => self.compile_expr(&**expr),
+            None => (),
+        }
+        match expr2 {
+            Some(expr2) => self.compile_expr(&**expr2),
+            None => (),
+        }
+    }
+
+    fn compile_stat(&mut self, stat: &Stat)
+    {
+        match stat {
+            Stat::Expr(expr, _) => {
+                self.compile_expr(&**expr);
+                self.emit(Instr::SetRetValue);
+            },
+            Stat::Assign(expr, expr2, pos) => {
+                self.compile_expr(&**expr2);
+                self.compile_assign(&**expr, pos);
+                self.emit(Instr::ClearRetValue);
+            },
+            Stat::If(expr, stats, else_if_pairs, else_stats, _) => {
+                let mut end_jump_idxs: Vec<usize> = Vec::new();
+                self.compile_expr(&**expr);
+                let jump_idx = self.emit(Instr::JumpIfFalse(0));
+                self.compile_stats(stats.as_slice());
+                end_jump_idxs.push(self.emit(Instr::Jump(0)));
+                self.patch(jump_idx);
+                for (else_if_expr, else_if_stats) in else_if_pairs {
+                    self.compile_expr(&**else_if_expr);
+                    let jump_idx = self.emit(Instr::JumpIfFalse(0));
+                    self.compile_stats(else_if_stats.as_slice());
+                    end_jump_idxs.push(self.emit(Instr::Jump(0)));
+                    self.patch(jump_idx);
+                }
+                match else_stats {
+                    Some(else_stats) => self.compile_stats(else_stats.as_slice()),
+                    None => (),
+                }
+                for end_jump_idx in end_jump_idxs {
+                    self.patch(end_jump_idx);
+                }
+            },
+            Stat::For(ident, expr, stats, pos) => {
+                self.compile_expr(&**expr);
+                let expr_pos_idx = self.add_pos(expr.pos());
+                self.emit(Instr::IterBegin(expr_pos_idx));
+                let is_handler = is_stop_in_try(stats.as_slice(), false);
+                let loop_begin_idx = if is_handler {
+                    Some(self.emit(Instr::LoopBegin(0, 0)))
+                } else {
+                    None
+                };
+                let pos_idx = self.add_pos(pos);
+                let next_idx = self.emit(Instr::IterNext(0, pos_idx));
+                self.emit_store(&Name::Var(ident.clone()), pos);
+                let break_jump_idxs = self.compile_loop_body(stats.as_slice(), next_idx);
+                self.emit(Instr::Jump(next_idx));
+                let end_idx = self.code.instrs.len();
+                self.patch(next_idx);
+                for break_jump_idx in break_jump_idxs {
+                    self.patch(break_jump_idx);
+                }
+                match loop_begin_idx {
+                    Some(loop_begin_idx) => {
+                        self.code.instrs[loop_begin_idx] = Instr::LoopBegin(end_idx, next_idx);
+                        self.emit(Instr::LoopEnd);
+                    },
+                    None => (),
+                }
+                self.emit(Instr::IterEnd);
+            },
+            Stat::While(expr, stats, _) => {
+                let is_handler = is_stop_in_try(stats.as_slice(), false);
+                let loop_begin_idx = if is_handler {
+                    Some(self.emit(Instr::LoopBegin(0, 0)))
+                } else {
+                    None
+                };
+                let cond_idx = self.code.instrs.len();
+                self.compile_expr(&**expr);
+                let jump_idx = self.emit(Instr::JumpIfFalse(0));
+                let break_jump_idxs = self.compile_loop_body(stats.as_slice(), cond_idx);
+                self.emit(Instr::Jump(cond_idx));
+                let end_idx = self.code.instrs.len();
+                self.patch(jump_idx);
+                for break_jump_idx in break_jump_idxs {
+                    self.patch(break_jump_idx);
+                }
+                match loop_begin_idx {
+                    Some(loop_begin_idx) => {
+                        self.code.instrs[loop_begin_idx] = Instr::LoopBegin(end_idx, cond_idx);
+                        self.emit(Instr::LoopEnd);
+                    },
+                    None => (),
+                }
+            },
+            Stat::Break(pos) => {
+                let is_jump = match self.loops.last() {
+                    Some(loop1) => loop1.try_depth == self.try_depth,
+                    None => false,
+                };
+                if is_jump {
+                    self.emit(Instr::ClearRetValue);
+                    let jump_idx = self.emit(Instr::Jump(0));
+                    match self.loops.last_mut() {
+                        Some(loop1) => loop1.break_jumps.push(jump_idx),
+                        None => (),
+                    }
+                } else {
+                    let pos_idx = self.add_pos(pos);
+                    self.emit(Instr::Stop(Stop::Break, pos_idx));
+                }
+            },
+            Stat::Continue(pos) => {
+                let continue_target = match self.loops.last() {
+                    Some(loop1) if loop1.try_depth == self.try_depth => Some(loop1.continue_target),
+                    _ => None,
+                };
+                match continue_target {
+                    Some(continue_target) => {
+                        self.emit(Instr::ClearRetValue);
+                        self.emit(Instr::Jump(continue_target));
+                    },
+                    None => {
+                        let pos_idx = self.add_pos(pos);
+                        self.emit(Instr::Stop(Stop::Continue, pos_idx));
+                    },
+                }
+            },
+            Stat::Return(expr, pos) => {
+                match expr {
+                    Some(expr) => self.compile_expr(&**expr),
+                    None => {
+                        let const_idx = self.add_const(Value::None);
+                        self.emit(Instr::Const(const_idx));
+                    },
+                }
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::Return(pos_idx));
+            },
+            Stat::Quit(pos) => {
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::Stop(Stop::Quit, pos_idx));
+            },
+            Stat::Try(try_stats, catch_pair, finally_stats, pos) => {
+                if catch_pair.is_none() && finally_stats.is_none() {
+                    self.compile_stats(try_stats.as_slice());
+                    return;
+                }
+                self.try_depth += 1;
+                let try_begin_idx = self.emit(Instr::TryBegin(None, None));
+                self.compile_stats(try_stats.as_slice());
+                self.emit(Instr::TryEnd);
+                let catch_idx = match catch_pair {
+                    Some((ident, catch_stats)) => {
+                        let jump_idx = self.emit(Instr::Jump(0));
+                        let catch_idx = self.code.instrs.len();
+                        self.emit_store(&Name::Var(ident.clone()), pos);
+                        self.compile_stats(catch_stats.as_slice());
+                        if finally_stats.is_some() {
+                            self.emit(Instr::CatchEnd);
+                        }
+                        self.patch(jump_idx);
+                        Some(catch_idx)
+                    },
+                    None => None,
+                };
+                let finally_idx = match finally_stats {
+                    Some(finally_stats) => {
+                        self.emit(Instr::FinallyBegin);
+                        let finally_idx = self.code.instrs.len();
+                        self.compile_stats(finally_stats.as_slice());
+                        self.emit(Instr::FinallyEnd);
+                        Some(finally_idx)
+                    },
+                    None => None,
+                };
+                self.code.instrs[try_begin_idx] = Instr::TryBegin(catch_idx, finally_idx);
+                self.try_depth -= 1;
+            },
+        }
+    }
+
+    fn compile_expr(&mut self, expr: &Expr)
+    {
+        match expr {
+            Expr::Lit(lit, pos) => self.compile_lit(lit, pos),
+            Expr::Var(name, pos) => {
+                let local_idx = match name {
+                    Name::Var(ident) => self.local_vars.get(ident).map(|i| *i),
+                    _ => None,
+                };
+                let pos_idx = self.add_pos(pos);
+                match local_idx {
+                    Some(local_idx) => {
+                        self.emit(Instr::LoadLocal(local_idx, pos_idx));
+                    },
+                    None => {
+                        let name_idx = self.add_name(name);
+                        self.emit(Instr::LoadVar(name_idx, pos_idx));
+                    },
+                }
+            },
+            Expr::App(expr2, exprs, named_args, pos) => {
+                self.compile_expr(&**expr2);
+                for expr3 in exprs {
+                    self.compile_expr(&**expr3);
+                }
+                let mut idents: Vec<String> = Vec::new();
+                for named_arg in named_args {
+                    match named_arg {
+                        NamedArg(ident, expr3, _) => {
+                            self.compile_expr(&**expr3);
+                            idents.push(ident.clone());
+                        },
+                    }
+                }
+                let ident_list_idx = self.add_ident_list(idents);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::App(exprs.len(), ident_list_idx, pos_idx));
+            },
+            Expr::UnaryOp(op, expr2, pos) => {
+                self.compile_expr(&**expr2);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::UnaryOp(*op, pos_idx));
+            },
+            Expr::BinOp(op, expr2, expr3, pos) => {
+                self.compile_expr(&**expr2);
+                self.compile_expr(&**expr3);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::BinOp(*op, pos_idx));
+            },
+            Expr::And(expr2, expr3, _) => {
+                self.compile_expr(&**expr2);
+                let jump_idx = self.emit(Instr::JumpIfFalseOrPop(0));
+                self.compile_expr(&**expr3);
+                self.patch(jump_idx);
+            },
+            Expr::Or(expr2, expr3, _) => {
+                self.compile_expr(&**expr2);
+                let jump_idx = self.emit(Instr::JumpIfTrueOrPop(0));
+                self.compile_expr(&**expr3);
+                self.patch(jump_idx);
+            },
+            Expr::Field(expr2, ident, pos) => {
+                self.compile_expr(&**expr2);
+                let ident_idx = self.add_string(ident.clone());
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::Field(ident_idx, pos_idx));
+            },
+            Expr::Index2(expr2, expr3, expr4, pos) => {
+                self.compile_expr(&**expr2);
+                self.compile_index2(expr3, expr4);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::Elem2(expr3.is_some(), expr4.is_some(), pos_idx));
+            },
+            Expr::Range(expr2, expr3, expr4, pos) => {
+                self.compile_expr(&**expr2);
+                self.compile_expr(&**expr3);
+                match expr4 {
+                    Some(expr4) => self.compile_expr(&**expr4),
+                    None => (),
+                }
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::Range(expr4.is_some(), pos_idx));
+            },
+            Expr::PropagateError(expr2, pos) => {
+                self.compile_expr(&**expr2);
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::PropagateError(pos_idx));
+            },
+            Expr::Lambda(fun, _) => {
+                if !self.compile_repeated_arg_error(&**fun) {
+                    let fun_idx = self.add_fun(fun);
+                    self.emit(Instr::Lambda(fun_idx));
+                }
+            },
+        }
+    }
+
+    fn compile_count_loop<F>(&mut self, expr: &Expr, f: F)
+        where F: FnOnce(&mut Self)
+    {
+        self.compile_expr(expr);
+        let pos_idx = self.add_pos(expr.pos());
+        self.emit(Instr::CountBegin(pos_idx));
+        let next_idx = self.emit(Instr::CountNext(0));
+        f(self);
+        self.emit(Instr::Jump(next_idx));
+        self.patch(next_idx);
+    }
+
+    fn compile_matrix_row(&mut self, matrix_row: &MatrixRow)
+    {
+        self.emit(Instr::MatrixRowBegin);
+        match matrix_row {
+            MatrixRow::Row(exprs) => {
+                for expr in exprs {
+                    self.compile_expr(&**expr);
+                    let pos_idx = self.add_pos(expr.pos());
+                    self.emit(Instr::MatrixElem(pos_idx));
+                }
+            },
+            MatrixRow::FilledRow(expr, expr2) => {
+                self.compile_count_loop(&**expr2, |compiler| {
+                        compiler.compile_expr(&**expr);
+                        let pos_idx = compiler.add_pos(expr.pos());
+                        compiler.emit(Instr::MatrixElem(pos_idx));
+                });
+            },
+        }
+    }
+
+    fn compile_lit(&mut self, lit: &Lit, pos: &Pos)
+    {
+        match lit {
+            Lit::None => {
+                let const_idx = self.add_const(Value::None);
+                self.emit(Instr::Const(const_idx));
+            },
+            Lit::Bool(b) => {
+                let const_idx = self.add_const(Value::Bool(*b));
+                self.emit(Instr::Const(const_idx));
+            },
+            Lit::Int(n) => {
+                let const_idx = self.add_const(Value::Int(*n));
+                self.emit(Instr::Const(const_idx));
+            },
+            Lit::Float(n) => {
+                let const_idx = self.add_const(Value::Float(*n));
+                self.emit(Instr::Const(const_idx));
+            },
+            Lit::String(s) => {
+                let const_idx = self.add_const(Value::Object(Arc::new(Object::String(s.clone()))));
+                self.emit(Instr::Const(const_idx));
+            },
+            Lit::Matrix(matrix_rows) => {
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::MatrixBegin);
+                for matrix_row in matrix_rows {
+                    self.compile_matrix_row(matrix_row);
+                    self.emit(Instr::MatrixRowEnd(pos_idx));
+                }
+                self.emit(Instr::MatrixEnd(pos_idx));
+            },
+            Lit::FilledMatrix(matrix_row, expr) => {
+                let pos_idx = self.add_pos(pos);
+                self.emit(Instr::MatrixBegin);
+                self.compile_count_loop(&**expr, |compiler| {
+                        compiler.compile_matrix_row(matrix_row);
+                        compiler.emit(Instr::MatrixRowEnd(pos_idx));
+                });
+                self.emit(Instr::MatrixEnd(pos_idx));
+            },
+            Lit::Array(exprs) => {
+                for expr in exprs {
+                    self.compile_expr(&**expr);
+                }
+                self.emit(Instr::Array(exprs.len()));
+            },
+            Lit::FilledArray(expr, expr2) => {
+                self.emit(Instr::ArrayBegin);
+                self.compile_count_loop(&**expr2, |compiler| {
+                        compiler.compile_expr(&**expr);
+                        compiler.emit(Instr::ArrayElem);
+                });
+                self.emit(Instr::ArrayEnd);
+            },
+            Lit::Struct(field_pairs) => {
+                let mut idents: Vec<String> = Vec::new();
+                for field_pair in field_pairs {
+                    match field_pair {
+                        FieldPair(ident, expr, pos2) => {
+                            if idents.contains(ident) {
+                                self.emit_error(format!("already defined field {}", ident), pos2);
+                                return;
+                            }
+                            self.compile_expr(&**expr);
+                            idents.push(ident.clone());
+                        },
+                    }
+                }
+                let ident_list_idx = self.add_ident_list(idents);
+                self.emit(Instr::Struct(ident_list_idx));
+            },
+            Lit::Dict(dict_pairs) => {
+                self.emit(Instr::DictBegin);
+                for dict_pair in dict_pairs {
+                    match dict_pair {
+                        DictPair(expr, expr2, pos2) => {
+                            self.compile_expr(&**expr);
+                            self.compile_expr(&**expr2);
+                            let pos_idx = self.add_pos(pos2);
+                            self.emit(Instr::DictPair(pos_idx));
+                        },
+                    }
+                }
+                self.emit(Instr::DictEnd);
+            },
+        }
+    }
+}
+
+fn is_stop_in_try(stats: &[Box<Stat>], is_in_try: bool) -> bool
+{
+    for stat in stats {
+        match &**stat {
+            Stat::If(_, stats2, else_if_pairs, else_stats, _) => {
+                if is_stop_in_try(stats2.as_slice(), is_in_try) {
+                    return true;
+                }
+                for (_, else_if_stats) in else_if_pairs {
+                    if is_stop_in_try(else_if_stats.as_slice(), is_in_try) {
+                        return true;
+                    }
+                }
+                match else_stats {
+                    Some(else_stats) => {
+                        if is_stop_in_try(else_stats.as_slice(), is_in_try) {
+                            return true;
+                        }
+                    },
+                    None => (),
+                }
+            },
+            Stat::Break(_) | Stat::Continue(_) => {
+                if is_in_try {
+                    return true;
+                }
+            },
+            Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                if is_stop_in_try(try_stats.as_slice(), true) {
+                    return true;
+                }
+                match catch_pair {
+                    Some((_, catch_stats)) => {
+                        if is_stop_in_try(catch_stats.as_slice(), true) {
+                            return true;
+                        }
+                    },
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => {
+                        if is_stop_in_try(finally_stats.as_slice(), true) {
+                            return true;
+                        }
+                    },
+                    None => (),
+                }
+            },
+            _ => (),
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,242 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io::Cursor;
+use crate::doc::*;
+use crate::lexer::*;
+use crate::parser::*;
+use super::*;
+
+#[test]
+fn test_compiler_compile_tree_compiles_tree()
+{
+    let s = "X = 1 + 2";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let code = Compiler::compile_tree(&tree);
+            assert_eq!(true, code.local_idents().is_empty());
+            assert_eq!(7, code.instrs().len());
+            match code.instrs()[0] {
+                Instr::ClearRetValue => assert!(true),
+                _ => assert!(false),
+            }
+            match code.instrs()[1] {
+                Instr::Const(0) => assert!(true),
+                _ => assert!(false),
+            }
+            match code.instrs()[2] {
+                Instr::Const(1) => assert!(true),
+                _ => assert!(false),
+            }
+            match code.instrs()[3] {
+                Instr::BinOp(BinOp::Add, pos_idx) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), code.poss()[pos_idx]),
+                _ => assert!(false),
+            }
+            match code.instrs()[4] {
+                Instr::StoreVar(name_idx, pos_idx) => {
+                    assert_eq!(Name::Var(String::from("X")), code.names()[name_idx]);
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), code.poss()[pos_idx]);
+                },
+                _ => assert!(false),
+            }
+            match code.instrs()[5] {
+                Instr::ClearRetValue => assert!(true),
+                _ => assert!(false),
+            }
+            match code.instrs()[6] {
+                Instr::ClearRetValue => assert!(true),
+                _ => assert!(false),
+            }
+            assert_eq!(Value::Int(1), code.consts()[0]);
+            assert_eq!(Value::Int(2), code.consts()[1]);
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_compiler_compile_fun_compiles_function_with_local_variables()
+{
+    let s = "
+function f(X)
+    Y = X + 1
+    Y
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Fun(_, fun, _) => {
+                            let code = Compiler::compile_fun(&**fun);
+                            assert_eq!(vec![String::from("X"), String::from("Y")], code.local_idents().to_vec());
+                            assert_eq!(8, code.instrs().len());
+                            match code.instrs()[0] {
+                                Instr::ClearRetValue => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[1] {
+                                Instr::LoadLocal(0, _) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[2] {
+                                Instr::Const(0) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[3] {
+                                Instr::BinOp(BinOp::Add, _) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[4] {
+                                Instr::StoreLocal(1) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[5] {
+                                Instr::ClearRetValue => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[6] {
+                                Instr::LoadLocal(1, _) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[7] {
+                                Instr::SetRetValue => assert!(true),
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_compiler_compile_fun_compiles_while_statement_with_break()
+{
+    let s = "
+function f(X)
+    while X
+        break
+    end
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Fun(_, fun, _) => {
+                            let code = Compiler::compile_fun(&**fun);
+                            assert_eq!(7, code.instrs().len());
+                            match code.instrs()[1] {
+                                Instr::LoadLocal(0, _) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[2] {
+                                Instr::JumpIfFalse(7) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[4] {
+                                Instr::ClearRetValue => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[5] {
+                                Instr::Jump(7) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[6] {
+                                Instr::Jump(1) => assert!(true),
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_compiler_compile_fun_compiles_while_statement_with_break_in_try_statement()
+{
+    let s = "
+function f(X)
+    while X
+        try
+            break
+        finally
+            X = false
+        end
+    end
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Fun(_, fun, _) => {
+                            let code = Compiler::compile_fun(&**fun);
+                            match code.instrs()[1] {
+                                Instr::LoopBegin(_, 2) => assert!(true),
+                                _ => assert!(false),
+                            }
+                            match code.instrs()[code.instrs().len() - 1] {
+                                Instr::LoopEnd => assert!(true),
+                                _ => assert!(false),
+                            }
+                            let is_break = code.instrs().iter().any(|instr| {
+                                    match instr {
+                                        Instr::Stop(Stop::Break, _) => true,
+                                        _ => false,
+                                    }
+                            });
+                            assert_eq!(true, is_break);
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
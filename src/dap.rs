@@ -0,0 +1,803 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of debug adapter.
+//!
+//! The debug adapter is a server of the Debug Adapter Protocol that allows to debug scripts in
+//! editors.
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io::BufRead;
+use std::io::Cursor;
+use std::io::Write;
+use std::mem;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::channel;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::thread;
+use std::thread::JoinHandle;
+use crate::serde_json;
+use crate::serde_json::json;
+use crate::debug::*;
+use crate::env::*;
+use crate::error::*;
+use crate::interp::*;
+use crate::intr::*;
+use crate::mod_node::*;
+use crate::parser::*;
+use crate::tree::*;
+use crate::utils::*;
+use crate::value::*;
+
+/// A structure of debug adapter interruption checker.
+///
+/// The debug adapter interruption checker has a pause flag and a stop flag that are set by a
+/// debug adapter server. The pause flag pauses an interpreter at a next statement. The stop flag
+/// is interpreted as an interruption.
+#[derive(Debug)]
+pub struct DapIntrChecker
+{
+    pause_flag: AtomicBool,
+    stop_flag: AtomicBool,
+}
+
+impl DapIntrChecker
+{
+    /// Creates a debug adapter interruption checker.
+    pub fn new() -> Self
+    { DapIntrChecker { pause_flag: AtomicBool::new(false), stop_flag: AtomicBool::new(false), } }
+
+    /// Sets the pause flag.
+    pub fn pause(&self)
+    { self.pause_flag.store(true, Ordering::SeqCst); }
+
+    /// Resets the pause flag and returns `true` if the pause flag was set, otherwise `false`.
+    pub fn take_pause(&self) -> bool
+    { self.pause_flag.swap(false, Ordering::SeqCst) }
+
+    /// Sets the stop flag.
+    pub fn stop(&self)
+    { self.stop_flag.store(true, Ordering::SeqCst); }
+
+    /// Returns `true` if the stop flag is set, otherwise `false`.
+    pub fn is_stop(&self) -> bool
+    { self.stop_flag.load(Ordering::SeqCst) }
+}
+
+impl IntrCheck for DapIntrChecker
+{
+    fn check(&self) -> Result<()>
+    {
+        if self.is_stop() {
+            Err(Error::Intr)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Request
+{
+    seq: u64,
+    command: String,
+    args: serde_json::Value,
+}
+
+impl Request
+{
+    fn from_message(msg: &serde_json::Value) -> Option<Self>
+    {
+        if msg["type"].as_str() != Some("request") {
+            return None;
+        }
+        match (msg["seq"].as_u64(), msg["command"].as_str()) {
+            (Some(seq), Some(command)) => Some(Request { seq, command: String::from(command), args: msg["arguments"].clone(), }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct DapWriter
+{
+    w: Arc<Mutex<Box<dyn Write + Send>>>,
+    seq: Arc<AtomicU64>,
+}
+
+impl DapWriter
+{
+    fn new(w: Box<dyn Write + Send>) -> Self
+    { DapWriter { w: Arc::new(Mutex::new(w)), seq: Arc::new(AtomicU64::new(0)), } }
+
+    fn send(&self, mut msg: serde_json::Value) -> Result<()>
+    {
+        let mut w_g = mutex_lock(&*self.w)?;
+        msg["seq"] = json!(self.seq.fetch_add(1, Ordering::SeqCst) + 1);
+        write_message(&mut **w_g, &msg)
+    }
+
+    fn send_response(&self, req: &Request, body: serde_json::Value) -> Result<()>
+    { self.send(json!({ "type": "response", "request_seq": req.seq, "success": true, "command": req.command, "body": body })) }
+
+    fn send_error_response(&self, req: &Request, msg: &str) -> Result<()>
+    { self.send(json!({ "type": "response", "request_seq": req.seq, "success": false, "command": req.command, "message": msg })) }
+
+    fn send_event(&self, event: &str, body: serde_json::Value) -> Result<()>
+    { self.send(json!({ "type": "event", "event": event, "body": body })) }
+
+    fn send_output(&self, cursor: &Arc<RwLock<Cursor<Vec<u8>>>>, category: &str) -> Result<()>
+    {
+        let buf = {
+            let mut cursor_g = rw_lock_write(&**cursor)?;
+            let buf = mem::take(cursor_g.get_mut());
+            cursor_g.set_position(0);
+            buf
+        };
+        if !buf.is_empty() {
+            self.send_event("output", json!({ "category": category, "output": String::from_utf8_lossy(buf.as_slice()) }))?;
+        }
+        Ok(())
+    }
+}
+
+struct DapState
+{
+    breakpoints: Mutex<BTreeMap<String, BTreeSet<u64>>>,
+    is_paused: AtomicBool,
+}
+
+#[derive(Clone, Debug)]
+struct StackFrame
+{
+    name: String,
+    pos: Pos,
+    depth: Option<usize>,
+}
+
+struct DapHook
+{
+    writer: DapWriter,
+    state: Arc<DapState>,
+    intr_checker: Arc<DapIntrChecker>,
+    receiver: Receiver<Request>,
+    step: Step,
+    is_entry: bool,
+    canonical_paths: HashMap<String, String>,
+    stdout: Arc<RwLock<Cursor<Vec<u8>>>>,
+    stderr: Arc<RwLock<Cursor<Vec<u8>>>>,
+}
+
+impl DapHook
+{
+    fn flush_output(&self) -> Result<()>
+    {
+        self.writer.send_output(&self.stdout, "stdout")?;
+        self.writer.send_output(&self.stderr, "stderr")
+    }
+
+    fn is_breakpoint(&mut self, pos: &Pos) -> Result<bool>
+    {
+        let breakpoints_g = mutex_lock(&self.state.breakpoints)?;
+        if breakpoints_g.is_empty() {
+            return Ok(false);
+        }
+        let path = match self.canonical_paths.get(&*pos.path) {
+            Some(path) => path.clone(),
+            None => {
+                let path = canonicalize_path(pos.path.as_str());
+                self.canonical_paths.insert((*pos.path).clone(), path.clone());
+                path
+            },
+        };
+        match breakpoints_g.get(&path) {
+            Some(lines) => Ok(lines.contains(&pos.line)),
+            None => Ok(false),
+        }
+    }
+
+    fn pause(&mut self, interp: &mut Interp, env: &mut Env, reason: &str, desc: Option<String>, frames: &[StackFrame]) -> Result<()>
+    {
+        self.flush_output()?;
+        self.state.is_paused.store(true, Ordering::SeqCst);
+        let mut body = json!({ "reason": reason, "threadId": 1, "allThreadsStopped": true });
+        match desc {
+            Some(desc) => {
+                body["description"] = json!(desc.clone());
+                body["text"] = json!(desc);
+            },
+            None => (),
+        }
+        self.writer.send_event("stopped", body)?;
+        let res = self.execute_requests(interp, env, frames);
+        self.state.is_paused.store(false, Ordering::SeqCst);
+        res
+    }
+
+    fn execute_requests(&mut self, interp: &mut Interp, env: &mut Env, frames: &[StackFrame]) -> Result<()>
+    {
+        loop {
+            let req = match self.receiver.recv() {
+                Ok(tmp_req) => tmp_req,
+                Err(_) => return Err(Error::Stop(Stop::Quit)),
+            };
+            match req.command.as_str() {
+                "continue" => {
+                    self.step = Step::Continue;
+                    self.state.is_paused.store(false, Ordering::SeqCst);
+                    self.writer.send_response(&req, json!({ "allThreadsContinued": true }))?;
+                    break;
+                },
+                "next" | "stepIn" | "stepOut" => {
+                    self.step = match req.command.as_str() {
+                        "next" => Step::Over(env.stack().len()),
+                        "stepIn" => Step::Into,
+                        _ => Step::Out(env.stack().len()),
+                    };
+                    self.state.is_paused.store(false, Ordering::SeqCst);
+                    self.writer.send_response(&req, json!({}))?;
+                    break;
+                },
+                "stackTrace" => {
+                    let mut stack_frames: Vec<serde_json::Value> = Vec::new();
+                    for (i, frame) in frames.iter().enumerate() {
+                        stack_frames.push(json!({
+                                "id": i,
+                                "name": frame.name,
+                                "source": source(&frame.pos),
+                                "line": frame.pos.line,
+                                "column": frame.pos.column
+                        }));
+                    }
+                    self.writer.send_response(&req, json!({ "stackFrames": stack_frames, "totalFrames": frames.len() }))?;
+                },
+                "scopes" => {
+                    match req.args["frameId"].as_u64() {
+                        Some(frame_id) if (frame_id as usize) < frames.len() => {
+                            let mut scopes: Vec<serde_json::Value> = Vec::new();
+                            match frames[frame_id as usize].depth {
+                                Some(depth) if depth > 0 => scopes.push(json!({ "name": "Locals", "variablesReference": frame_id * 2 + 1, "expensive": false })),
+                                _ => (),
+                            }
+                            scopes.push(json!({ "name": "Globals", "variablesReference": frame_id * 2 + 2, "expensive": false }));
+                            self.writer.send_response(&req, json!({ "scopes": scopes }))?;
+                        },
+                        _ => self.writer.send_error_response(&req, "invalid frame")?,
+                    }
+                },
+                "variables" => {
+                    match req.args["variablesReference"].as_u64() {
+                        Some(var_ref) if var_ref > 0 && (((var_ref - 1) / 2) as usize) < frames.len() => {
+                            let vars = frame_vars(env, &frames[((var_ref - 1) / 2) as usize], var_ref % 2 == 1)?;
+                            let mut variables: Vec<serde_json::Value> = Vec::new();
+                            for (ident, value) in &vars {
+                                variables.push(json!({ "name": ident, "value": format!("{}", value), "variablesReference": 0 }));
+                            }
+                            self.writer.send_response(&req, json!({ "variables": variables }))?;
+                        },
+                        _ => self.writer.send_error_response(&req, "invalid variables reference")?,
+                    }
+                },
+                "evaluate" => {
+                    let expr = match req.args["expression"].as_str() {
+                        Some(s) => parse_expr(s),
+                        None => None,
+                    };
+                    match expr {
+                        Some(expr) => {
+                            let res = interp.eval_expr(env, &*expr);
+                            self.flush_output()?;
+                            match res {
+                                Ok(value) => self.writer.send_response(&req, json!({ "result": format!("{}", value), "variablesReference": 0 }))?,
+                                Err(err) => self.writer.send_error_response(&req, format!("{}", err).as_str())?,
+                            }
+                        },
+                        None => self.writer.send_error_response(&req, "invalid expression")?,
+                    }
+                },
+                "disconnect" | "terminate" => return Err(Error::Stop(Stop::Quit)),
+                _ => self.writer.send_error_response(&req, format!("unsupported request {}", req.command).as_str())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DebugHook for DapHook
+{
+    fn before_stat(&mut self, interp: &mut Interp, env: &mut Env, stat: &Stat) -> Result<()>
+    {
+        let pos = stat.pos();
+        let depth = env.stack().len();
+        self.flush_output()?;
+        self.intr_checker.check()?;
+        let reason = if self.intr_checker.take_pause() {
+            Some("pause")
+        } else if self.is_breakpoint(pos)? {
+            Some("breakpoint")
+        } else {
+            match self.step {
+                Step::Continue => None,
+                Step::Into => Some("step"),
+                Step::Over(depth2) if depth <= depth2 => Some("step"),
+                Step::Out(depth2) if depth < depth2 => Some("step"),
+                _ => None,
+            }
+        };
+        let reason = if self.is_entry { Some("entry") } else { reason };
+        self.is_entry = false;
+        match reason {
+            Some(reason) => {
+                let frames = stack_frames_with_depths(interp.stack_trace(), env);
+                self.pause(interp, env, reason, None, frames.as_slice())
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+/// A debug adapter server structure.
+///
+/// The debug adapter server reads requests of the Debug Adapter Protocol from a reader and writes
+/// responses and events to a writer. A script of the `launch` request is interpreted in other
+/// thread after the `configurationDone` request. The standard output and the standard error of
+/// the script are sent as `output` events.
+pub struct DapServer
+{
+    r: Box<dyn BufRead>,
+    writer: DapWriter,
+    root_mod: Arc<RwLock<ModNode<Value, ()>>>,
+    lib_path: OsString,
+    doc_path: OsString,
+    state: Arc<DapState>,
+    intr_checker: Arc<DapIntrChecker>,
+    launch_args: Option<(String, Vec<String>, bool)>,
+    is_configuration_done: bool,
+    sender: Option<Sender<Request>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DapServer
+{
+    /// Creates a debug adapter server.
+    ///
+    /// The debug adapter server reads requests from the reader and writes responses and events
+    /// to the writer. Also, this method takes a root module and paths of libraries and
+    /// documentations.
+    pub fn new(r: Box<dyn BufRead>, w: Box<dyn Write + Send>, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString) -> Self
+    {
+        DapServer {
+            r,
+            writer: DapWriter::new(w),
+            root_mod,
+            lib_path,
+            doc_path,
+            state: Arc::new(DapState { breakpoints: Mutex::new(BTreeMap::new()), is_paused: AtomicBool::new(false), }),
+            intr_checker: Arc::new(DapIntrChecker::new()),
+            launch_args: None,
+            is_configuration_done: false,
+            sender: None,
+            thread: None,
+        }
+    }
+
+    /// Runs the debug adapter server.
+    ///
+    /// The debug adapter server runs to the `disconnect` request or an end of file.
+    pub fn run(&mut self) -> Result<()>
+    {
+        let res = self.serve();
+        let res2 = self.stop();
+        match res {
+            Ok(()) => res2,
+            Err(err) => Err(err),
+        }
+    }
+
+    fn serve(&mut self) -> Result<()>
+    {
+        loop {
+            let msg = match read_message(&mut *self.r)? {
+                Some(tmp_msg) => tmp_msg,
+                None => break,
+            };
+            match Request::from_message(&msg) {
+                Some(req) => {
+                    if !self.execute_request(req)? {
+                        break;
+                    }
+                },
+                None => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()>
+    {
+        self.intr_checker.stop();
+        self.sender = None;
+        match self.thread.take() {
+            Some(thread) => {
+                match thread.join() {
+                    Ok(()) => Ok(()),
+                    Err(_) => Err(Error::Dap(String::from("can't join thread"))),
+                }
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn execute_request(&mut self, req: Request) -> Result<bool>
+    {
+        match req.command.as_str() {
+            "initialize" => {
+                self.writer.send_response(&req, json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsEvaluateForHovers": true,
+                        "supportsTerminateRequest": true
+                }))?;
+                self.writer.send_event("initialized", json!({}))?;
+            },
+            "launch" => {
+                match req.args["program"].as_str() {
+                    Some(program) => {
+                        let mut args: Vec<String> = Vec::new();
+                        match req.args["args"].as_array() {
+                            Some(arg_values) => {
+                                for arg_value in arg_values {
+                                    match arg_value.as_str() {
+                                        Some(arg) => args.push(String::from(arg)),
+                                        None => (),
+                                    }
+                                }
+                            },
+                            None => (),
+                        }
+                        let is_stop_on_entry = req.args["stopOnEntry"].as_bool().unwrap_or(false);
+                        self.launch_args = Some((canonicalize_path(program), args, is_stop_on_entry));
+                        self.writer.send_response(&req, json!({}))?;
+                        self.start()?;
+                    },
+                    None => self.writer.send_error_response(&req, "no program")?,
+                }
+            },
+            "setBreakpoints" => {
+                match req.args["source"]["path"].as_str() {
+                    Some(path) => {
+                        let mut lines: BTreeSet<u64> = BTreeSet::new();
+                        let mut breakpoints: Vec<serde_json::Value> = Vec::new();
+                        match req.args["breakpoints"].as_array() {
+                            Some(breakpoint_values) => {
+                                for breakpoint_value in breakpoint_values {
+                                    match breakpoint_value["line"].as_u64() {
+                                        Some(line) => {
+                                            lines.insert(line);
+                                            breakpoints.push(json!({ "verified": true, "line": line }));
+                                        },
+                                        None => breakpoints.push(json!({ "verified": false })),
+                                    }
+                                }
+                            },
+                            None => (),
+                        }
+                        {
+                            let mut breakpoints_g = mutex_lock(&self.state.breakpoints)?;
+                            if lines.is_empty() {
+                                breakpoints_g.remove(&canonicalize_path(path));
+                            } else {
+                                breakpoints_g.insert(canonicalize_path(path), lines);
+                            }
+                        }
+                        self.writer.send_response(&req, json!({ "breakpoints": breakpoints }))?;
+                    },
+                    None => self.writer.send_error_response(&req, "no source path")?,
+                }
+            },
+            "setExceptionBreakpoints" => self.writer.send_response(&req, json!({}))?,
+            "configurationDone" => {
+                self.is_configuration_done = true;
+                self.writer.send_response(&req, json!({}))?;
+                self.start()?;
+            },
+            "threads" => self.writer.send_response(&req, json!({ "threads": [{ "id": 1, "name": "main" }] }))?,
+            "pause" => {
+                self.intr_checker.pause();
+                self.writer.send_response(&req, json!({}))?;
+            },
+            "stackTrace" | "scopes" | "variables" | "evaluate" | "continue" | "next" | "stepIn" | "stepOut" => {
+                let req = if self.state.is_paused.load(Ordering::SeqCst) {
+                    match &self.sender {
+                        Some(sender) => {
+                            match sender.send(req) {
+                                Ok(()) => None,
+                                Err(err) => Some(err.0),
+                            }
+                        },
+                        None => Some(req),
+                    }
+                } else {
+                    Some(req)
+                };
+                match req {
+                    Some(req) => {
+                        match req.command.as_str() {
+                            "stackTrace" => self.writer.send_response(&req, json!({ "stackFrames": [], "totalFrames": 0 }))?,
+                            "continue" => self.writer.send_response(&req, json!({ "allThreadsContinued": true }))?,
+                            "next" | "stepIn" | "stepOut" => self.writer.send_response(&req, json!({}))?,
+                            _ => self.writer.send_error_response(&req, "script isn't paused")?,
+                        }
+                    },
+                    None => (),
+                }
+            },
+            "disconnect" | "terminate" => {
+                self.intr_checker.stop();
+                if self.state.is_paused.load(Ordering::SeqCst) {
+                    match &self.sender {
+                        Some(sender) => {
+                            let _res = sender.send(req.clone());
+                        },
+                        None => (),
+                    }
+                }
+                self.writer.send_response(&req, json!({}))?;
+                if req.command.as_str() == "disconnect" {
+                    return Ok(false);
+                }
+            },
+            _ => self.writer.send_error_response(&req, format!("unsupported request {}", req.command).as_str())?,
+        }
+        Ok(true)
+    }
+
+    fn start(&mut self) -> Result<()>
+    {
+        if !self.is_configuration_done || self.thread.is_some() {
+            return Ok(());
+        }
+        let (path, args, is_stop_on_entry) = match self.launch_args.take() {
+            Some(launch_args) => launch_args,
+            None => return Ok(()),
+        };
+        let (sender, receiver) = channel::<Request>();
+        let stdout = Arc::new(RwLock::new(Cursor::new(Vec::new())));
+        let stderr = Arc::new(RwLock::new(Cursor::new(Vec::new())));
+        let hook = DapHook {
+            writer: self.writer.clone(),
+            state: self.state.clone(),
+            intr_checker: self.intr_checker.clone(),
+            receiver,
+            step: Step::Continue,
+            is_entry: is_stop_on_entry,
+            canonical_paths: HashMap::new(),
+            stdout,
+            stderr,
+        };
+        let root_mod = self.root_mod.clone();
+        let lib_path = self.lib_path.clone();
+        let doc_path = self.doc_path.clone();
+        self.sender = Some(sender);
+        self.thread = Some(thread::spawn(move || {
+                    match interpret_script(path, args, root_mod, lib_path, doc_path, hook) {
+                        Ok(()) => (),
+                        Err(err) => eprint_error(&err),
+                    }
+        }));
+        Ok(())
+    }
+}
+
+fn interpret_script(path: String, args: Vec<String>, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, hook: DapHook) -> Result<()>
+{
+    let writer = hook.writer.clone();
+    let stdout = hook.stdout.clone();
+    let stderr = hook.stderr.clone();
+    let intr_checker: Arc<dyn IntrCheck + Send + Sync> = hook.intr_checker.clone();
+    let shared_env = SharedEnv::new_with_intr_checker(lib_path, doc_path, args, intr_checker);
+    let mut env = Env::new_with_script_dir_and_domain_and_shared_env(root_mod, PathBuf::from("."), None, Arc::new(RwLock::new(shared_env)));
+    env.set_stdin(Input::Null);
+    env.set_stdout(Output::Cursor(stdout.clone()));
+    env.set_stderr(Output::Cursor(stderr.clone()));
+    let hook = Arc::new(Mutex::new(hook));
+    let mut interp = Interp::new();
+    interp.set_debug_hook(Some(hook.clone()));
+    let exit_code = match parse(path) {
+        Ok(tree) => {
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => 0,
+                Err(Error::Stop(Stop::ErrorPropagation)) => {
+                    writer.send_event("output", json!({ "category": "stderr", "output": format!("{}\n", interp.ret_value()) }))?;
+                    1
+                },
+                Err(Error::Stop(Stop::Quit)) => 0,
+                Err(Error::Stop(Stop::Exit(code))) => code,
+                Err(err) => {
+                    writer.send_event("output", json!({ "category": "stderr", "output": format!("{}\n", err) }))?;
+                    match err {
+                        Error::Intr => (),
+                        _ => {
+                            interp.set_debug_hook(None);
+                            let frames = stack_frames_from_stack_trace(interp.stack_trace());
+                            let mut hook_g = mutex_lock(&*hook)?;
+                            match hook_g.pause(&mut interp, &mut env, "exception", Some(format!("{}", err)), frames.as_slice()) {
+                                Ok(()) | Err(Error::Stop(Stop::Quit)) => (),
+                                Err(err) => return Err(err),
+                            }
+                        },
+                    }
+                    1
+                },
+            }
+        },
+        Err(err) => {
+            writer.send_event("output", json!({ "category": "stderr", "output": format!("{}\n", err) }))?;
+            1
+        },
+    };
+    writer.send_output(&stdout, "stdout")?;
+    writer.send_output(&stderr, "stderr")?;
+    writer.send_event("exited", json!({ "exitCode": exit_code }))?;
+    writer.send_event("terminated", json!({}))
+}
+
+fn stack_frames_from_stack_trace(stack_trace: &[(Option<Value>, Pos)]) -> Vec<StackFrame>
+{
+    let mut frames: Vec<StackFrame> = Vec::new();
+    let mut frame_pos: Option<Pos> = None;
+    for (fun_value, pos) in stack_trace {
+        if frame_pos.is_none() {
+            frame_pos = Some(pos.clone());
+        }
+        match fun_value {
+            Some(fun_value) => {
+                match frame_pos.take() {
+                    Some(frame_pos) => frames.push(StackFrame { name: format!("{}", fun_value), pos: frame_pos, depth: None, }),
+                    None => (),
+                }
+            },
+            None => (),
+        }
+    }
+    match frame_pos {
+        Some(frame_pos) => frames.push(StackFrame { name: String::from("(main)"), pos: frame_pos, depth: None, }),
+        None => (),
+    }
+    frames
+}
+
+fn stack_frames_with_depths(stack_trace: &[(Option<Value>, Pos)], env: &Env) -> Vec<StackFrame>
+{
+    let mut frames = stack_frames_from_stack_trace(stack_trace);
+    let frame_count = frames.len();
+    if frame_count == env.stack().len() + 1 {
+        for (i, frame) in frames.iter_mut().enumerate() {
+            frame.depth = Some(frame_count - 1 - i);
+        }
+    }
+    frames
+}
+
+fn frame_vars(env: &Env, frame: &StackFrame, is_local: bool) -> Result<Vec<(String, Value)>>
+{
+    match frame.depth {
+        Some(depth) if depth > 0 => {
+            match env.stack().get(depth - 1) {
+                Some((fun_mod, local_vars)) => {
+                    if is_local {
+                        Ok(local_vars.iter().map(|(ident, value)| (ident.clone(), value.clone())).collect())
+                    } else {
+                        mod_vars(fun_mod)
+                    }
+                },
+                None => Ok(Vec::new()),
+            }
+        },
+        Some(_) => {
+            if is_local {
+                Ok(Vec::new())
+            } else {
+                mod_vars(env.current_mod())
+            }
+        },
+        None => {
+            if is_local {
+                Ok(Vec::new())
+            } else {
+                mod_vars(env.root_mod())
+            }
+        },
+    }
+}
+
+fn source(pos: &Pos) -> serde_json::Value
+{
+    let name = match PathBuf::from(pos.path.as_str()).file_name() {
+        Some(file_name) => file_name.to_string_lossy().into_owned(),
+        None => (*pos.path).clone(),
+    };
+    json!({ "name": name, "path": pos.path.as_str() })
+}
+
+fn canonicalize_path(path: &str) -> String
+{
+    match fs::canonicalize(path) {
+        Ok(path_buf) => path_buf.to_string_lossy().into_owned(),
+        Err(_) => String::from(path),
+    }
+}
+
+fn read_message(r: &mut dyn BufRead) -> Result<Option<serde_json::Value>>
+{
+    let mut content_len: Option<usize> = None;
+    let mut is_first = true;
+    loop {
+        let mut line = String::new();
+        match r.read_line(&mut line) {
+            Ok(0) if is_first => return Ok(None),
+            Ok(0) => return Err(Error::Dap(String::from("unexpected end of file"))),
+            Ok(_) => (),
+            Err(err) => return Err(Error::Io(err)),
+        }
+        is_first = false;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        match line.split_once(':') {
+            Some((name, value)) if name.trim().eq_ignore_ascii_case("Content-Length") => {
+                match value.trim().parse::<usize>() {
+                    Ok(len) => content_len = Some(len),
+                    Err(_) => return Err(Error::Dap(String::from("invalid content length"))),
+                }
+            },
+            _ => (),
+        }
+    }
+    let content_len = match content_len {
+        Some(tmp_content_len) => tmp_content_len,
+        None => return Err(Error::Dap(String::from("no content length"))),
+    };
+    let mut buf = vec![0u8; content_len];
+    match r.read_exact(buf.as_mut_slice()) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    match serde_json::from_slice(buf.as_slice()) {
+        Ok(msg) => Ok(Some(msg)),
+        Err(err) => Err(Error::SerdeJson(err)),
+    }
+}
+
+fn write_message(w: &mut dyn Write, msg: &serde_json::Value) -> Result<()>
+{
+    let s = match serde_json::to_string(msg) {
+        Ok(tmp_s) => tmp_s,
+        Err(err) => return Err(Error::SerdeJson(err)),
+    };
+    match write!(w, "Content-Length: {}\r\n\r\n{}", s.len(), s) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    match w.flush() {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,219 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io;
+use std::io::BufReader;
+use std::io::Read;
+use sealed_test::prelude::*;
+use crate::builtins::add_std_builtin_funs;
+use super::*;
+
+struct ChannelReader
+{
+    receiver: Receiver<Vec<u8>>,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for ChannelReader
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        if self.pos >= self.buf.len() {
+            match self.receiver.recv() {
+                Ok(buf2) => {
+                    self.buf = buf2;
+                    self.pos = 0;
+                },
+                Err(_) => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.buf.len() - self.pos);
+        buf[..len].copy_from_slice(&self.buf[self.pos..(self.pos + len)]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+struct ChannelWriter
+{
+    sender: Sender<Vec<u8>>,
+}
+
+impl Write for ChannelWriter
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        let _res = self.sender.send(buf.to_vec());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()>
+    { Ok(()) }
+}
+
+struct Client
+{
+    sender: Option<Sender<Vec<u8>>>,
+    r: BufReader<ChannelReader>,
+    seq: u64,
+}
+
+impl Client
+{
+    fn send_request(&mut self, command: &str, args: serde_json::Value) -> u64
+    {
+        self.seq += 1;
+        let mut buf: Vec<u8> = Vec::new();
+        write_message(&mut buf, &json!({ "seq": self.seq, "type": "request", "command": command, "arguments": args })).unwrap();
+        self.sender.as_ref().unwrap().send(buf).unwrap();
+        self.seq
+    }
+
+    fn read_until<F>(&mut self, mut f: F) -> Vec<serde_json::Value>
+        where F: FnMut(&serde_json::Value) -> bool
+    {
+        let mut msgs: Vec<serde_json::Value> = Vec::new();
+        loop {
+            let msg = read_message(&mut self.r).unwrap().unwrap();
+            let is_end = f(&msg);
+            msgs.push(msg);
+            if is_end {
+                break;
+            }
+        }
+        msgs
+    }
+
+    fn read_response(&mut self, seq: u64) -> serde_json::Value
+    {
+        let mut msgs = self.read_until(|msg| msg["type"].as_str() == Some("response") && msg["request_seq"].as_u64() == Some(seq));
+        msgs.pop().unwrap()
+    }
+
+    fn read_event(&mut self, event: &str) -> Vec<serde_json::Value>
+    { self.read_until(|msg| msg["type"].as_str() == Some("event") && msg["event"].as_str() == Some(event)) }
+}
+
+fn start_server() -> (Client, JoinHandle<()>)
+{
+    let (client_sender, server_receiver) = channel::<Vec<u8>>();
+    let (server_sender, client_receiver) = channel::<Vec<u8>>();
+    let thread = thread::spawn(move || {
+            let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+            add_std_builtin_funs(&mut root_mod);
+            let r = BufReader::new(ChannelReader { receiver: server_receiver, buf: Vec::new(), pos: 0, });
+            let w = ChannelWriter { sender: server_sender, };
+            let mut server = DapServer::new(Box::new(r), Box::new(w), Arc::new(RwLock::new(root_mod)), OsString::from("lib"), OsString::from("doc"));
+            server.run().unwrap();
+    });
+    let client = Client {
+        sender: Some(client_sender),
+        r: BufReader::new(ChannelReader { receiver: client_receiver, buf: Vec::new(), pos: 0, }),
+        seq: 0,
+    };
+    (client, thread)
+}
+
+#[sealed_test]
+fn test_dap_server_run_pauses_script_at_breakpoint()
+{
+    let script_content = "
+function f(a)
+    b = a + 1
+    b
+end
+x = 1
+y = f(x)
+println(y)
+";
+    fs::write("test.un", &script_content[1..]).unwrap();
+    let (mut client, thread) = start_server();
+    let seq = client.send_request("initialize", json!({ "adapterID": "unlab" }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["success"].as_bool());
+    assert_eq!(Some(true), res["body"]["supportsConfigurationDoneRequest"].as_bool());
+    client.read_event("initialized");
+    let seq = client.send_request("launch", json!({ "program": "test.un" }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["success"].as_bool());
+    let seq = client.send_request("setBreakpoints", json!({ "source": { "path": "test.un" }, "breakpoints": [{ "line": 2 }] }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["body"]["breakpoints"][0]["verified"].as_bool());
+    assert_eq!(Some(2), res["body"]["breakpoints"][0]["line"].as_u64());
+    let seq = client.send_request("configurationDone", json!({}));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["success"].as_bool());
+    let msgs = client.read_event("stopped");
+    assert_eq!(Some("breakpoint"), msgs.last().unwrap()["body"]["reason"].as_str());
+    let seq = client.send_request("stackTrace", json!({ "threadId": 1 }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(2), res["body"]["totalFrames"].as_u64());
+    assert_eq!(Some(2), res["body"]["stackFrames"][0]["line"].as_u64());
+    assert_eq!(Some(6), res["body"]["stackFrames"][1]["line"].as_u64());
+    assert_eq!(Some("f"), res["body"]["stackFrames"][0]["name"].as_str());
+    assert_eq!(Some("(main)"), res["body"]["stackFrames"][1]["name"].as_str());
+    assert_eq!(Some("test.un"), res["body"]["stackFrames"][0]["source"]["name"].as_str());
+    let seq = client.send_request("scopes", json!({ "frameId": 0 }));
+    let res = client.read_response(seq);
+    assert_eq!(Some("Locals"), res["body"]["scopes"][0]["name"].as_str());
+    let var_ref = res["body"]["scopes"][0]["variablesReference"].as_u64().unwrap();
+    let seq = client.send_request("variables", json!({ "variablesReference": var_ref }));
+    let res = client.read_response(seq);
+    assert_eq!(Some("a"), res["body"]["variables"][0]["name"].as_str());
+    assert_eq!(Some("1"), res["body"]["variables"][0]["value"].as_str());
+    let seq = client.send_request("evaluate", json!({ "expression": "a + 1", "frameId": 0 }));
+    let res = client.read_response(seq);
+    assert_eq!(Some("2"), res["body"]["result"].as_str());
+    let seq = client.send_request("continue", json!({ "threadId": 1 }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["success"].as_bool());
+    let msgs = client.read_event("terminated");
+    assert!(msgs.iter().any(|msg| msg["event"].as_str() == Some("output") && msg["body"]["output"].as_str() == Some("2\n")));
+    assert!(msgs.iter().any(|msg| msg["event"].as_str() == Some("exited") && msg["body"]["exitCode"].as_i64() == Some(0)));
+    let seq = client.send_request("disconnect", json!({}));
+    let res = client.read_response(seq);
+    assert_eq!(Some(true), res["success"].as_bool());
+    client.sender = None;
+    thread.join().unwrap();
+}
+
+#[sealed_test]
+fn test_dap_server_run_stops_script_at_exception()
+{
+    let script_content = "
+x = 1
+y = x + z
+";
+    fs::write("test.un", &script_content[1..]).unwrap();
+    let (mut client, thread) = start_server();
+    let seq = client.send_request("initialize", json!({ "adapterID": "unlab" }));
+    client.read_response(seq);
+    let seq = client.send_request("launch", json!({ "program": "test.un" }));
+    client.read_response(seq);
+    let seq = client.send_request("configurationDone", json!({}));
+    client.read_response(seq);
+    let msgs = client.read_event("stopped");
+    assert_eq!(Some("exception"), msgs.last().unwrap()["body"]["reason"].as_str());
+    let seq = client.send_request("stackTrace", json!({ "threadId": 1 }));
+    let res = client.read_response(seq);
+    assert_eq!(Some(1), res["body"]["totalFrames"].as_u64());
+    assert_eq!(Some(2), res["body"]["stackFrames"][0]["line"].as_u64());
+    let seq = client.send_request("scopes", json!({ "frameId": 0 }));
+    let res = client.read_response(seq);
+    let var_ref = res["body"]["scopes"][0]["variablesReference"].as_u64().unwrap();
+    let seq = client.send_request("variables", json!({ "variablesReference": var_ref }));
+    let res = client.read_response(seq);
+    let vars = res["body"]["variables"].as_array().unwrap();
+    assert!(vars.iter().any(|var| var["name"].as_str() == Some("x") && var["value"].as_str() == Some("1")));
+    let seq = client.send_request("continue", json!({ "threadId": 1 }));
+    client.read_response(seq);
+    let msgs = client.read_event("terminated");
+    assert!(msgs.iter().any(|msg| msg["event"].as_str() == Some("exited") && msg["body"]["exitCode"].as_i64() == Some(1)));
+    client.sender = None;
+    thread.join().unwrap();
+}
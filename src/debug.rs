@@ -0,0 +1,325 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A debug module.
+use std::collections::BTreeSet;
+use std::io::Cursor;
+use std::io::Write;
+use std::io;
+use std::sync::Arc;
+use std::sync::RwLock;
+use crate::doc::*;
+use crate::env::*;
+use crate::error::*;
+use crate::interp::*;
+use crate::lexer::*;
+use crate::mod_node::*;
+use crate::parser::*;
+use crate::tree::*;
+use crate::utils::*;
+use crate::value::*;
+
+/// A trait of debug hook.
+///
+/// The debug hook is called by an interpreter before each statement if the debug hook is set for
+/// the interpreter. The debug hook can be used by a debugger or an integration with an IDE.
+pub trait DebugHook
+{
+    /// Is called before interpreting the statement.
+    ///
+    /// If this method returns an error, the interpreter stops interpreting with this error.
+    fn before_stat(&mut self, interp: &mut Interp, env: &mut Env, stat: &Stat) -> Result<()>;
+}
+
+/// An enumeration of step.
+///
+/// The step determines where a debugger pauses an interpreter. The depths are the numbers of
+/// functions on the stack of environment.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Step
+{
+    /// Pauses only at the breakpoints.
+    Continue,
+    /// Pauses at a next statement.
+    Into,
+    /// Pauses at a next statement in the function with the depth or with lower depth.
+    Over(usize),
+    /// Pauses at a next statement in the function with lower depth than the depth.
+    Out(usize),
+}
+
+/// A debugger structure.
+///
+/// The debugger is a debug hook that pauses an interpreter at the breakpoints or after the steps.
+/// The paused debugger reads commands by a line reader. The commands allow to set breakpoints,
+/// step, inspect local variables and module variables, and evaluate expressions in the paused
+/// function.
+pub struct Debugger
+{
+    breakpoints: BTreeSet<(String, u64)>,
+    step: Step,
+    line_reader: Box<dyn FnMut(&str) -> Option<String> + Send>,
+}
+
+impl Debugger
+{
+    /// Creates a debugger with the line reader.
+    ///
+    /// The line reader takes a prompt and returns a read line or `None` for an end of file. The
+    /// created debugger pauses the interpreter at a first statement.
+    pub fn new(line_reader: Box<dyn FnMut(&str) -> Option<String> + Send>) -> Self
+    { Debugger { breakpoints: BTreeSet::new(), step: Step::Into, line_reader, } }
+
+    /// Returns the breakpoints as pairs of paths and lines.
+    pub fn breakpoints(&self) -> &BTreeSet<(String, u64)>
+    { &self.breakpoints }
+
+    /// Adds the breakpoint and returns `true` if the breakpoint isn't already added, otherwise
+    /// `false`.
+    pub fn add_breakpoint(&mut self, path: String, line: u64) -> bool
+    { self.breakpoints.insert((path, line)) }
+
+    /// Removes the breakpoint and returns `true` if the breakpoint is removed, otherwise `false`.
+    pub fn remove_breakpoint(&mut self, path: String, line: u64) -> bool
+    { self.breakpoints.remove(&(path, line)) }
+
+    /// Returns the step.
+    pub fn step(&self) -> Step
+    { self.step }
+
+    /// Sets the step.
+    pub fn set_step(&mut self, step: Step)
+    { self.step = step; }
+
+    /// Returns `true` if the debugger pauses the interpreter at the file position, otherwise
+    /// `false`.
+    pub fn is_pause(&self, env: &Env, pos: &Pos) -> bool
+    {
+        let is_breakpoint = self.breakpoints.contains(&((*pos.path).clone(), pos.line));
+        let depth = env.stack().len();
+        match self.step {
+            Step::Continue => is_breakpoint,
+            Step::Into => true,
+            Step::Over(depth2) => is_breakpoint || depth <= depth2,
+            Step::Out(depth2) => is_breakpoint || depth < depth2,
+        }
+    }
+
+    /// Executes the command for the debugger paused at the file position.
+    ///
+    /// This method writes an output of the command to the writer and returns `true` if the
+    /// interpreter should be resumed, otherwise `false`. The `quit` command returns a quit error.
+    pub fn execute_command(&mut self, interp: &mut Interp, env: &mut Env, pos: &Pos, line: &str, w: &mut dyn Write) -> Result<bool>
+    {
+        let line = line.trim();
+        let (cmd, arg) = match line.find(char::is_whitespace) {
+            Some(i) => (&line[..i], line[i..].trim()),
+            None => (line, ""),
+        };
+        match cmd {
+            "" => Ok(false),
+            "c" | "continue" => {
+                self.step = Step::Continue;
+                Ok(true)
+            },
+            "s" | "step" => {
+                self.step = Step::Into;
+                Ok(true)
+            },
+            "n" | "next" => {
+                self.step = Step::Over(env.stack().len());
+                Ok(true)
+            },
+            "f" | "finish" => {
+                self.step = Step::Out(env.stack().len());
+                Ok(true)
+            },
+            "b" | "break" => {
+                match parse_breakpoint(arg, pos) {
+                    Some((path, line)) => {
+                        write_line(w, format!("breakpoint at {}: {}", path, line).as_str())?;
+                        self.add_breakpoint(path, line);
+                    },
+                    None => write_line(w, "invalid breakpoint")?,
+                }
+                Ok(false)
+            },
+            "d" | "delete" => {
+                match parse_breakpoint(arg, pos) {
+                    Some((path, line)) => {
+                        if !self.remove_breakpoint(path, line) {
+                            write_line(w, "no breakpoint")?;
+                        }
+                    },
+                    None => write_line(w, "invalid breakpoint")?,
+                }
+                Ok(false)
+            },
+            "bl" | "breakpoints" => {
+                for (path, line) in &self.breakpoints {
+                    write_line(w, format!("{}: {}", path, line).as_str())?;
+                }
+                Ok(false)
+            },
+            "w" | "where" => {
+                write_line(w, format!("{}: {}.{}", pos.path, pos.line, pos.column).as_str())?;
+                Ok(false)
+            },
+            "l" | "locals" => {
+                match env.stack().last() {
+                    Some((_, local_vars)) => {
+                        for (ident, value) in local_vars {
+                            write_line(w, format!("{} = {}", ident, value).as_str())?;
+                        }
+                    },
+                    None => write_line(w, "no local variables")?,
+                }
+                Ok(false)
+            },
+            "g" | "globals" => {
+                let mod1 = match env.stack().last() {
+                    Some((fun_mod, _)) => fun_mod.clone(),
+                    None => env.current_mod().clone(),
+                };
+                let vars = mod_vars(&mod1)?;
+                for (ident, value) in &vars {
+                    write_line(w, format!("{} = {}", ident, value).as_str())?;
+                }
+                Ok(false)
+            },
+            "p" | "print" => {
+                match parse_expr(arg) {
+                    Some(expr) => {
+                        match interp.eval_expr(env, &*expr) {
+                            Ok(value) => write_line(w, format!("{}", value).as_str())?,
+                            Err(err) => write_line(w, format!("{}", err).as_str())?,
+                        }
+                    },
+                    None => write_line(w, "invalid expression")?,
+                }
+                Ok(false)
+            },
+            "q" | "quit" => Err(Error::Stop(Stop::Quit)),
+            "h" | "help" => {
+                write_line(w, "c, continue        continue to next breakpoint")?;
+                write_line(w, "s, step            step into function")?;
+                write_line(w, "n, next            step over function")?;
+                write_line(w, "f, finish          step out of function")?;
+                write_line(w, "b, break [PATH:]LINE")?;
+                write_line(w, "                   add breakpoint")?;
+                write_line(w, "d, delete [PATH:]LINE")?;
+                write_line(w, "                   delete breakpoint")?;
+                write_line(w, "bl, breakpoints    show breakpoints")?;
+                write_line(w, "w, where           show current position")?;
+                write_line(w, "l, locals          show local variables")?;
+                write_line(w, "g, globals         show module variables")?;
+                write_line(w, "p, print EXPR      evaluate expression")?;
+                write_line(w, "q, quit            quit")?;
+                write_line(w, "h, help            show this help")?;
+                Ok(false)
+            },
+            _ => {
+                write_line(w, format!("unknown command {}", cmd).as_str())?;
+                Ok(false)
+            },
+        }
+    }
+}
+
+impl DebugHook for Debugger
+{
+    fn before_stat(&mut self, interp: &mut Interp, env: &mut Env, stat: &Stat) -> Result<()>
+    {
+        let pos = stat.pos();
+        if !self.is_pause(env, pos) {
+            return Ok(());
+        }
+        let mut stdout = io::stdout();
+        write_line(&mut stdout, format!("paused at {}: {}.{}", pos.path, pos.line, pos.column).as_str())?;
+        loop {
+            match (self.line_reader)("debug> ") {
+                Some(line) => {
+                    if self.execute_command(interp, env, pos, line.as_str(), &mut stdout)? {
+                        break;
+                    }
+                },
+                None => return Err(Error::Stop(Stop::Quit)),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_line(w: &mut dyn Write, s: &str) -> Result<()>
+{
+    match writeln!(w, "{}", s) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+pub(crate) fn mod_vars(mod1: &Arc<RwLock<ModNode<Value, ()>>>) -> Result<Vec<(String, Value)>>
+{
+    let mut vars: Vec<(String, Value)> = Vec::new();
+    {
+        let mod_g = rw_lock_read(&**mod1)?;
+        for (ident, value) in mod_g.vars() {
+            match value {
+                Value::Object(object) => {
+                    match &**object {
+                        Object::BuiltinFun(_, _) => (),
+                        _ => vars.push((ident.clone(), value.clone())),
+                    }
+                },
+                _ => vars.push((ident.clone(), value.clone())),
+            }
+        }
+    }
+    vars.sort_by(|(ident, _), (ident2, _)| ident.cmp(ident2));
+    Ok(vars)
+}
+
+fn parse_breakpoint(s: &str, pos: &Pos) -> Option<(String, u64)>
+{
+    let (path, line_s) = match s.rfind(':') {
+        Some(i) => (String::from(&s[..i]), &s[(i + 1)..]),
+        None => ((*pos.path).clone(), s),
+    };
+    match line_s.trim().parse::<u64>() {
+        Ok(line) if !path.is_empty() => Some((path, line)),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_expr(s: &str) -> Option<Box<Expr>>
+{
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("(debug)")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(mut nodes)) => {
+            if nodes.len() != 1 {
+                return None;
+            }
+            match nodes.pop() {
+                Some(Node::Stat(stat)) => {
+                    match *stat {
+                        Stat::Expr(expr, _) => Some(expr),
+                        _ => None,
+                    }
+                },
+                _ => None,
+            }
+        },
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
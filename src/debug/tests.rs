@@ -0,0 +1,140 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::sync::Mutex;
+use std::sync::RwLock;
+use super::*;
+
+#[test]
+fn test_debugger_is_pause_returns_flags_for_steps_and_breakpoints()
+{
+    let env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut debugger = Debugger::new(Box::new(|_| None));
+    let pos = Pos::new(Arc::new(String::from("test.un")), 2, 1);
+    let pos2 = Pos::new(Arc::new(String::from("test.un")), 3, 1);
+    assert_eq!(true, debugger.is_pause(&env, &pos));
+    debugger.set_step(Step::Continue);
+    assert_eq!(false, debugger.is_pause(&env, &pos));
+    assert_eq!(true, debugger.add_breakpoint(String::from("test.un"), 2));
+    assert_eq!(true, debugger.is_pause(&env, &pos));
+    assert_eq!(false, debugger.is_pause(&env, &pos2));
+    debugger.set_step(Step::Over(0));
+    assert_eq!(true, debugger.is_pause(&env, &pos2));
+    debugger.set_step(Step::Out(0));
+    assert_eq!(false, debugger.is_pause(&env, &pos2));
+    assert_eq!(true, debugger.remove_breakpoint(String::from("test.un"), 2));
+    assert_eq!(false, debugger.is_pause(&env, &pos));
+}
+
+#[test]
+fn test_debugger_execute_command_executes_commands()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    match env.set_var(&Name::Var(String::from("X")), Value::Int(2)) {
+        Ok(true) => assert!(true),
+        _ => assert!(false),
+    }
+    let mut interp = Interp::new();
+    let mut debugger = Debugger::new(Box::new(|_| None));
+    let pos = Pos::new(Arc::new(String::from("test.un")), 2, 5);
+    let mut w: Vec<u8> = Vec::new();
+    match debugger.execute_command(&mut interp, &mut env, &pos, "b 4", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "break other.un:10", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "where", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "l", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "g", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "p X + 1", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    match debugger.execute_command(&mut interp, &mut env, &pos, "p Y", &mut w) {
+        Ok(false) => assert!(true),
+        _ => assert!(false),
+    }
+    let expected_s = "
+breakpoint at test.un: 4
+breakpoint at other.un: 10
+test.un: 2.5
+no local variables
+X = 2
+3
+variable Y isn't set
+";
+    assert_eq!(String::from(&expected_s[1..]), String::from_utf8(w).unwrap());
+    assert_eq!(true, interp.stack_trace().is_empty());
+    assert_eq!(true, debugger.breakpoints().contains(&(String::from("test.un"), 4)));
+    assert_eq!(true, debugger.breakpoints().contains(&(String::from("other.un"), 10)));
+    let mut w2: Vec<u8> = Vec::new();
+    match debugger.execute_command(&mut interp, &mut env, &pos, "c", &mut w2) {
+        Ok(true) => assert!(true),
+        _ => assert!(false),
+    }
+    assert_eq!(Step::Continue, debugger.step());
+    match debugger.execute_command(&mut interp, &mut env, &pos, "q", &mut w2) {
+        Err(Error::Stop(Stop::Quit)) => assert!(true),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_debugger_before_stat_pauses_interpreter_at_breakpoint()
+{
+    let s = "
+function f(X)
+    Y = X + 1
+    Y
+end
+Z = f(1)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let prompts: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+            let prompts2 = prompts.clone();
+            let mut cmds = vec![String::from("c"), String::from("c"), String::from("b 3")];
+            let debugger = Debugger::new(Box::new(move |prompt| {
+                    prompts2.lock().unwrap().push(String::from(prompt));
+                    cmds.pop()
+            }));
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            interp.set_debug_hook(Some(Arc::new(Mutex::new(debugger))));
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            assert_eq!(3, prompts.lock().unwrap().len());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("Z")) {
+                Some(Value::Int(2)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
@@ -88,6 +88,18 @@ pub enum BuiltinFunArg
     DotDotDot,
 }
 
+/// An enumeration of argument of function.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FunArg
+{
+    /// An argument.
+    Arg(String),
+    /// An argument with a default value which is stored as a string of expression.
+    DefaultArg(String, String),
+    /// A rest argument.
+    RestArg(String),
+}
+
 /// A signature structure.
 ///
 /// The signature specifies whether a variable is a normal variable, a function, or a built-in
@@ -99,7 +111,7 @@ pub enum Sig
     /// A normal variable.
     Var,
     /// A function.
-    Fun(Vec<String>),
+    Fun(Vec<FunArg>),
     /// A built-in function.
     BuiltinFun(Vec<BuiltinFunArg>),
 }
@@ -317,7 +329,15 @@ impl DocTreeGen
                 match &**fun {
                     Fun(args, _) => {
                         let mut sig_current_mod_g = rw_lock_write(&*self.env.sig_current_mod)?;
-                        sig_current_mod_g.add_var(ident.clone(), Sig::Fun(args.iter().map(|a| a.0.clone()).collect()));
+                        let mut fun_args: Vec<FunArg> = Vec::new();
+                        for arg in args {
+                            match arg {
+                                Arg(ident2, ArgKind::Normal, _) => fun_args.push(FunArg::Arg(ident2.clone())),
+                                Arg(ident2, ArgKind::Default(expr), _) => fun_args.push(FunArg::DefaultArg(ident2.clone(), format!("{}", expr))),
+                                Arg(ident2, ArgKind::Rest, _) => fun_args.push(FunArg::RestArg(ident2.clone())),
+                            }
+                        }
+                        sig_current_mod_g.add_var(ident.clone(), Sig::Fun(fun_args));
                     },
                 }
             },
@@ -330,7 +350,7 @@ impl DocTreeGen
         match stat {
             Stat::Expr(expr, _) => {
                 match &**expr {
-                    Expr::App(expr2, exprs, _) => {
+                    Expr::App(expr2, exprs, _, _) => {
                         let is_run_with_doc = match &**expr2 {
                             Expr::Var(Name::Abs(idents, ident), _) => idents.is_empty() && ident == &self.run_with_doc_ident,
                             Expr::Var(Name::Rel(_, _), _) => false,
@@ -501,7 +521,11 @@ impl DocGen
                     if !is_first {
                         html.push_str(", ");
                     }
-                    html.push_str(format!("<span class=\"arg\">{}</span>", arg).as_str());
+                    match arg {
+                        FunArg::Arg(ident) => html.push_str(format!("<span class=\"arg\">{}</span>", ident).as_str()),
+                        FunArg::DefaultArg(ident, default) => html.push_str(format!("<span class=\"arg\">{}</span> = {}", ident, str_to_html(default)).as_str()),
+                        FunArg::RestArg(ident) => html.push_str(format!("<span class=\"arg\">{}</span>...", ident).as_str()),
+                    }
                     is_first = false;
                 }
                 html.push(')');
@@ -57,14 +57,14 @@ Y = 2
                     assert_eq!(true, a_subtrees.is_empty());
                     let a_var_desc_pairs = a_subtree_g.var_desc_pairs();
                     assert_eq!(2, a_var_desc_pairs.len());
-                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![String::from("X")]), Some(&String::from("Some text2.\nSome text3.\n"))))));
+                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), Some(&String::from("Some text2.\nSome text3.\n"))))));
                     assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("X"), (&Sig::Var, Some(&String::from("Some text4.\n"))))));
                 },
                 None => assert!(false),
             }
             let var_desc_pairs = doc_tree_g.var_desc_pairs();
             assert_eq!(2, var_desc_pairs.len());
-            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![String::from("X")]), Some(&String::from("Some text5.\n"))))));
+            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), Some(&String::from("Some text5.\n"))))));
             assert_eq!(true, var_desc_pairs.contains(&(&String::from("Y"), (&Sig::Var, Some(&String::from("Some text6.\n"))))));
         },
         Err(_) => assert!(false),
@@ -110,20 +110,52 @@ Y = 2
                     assert_eq!(true, a_subtrees.is_empty());
                     let a_var_desc_pairs = a_subtree_g.var_desc_pairs();
                     assert_eq!(2, a_var_desc_pairs.len());
-                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![String::from("X")]), None))));
+                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), None))));
                     assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("X"), (&Sig::Var, None))));
                 },
                 None => assert!(false),
             }
             let var_desc_pairs = doc_tree_g.var_desc_pairs();
             assert_eq!(2, var_desc_pairs.len());
-            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![String::from("X")]), None))));
+            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), None))));
             assert_eq!(true, var_desc_pairs.contains(&(&String::from("Y"), (&Sig::Var, None))));
         },
         Err(_) => assert!(false),
     }
 }
 
+#[sealed_test]
+fn test_doc_tree_gen_generate_generates_documentation_tree_with_default_and_rest_arguments()
+{
+    let s = "
+%% Some text.
+function f(X, Y = [1, 2], Z...)
+    X + Y
+end
+";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new_with_doc_flag(Arc::new(String::from("test.un")), &mut cursor, true);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new_with_doc_root_mod(path, tokens, Some(Arc::new(RwLock::new(ModNode::new(None)))));
+    let tree = parser.parse().unwrap();
+    let mut doc_tree_gen = DocTreeGen::new(parser.doc_root_mod().unwrap().clone());
+    match doc_tree_gen.generate(&tree) {
+        Ok(doc_tree) => {
+            let doc_tree_g = doc_tree.read().unwrap();
+            let var_desc_pairs = doc_tree_g.var_desc_pairs();
+            assert_eq!(1, var_desc_pairs.len());
+            let sig = Sig::Fun(vec![
+                    FunArg::Arg(String::from("X")),
+                    FunArg::DefaultArg(String::from("Y"), String::from("[1, 2]")),
+                    FunArg::RestArg(String::from("Z"))
+            ]);
+            assert_eq!(true, var_desc_pairs.contains(&(&String::from("f"), (&sig, Some(&String::from("Some text.\n"))))));
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[sealed_test]
 fn test_doc_tree_gen_generate_generates_documentation_tree_with_included_files()
 {
@@ -198,7 +230,7 @@ Y = 2
                             assert_eq!(true, a_b_subtrees.is_empty());
                             let a_b_var_desc_pairs = a_b_subtree_g.var_desc_pairs();
                             assert_eq!(1, a_b_var_desc_pairs.len());
-                            assert_eq!(true, a_b_var_desc_pairs.contains(&(&String::from("f1"), (&Sig::Fun(vec![String::from("X"), String::from("Y")]), Some(&String::from("Some text12.\n"))))));
+                            assert_eq!(true, a_b_var_desc_pairs.contains(&(&String::from("f1"), (&Sig::Fun(vec![FunArg::Arg(String::from("X")), FunArg::Arg(String::from("Y"))]), Some(&String::from("Some text12.\n"))))));
                         },
                         None => assert!(false),
                     }
@@ -210,7 +242,7 @@ Y = 2
                             assert_eq!(true, a_c_subtrees.is_empty());
                             let a_c_var_desc_pairs = a_c_subtree_g.var_desc_pairs();
                             assert_eq!(1, a_c_var_desc_pairs.len());
-                            assert_eq!(true, a_c_var_desc_pairs.contains(&(&String::from("f2"), (&Sig::Fun(vec![String::from("Y"), String::from("Z")]), Some(&String::from("Some text22.\n"))))));
+                            assert_eq!(true, a_c_var_desc_pairs.contains(&(&String::from("f2"), (&Sig::Fun(vec![FunArg::Arg(String::from("Y")), FunArg::Arg(String::from("Z"))]), Some(&String::from("Some text22.\n"))))));
                         },
                         None => assert!(false),
                     }
@@ -223,7 +255,7 @@ Y = 2
             }
             let var_desc_pairs = doc_tree_g.var_desc_pairs();
             assert_eq!(2, var_desc_pairs.len());
-            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![String::from("X")]), Some(&String::from("Some text2.\n"))))));
+            assert_eq!(true, var_desc_pairs.contains(&(&String::from("g"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), Some(&String::from("Some text2.\n"))))));
             assert_eq!(true, var_desc_pairs.contains(&(&String::from("Y"), (&Sig::Var, Some(&String::from("Some text3.\n"))))));
         },
         Err(_) => assert!(false),
@@ -298,7 +330,7 @@ end
                                     assert_eq!(true, a_b_c_subtrees.is_empty());
                                     let a_b_c_var_desc_pairs = a_b_c_subtree_g.var_desc_pairs();
                                     assert_eq!(1, a_b_c_var_desc_pairs.len());
-                                    assert_eq!(true, a_b_c_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![String::from("X"), String::from("Y")]), Some(&String::from("Some text4.\n"))))));
+                                    assert_eq!(true, a_b_c_var_desc_pairs.contains(&(&String::from("f"), (&Sig::Fun(vec![FunArg::Arg(String::from("X")), FunArg::Arg(String::from("Y"))]), Some(&String::from("Some text4.\n"))))));
                                 },
                                 None => assert!(false),
                             }
@@ -407,7 +439,7 @@ end
                     assert_eq!(true, a_subtrees.is_empty());
                     let a_var_desc_pairs = a_subtree_g.var_desc_pairs();
                     assert_eq!(2, a_var_desc_pairs.len());
-                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("runwithdoc"), (&Sig::Fun(vec![String::from("X")]), None))));
+                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("runwithdoc"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), None))));
                     assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("X"), (&Sig::Var, None))));
                 },
                 None => assert!(false),
@@ -458,7 +490,7 @@ end
                     assert_eq!(true, a_subtrees.is_empty());
                     let a_var_desc_pairs = a_subtree_g.var_desc_pairs();
                     assert_eq!(1, a_var_desc_pairs.len());
-                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("runwithdoc"), (&Sig::Fun(vec![String::from("X")]), None))));
+                    assert_eq!(true, a_var_desc_pairs.contains(&(&String::from("runwithdoc"), (&Sig::Fun(vec![FunArg::Arg(String::from("X"))]), None))));
                 },
                 None => assert!(false),
             }
@@ -8,6 +8,7 @@
 //! An environment module.
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::ffi::OsString;
@@ -16,6 +17,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::sync::Weak;
 use std::time::Instant;
 #[cfg(feature = "plot")]
 use crate::winit;
@@ -355,29 +357,53 @@ impl Env
 
     /// Pushes the function module and a local variables to the stack for applies the function.
     ///
-    /// This method pushes the function module and the local variables and returns `true` if all
-    /// identifiers of arguments are different, otherwise this method returns `false`. 
+    /// This method pushes the function module and the local variables and returns `true` if
+    /// values are passed to all arguments without default values, otherwise this method returns
+    /// `false`.
     pub fn push_fun_mod_and_local_vars(&mut self, fun_mod_idents: &[String], args: &[Arg], arg_values: &[Value]) -> Result<bool>
+    { self.push_fun_mod_and_local_vars_with_named_args(fun_mod_idents, args, arg_values, &[]) }
+
+    /// Pushes the function module and a local variables to the stack for applies the function
+    /// with the named arguments.
+    ///
+    /// This method pushes the function module and the local variables and returns `true` if
+    /// values are passed to all arguments without default values, otherwise this method returns
+    /// `false`. A rest argument takes an array of remaining argument values. Arguments with
+    /// default values which don't have passed values aren't set.
+    pub fn push_fun_mod_and_local_vars_with_named_args(&mut self, fun_mod_idents: &[String], args: &[Arg], arg_values: &[Value], named_arg_values: &[(String, Value)]) -> Result<bool>
     {
         let fun_mod = match ModNode::mod_from(&self.root_mod, fun_mod_idents, false)? {
             Some(tmp_fun_mod) => tmp_fun_mod,
             None => return Err(Error::NoFunMod),
         };
-        if args.len() != arg_values.len() {
-            return Ok(false);
-        }
         let mut local_vars: BTreeMap<String, Value> = BTreeMap::new();
-        for (arg, value) in args.iter().zip(arg_values.iter()) {
-            match arg {
-                Arg(ident, _) => {
-                    local_vars.insert(ident.clone(), value.clone());
-                },
-            }
+        if !insert_arg_values(&mut local_vars, args, arg_values, named_arg_values)? {
+            return Ok(false);
         }
         self.stack.push((fun_mod, local_vars));
         Ok(true)
     }
 
+    /// Pushes the module and the local variables of anonymous function to the stack to apply the
+    /// anonymous function.
+    ///
+    /// This method pushes the module and the local variables which are the captured local
+    /// variables with the arguments and returns `true` if values are passed to all arguments
+    /// without default values, otherwise this method returns `false`.
+    pub fn push_lambda_mod_and_local_vars(&mut self, lambda_mod: &Weak<RwLock<ModNode<Value, ()>>>, captured_vars: &BTreeMap<String, Value>, args: &[Arg], arg_values: &[Value], named_arg_values: &[(String, Value)]) -> Result<bool>
+    {
+        let lambda_mod = match lambda_mod.upgrade() {
+            Some(tmp_lambda_mod) => tmp_lambda_mod,
+            None => return Err(Error::NoFunMod),
+        };
+        let mut local_vars = captured_vars.clone();
+        if !insert_arg_values(&mut local_vars, args, arg_values, named_arg_values)? {
+            return Ok(false);
+        }
+        self.stack.push((lambda_mod, local_vars));
+        Ok(true)
+    }
+
     /// Removes the last functoin module and the last local varables from stack.
     pub fn pop_fun_mod_and_local_vars(&mut self)
     { self.stack.pop(); }
@@ -537,6 +563,92 @@ impl Env
             None => false,
         }
     }
+
+    /// Returns the local variable value for the identifier if the local variable exists,
+    /// otherwise `None`.
+    pub fn local_var(&self, ident: &String) -> Option<Value>
+    {
+        match self.stack.last() {
+            Some((_, local_vars)) => local_vars.get(ident).map(|v| v.clone()),
+            None => None,
+        }
+    }
+
+    /// Returns the number of local variables.
+    pub fn local_var_count(&self) -> usize
+    {
+        match self.stack.last() {
+            Some((_, local_vars)) => local_vars.len(),
+            None => 0,
+        }
+    }
+
+    /// Sets the local variable value for the identifier and returns `true` if the stack isn't
+    /// empty, otherwise this method returns `false`.
+    pub fn set_local_var(&mut self, ident: &String, value: Value) -> bool
+    {
+        match self.stack.last_mut() {
+            Some((_, local_vars)) => {
+                local_vars.insert(ident.clone(), value);
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+fn insert_arg_values(local_vars: &mut BTreeMap<String, Value>, args: &[Arg], arg_values: &[Value], named_arg_values: &[(String, Value)]) -> Result<bool>
+{
+    let mut set_idents: BTreeSet<String> = BTreeSet::new();
+    let mut is_rest = false;
+    for (i, arg) in args.iter().enumerate() {
+        match arg {
+            Arg(ident, ArgKind::Rest, _) => {
+                let rest_values = match arg_values.get(i..) {
+                    Some(tmp_rest_values) => tmp_rest_values.to_vec(),
+                    None => Vec::new(),
+                };
+                local_vars.insert(ident.clone(), Value::Ref(Arc::new(RwLock::new(MutObject::Array(rest_values)))));
+                set_idents.insert(ident.clone());
+                is_rest = true;
+            },
+            Arg(ident, _, _) => {
+                match arg_values.get(i) {
+                    Some(value) => {
+                        local_vars.insert(ident.clone(), value.clone());
+                        set_idents.insert(ident.clone());
+                    },
+                    None => (),
+                }
+            },
+        }
+    }
+    if !is_rest && arg_values.len() > args.len() {
+        return Ok(false);
+    }
+    for (ident, value) in named_arg_values {
+        match args.iter().find(|arg| &arg.0 == ident) {
+            Some(Arg(_, ArgKind::Normal | ArgKind::Default(_), _)) => {
+                if set_idents.contains(ident) {
+                    return Err(Error::Interp(format!("already set argument {}", ident)));
+                }
+                local_vars.insert(ident.clone(), value.clone());
+                set_idents.insert(ident.clone());
+            },
+            Some(Arg(_, ArgKind::Rest, _)) | None => return Err(Error::Interp(format!("no argument {}", ident))),
+        }
+    }
+    for arg in args {
+        match arg {
+            Arg(ident, ArgKind::Normal, _) => {
+                if !set_idents.contains(ident) {
+                    return Ok(false);
+                }
+            },
+            _ => (),
+        }
+    }
+    Ok(true)
 }
 
 #[cfg(test)]
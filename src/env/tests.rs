@@ -237,8 +237,8 @@ fn test_env_push_fun_mod_and_local_vars_pushes_function_module_and_local_variabl
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(1), Value::Float(2.5)];
     match env.push_fun_mod_and_local_vars(&[String::from("a"), String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -271,8 +271,8 @@ fn test_env_push_fun_mod_and_local_vars_pushes_function_module_and_local_variabl
         None => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Z"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Z"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Bool(true), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -360,8 +360,8 @@ fn test_env_push_fun_mod_and_local_vars_does_not_push_function_module_and_local_
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(1)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -381,8 +381,8 @@ fn test_env_push_fun_mod_and_local_vars_complains_on_no_function_module()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(1), Value::Float(2.5)];
     match env.push_fun_mod_and_local_vars(&[String::from("c")], args.as_slice(), arg_values.as_slice()) {
@@ -418,8 +418,8 @@ fn test_env_reset_resets_environment()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(1), Value::Float(2.5)];
     match env.push_fun_mod_and_local_vars(&[String::from("a"), String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -427,8 +427,8 @@ fn test_env_reset_resets_environment()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Z"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Z"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Bool(true), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -790,8 +790,8 @@ fn test_env_var_returns_values_for_variable_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a"), String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -815,8 +815,8 @@ fn test_env_var_returns_values_for_variable_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(3), Value::Float(1.5)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -886,8 +886,8 @@ fn test_env_var_returns_values_for_relative_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("b"), String::from("c")], args.as_slice(), arg_values.as_slice()) {
@@ -908,8 +908,8 @@ fn test_env_var_returns_values_for_relative_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -967,8 +967,8 @@ fn test_env_var_returns_values_for_absolute_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -1107,8 +1107,8 @@ fn test_env_var_returns_values_for_variable_names_and_used_variables_and_local_v
         current_mod_g.add_var(String::from("Y2"), Value::Float(1.5));
     }
     let args = vec![
-        Arg(String::from("X2"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y2"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X2"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y2"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(3.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[], args.as_slice(), arg_values.as_slice()) {
@@ -1652,8 +1652,8 @@ fn test_env_set_var_sets_values_for_variable_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a"), String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -1697,8 +1697,8 @@ fn test_env_set_var_sets_values_for_variable_names_and_local_variables()
         None => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Int(3), Value::Float(1.5)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -1839,8 +1839,8 @@ fn test_env_set_var_sets_values_for_relative_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("b"), String::from("c")], args.as_slice(), arg_values.as_slice()) {
@@ -1885,8 +1885,8 @@ fn test_env_set_var_sets_values_for_relative_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("b")], args.as_slice(), arg_values.as_slice()) {
@@ -1998,8 +1998,8 @@ fn test_env_set_var_sets_values_for_absolute_names_and_local_variables()
         _ => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[String::from("a")], args.as_slice(), arg_values.as_slice()) {
@@ -2370,8 +2370,8 @@ fn test_env_remove_local_var_removes_local_variable()
     let root_mod: Arc<RwLock<ModNode<Value, ()>>> = Arc::new(RwLock::new(ModNode::new(())));
     let mut env = Env::new(root_mod.clone());
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[], args.as_slice(), arg_values.as_slice()) {
@@ -2393,8 +2393,8 @@ fn test_env_remove_local_var_removes_local_variable()
         None => assert!(false),
     }
     let args = vec![
-        Arg(String::from("X"), Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
-        Arg(String::from("Y"), Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
+        Arg(String::from("X"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 1)),
+        Arg(String::from("Y"), ArgKind::Normal, Pos::new(Arc::new(String::from("test.unl")), 1, 2)),
     ];
     let arg_values = vec![Value::Float(1.5), Value::Int(2)];
     match env.push_fun_mod_and_local_vars(&[], args.as_slice(), arg_values.as_slice()) {
@@ -104,6 +104,8 @@ pub enum Error
     PkgPathConflicts(PkgName, Option<PkgName>, Vec<PathBuf>, PkgPathConflict),
     ///  A tester error.
     Tester(String),
+    /// A debug adapter error.
+    Dap(String),
     /// A matrix error.
     Matrix(matrix::Error),
     /// A mutex can't be locked.
@@ -202,6 +204,7 @@ impl fmt::Display for Error
                 Ok(())
             },
             Error::Tester(msg) => write!(f, "{}", msg),
+            Error::Dap(msg) => write!(f, "debug adapter error: {}", msg),
             Error::Matrix(err) => write!(f, "matrix error: {}", err),
             Error::Mutex => write!(f, "can't lock mutex"),
             Error::RwLockRead => write!(f, "can't read rw lock"),
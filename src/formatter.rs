@@ -0,0 +1,879 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of source code formatter.
+//!
+//! The formatter parses a script and prints the syntax tree of this script with consistent
+//! indentation, operator spacing, and alignment of matrix rows. Comments and documentation
+//! comments are kept at their places. Interpolated strings are printed as they are written in
+//! the script.
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::Arc;
+use crate::doc::*;
+use crate::error::*;
+use crate::lexer::*;
+use crate::parser::*;
+use crate::tree::*;
+
+const INDENT: &str = "    ";
+
+struct Printer<'a>
+{
+    src_lines: Vec<&'a str>,
+    comments: Vec<(String, Pos)>,
+    comment_index: usize,
+    closer_lines: Vec<u64>,
+    closer_index: usize,
+    lines: Vec<String>,
+    line: Option<String>,
+    indent: usize,
+    last_src_line: u64,
+    is_last_comment: bool,
+}
+
+impl<'a> Printer<'a>
+{
+    fn new(s: &'a str, comments: Vec<(String, Pos)>, closer_lines: Vec<u64>) -> Self
+    {
+        Printer {
+            src_lines: s.lines().collect(),
+            comments,
+            comment_index: 0,
+            closer_lines,
+            closer_index: 0,
+            lines: Vec::new(),
+            line: None,
+            indent: 0,
+            last_src_line: 0,
+            is_last_comment: false,
+        }
+    }
+
+    fn src_line(&self, line: u64) -> Option<&'a str>
+    {
+        if line >= 1 {
+            self.src_lines.get((line - 1) as usize).map(|s| *s)
+        } else {
+            None
+        }
+    }
+
+    fn is_trailing_comment(&self, pos: &Pos) -> bool
+    {
+        match self.src_line(pos.line) {
+            Some(src_line) => src_line.chars().take(pos.column.saturating_sub(1)).any(|c| !c.is_whitespace()),
+            None => false,
+        }
+    }
+
+    fn push_blank_line_before(&mut self, line: u64)
+    {
+        match self.lines.last() {
+            Some(last_line) if !last_line.is_empty() => {
+                if line > self.last_src_line + 1 {
+                    let is_blank_line = (self.last_src_line + 1..line).any(|l| self.src_line(l).map(|s| s.trim().is_empty()).unwrap_or(false));
+                    if is_blank_line {
+                        self.lines.push(String::new());
+                        self.is_last_comment = true;
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+
+    fn end_line(&mut self)
+    {
+        match self.line.take() {
+            Some(line) => {
+                self.lines.push(String::from(line.trim_end()));
+                self.is_last_comment = false;
+            },
+            None => (),
+        }
+    }
+
+    fn flush_comments(&mut self, line: u64, indent: usize)
+    {
+        while self.comment_index < self.comments.len() && self.comments[self.comment_index].1.line < line {
+            let (comment, pos) = self.comments[self.comment_index].clone();
+            self.comment_index += 1;
+            if self.is_trailing_comment(&pos) && !self.is_last_comment && !self.lines.is_empty() {
+                match self.lines.last_mut() {
+                    Some(last_line) => {
+                        last_line.push(' ');
+                        last_line.push_str(comment.as_str());
+                    },
+                    None => (),
+                }
+            } else {
+                self.push_blank_line_before(pos.line);
+                self.lines.push(format!("{}{}", INDENT.repeat(indent), comment));
+            }
+            self.is_last_comment = true;
+            self.last_src_line = self.last_src_line.max(pos.line);
+        }
+    }
+
+    fn begin_line(&mut self, indent: usize, line: u64, is_closing: bool)
+    {
+        self.end_line();
+        if is_closing {
+            self.flush_comments(line, indent + 1);
+        } else {
+            self.flush_comments(line, indent);
+            self.push_blank_line_before(line);
+        }
+        self.last_src_line = self.last_src_line.max(line);
+        self.line = Some(INDENT.repeat(indent));
+        self.indent = indent;
+    }
+
+    fn write(&mut self, s: &str)
+    {
+        match &mut self.line {
+            Some(line) => line.push_str(s),
+            None => self.line = Some(String::from(s)),
+        }
+    }
+
+    fn next_closer_line(&mut self) -> u64
+    {
+        let line = self.closer_lines.get(self.closer_index).map(|l| *l).unwrap_or(self.last_src_line);
+        self.closer_index += 1;
+        line
+    }
+
+    fn write_closer(&mut self, s: &str)
+    {
+        self.next_closer_line();
+        self.write(s);
+    }
+
+    fn write_closer_in_line(&mut self, indent: usize, s: &str)
+    {
+        let line = self.next_closer_line();
+        self.begin_line(indent, line, true);
+        self.write(s);
+    }
+
+    fn finish(mut self) -> String
+    {
+        self.end_line();
+        self.flush_comments(u64::MAX, 0);
+        let mut s = String::new();
+        for line in &self.lines {
+            s.push_str(line.as_str());
+            s.push('\n');
+        }
+        s
+    }
+
+    fn lit_lexeme(&self, pos: &Pos) -> Option<String>
+    {
+        let cs: Vec<char> = self.src_line(pos.line)?.chars().skip(pos.column.saturating_sub(1)).collect();
+        match cs.first() {
+            Some('"') => {
+                let mut i = 1;
+                loop {
+                    match cs.get(i) {
+                        Some('\\') => i += 2,
+                        Some('"') => return Some(cs[..(i + 1)].iter().collect()),
+                        Some(_) => i += 1,
+                        None => return None,
+                    }
+                }
+            },
+            Some(c) if c.is_ascii_digit() => {
+                let mut i = 0;
+                if cs[0] == '0' && (cs.get(1) == Some(&'x') || cs.get(1) == Some(&'X')) {
+                    i = 2;
+                    while cs.get(i).map(|c2| c2.is_ascii_hexdigit()).unwrap_or(false) {
+                        i += 1;
+                    }
+                } else {
+                    while cs.get(i).map(|c2| c2.is_ascii_digit()).unwrap_or(false) {
+                        i += 1;
+                    }
+                    if cs.get(i) == Some(&'.') && cs.get(i + 1).map(|c2| c2.is_ascii_digit()).unwrap_or(false) {
+                        i += 1;
+                        while cs.get(i).map(|c2| c2.is_ascii_digit()).unwrap_or(false) {
+                            i += 1;
+                        }
+                    }
+                    if cs.get(i) == Some(&'e') || cs.get(i) == Some(&'E') {
+                        let mut j = i + 1;
+                        if cs.get(j) == Some(&'+') || cs.get(j) == Some(&'-') {
+                            j += 1;
+                        }
+                        if cs.get(j).map(|c2| c2.is_ascii_digit()).unwrap_or(false) {
+                            i = j;
+                            while cs.get(i).map(|c2| c2.is_ascii_digit()).unwrap_or(false) {
+                                i += 1;
+                            }
+                        }
+                    }
+                }
+                Some(cs[..i].iter().collect())
+            },
+            _ => None,
+        }
+    }
+
+    fn interp_string_lexeme(&self, expr: &Expr) -> Option<String>
+    {
+        match expr {
+            Expr::Var(_, pos) => {
+                let cs: Vec<char> = self.src_line(pos.line)?.chars().skip(pos.column.saturating_sub(1)).collect();
+                if cs.get(0) != Some(&'$') || cs.get(1) != Some(&'"') {
+                    return None;
+                }
+                let mut i = 2;
+                loop {
+                    match cs.get(i) {
+                        Some('\\') => i += 2,
+                        Some('"') => return Some(cs[..(i + 1)].iter().collect()),
+                        Some('{') if cs.get(i + 1) == Some(&'{') => i += 2,
+                        Some('{') => {
+                            i += 1;
+                            let mut depth = 0usize;
+                            loop {
+                                match cs.get(i) {
+                                    Some('(' | '[' | '{') => {
+                                        depth += 1;
+                                        i += 1;
+                                    },
+                                    Some('}') if depth == 0 => {
+                                        i += 1;
+                                        break;
+                                    },
+                                    Some(')' | ']' | '}') => {
+                                        depth = depth.saturating_sub(1);
+                                        i += 1;
+                                    },
+                                    Some('"') => {
+                                        i += 1;
+                                        loop {
+                                            match cs.get(i) {
+                                                Some('\\') => i += 2,
+                                                Some('"') => {
+                                                    i += 1;
+                                                    break;
+                                                },
+                                                Some(_) => i += 1,
+                                                None => return None,
+                                            }
+                                        }
+                                    },
+                                    Some(_) => i += 1,
+                                    None => return None,
+                                }
+                            }
+                        },
+                        Some(_) => i += 1,
+                        None => return None,
+                    }
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn print_nodes(&mut self, nodes: &[Node], indent: usize)
+    {
+        for node in nodes {
+            match node {
+                Node::Def(def) => self.print_def(&**def, indent),
+                Node::Stat(stat) => self.print_stat(&**stat, indent),
+            }
+        }
+    }
+
+    fn print_def(&mut self, def: &Def, indent: usize)
+    {
+        match def {
+            Def::Mod(ident, mod1, pos) => {
+                self.begin_line(indent, pos.line, false);
+                self.write(format!("module {}", ident).as_str());
+                match &**mod1 {
+                    Mod(nodes) => self.print_nodes(nodes.as_slice(), indent + 1),
+                }
+                self.write_closer_in_line(indent, "end");
+            },
+            Def::Fun(ident, fun, pos) => {
+                self.begin_line(indent, pos.line, false);
+                self.write(format!("function {}(", ident).as_str());
+                match &**fun {
+                    Fun(args, stats) => {
+                        self.print_args(args.as_slice());
+                        self.write(")");
+                        self.print_stats(stats.as_slice(), indent + 1);
+                    },
+                }
+                self.write_closer_in_line(indent, "end");
+            },
+        }
+    }
+
+    fn print_args(&mut self, args: &[Arg])
+    {
+        let mut is_first = true;
+        for arg in args {
+            if !is_first {
+                self.write(", ");
+            }
+            match arg {
+                Arg(ident, ArgKind::Normal, _) => self.write(ident.as_str()),
+                Arg(ident, ArgKind::Default(expr), _) => {
+                    self.write(format!("{} = ", ident).as_str());
+                    self.print_expr(&**expr, 0);
+                },
+                Arg(ident, ArgKind::Rest, _) => self.write(format!("{}...", ident).as_str()),
+            }
+            is_first = false;
+        }
+    }
+
+    fn print_stats(&mut self, stats: &[Box<Stat>], indent: usize)
+    {
+        for stat in stats {
+            self.print_stat(&**stat, indent);
+        }
+    }
+
+    fn print_stat(&mut self, stat: &Stat, indent: usize)
+    {
+        self.begin_line(indent, stat.pos().line, false);
+        self.print_stat_without_line(stat, indent);
+    }
+
+    fn print_stat_without_line(&mut self, stat: &Stat, indent: usize)
+    {
+        match stat {
+            Stat::Expr(expr, _) => self.print_expr(&**expr, 0),
+            Stat::Assign(expr, expr2, _) => {
+                self.print_expr(&**expr, 0);
+                self.write(" = ");
+                self.print_expr(&**expr2, 0);
+            },
+            Stat::If(expr, stats, else_if_pairs, else_stats, _) => {
+                self.write("if ");
+                self.print_expr(&**expr, 0);
+                self.print_stats(stats.as_slice(), indent + 1);
+                for (else_if_expr, else_if_stats) in else_if_pairs {
+                    self.write_closer_in_line(indent, "else if ");
+                    self.print_expr(&**else_if_expr, 0);
+                    self.print_stats(else_if_stats.as_slice(), indent + 1);
+                }
+                match else_stats {
+                    Some(else_stats) => {
+                        self.write_closer_in_line(indent, "else");
+                        self.print_stats(else_stats.as_slice(), indent + 1);
+                    },
+                    None => (),
+                }
+                self.write_closer_in_line(indent, "end");
+            },
+            Stat::For(ident, expr, stats, _) => {
+                self.write(format!("for {} in ", ident).as_str());
+                self.print_expr(&**expr, 0);
+                self.print_stats(stats.as_slice(), indent + 1);
+                self.write_closer_in_line(indent, "end");
+            },
+            Stat::While(expr, stats, _) => {
+                self.write("while ");
+                self.print_expr(&**expr, 0);
+                self.print_stats(stats.as_slice(), indent + 1);
+                self.write_closer_in_line(indent, "end");
+            },
+            Stat::Break(_) => self.write("break"),
+            Stat::Continue(_) => self.write("continue"),
+            Stat::Return(expr, _) => {
+                self.write("return");
+                match expr {
+                    Some(expr) => {
+                        self.write(" ");
+                        self.print_expr(&**expr, 0);
+                    },
+                    None => (),
+                }
+            },
+            Stat::Quit(_) => self.write("quit"),
+            Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                self.write("try");
+                self.print_stats(try_stats.as_slice(), indent + 1);
+                match catch_pair {
+                    Some((ident, catch_stats)) => {
+                        self.write_closer_in_line(indent, format!("catch {}", ident).as_str());
+                        self.print_stats(catch_stats.as_slice(), indent + 1);
+                    },
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => {
+                        self.write_closer_in_line(indent, "finally");
+                        self.print_stats(finally_stats.as_slice(), indent + 1);
+                    },
+                    None => (),
+                }
+                self.write_closer_in_line(indent, "end");
+            },
+        }
+    }
+
+    fn print_exprs(&mut self, exprs: &[Box<Expr>])
+    {
+        let mut is_first = true;
+        for expr in exprs {
+            if !is_first {
+                self.write(", ");
+            }
+            self.print_expr(&**expr, 0);
+            is_first = false;
+        }
+    }
+
+    fn print_expr(&mut self, expr: &Expr, prec: u32)
+    {
+        if expr.prec() < prec {
+            self.write("(");
+            self.print_expr_without_parens(expr);
+            self.write(")");
+        } else {
+            self.print_expr_without_parens(expr);
+        }
+    }
+
+    fn print_index(&mut self, expr: &Option<Box<Expr>>)
+    {
+        match expr {
+            Some(expr) => self.print_expr(&**expr, 0),
+            None => self.write(":"),
+        }
+    }
+
+    fn print_expr_without_parens(&mut self, expr: &Expr)
+    {
+        match expr {
+            Expr::Lit(lit, pos) => self.print_lit(lit, pos),
+            Expr::Var(name, _) => self.write(format!("{}", name).as_str()),
+            Expr::App(expr2, exprs, named_args, _) => {
+                match self.interp_string_lexeme(&**expr2) {
+                    Some(lexeme) => self.write(lexeme.as_str()),
+                    None => {
+                        self.print_expr(&**expr2, 10);
+                        self.write("(");
+                        self.print_exprs(exprs.as_slice());
+                        let mut is_first = exprs.is_empty();
+                        for named_arg in named_args {
+                            if !is_first {
+                                self.write(", ");
+                            }
+                            self.write(format!("{} = ", named_arg.0).as_str());
+                            self.print_expr(&*named_arg.1, 0);
+                            is_first = false;
+                        }
+                        self.write(")");
+                    },
+                }
+            },
+            Expr::UnaryOp(UnaryOp::Transpose, expr2, _) => {
+                match &**expr2 {
+                    Expr::UnaryOp(UnaryOp::Transpose, _, _) => self.print_expr(&**expr2, 8),
+                    _ => self.print_expr(&**expr2, 9),
+                }
+                self.write("'");
+            },
+            Expr::UnaryOp(op, expr2, _) => {
+                self.write(format!("{}", op).as_str());
+                self.print_expr(&**expr2, 8);
+            },
+            Expr::BinOp(BinOp::Index, expr2, expr3, _) => {
+                self.print_expr(&**expr2, 10);
+                self.write("[");
+                self.print_expr(&**expr3, 0);
+                self.write_closer("]");
+            },
+            Expr::BinOp(op, expr2, expr3, _) => {
+                self.print_expr(&**expr2, expr.prec());
+                self.write(format!(" {} ", op).as_str());
+                self.print_expr(&**expr3, expr.prec() + 1);
+            },
+            Expr::And(expr2, expr3, _) => {
+                self.print_expr(&**expr2, 2);
+                self.write(" and ");
+                self.print_expr(&**expr3, 3);
+            },
+            Expr::Or(expr2, expr3, _) => {
+                self.print_expr(&**expr2, 1);
+                self.write(" or ");
+                self.print_expr(&**expr3, 2);
+            },
+            Expr::Field(expr2, ident, _) => {
+                self.print_expr(&**expr2, 10);
+                self.write(format!(".{}", ident).as_str());
+            },
+            Expr::Index2(expr2, expr3, expr4, _) => {
+                self.print_expr(&**expr2, 10);
+                self.write("[");
+                self.print_index(expr3);
+                self.write(", ");
+                self.print_index(expr4);
+                self.write_closer("]");
+            },
+            Expr::Range(expr2, expr3, expr4, _) => {
+                self.print_expr(&**expr2, 6);
+                self.write(" to ");
+                self.print_expr(&**expr3, 6);
+                match expr4 {
+                    Some(expr4) => {
+                        self.write(" by ");
+                        self.print_expr(&**expr4, 6);
+                    },
+                    None => (),
+                }
+            },
+            Expr::PropagateError(expr2, _) => {
+                self.print_expr(&**expr2, 9);
+                self.write("?");
+            },
+            Expr::Lambda(fun, pos) => {
+                match &**fun {
+                    Fun(args, stats) => {
+                        self.write("function(");
+                        self.print_args(args.as_slice());
+                        self.write(")");
+                        if is_one_line_lambda(stats.as_slice(), pos) {
+                            for stat in stats {
+                                self.write(" ");
+                                let indent = self.indent;
+                                self.print_stat_without_line(&**stat, indent);
+                            }
+                            self.write_closer(" end");
+                        } else {
+                            let indent = self.indent;
+                            self.print_stats(stats.as_slice(), indent + 1);
+                            self.write_closer_in_line(indent, "end");
+                        }
+                    },
+                }
+            },
+        }
+    }
+
+    fn print_matrix_row(&mut self, matrix_row: &MatrixRow)
+    {
+        match matrix_row {
+            MatrixRow::Row(exprs) => self.print_exprs(exprs.as_slice()),
+            MatrixRow::FilledRow(expr, expr2) => {
+                self.print_expr(&**expr, 0);
+                self.write(" fill ");
+                self.print_expr(&**expr2, 0);
+            },
+        }
+    }
+
+    fn matrix_row_to_cells(&mut self, matrix_row: &MatrixRow) -> Option<Vec<String>>
+    {
+        match matrix_row {
+            MatrixRow::Row(exprs) => {
+                let mut cells: Vec<String> = Vec::new();
+                for expr in exprs {
+                    let saved_line = self.line.replace(String::new());
+                    self.print_expr(&**expr, 0);
+                    cells.push(self.line.take().unwrap_or(String::new()));
+                    self.line = saved_line;
+                }
+                Some(cells)
+            },
+            MatrixRow::FilledRow(_, _) => None,
+        }
+    }
+
+    fn print_matrix_rows(&mut self, matrix_rows: &[MatrixRow], line: u64, indent: usize)
+    {
+        if matrix_rows.iter().all(|r| is_one_line_matrix_row(r)) {
+            let mut rows: Vec<(u64, Option<Vec<String>>)> = Vec::new();
+            let mut widths: Vec<usize> = Vec::new();
+            for matrix_row in matrix_rows {
+                let cells = match matrix_row {
+                    MatrixRow::Row(_) => self.matrix_row_to_cells(matrix_row),
+                    MatrixRow::FilledRow(_, _) => None,
+                };
+                match &cells {
+                    Some(cells) => {
+                        for (i, cell) in cells.iter().enumerate() {
+                            if i >= widths.len() {
+                                widths.push(0);
+                            }
+                            widths[i] = widths[i].max(cell.chars().count());
+                        }
+                    },
+                    None => (),
+                }
+                rows.push((matrix_row_line(matrix_row, line), cells));
+            }
+            for (matrix_row, (row_line, cells)) in matrix_rows.iter().zip(rows.iter()) {
+                self.begin_line(indent + 1, *row_line, false);
+                match cells {
+                    Some(cells) => {
+                        let aligned_cells: Vec<String> = cells.iter().enumerate().map(|p| format!("{:>1$}", p.1, widths[p.0])).collect();
+                        self.write(aligned_cells.join(", ").as_str());
+                    },
+                    None => self.print_matrix_row(matrix_row),
+                }
+            }
+        } else {
+            for matrix_row in matrix_rows {
+                self.begin_line(indent + 1, matrix_row_line(matrix_row, line), false);
+                self.print_matrix_row(matrix_row);
+            }
+        }
+    }
+
+    fn print_lit(&mut self, lit: &Lit, pos: &Pos)
+    {
+        match lit {
+            Lit::Int(_) | Lit::Float(_) | Lit::String(_) => {
+                match self.lit_lexeme(pos) {
+                    Some(lexeme) => self.write(lexeme.as_str()),
+                    None => self.write(format!("{}", lit).as_str()),
+                }
+            },
+            Lit::Matrix(matrix_rows) => {
+                self.write("[");
+                if is_multi_line_matrix(matrix_rows.as_slice(), pos) {
+                    let indent = self.indent;
+                    self.print_matrix_rows(matrix_rows.as_slice(), pos.line, indent);
+                    self.write_closer_in_line(indent, "]");
+                } else {
+                    let mut is_first = true;
+                    for matrix_row in matrix_rows {
+                        if !is_first {
+                            self.write("; ");
+                        }
+                        self.print_matrix_row(matrix_row);
+                        is_first = false;
+                    }
+                    self.write_closer("]");
+                }
+            },
+            Lit::FilledMatrix(matrix_row, expr) => {
+                self.write("[");
+                if matrix_row_line(matrix_row, pos.line) != pos.line {
+                    let indent = self.indent;
+                    self.print_matrix_rows(&[matrix_row.clone()], pos.line, indent);
+                    self.begin_line(indent + 1, expr.pos().line, false);
+                    self.write("fill ");
+                    self.print_expr(&**expr, 0);
+                    self.write_closer_in_line(indent, "]");
+                } else {
+                    self.print_matrix_row(matrix_row);
+                    self.write("; fill ");
+                    self.print_expr(&**expr, 0);
+                    self.write_closer("]");
+                }
+            },
+            Lit::Array(exprs) => {
+                if !exprs.is_empty() {
+                    self.write(".[ ");
+                    self.print_exprs(exprs.as_slice());
+                    self.write_closer(" .]");
+                } else {
+                    self.write_closer(".[.]");
+                }
+            },
+            Lit::FilledArray(expr, expr2) => {
+                self.write(".[ ");
+                self.print_expr(&**expr, 0);
+                self.write(" fill ");
+                self.print_expr(&**expr2, 0);
+                self.write_closer(" .]");
+            },
+            Lit::Struct(field_pairs) => {
+                if field_pairs.is_empty() {
+                    self.write_closer("{}");
+                } else if field_pairs.iter().any(|fp| fp.pos().line != pos.line) {
+                    self.write("{");
+                    let indent = self.indent;
+                    for field_pair in field_pairs {
+                        self.begin_line(indent + 1, field_pair.pos().line, false);
+                        self.write(format!("{}: ", field_pair.0).as_str());
+                        self.print_expr(&*field_pair.1, 0);
+                    }
+                    self.write_closer_in_line(indent, "}");
+                } else {
+                    self.write("{ ");
+                    let mut is_first = true;
+                    for field_pair in field_pairs {
+                        if !is_first {
+                            self.write("; ");
+                        }
+                        self.write(format!("{}: ", field_pair.0).as_str());
+                        self.print_expr(&*field_pair.1, 0);
+                        is_first = false;
+                    }
+                    self.write_closer(" }");
+                }
+            },
+            Lit::Dict(dict_pairs) => {
+                if dict_pairs.is_empty() {
+                    self.write_closer(".{.}");
+                } else if dict_pairs.iter().any(|dp| dp.pos().line != pos.line) {
+                    self.write(".{");
+                    let indent = self.indent;
+                    for dict_pair in dict_pairs {
+                        self.begin_line(indent + 1, dict_pair.pos().line, false);
+                        self.print_expr(&*dict_pair.0, 0);
+                        self.write(": ");
+                        self.print_expr(&*dict_pair.1, 0);
+                    }
+                    self.write_closer_in_line(indent, ".}");
+                } else {
+                    self.write(".{ ");
+                    let mut is_first = true;
+                    for dict_pair in dict_pairs {
+                        if !is_first {
+                            self.write("; ");
+                        }
+                        self.print_expr(&*dict_pair.0, 0);
+                        self.write(": ");
+                        self.print_expr(&*dict_pair.1, 0);
+                        is_first = false;
+                    }
+                    self.write_closer(" .}");
+                }
+            },
+            _ => self.write(format!("{}", lit).as_str()),
+        }
+    }
+}
+
+fn matrix_row_line(matrix_row: &MatrixRow, line: u64) -> u64
+{
+    match matrix_row {
+        MatrixRow::Row(exprs) => exprs.first().map(|e| e.pos().line).unwrap_or(line),
+        MatrixRow::FilledRow(expr, _) => expr.pos().line,
+    }
+}
+
+fn is_multi_line_matrix(matrix_rows: &[MatrixRow], pos: &Pos) -> bool
+{ matrix_rows.iter().any(|r| matrix_row_line(r, pos.line) != pos.line) }
+
+fn is_one_line_lambda(stats: &[Box<Stat>], pos: &Pos) -> bool
+{
+    match stats {
+        [] => true,
+        [stat] => {
+            match &**stat {
+                Stat::Expr(_, _) | Stat::Assign(_, _, _) | Stat::Return(_, _) | Stat::Break(_) | Stat::Continue(_) | Stat::Quit(_) => stat.pos().line == pos.line,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn is_one_line_matrix_row(matrix_row: &MatrixRow) -> bool
+{
+    match matrix_row {
+        MatrixRow::Row(exprs) => exprs.iter().all(|e| is_one_line_expr(&**e)),
+        MatrixRow::FilledRow(expr, expr2) => is_one_line_expr(&**expr) && is_one_line_expr(&**expr2),
+    }
+}
+
+fn is_one_line_stat(stat: &Stat) -> bool
+{
+    match stat {
+        Stat::Expr(expr, _) => is_one_line_expr(&**expr),
+        Stat::Assign(expr, expr2, _) => is_one_line_expr(&**expr) && is_one_line_expr(&**expr2),
+        Stat::Return(Some(expr), _) => is_one_line_expr(&**expr),
+        Stat::Return(None, _) | Stat::Break(_) | Stat::Continue(_) | Stat::Quit(_) => true,
+        _ => false,
+    }
+}
+
+fn is_one_line_expr(expr: &Expr) -> bool
+{
+    match expr {
+        Expr::Lit(Lit::Matrix(matrix_rows), pos) => !is_multi_line_matrix(matrix_rows.as_slice(), pos) && matrix_rows.iter().all(|r| is_one_line_matrix_row(r)),
+        Expr::Lit(Lit::FilledMatrix(matrix_row, expr2), pos) => matrix_row_line(matrix_row, pos.line) == pos.line && is_one_line_matrix_row(matrix_row) && is_one_line_expr(&**expr2),
+        Expr::Lit(Lit::Array(exprs), _) => exprs.iter().all(|e| is_one_line_expr(&**e)),
+        Expr::Lit(Lit::FilledArray(expr2, expr3), _) => is_one_line_expr(&**expr2) && is_one_line_expr(&**expr3),
+        Expr::Lit(Lit::Struct(field_pairs), pos) => field_pairs.iter().all(|fp| fp.pos().line == pos.line && is_one_line_expr(&*fp.1)),
+        Expr::Lit(Lit::Dict(dict_pairs), pos) => dict_pairs.iter().all(|dp| dp.pos().line == pos.line && is_one_line_expr(&*dp.0) && is_one_line_expr(&*dp.1)),
+        Expr::Lit(_, _) | Expr::Var(_, _) => true,
+        Expr::App(expr2, exprs, named_args, _) => is_one_line_expr(&**expr2) && exprs.iter().all(|e| is_one_line_expr(&**e)) && named_args.iter().all(|a| is_one_line_expr(&*a.1)),
+        Expr::UnaryOp(_, expr2, _) | Expr::Field(expr2, _, _) | Expr::PropagateError(expr2, _) => is_one_line_expr(&**expr2),
+        Expr::BinOp(_, expr2, expr3, _) | Expr::And(expr2, expr3, _) | Expr::Or(expr2, expr3, _) => is_one_line_expr(&**expr2) && is_one_line_expr(&**expr3),
+        Expr::Index2(expr2, expr3, expr4, _) => is_one_line_expr(&**expr2) && expr3.as_ref().map(|e| is_one_line_expr(&**e)).unwrap_or(true) && expr4.as_ref().map(|e| is_one_line_expr(&**e)).unwrap_or(true),
+        Expr::Range(expr2, expr3, expr4, _) => is_one_line_expr(&**expr2) && is_one_line_expr(&**expr3) && expr4.as_ref().map(|e| is_one_line_expr(&**e)).unwrap_or(true),
+        Expr::Lambda(fun, pos) => {
+            match &**fun {
+                Fun(args, stats) => {
+                    let are_one_line_args = args.iter().all(|a| {
+                            match a {
+                                Arg(_, ArgKind::Default(expr2), _) => is_one_line_expr(&**expr2),
+                                _ => true,
+                            }
+                    });
+                    are_one_line_args && is_one_line_lambda(stats.as_slice(), pos) && stats.iter().all(|s| is_one_line_stat(&**s))
+                },
+            }
+        },
+    }
+}
+
+/// Formats the script from the string.
+///
+/// The string is parsed by the parser and its syntax tree is printed with consistent
+/// indentation, operator spacing, and alignment of matrix rows. Comments, documentation
+/// comments, and single blank lines between statements are kept. This function returns the
+/// formatted script or an error if the script can't be parsed.
+pub fn format_str(path: Arc<String>, s: &str) -> Result<String>
+{
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new_with_comment_flag(path.clone(), &mut cursor, true);
+    let mut closer_lines: Vec<u64> = Vec::new();
+    loop {
+        match lexer.next() {
+            Some(Ok((Token::End | Token::Else | Token::Catch | Token::Finally | Token::RBracket | Token::DotRBracket | Token::RBrace | Token::DotRBrace, pos))) => closer_lines.push(pos.line),
+            Some(Ok(_)) => (),
+            Some(Err(err)) => return Err(err),
+            None => break,
+        }
+    }
+    let comments = lexer.take_comments();
+    let mut cursor2 = Cursor::new(s.as_bytes());
+    let mut lexer2 = Lexer::new(path, &mut cursor2);
+    let parser_path = lexer2.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer2;
+    let mut parser = Parser::new(parser_path, tokens);
+    let tree = parser.parse()?;
+    let mut printer = Printer::new(s, comments, closer_lines);
+    match &tree {
+        Tree(nodes) => printer.print_nodes(nodes.as_slice(), 0),
+    }
+    Ok(printer.finish())
+}
+
+/// Formats the script is refered by the path.
+///
+/// See [`format_str`].
+pub fn format<P: AsRef<Path>>(path: P) -> Result<String>
+{
+    let path_s = Arc::new(path.as_ref().to_string_lossy().into_owned());
+    match fs::read_to_string(path.as_ref()) {
+        Ok(s) => format_str(path_s, s.as_str()),
+        Err(err) => Err(Error::ParserIo(path_s, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,278 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use super::*;
+
+fn parse_str(s: &str) -> Result<Tree>
+{
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    parser.parse()
+}
+
+fn tree_without_positions(tree: &Tree) -> String
+{
+    let s = format!("{:?}", tree);
+    let mut t = String::new();
+    let mut rest = s.as_str();
+    loop {
+        match rest.find("Pos {") {
+            Some(i) => {
+                t.push_str(&rest[..i]);
+                rest = &rest[i..];
+                match rest.find('}') {
+                    Some(j) => rest = &rest[(j + 1)..],
+                    None => break,
+                }
+            },
+            None => {
+                t.push_str(rest);
+                break;
+            },
+        }
+    }
+    t
+}
+
+fn parser_test_scripts() -> Vec<String>
+{
+    let s = include_str!("../parser/tests.rs");
+    let mut scripts: Vec<String> = Vec::new();
+    let mut rest = s;
+    loop {
+        match rest.find("let s = \"") {
+            Some(i) => {
+                rest = &rest[(i + 9)..];
+                match rest.find("\";\n") {
+                    Some(j) => {
+                        let script = rest[..j].replace("\\\"", "\"").replace("\\\\", "\\");
+                        scripts.push(String::from(script.strip_prefix('\n').unwrap_or(script.as_str())));
+                        rest = &rest[(j + 3)..];
+                    },
+                    None => break,
+                }
+            },
+            None => break,
+        }
+    }
+    scripts
+}
+
+#[test]
+fn test_format_str_formats_indentation_and_operator_spacing()
+{
+    let s = "
+module   m
+function f(x,y=2,  zs...)
+if x<y and not(x==1)
+  return x+y*2
+   else if x>=y
+return (x-y)/2
+else
+ return -x'
+end
+end
+end
+for i in 1 to  10 by 2
+  while i<10; i=i+1; end
+end
+try
+f(1, y=(1+2)*3)
+catch e
+println(e)
+finally
+println(X[1].a?)
+end
+";
+    let expected = "
+module m
+    function f(x, y = 2, zs...)
+        if x < y and not (x == 1)
+            return x + y * 2
+        else if x >= y
+            return (x - y) / 2
+        else
+            return -x'
+        end
+    end
+end
+for i in 1 to 10 by 2
+    while i < 10
+        i = i + 1
+    end
+end
+try
+    f(1, y = (1 + 2) * 3)
+catch e
+    println(e)
+finally
+    println(X[1].a?)
+end
+";
+    match format_str(Arc::new(String::from("test.un")), &s[1..]) {
+        Ok(t) => assert_eq!(String::from(&expected[1..]), t),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_format_str_keeps_comments_and_blank_lines()
+{
+    let s = "
+#!/usr/bin/env unlab-gpu --
+## A module.
+module m # module comment
+
+
+  ## A function.
+  function f()
+       # first comment
+   X = 1 # second comment
+  # third comment
+  end
+end
+# last comment
+";
+    let expected = "
+#!/usr/bin/env unlab-gpu --
+## A module.
+module m # module comment
+
+    ## A function.
+    function f()
+        # first comment
+        X = 1 # second comment
+        # third comment
+    end
+end
+# last comment
+";
+    match format_str(Arc::new(String::from("test.un")), &s[1..]) {
+        Ok(t) => assert_eq!(String::from(&expected[1..]), t),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_format_str_aligns_matrix_rows()
+{
+    let s = "
+A = [1,2;3,4]
+B = [
+1, 20, 3
+400, 5, 0x6
+]
+C = [
+  1 fill 3
+  fill 2
+]
+D = {
+a: 1
+  b: function(x) x + 1 end
+}
+E = map(function(x)
+y = x * 2
+y
+end, .[1,2.5e3,\"a\\\"b\" .])
+";
+    let expected = "
+A = [1, 2; 3, 4]
+B = [
+      1, 20,   3
+    400,  5, 0x6
+]
+C = [
+    1 fill 3
+    fill 2
+]
+D = {
+    a: 1
+    b: function(x) x + 1 end
+}
+E = map(function(x)
+    y = x * 2
+    y
+end, .[ 1, 2.5e3, \"a\\\"b\" .])
+";
+    match format_str(Arc::new(String::from("test.un")), &s[1..]) {
+        Ok(t) => assert_eq!(String::from(&expected[1..]), t),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_format_str_keeps_interpolated_strings()
+{
+    let s = "
+x=1
+s=$\"x = {x+1:>4}, {{braces}}, {f(\"}\", [1, 2])} \\\"end\\\"\"
+println( $\"{x}\" )
+";
+    let expected = "
+x = 1
+s = $\"x = {x+1:>4}, {{braces}}, {f(\"}\", [1, 2])} \\\"end\\\"\"
+println($\"{x}\")
+";
+    match format_str(Arc::new(String::from("test.un")), &s[1..]) {
+        Ok(t) => {
+            assert_eq!(String::from(&expected[1..]), t);
+            match format_str(Arc::new(String::from("test.un")), t.as_str()) {
+                Ok(t2) => assert_eq!(t, t2),
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_format_str_round_trips_parser_test_scripts()
+{
+    let scripts = parser_test_scripts();
+    let mut count = 0;
+    for script in &scripts {
+        let tree = match parse_str(script.as_str()) {
+            Ok(tmp_tree) => tmp_tree,
+            Err(_) => continue,
+        };
+        let formatted_script = match format_str(Arc::new(String::from("test.un")), script.as_str()) {
+            Ok(tmp_formatted_script) => tmp_formatted_script,
+            Err(err) => panic!("{}: {}", err, script),
+        };
+        let formatted_tree = match parse_str(formatted_script.as_str()) {
+            Ok(tmp_formatted_tree) => tmp_formatted_tree,
+            Err(err) => panic!("{}: {}", err, formatted_script),
+        };
+        assert_eq!(tree_without_positions(&tree), tree_without_positions(&formatted_tree), "{}", formatted_script);
+        match format_str(Arc::new(String::from("test.un")), formatted_script.as_str()) {
+            Ok(t) => assert_eq!(formatted_script, t),
+            Err(_) => assert!(false),
+        }
+        count += 1;
+    }
+    assert!(count >= 50);
+}
+
+#[test]
+fn test_format_str_complains_on_syntax_error()
+{
+    let s = "
+if X
+    Y =
+end
+";
+    match format_str(Arc::new(String::from("test.un")), &s[1..]) {
+        Err(Error::Parser(pos, msg)) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 8), pos);
+            assert_eq!(String::from("unexpected token"), msg);
+        },
+        _ => assert!(false),
+    }
+}
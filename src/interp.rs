@@ -8,14 +8,22 @@
 //! An interpreter module.
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::fmt;
+use std::mem;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::RwLock;
+use std::sync::Weak;
+use crate::bytecode::*;
+use crate::debug::*;
 use crate::env::*;
 use crate::error::*;
 use crate::tree::*;
 use crate::utils::*;
 use crate::value::*;
 
+const MIN_MAX_CODE_COUNT: usize = 256;
+
 /// An interpreter structure.
 ///
 /// The interpreter interprets a syntax tree that can be produced by a parser while parsing
@@ -23,18 +31,57 @@ use crate::value::*;
 /// stores a stack trace that can contain functions and contains file positions. These functions
 /// are functons in which occurred the interpreter error. Also, the interpreter contains a return
 /// value that can be an error for an error propagation.
-#[derive(Clone, Debug)]
+///
+/// The interpreter can compile the syntax tree and the functions to a bytecode and execute the
+/// bytecode by a virtual machine instead of interpreting the syntax tree. The virtual machine
+/// has the same semantics as the interpreting of syntax tree but the local variables of functions
+/// are resolved while compilation.
+///
+/// The interpreter can have a debug hook that is called before each statement. If the
+/// interpreter has the debug hook, the interpreter interprets the syntax tree instead of the
+/// executing the bytecode. While the debug hook is called, the stack trace contains the called
+/// functions and the current file positions of these functions.
+#[derive(Clone)]
 pub struct Interp
 {
     stack_trace: Vec<(Option<Value>, Pos)>,
     ret_value: Value,
+    is_vm: bool,
+    codes: BTreeMap<usize, (Weak<Fun>, Arc<Code>)>,
+    max_code_count: usize,
+    debug_hook: Option<Arc<Mutex<dyn DebugHook + Send>>>,
+    debug_funs: Vec<(Value, Pos)>,
+    debug_pos: Option<Pos>,
 }
 
 impl Interp
 {
     /// Creates an interpreter.
     pub fn new() -> Self
-    { Interp { stack_trace: Vec::new(), ret_value: Value::None, } }
+    { Self::new_with_vm_flag(false) }
+
+    /// Creates an interpreter with the flag of virtual machine.
+    ///
+    /// If the flag of virtual machine is `true`, the interpreter executes a bytecode by the
+    /// virtual machine.
+    pub fn new_with_vm_flag(is_vm: bool) -> Self
+    { Interp { stack_trace: Vec::new(), ret_value: Value::None, is_vm, codes: BTreeMap::new(), max_code_count: MIN_MAX_CODE_COUNT, debug_hook: None, debug_funs: Vec::new(), debug_pos: None, } }
+
+    /// Returns `true` if the interpreter executes a bytecode by the virtual machine, otherwise
+    /// `false`.
+    pub fn is_vm(&self) -> bool
+    { self.is_vm }
+
+    /// Returns the debug hook.
+    pub fn debug_hook(&self) -> Option<&Arc<Mutex<dyn DebugHook + Send>>>
+    { self.debug_hook.as_ref() }
+
+    /// Sets the debug hook.
+    pub fn set_debug_hook(&mut self, debug_hook: Option<Arc<Mutex<dyn DebugHook + Send>>>)
+    { self.debug_hook = debug_hook; }
+
+    fn is_vm_used(&self) -> bool
+    { self.is_vm && self.debug_hook.is_none() }
     
     /// Returns the stack trace.
     pub fn stack_trace(&self) -> &[(Option<Value>, Pos)]
@@ -53,7 +100,23 @@ impl Interp
     { 
         match tree {
             Tree(nodes) => {
-                let res = match self.interpret_nodes(env, nodes) {
+                let res = if self.is_vm_used() {
+                    let code = Compiler::compile_tree(tree);
+                    let mut frame = Frame::new(0, 0, &[]);
+                    match self.execute(env, &code, &mut frame) {
+                        Ok(()) => Ok(()),
+                        Err(err) => {
+                            match &err {
+                                Error::Stop(Stop::ErrorPropagation) => (),
+                                _ => self.ret_value = Value::None,
+                            }
+                            Err(err)
+                        },
+                    }
+                } else {
+                    self.interpret_nodes(env, nodes)
+                };
+                let res = match res {
                     Ok(()) => Ok(()),
                     Err(Error::Stop(Stop::Break)) => Err(Error::Interp(String::from("break isn't in loop"))),
                     Err(Error::Stop(Stop::Continue)) => Err(Error::Interp(String::from("continue isn't in loop"))),
@@ -73,9 +136,16 @@ impl Interp
 
     /// Applies the function to the arguments.
     ///
-    /// This method applies the function to the argument if the value is a function or a built-in
-    /// function, otherwise returns an error.
+    /// This method applies the function to the argument if the value is a function, an anonymous
+    /// function, or a built-in function, otherwise returns an error.
     pub fn apply_fun(&mut self, env: &mut Env, fun_value: &Value, arg_values: &[Value]) -> Result<Value>
+    { self.apply_fun_with_named_args(env, fun_value, arg_values, &[]) }
+
+    /// Applies the function to the arguments and the named arguments.
+    ///
+    /// This method is similar to [`apply_fun`](Self::apply_fun) but also passes the named
+    /// arguments to the function. The built-in functions don't take the named arguments.
+    pub fn apply_fun_with_named_args(&mut self, env: &mut Env, fun_value: &Value, arg_values: &[Value], named_arg_values: &[(String, Value)]) -> Result<Value>
     {
         match fun_value {
             Value::Object(fun_object) => {
@@ -83,38 +153,42 @@ impl Interp
                     Object::Fun(fun_mod_idents, _, fun) => {
                         match &**fun {
                             Fun(args, stats) => {
-                                match env.push_fun_mod_and_local_vars(fun_mod_idents.as_slice(), args, arg_values) {
+                                match env.push_fun_mod_and_local_vars_with_named_args(fun_mod_idents.as_slice(), args, arg_values, named_arg_values) {
                                     Ok(true) => (),
                                     Ok(false) => return Err(Error::Interp(String::from("invalid number of arguments"))),
                                     Err(err) => return Err(err),
                                 }
-                                let res = match self.interpret_stats(env, stats.as_slice()) {
-                                    Ok(()) => Ok(self.ret_value.clone()),
-                                    Err(Error::Stop(Stop::Break)) => Err(Error::Interp(String::from("break isn't in loop"))),
-                                    Err(Error::Stop(Stop::Continue)) => Err(Error::Interp(String::from("continue isn't in loop"))),
-                                    Err(Error::Stop(Stop::Return | Stop::ErrorPropagation)) => {
-                                        self.stack_trace.clear();
-                                        Ok(self.ret_value.clone())
-                                    },
-                                    Err(err) => Err(err),
-                                };
-                                env.pop_fun_mod_and_local_vars();
-                                match res {
-                                    Ok(value) => Ok(value),
-                                    Err(err) => {
-                                        match self.stack_trace.pop() {
-                                            Some((_, pos)) => {
-                                                self.stack_trace.push((Some(fun_value.clone()), pos));
-                                            },
-                                            None => (),
-                                        }
-                                        Err(err)
-                                    },
+                                if self.is_vm_used() {
+                                    self.execute_fun(env, fun_value, fun, arg_values.len(), named_arg_values)
+                                } else {
+                                    self.interpret_fun_stats(env, fun_value, args.as_slice(), arg_values.len(), named_arg_values, stats.as_slice())
+                                }
+                            },
+                        }
+                    },
+                    Object::Lambda(lambda_mod, captured_vars, fun) => {
+                        match &**fun {
+                            Fun(args, stats) => {
+                                match env.push_lambda_mod_and_local_vars(lambda_mod, captured_vars, args, arg_values, named_arg_values) {
+                                    Ok(true) => (),
+                                    Ok(false) => return Err(Error::Interp(String::from("invalid number of arguments"))),
+                                    Err(err) => return Err(err),
+                                }
+                                if self.is_vm_used() {
+                                    self.execute_fun(env, fun_value, fun, arg_values.len(), named_arg_values)
+                                } else {
+                                    self.interpret_fun_stats(env, fun_value, args.as_slice(), arg_values.len(), named_arg_values, stats.as_slice())
                                 }
                             },
                         }
                     },
-                    Object::BuiltinFun(_, f) => f(self, env, arg_values),
+                    Object::BuiltinFun(_, f) => {
+                        if !named_arg_values.is_empty() {
+                            self.ret_value = Value::None;
+                            return Err(Error::Interp(String::from("built-in function doesn't take named arguments")));
+                        }
+                        f(self, env, arg_values)
+                    },
                     _ => {
                         self.ret_value = Value::None;
                         Err(Error::Interp(format!("value isn't function")))
@@ -128,6 +202,124 @@ impl Interp
         }
     }
 
+    /// Evaluates the expression in the current function or the current module.
+    ///
+    /// This method doesn't change the stack trace and the return value. This method can be used
+    /// to evaluate the expression for the interpreter paused by the debug hook.
+    pub fn eval_expr(&mut self, env: &mut Env, expr: &Expr) -> Result<Value>
+    {
+        let stack_trace = mem::take(&mut self.stack_trace);
+        let ret_value = self.ret_value.clone();
+        let res = self.interpret_expr(env, expr);
+        self.stack_trace = stack_trace;
+        self.ret_value = ret_value;
+        res
+    }
+
+    fn call_debug_hook(&mut self, env: &mut Env, stat: &Stat) -> Result<()>
+    {
+        match self.debug_hook.take() {
+            Some(debug_hook) => {
+                let mut stack_trace: Vec<(Option<Value>, Pos)> = Vec::new();
+                let mut pos = stat.pos().clone();
+                for (fun_value, call_pos) in self.debug_funs.iter().rev() {
+                    stack_trace.push((Some(fun_value.clone()), pos));
+                    pos = call_pos.clone();
+                }
+                stack_trace.push((None, pos));
+                self.debug_pos = Some(stat.pos().clone());
+                let saved_stack_trace = mem::replace(&mut self.stack_trace, stack_trace);
+                let res = match debug_hook.lock() {
+                    Ok(mut debug_hook_g) => debug_hook_g.before_stat(self, env, stat),
+                    Err(_) => Err(Error::Mutex),
+                };
+                self.stack_trace = saved_stack_trace;
+                self.debug_hook = Some(debug_hook);
+                res
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn interpret_default_args(&mut self, env: &mut Env, args: &[Arg], arg_value_count: usize, named_arg_values: &[(String, Value)]) -> Result<()>
+    {
+        for (i, arg) in args.iter().enumerate() {
+            match arg {
+                Arg(ident, ArgKind::Default(expr), pos) => {
+                    if i >= arg_value_count && named_arg_values.iter().all(|(ident2, _)| ident2 != ident) {
+                        let value = self.interpret_expr(env, &**expr)?;
+                        match env.set_var(&Name::Var(ident.clone()), value) {
+                            Ok(true) => (),
+                            Ok(false) => {
+                                self.stack_trace.push((None, pos.clone()));
+                                self.ret_value = Value::None;
+                                return Err(Error::Interp(format!("undefined module for variable {}", ident)));
+                            },
+                            Err(err) => {
+                                self.stack_trace.push((None, pos.clone()));
+                                self.ret_value = Value::None;
+                                return Err(err);
+                            },
+                        }
+                    }
+                },
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn interpret_fun_stats(&mut self, env: &mut Env, fun_value: &Value, args: &[Arg], arg_value_count: usize, named_arg_values: &[(String, Value)], stats: &[Box<Stat>]) -> Result<Value>
+    {
+        let call_pos = match (&self.debug_hook, &self.debug_pos) {
+            (Some(_), Some(pos)) => {
+                self.debug_funs.push((fun_value.clone(), pos.clone()));
+                Some(pos.clone())
+            },
+            (_, _) => None,
+        };
+        let res = match self.interpret_default_args(env, args, arg_value_count, named_arg_values) {
+            Ok(()) => self.interpret_stats(env, stats),
+            Err(err) => Err(err),
+        };
+        let res = self.end_fun(env, fun_value, res);
+        match call_pos {
+            Some(call_pos) => {
+                self.debug_funs.pop();
+                self.debug_pos = Some(call_pos);
+            },
+            None => (),
+        }
+        res
+    }
+
+    fn end_fun(&mut self, env: &mut Env, fun_value: &Value, res: Result<()>) -> Result<Value>
+    {
+        let res = match res {
+            Ok(()) => Ok(self.ret_value.clone()),
+            Err(Error::Stop(Stop::Break)) => Err(Error::Interp(String::from("break isn't in loop"))),
+            Err(Error::Stop(Stop::Continue)) => Err(Error::Interp(String::from("continue isn't in loop"))),
+            Err(Error::Stop(Stop::Return | Stop::ErrorPropagation)) => {
+                self.stack_trace.clear();
+                Ok(self.ret_value.clone())
+            },
+            Err(err) => Err(err),
+        };
+        env.pop_fun_mod_and_local_vars();
+        match res {
+            Ok(value) => Ok(value),
+            Err(err) => {
+                match self.stack_trace.pop() {
+                    Some((_, pos)) => {
+                        self.stack_trace.push((Some(fun_value.clone()), pos));
+                    },
+                    None => (),
+                }
+                Err(err)
+            },
+        }
+    }
+
     fn interpret_node(&mut self, env: &mut Env, node: &Node) -> Result<()>
     {
         match node {
@@ -193,7 +385,7 @@ impl Interp
                         let mut idents: BTreeSet<&String> = BTreeSet::new();
                         for arg in args {
                             match arg {
-                                Arg(ident, pos2) => {
+                                Arg(ident, _, pos2) => {
                                     if idents.contains(&ident) {
                                         self.stack_trace.push((None, pos2.clone()));
                                         self.ret_value = Value::None;
@@ -223,57 +415,92 @@ impl Interp
         Ok(())
     }
     
-    fn interpret_stat(&mut self, env: &mut Env, stat: &Stat) -> Result<()>
+    fn interpret_assign(&mut self, env: &mut Env, expr: &Expr, value2: Value, pos: &Pos) -> Result<()>
     {
-        match stat {
-            Stat::Expr(expr, _) => self.ret_value = self.interpret_expr(env, &**expr)?,
-            Stat::Assign(expr, expr2, pos) => {
-                let value2 = self.interpret_expr(env, &**expr2)?;
-                match &**expr {
-                    Expr::Var(name, _) => {
-                        match env.set_var(name, value2) {
-                            Ok(true) => (),
-                            Ok(false) => {
-                                self.stack_trace.push((None, pos.clone()));
-                                self.ret_value = Value::None;
-                                return Err(Error::Interp(format!("undefined module for variable {}", name)));
-                            },
-                            Err(err) => {
-                                self.stack_trace.push((None, pos.clone()));
-                                self.ret_value = Value::None;
-                                return Err(err);
-                            },
-                        }
+        match expr {
+            Expr::Var(name, _) => {
+                match env.set_var(name, value2) {
+                    Ok(true) => (),
+                    Ok(false) => {
+                        self.stack_trace.push((None, pos.clone()));
+                        self.ret_value = Value::None;
+                        return Err(Error::Interp(format!("undefined module for variable {}", name)));
                     },
-                    Expr::BinOp(BinOp::Index, expr3, expr4, _) => {
-                        let value = self.interpret_expr(env, expr3)?;
-                        let idx_value = self.interpret_expr(env, expr4)?;
-                        match value.set_elem(&idx_value, value2) {
-                            Ok(()) => (),
-                            Err(err) => {
-                                self.stack_trace.push((None, pos.clone()));
-                                self.ret_value = Value::None;
-                                return Err(err);
-                            },
-                        }
+                    Err(err) => {
+                        self.stack_trace.push((None, pos.clone()));
+                        self.ret_value = Value::None;
+                        return Err(err);
                     },
-                    Expr::Field(expr3, ident, _) => {
-                        let value = self.interpret_expr(env, expr3)?;
-                        match value.set_field(ident.clone(), value2) {
-                            Ok(()) => (),
-                            Err(err) => {
-                                self.stack_trace.push((None, pos.clone()));
-                                self.ret_value = Value::None;
-                                return Err(err);
-                            },
-                        }
+                }
+            },
+            Expr::BinOp(BinOp::Index, expr3, expr4, _) => {
+                let value = self.interpret_expr(env, expr3)?;
+                let idx_value = self.interpret_expr(env, expr4)?;
+                match value.set_elem(&idx_value, value2) {
+                    Ok(()) => (),
+                    Err(err) => {
+                        self.stack_trace.push((None, pos.clone()));
+                        self.ret_value = Value::None;
+                        return Err(err);
                     },
-                    _ => {
-                        self.stack_trace.push((None, expr.pos().clone()));
+                }
+            },
+            Expr::Field(expr3, ident, _) => {
+                let value = self.interpret_expr(env, expr3)?;
+                match value.set_field(ident.clone(), value2) {
+                    Ok(()) => (),
+                    Err(err) => {
+                        self.stack_trace.push((None, pos.clone()));
+                        self.ret_value = Value::None;
+                        return Err(err);
+                    },
+                }
+            },
+            Expr::Index2(expr3, expr4, expr5, _) => {
+                let value = self.interpret_expr(env, expr3)?;
+                let value4 = match expr4 {
+                    Some(expr4) => Some(self.interpret_expr(env, expr4)?),
+                    None => None,
+                };
+                let value5 = match expr5 {
+                    Some(expr5) => Some(self.interpret_expr(env, expr5)?),
+                    None => None,
+                };
+                match value.set_elem2(value4.as_ref(), value5.as_ref(), value2) {
+                    Ok(new_value) => self.interpret_assign(env, expr3, new_value, pos)?,
+                    Err(err) => {
+                        self.stack_trace.push((None, pos.clone()));
                         self.ret_value = Value::None;
-                        return Err(Error::Interp(String::from("expression isn't assignable")));
+                        return Err(err);
                     },
                 }
+            },
+            _ => {
+                self.stack_trace.push((None, expr.pos().clone()));
+                self.ret_value = Value::None;
+                return Err(Error::Interp(String::from("expression isn't assignable")));
+            },
+        }
+        Ok(())
+    }
+
+    fn interpret_stat(&mut self, env: &mut Env, stat: &Stat) -> Result<()>
+    {
+        if self.debug_hook.is_some() {
+            match self.call_debug_hook(env, stat) {
+                Ok(()) => (),
+                Err(err) => {
+                    self.stack_trace.push((None, stat.pos().clone()));
+                    self.ret_value = Value::None;
+                    return Err(err);
+                },
+            }
+        }
+        match stat {
+            Stat::Expr(expr, _) => self.ret_value = self.interpret_expr(env, &**expr)?,
+            Stat::Assign(expr, expr2, pos) => {
+                let value2 = self.interpret_expr(env, &**expr2)?;
+                self.interpret_assign(env, &**expr, value2, pos)?;
                 self.ret_value = Value::None;
             },
             Stat::If(expr, stats, else_if_pairs, else_stats, _) => {
@@ -389,6 +616,49 @@ impl Interp
                 self.ret_value = Value::None;
                 return Err(Error::Stop(Stop::Quit));
             },
+            Stat::Try(try_stats, catch_pair, finally_stats, pos) => {
+                let res = match self.interpret_stats(env, try_stats.as_slice()) {
+                    Ok(()) => Ok(()),
+                    Err(err) => {
+                        let kind_and_msg = match &err {
+                            Error::Interp(msg) => Some((String::from("interp"), msg.clone())),
+                            Error::Matrix(err2) => Some((String::from("matrix"), format!("{}", err2))),
+                            _ => None,
+                        };
+                        match (kind_and_msg, catch_pair) {
+                            (Some((kind, msg)), Some((ident, catch_stats))) => {
+                                self.stack_trace.clear();
+                                let err_value = Value::Object(Arc::new(Object::Error(kind, msg)));
+                                match env.set_var(&Name::Var(ident.clone()), err_value) {
+                                    Ok(true) => self.interpret_stats(env, catch_stats.as_slice()),
+                                    Ok(false) => {
+                                        self.stack_trace.push((None, pos.clone()));
+                                        self.ret_value = Value::None;
+                                        Err(Error::Interp(format!("undefined module for variable {}", ident)))
+                                    },
+                                    Err(err2) => {
+                                        self.stack_trace.push((None, pos.clone()));
+                                        self.ret_value = Value::None;
+                                        Err(err2)
+                                    },
+                                }
+                            },
+                            (_, _) => Err(err),
+                        }
+                    },
+                };
+                match finally_stats {
+                    Some(finally_stats) => {
+                        let ret_value = self.ret_value.clone();
+                        let stack_trace = mem::take(&mut self.stack_trace);
+                        self.interpret_stats(env, finally_stats.as_slice())?;
+                        self.ret_value = ret_value;
+                        self.stack_trace = stack_trace;
+                    },
+                    None => (),
+                }
+                res?;
+            },
         }
         Ok(())
     }
@@ -421,13 +691,19 @@ impl Interp
                     },
                 }
             },
-            Expr::App(expr2, exprs, pos) => {
+            Expr::App(expr2, exprs, named_args, pos) => {
                 let fun_value = self.interpret_expr(env, &**expr2)?;
                 let mut arg_values: Vec<Value> = Vec::new();
                 for expr3 in exprs {
                     arg_values.push(self.interpret_expr(env, &**expr3)?);
                 }
-                match self.apply_fun(env, &fun_value, arg_values.as_slice()) {
+                let mut named_arg_values: Vec<(String, Value)> = Vec::new();
+                for named_arg in named_args {
+                    match named_arg {
+                        NamedArg(ident, expr3, _) => named_arg_values.push((ident.clone(), self.interpret_expr(env, &**expr3)?)),
+                    }
+                }
+                match self.apply_fun_with_named_args(env, &fun_value, arg_values.as_slice(), named_arg_values.as_slice()) {
                     Ok(value) => Ok(value),
                     Err(err) => {
                         self.stack_trace.push((None, pos.clone()));
@@ -486,6 +762,25 @@ impl Interp
                     },
                 }
             },
+            Expr::Index2(expr2, expr3, expr4, pos) => {
+                let value2 = self.interpret_expr(env, &**expr2)?;
+                let value3 = match expr3 {
+                    Some(expr3) => Some(self.interpret_expr(env, &**expr3)?),
+                    None => None,
+                };
+                let value4 = match expr4 {
+                    Some(expr4) => Some(self.interpret_expr(env, &**expr4)?),
+                    None => None,
+                };
+                match value2.elem2(value3.as_ref(), value4.as_ref()) {
+                    Ok(value) => Ok(value),
+                    Err(err) => {
+                        self.stack_trace.push((None, pos.clone()));
+                        self.ret_value = Value::None;
+                        Err(err)
+                    },
+                }
+            },
             Expr::Range(expr2, expr3, expr4, pos) => {
                 let value2 = self.interpret_expr(env, &**expr2)?;
                 let value3 = self.interpret_expr(env, &**expr3)?;
@@ -493,18 +788,35 @@ impl Interp
                     Some(expr4) => Some(self.interpret_expr(env, &**expr4)?),
                     None => None,
                 };
-                match (&value2, &value3, &value4) {
-                    (Value::Int(a), Value::Int(b), None) => Ok(Value::Object(Arc::new(Object::IntRange(*a, *b, 1)))),
-                    (Value::Int(a), Value::Int(b), Some(Value::Int(c))) => Ok(Value::Object(Arc::new(Object::IntRange(*a, *b, *c)))),
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_), None) => Ok(Value::Object(Arc::new(Object::FloatRange(value2.to_f32(), value3.to_f32(), 1.0)))),
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_), Some(value4 @ (Value::Int(_) | Value::Float(_)))) => Ok(Value::Object(Arc::new(Object::FloatRange(value2.to_f32(), value3.to_f32(), value4.to_f32())))),
-                    (_, _, _) => {
+                match create_range(&value2, &value3, &value4) {
+                    Some(value) => Ok(value),
+                    None => {
                         self.stack_trace.push((None, pos.clone()));
                         self.ret_value = Value::None;
                         Err(Error::Interp(String::from("unsupported types for range creation")))
                     },
                 }
             },
+            Expr::Lambda(fun, _) => {
+                match &**fun {
+                    Fun(args, _) => {
+                        let mut idents: BTreeSet<&String> = BTreeSet::new();
+                        for arg in args {
+                            match arg {
+                                Arg(ident, _, pos2) => {
+                                    if idents.contains(&ident) {
+                                        self.stack_trace.push((None, pos2.clone()));
+                                        self.ret_value = Value::None;
+                                        return Err(Error::Interp(format!("already defined argument {}", ident)));
+                                    }
+                                    idents.insert(ident);
+                                },
+                            }
+                        }
+                    },
+                }
+                Ok(create_lambda(env, fun))
+            },
             Expr::PropagateError(expr2, pos) => {
                 let value2 = self.interpret_expr(env, &**expr2)?;
                 match &value2 {
@@ -697,7 +1009,708 @@ impl Interp
                 }
                 Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))))
             },
+            Lit::Dict(dict_pairs) => {
+                let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+                for dict_pair in dict_pairs {
+                    match dict_pair {
+                        DictPair(expr, expr2, pos2) => {
+                            let key_value = self.interpret_expr(env, &**expr)?;
+                            let value = self.interpret_expr(env, &**expr2)?;
+                            match insert_dict_pair(&mut pairs, &key_value, value) {
+                                Ok(()) => (),
+                                Err(err) => {
+                                    self.stack_trace.push((None, pos2.clone()));
+                                    self.ret_value = Value::None;
+                                    return Err(err);
+                                },
+                            }
+                        },
+                    }
+                }
+                Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs)))))
+            },
+        }
+    }
+
+    fn fun_code(&mut self, fun: &Arc<Fun>) -> Arc<Code>
+    {
+        // A weak reference keeps an allocation of function, so an address of function in the
+        // cache isn't reused by other function.
+        let key = Arc::as_ptr(fun) as usize;
+        match self.codes.get(&key) {
+            Some((_, code)) => code.clone(),
+            None => {
+                if self.codes.len() >= self.max_code_count {
+                    self.codes.retain(|_, (weak_fun, _)| weak_fun.strong_count() > 0);
+                    self.max_code_count = (self.codes.len() * 2).max(MIN_MAX_CODE_COUNT);
+                }
+                let code = Arc::new(Compiler::compile_fun(&**fun));
+                self.codes.insert(key, (Arc::downgrade(fun), code.clone()));
+                code
+            },
+        }
+    }
+
+    fn execute_fun(&mut self, env: &mut Env, fun_value: &Value, fun: &Arc<Fun>, arg_value_count: usize, named_arg_values: &[(String, Value)]) -> Result<Value>
+    {
+        let code = self.fun_code(fun);
+        let mut frame = Frame::new(code.local_idents().len(), arg_value_count, named_arg_values);
+        frame.reload(env, &*code);
+        let res = self.execute(env, &*code, &mut frame);
+        self.end_fun(env, fun_value, res)
+    }
+
+    fn error_at(&mut self, pos: &Pos, err: Error) -> Error
+    {
+        self.stack_trace.push((None, pos.clone()));
+        self.ret_value = Value::None;
+        err
+    }
+
+    fn execute(&mut self, env: &mut Env, code: &Code, frame: &mut Frame<'_>) -> Result<()>
+    {
+        let mut ip = 0usize;
+        loop {
+            match self.execute_instrs(env, code, frame, &mut ip) {
+                Ok(()) => return Ok(()),
+                Err(err) => self.handle_error(frame, err, &mut ip)?,
+            }
+        }
+    }
+
+    fn handle_error(&mut self, frame: &mut Frame<'_>, err: Error, ip: &mut usize) -> Result<()>
+    {
+        loop {
+            match frame.handlers.pop() {
+                Some(Handler::Loop(break_ip, continue_ip, depths)) => {
+                    let new_ip = match &err {
+                        Error::Stop(Stop::Break) => Some(break_ip),
+                        Error::Stop(Stop::Continue) => Some(continue_ip),
+                        _ => None,
+                    };
+                    match new_ip {
+                        Some(new_ip) => {
+                            self.stack_trace.clear();
+                            frame.truncate(depths);
+                            frame.handlers.push(Handler::Loop(break_ip, continue_ip, depths));
+                            *ip = new_ip;
+                            return Ok(());
+                        },
+                        None => (),
+                    }
+                },
+                Some(Handler::Try(catch_ip, finally_ip, depths)) => {
+                    let kind_and_msg = match &err {
+                        Error::Interp(msg) => Some((String::from("interp"), msg.clone())),
+                        Error::Matrix(err2) => Some((String::from("matrix"), format!("{}", err2))),
+                        _ => None,
+                    };
+                    match (kind_and_msg, catch_ip, finally_ip) {
+                        (Some((kind, msg)), Some(catch_ip), _) => {
+                            self.stack_trace.clear();
+                            frame.truncate(depths);
+                            match finally_ip {
+                                Some(finally_ip) => frame.handlers.push(Handler::Catch(finally_ip, depths)),
+                                None => (),
+                            }
+                            frame.stack.push(Value::Object(Arc::new(Object::Error(kind, msg))));
+                            *ip = catch_ip;
+                            return Ok(());
+                        },
+                        (_, _, Some(finally_ip)) => {
+                            self.enter_finally(frame, Some(err), finally_ip, depths, ip);
+                            return Ok(());
+                        },
+                        (_, _, None) => (),
+                    }
+                },
+                Some(Handler::Catch(finally_ip, depths)) => {
+                    self.enter_finally(frame, Some(err), finally_ip, depths, ip);
+                    return Ok(());
+                },
+                Some(Handler::Finally(_, _, _)) => (),
+                None => return Err(err),
+            }
+        }
+    }
+
+    fn enter_finally(&mut self, frame: &mut Frame<'_>, pending_err: Option<Error>, finally_ip: usize, depths: Depths, ip: &mut usize)
+    {
+        frame.truncate(depths);
+        let stack_trace = mem::take(&mut self.stack_trace);
+        frame.handlers.push(Handler::Finally(pending_err, self.ret_value.clone(), stack_trace));
+        *ip = finally_ip;
+    }
+
+    fn execute_instrs(&mut self, env: &mut Env, code: &Code, frame: &mut Frame<'_>, ip: &mut usize) -> Result<()>
+    {
+        let instrs = code.instrs();
+        while *ip < instrs.len() {
+            let instr = instrs[*ip];
+            *ip += 1;
+            match instr {
+                Instr::Const(const_idx) => frame.stack.push(code.consts()[const_idx].clone()),
+                Instr::LoadLocal(local_idx, pos_idx) => {
+                    let value = match &frame.slots[local_idx] {
+                        Some(value) => value.clone(),
+                        None => self.load_var(env, &Name::Var(code.local_idents()[local_idx].clone()), &code.poss()[pos_idx])?,
+                    };
+                    frame.stack.push(value);
+                },
+                Instr::LoadVar(name_idx, pos_idx) => {
+                    let value = self.load_var(env, &code.names()[name_idx], &code.poss()[pos_idx])?;
+                    frame.stack.push(value);
+                },
+                Instr::StoreLocal(local_idx) => {
+                    frame.slots[local_idx] = Some(frame.pop());
+                    frame.is_flushed = false;
+                },
+                Instr::StoreVar(name_idx, pos_idx) => {
+                    let value = frame.pop();
+                    self.store_var(env, &code.names()[name_idx], value, &code.poss()[pos_idx])?;
+                },
+                Instr::App(arg_value_count, ident_list_idx, pos_idx) => {
+                    let idents = &code.ident_lists()[ident_list_idx];
+                    let named_arg_value_start = frame.stack.len() - idents.len();
+                    let named_arg_values: Vec<(String, Value)> = idents.iter().map(|ident| ident.clone()).zip(frame.stack.drain(named_arg_value_start..)).collect();
+                    let arg_value_start = frame.stack.len() - arg_value_count;
+                    let arg_values: Vec<Value> = frame.stack.drain(arg_value_start..).collect();
+                    let fun_value = frame.pop();
+                    let res = if is_builtin_fun(&fun_value) {
+                        // The built-in functions can use the local variables.
+                        frame.flush(env, code);
+                        let local_var_count = env.local_var_count();
+                        let res = self.apply_fun_with_named_args(env, &fun_value, arg_values.as_slice(), named_arg_values.as_slice());
+                        if env.local_var_count() != local_var_count {
+                            frame.reload(env, code);
+                        }
+                        res
+                    } else {
+                        self.apply_fun_with_named_args(env, &fun_value, arg_values.as_slice(), named_arg_values.as_slice())
+                    };
+                    match res {
+                        Ok(value) => frame.stack.push(value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::UnaryOp(op, pos_idx) => {
+                    let value2 = frame.pop();
+                    match value2.unary_op(op) {
+                        Ok(value) => frame.stack.push(value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::BinOp(op, pos_idx) => {
+                    let value3 = frame.pop();
+                    let value2 = frame.pop();
+                    match value2.bin_op(op, &value3) {
+                        Ok(value) => frame.stack.push(value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::Field(ident_idx, pos_idx) => {
+                    let value2 = frame.pop();
+                    match value2.field(&code.strings()[ident_idx]) {
+                        Ok(value) => frame.stack.push(value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::Elem2(is_row_idx, is_col_idx, pos_idx) => {
+                    let value4 = if is_col_idx {
+                        Some(frame.pop())
+                    } else {
+                        None
+                    };
+                    let value3 = if is_row_idx {
+                        Some(frame.pop())
+                    } else {
+                        None
+                    };
+                    let value2 = frame.pop();
+                    match value2.elem2(value3.as_ref(), value4.as_ref()) {
+                        Ok(value) => frame.stack.push(value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::Range(is_step, pos_idx) => {
+                    let value4 = if is_step {
+                        Some(frame.pop())
+                    } else {
+                        None
+                    };
+                    let value3 = frame.pop();
+                    let value2 = frame.pop();
+                    match create_range(&value2, &value3, &value4) {
+                        Some(value) => frame.stack.push(value),
+                        None => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("unsupported types for range creation")))),
+                    }
+                },
+                Instr::Lambda(fun_idx) => {
+                    frame.flush(env, code);
+                    frame.stack.push(create_lambda(env, &code.funs()[fun_idx]));
+                },
+                Instr::PropagateError(pos_idx) => {
+                    let is_err = match frame.stack.last() {
+                        Some(Value::None) => true,
+                        Some(Value::Object(object)) => {
+                            match &**object {
+                                Object::Error(_, _) => true,
+                                _ => false,
+                            }
+                        },
+                        _ => false,
+                    };
+                    if is_err {
+                        self.stack_trace.push((None, code.poss()[pos_idx].clone()));
+                        self.ret_value = frame.pop();
+                        return Err(Error::Stop(Stop::ErrorPropagation));
+                    }
+                },
+                Instr::Array(elem_count) => {
+                    let elem_start = frame.stack.len() - elem_count;
+                    let elems: Vec<Value> = frame.stack.drain(elem_start..).collect();
+                    frame.stack.push(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems)))));
+                },
+                Instr::Struct(ident_list_idx) => {
+                    let idents = &code.ident_lists()[ident_list_idx];
+                    let field_start = frame.stack.len() - idents.len();
+                    let fields: BTreeMap<String, Value> = idents.iter().map(|ident| ident.clone()).zip(frame.stack.drain(field_start..)).collect();
+                    frame.stack.push(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))));
+                },
+                Instr::MatrixBegin => frame.builders.push(Builder::Matrix(Vec::new(), 0, None)),
+                Instr::MatrixRowBegin => frame.builders.push(Builder::Row(Vec::new())),
+                Instr::MatrixElem(pos_idx) => {
+                    let value = frame.pop();
+                    match value.to_opt_f32() {
+                        Some(x) => {
+                            match frame.builders.last_mut() {
+                                Some(Builder::Row(xs)) => xs.push(x),
+                                _ => (),
+                            }
+                        },
+                        None => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("can't convert value to floating-point number")))),
+                    }
+                },
+                Instr::MatrixRowEnd(pos_idx) => {
+                    let ys = match frame.builders.pop() {
+                        Some(Builder::Row(ys)) => ys,
+                        _ => Vec::new(),
+                    };
+                    match frame.builders.last_mut() {
+                        Some(Builder::Matrix(xs, row_count, col_count)) => {
+                            if col_count.map(|n| n == ys.len()).unwrap_or(true) {
+                                xs.extend_from_slice(ys.as_slice());
+                                *col_count = Some(ys.len());
+                            } else {
+                                return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("numbers of columns of matrix rows aren't equal"))));
+                            }
+                            match row_count.checked_add(1) {
+                                Some(new_row_count) => *row_count = new_row_count,
+                                None => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("too many matrix rows")))),
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Instr::MatrixEnd(pos_idx) => {
+                    match frame.builders.pop() {
+                        Some(Builder::Matrix(xs, row_count, col_count)) => {
+                            match matrix_create_and_set_elems(row_count, col_count.unwrap_or(0), xs.as_slice()) {
+                                Ok(a) => frame.stack.push(Value::Object(Arc::new(Object::Matrix(a)))),
+                                Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Instr::ArrayBegin => frame.builders.push(Builder::Array(Vec::new())),
+                Instr::ArrayElem => {
+                    let value = frame.pop();
+                    match frame.builders.last_mut() {
+                        Some(Builder::Array(elems)) => elems.push(value),
+                        _ => (),
+                    }
+                },
+                Instr::ArrayEnd => {
+                    match frame.builders.pop() {
+                        Some(Builder::Array(elems)) => frame.stack.push(Value::Ref(Arc::new(RwLock::new(MutObject::Array(elems))))),
+                        _ => (),
+                    }
+                },
+                Instr::DictBegin => frame.builders.push(Builder::Dict(BTreeMap::new())),
+                Instr::DictPair(pos_idx) => {
+                    let value = frame.pop();
+                    let key_value = frame.pop();
+                    match frame.builders.last_mut() {
+                        Some(Builder::Dict(pairs)) => {
+                            match insert_dict_pair(pairs, &key_value, value) {
+                                Ok(()) => (),
+                                Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Instr::DictEnd => {
+                    match frame.builders.pop() {
+                        Some(Builder::Dict(pairs)) => frame.stack.push(Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))))),
+                        _ => (),
+                    }
+                },
+                Instr::CountBegin(pos_idx) => {
+                    let value = frame.pop();
+                    match value.to_opt_i64() {
+                        Some(n) => frame.counts.push(n),
+                        None => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("can't convert value to integer number")))),
+                    }
+                },
+                Instr::CountNext(target) => {
+                    let is_next = match frame.counts.last_mut() {
+                        Some(n) if *n > 0 => {
+                            *n -= 1;
+                            true
+                        },
+                        _ => false,
+                    };
+                    if !is_next {
+                        frame.counts.pop();
+                        *ip = target;
+                    }
+                },
+                Instr::Jump(target) => *ip = target,
+                Instr::JumpIfFalse(target) => {
+                    if !frame.pop().to_bool() {
+                        *ip = target;
+                    }
+                },
+                Instr::JumpIfFalseOrPop(target) => {
+                    if frame.stack.last().map(|v| v.to_bool()).unwrap_or(false) {
+                        frame.stack.pop();
+                    } else {
+                        *ip = target;
+                    }
+                },
+                Instr::JumpIfTrueOrPop(target) => {
+                    if frame.stack.last().map(|v| v.to_bool()).unwrap_or(false) {
+                        *ip = target;
+                    } else {
+                        frame.stack.pop();
+                    }
+                },
+                Instr::SetRetValue => self.ret_value = frame.pop(),
+                Instr::ClearRetValue => self.ret_value = Value::None,
+                Instr::SetElem(pos_idx) => {
+                    let idx_value = frame.pop();
+                    let value = frame.pop();
+                    let value2 = frame.pop();
+                    match value.set_elem(&idx_value, value2) {
+                        Ok(()) => (),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::SetElem2(is_row_idx, is_col_idx, pos_idx) => {
+                    let col_idx_value = if is_col_idx {
+                        Some(frame.pop())
+                    } else {
+                        None
+                    };
+                    let row_idx_value = if is_row_idx {
+                        Some(frame.pop())
+                    } else {
+                        None
+                    };
+                    let value = frame.pop();
+                    let value2 = frame.pop();
+                    match value.set_elem2(row_idx_value.as_ref(), col_idx_value.as_ref(), value2) {
+                        Ok(new_value) => frame.stack.push(new_value),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::SetField(ident_idx, pos_idx) => {
+                    let value = frame.pop();
+                    let value2 = frame.pop();
+                    match value.set_field(code.strings()[ident_idx].clone(), value2) {
+                        Ok(()) => (),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::IterBegin(pos_idx) => {
+                    let value = frame.pop();
+                    match value.owned_iter() {
+                        Ok(Some(iter)) => frame.iters.push(iter),
+                        Ok(None) => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(String::from("value isn't iterable")))),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::IterNext(target, pos_idx) => {
+                    let elem = match frame.iters.last_mut() {
+                        Some(iter) => iter.next(),
+                        None => None,
+                    };
+                    match elem {
+                        Some(Ok(elem)) => frame.stack.push(elem),
+                        Some(Err(err)) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                        None => *ip = target,
+                    }
+                },
+                Instr::IterEnd => {
+                    frame.iters.pop();
+                },
+                Instr::LoopBegin(break_target, continue_target) => {
+                    let depths = frame.depths();
+                    frame.handlers.push(Handler::Loop(break_target, continue_target, depths));
+                },
+                Instr::TryBegin(catch_target, finally_target) => {
+                    let depths = frame.depths();
+                    frame.handlers.push(Handler::Try(catch_target, finally_target, depths));
+                },
+                Instr::LoopEnd | Instr::TryEnd | Instr::CatchEnd => {
+                    frame.handlers.pop();
+                },
+                Instr::FinallyBegin => {
+                    let stack_trace = mem::take(&mut self.stack_trace);
+                    frame.handlers.push(Handler::Finally(None, self.ret_value.clone(), stack_trace));
+                },
+                Instr::FinallyEnd => {
+                    match frame.handlers.pop() {
+                        Some(Handler::Finally(pending_err, ret_value, stack_trace)) => {
+                            self.ret_value = ret_value;
+                            self.stack_trace = stack_trace;
+                            match pending_err {
+                                Some(err) => return Err(err),
+                                None => (),
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                Instr::Return(pos_idx) => {
+                    self.ret_value = frame.pop();
+                    self.stack_trace.push((None, code.poss()[pos_idx].clone()));
+                    return Err(Error::Stop(Stop::Return));
+                },
+                Instr::Stop(stop, pos_idx) => return Err(self.error_at(&code.poss()[pos_idx], Error::Stop(stop))),
+                Instr::Error(msg_idx, pos_idx) => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(code.strings()[msg_idx].clone()))),
+                Instr::DefaultArg(arg_idx, target) => {
+                    let ident = &code.local_idents()[arg_idx];
+                    if arg_idx < frame.arg_value_count || frame.named_arg_values.iter().any(|(ident2, _)| ident2 == ident) {
+                        *ip = target;
+                    }
+                },
+                Instr::Fun(ident_idx, fun_idx, pos_idx) => {
+                    let ident = &code.strings()[ident_idx];
+                    match env.add_fun(ident.clone(), code.funs()[fun_idx].clone()) {
+                        Ok(true) => (),
+                        Ok(false) => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(format!("already variable {} is set", ident)))),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::ModBegin(ident_idx, pos_idx) => {
+                    let ident = &code.strings()[ident_idx];
+                    match env.add_and_push_mod(ident.clone()) {
+                        Ok(true) => (),
+                        Ok(false) => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(format!("already defined module {}", ident)))),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+                Instr::ModEnd(ident_idx, pos_idx) => {
+                    match env.pop_mod() {
+                        Ok(true) => (),
+                        Ok(false) => return Err(self.error_at(&code.poss()[pos_idx], Error::Interp(format!("can't pop module {}", code.strings()[ident_idx])))),
+                        Err(err) => return Err(self.error_at(&code.poss()[pos_idx], err)),
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn load_var(&mut self, env: &Env, name: &Name, pos: &Pos) -> Result<Value>
+    {
+        match env.var(name) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(self.error_at(pos, Error::Interp(format!("variable {} isn't set", name)))),
+            Err(err) => Err(self.error_at(pos, err)),
+        }
+    }
+
+    fn store_var(&mut self, env: &mut Env, name: &Name, value: Value, pos: &Pos) -> Result<()>
+    {
+        match env.set_var(name, value) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(self.error_at(pos, Error::Interp(format!("undefined module for variable {}", name)))),
+            Err(err) => Err(self.error_at(pos, err)),
+        }
+    }
+}
+
+impl fmt::Debug for Interp
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Interp")
+            .field("stack_trace", &self.stack_trace)
+            .field("ret_value", &self.ret_value)
+            .field("is_vm", &self.is_vm)
+            .field("codes", &self.codes)
+            .field("max_code_count", &self.max_code_count)
+            .field("debug_hook", &self.debug_hook.as_ref().map(|_| "DebugHook"))
+            .field("debug_funs", &self.debug_funs)
+            .field("debug_pos", &self.debug_pos)
+            .finish()
+    }
+}
+
+fn create_range(value2: &Value, value3: &Value, value4: &Option<Value>) -> Option<Value>
+{
+    match (value2, value3, value4) {
+        (Value::Int(a), Value::Int(b), None) => Some(Value::Object(Arc::new(Object::IntRange(*a, *b, 1)))),
+        (Value::Int(a), Value::Int(b), Some(Value::Int(c))) => Some(Value::Object(Arc::new(Object::IntRange(*a, *b, *c)))),
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_), None) => Some(Value::Object(Arc::new(Object::FloatRange(value2.to_f64(), value3.to_f64(), 1.0)))),
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_), Some(value4 @ (Value::Int(_) | Value::Float(_)))) => Some(Value::Object(Arc::new(Object::FloatRange(value2.to_f64(), value3.to_f64(), value4.to_f64())))),
+        (_, _, _) => None,
+    }
+}
+
+fn create_lambda(env: &Env, fun: &Arc<Fun>) -> Value
+{
+    let (lambda_mod, captured_vars) = match env.stack().last() {
+        Some((fun_mod, local_vars)) => (Arc::downgrade(fun_mod), local_vars.clone()),
+        None => (Arc::downgrade(env.current_mod()), BTreeMap::new()),
+    };
+    Value::Object(Arc::new(Object::Lambda(lambda_mod, captured_vars, fun.clone())))
+}
+
+fn is_builtin_fun(value: &Value) -> bool
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::BuiltinFun(_, _) => true,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Depths
+{
+    stack_len: usize,
+    iter_count: usize,
+    count_count: usize,
+    builder_count: usize,
+    handler_count: usize,
+}
+
+#[derive(Debug)]
+enum Handler
+{
+    Loop(usize, usize, Depths),
+    Try(Option<usize>, Option<usize>, Depths),
+    Catch(usize, Depths),
+    Finally(Option<Error>, Value, Vec<(Option<Value>, Pos)>),
+}
+
+#[derive(Debug)]
+enum Builder
+{
+    Matrix(Vec<f32>, usize, Option<usize>),
+    Row(Vec<f32>),
+    Array(Vec<Value>),
+    Dict(BTreeMap<DictKey, Value>),
+}
+
+fn insert_dict_pair(pairs: &mut BTreeMap<DictKey, Value>, key_value: &Value, value: Value) -> Result<()>
+{
+    let key = DictKey::from_value(key_value)?;
+    if pairs.contains_key(&key) {
+        return Err(Error::Interp(format!("already defined key {}", key)));
+    }
+    pairs.insert(key, value);
+    Ok(())
+}
+
+struct Frame<'a>
+{
+    slots: Vec<Option<Value>>,
+    is_flushed: bool,
+    arg_value_count: usize,
+    named_arg_values: &'a [(String, Value)],
+    stack: Vec<Value>,
+    iters: Vec<Iter<'static>>,
+    counts: Vec<i64>,
+    builders: Vec<Builder>,
+    handlers: Vec<Handler>,
+}
+
+impl<'a> Frame<'a>
+{
+    fn new(local_var_count: usize, arg_value_count: usize, named_arg_values: &'a [(String, Value)]) -> Self
+    {
+        Frame {
+            slots: vec![None; local_var_count],
+            is_flushed: true,
+            arg_value_count,
+            named_arg_values,
+            stack: Vec::new(),
+            iters: Vec::new(),
+            counts: Vec::new(),
+            builders: Vec::new(),
+            handlers: Vec::new(),
+        }
+    }
+
+    fn pop(&mut self) -> Value
+    {
+        match self.stack.pop() {
+            Some(value) => value,
+            None => Value::None,
+        }
+    }
+
+    fn depths(&self) -> Depths
+    {
+        Depths {
+            stack_len: self.stack.len(),
+            iter_count: self.iters.len(),
+            count_count: self.counts.len(),
+            builder_count: self.builders.len(),
+            handler_count: self.handlers.len(),
+        }
+    }
+
+    fn truncate(&mut self, depths: Depths)
+    {
+        self.stack.truncate(depths.stack_len);
+        self.iters.truncate(depths.iter_count);
+        self.counts.truncate(depths.count_count);
+        self.builders.truncate(depths.builder_count);
+        self.handlers.truncate(depths.handler_count);
+    }
+
+    fn flush(&mut self, env: &mut Env, code: &Code)
+    {
+        if !self.is_flushed {
+            for (slot, ident) in self.slots.iter().zip(code.local_idents().iter()) {
+                match slot {
+                    Some(value) => {
+                        env.set_local_var(ident, value.clone());
+                    },
+                    None => (),
+                }
+            }
+            self.is_flushed = true;
+        }
+    }
+
+    fn reload(&mut self, env: &Env, code: &Code)
+    {
+        for (slot, ident) in self.slots.iter_mut().zip(code.local_idents().iter()) {
+            *slot = env.local_var(ident);
         }
+        self.is_flushed = true;
     }
 }
 
@@ -678,6 +678,168 @@ X = [
     }
 }
 
+#[test]
+fn test_interp_interpret_interprets_index2_expressions()
+{
+    let s = "
+X = [
+    1, 2, 3
+    4, 5, 6
+    7, 8, 9
+]
+Y = X[1 to 3 by 2, 2 to 3] + X[1 to 2, :][:, 1 to 2]
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("Y")) {
+                Some(value) => {
+                    let a = vec![
+                        3.0, 5.0,
+                        12.0, 14.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, a.clone()));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_assignment_statement_for_index2_expression()
+{
+    let s = "
+Y = [
+    1, 2, 3
+    4, 5, 6
+    7, 8, 9
+]
+Y[:, 1] = 0
+Y[2 to 3, 2 to 3] = [10, 11; 12, 13]
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("Y")) {
+                Some(value) => {
+                    let a = vec![
+                        0.0, 2.0, 3.0,
+                        0.0, 10.0, 11.0,
+                        0.0, 12.0, 13.0
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(3, 3, TransposeFlag::NoTranspose, a.clone()));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_index2_expressions_and_assignments_for_non_finite_elements()
+{
+    let s = "
+X = [
+    inf, 1, 2
+    3, nan, 4
+]
+Y = X[1 to 2, 2 to 3]
+Z = X
+Z[1 to 2, 3] = [100000000; 1]
+Z[2, 1] = -inf
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("Y")) {
+                Some(value) => {
+                    match value.to_matrix_array().unwrap() {
+                        Value::Object(object) => {
+                            match &*object {
+                                Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, xs) => {
+                                    assert_eq!(1.0, xs[0]);
+                                    assert_eq!(2.0, xs[1]);
+                                    assert_eq!(true, xs[2].is_nan());
+                                    assert_eq!(4.0, xs[3]);
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("Z")) {
+                Some(value) => {
+                    match value.to_matrix_array().unwrap() {
+                        Value::Object(object) => {
+                            match &*object {
+                                Object::MatrixArray(2, 3, TransposeFlag::NoTranspose, xs) => {
+                                    assert_eq!(f32::INFINITY, xs[0]);
+                                    assert_eq!(1.0, xs[1]);
+                                    assert_eq!(100000000.0, xs[2]);
+                                    assert_eq!(-f32::INFINITY, xs[3]);
+                                    assert_eq!(true, xs[4].is_nan());
+                                    assert_eq!(1.0, xs[5]);
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_interp_interpret_interprets_matrix_literal_with_filled_rows()
 {
@@ -939,6 +1101,168 @@ X = { a: 1; b: 2.5; c: false; }
     }
 }
 
+#[test]
+fn test_interp_interpret_interprets_dictionary_literal()
+{
+    let s = "
+X = .{ 1: 2.5; 2.0: \"a\"; \"b\": false; .}
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => {
+                    let mut expected_pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+                    expected_pairs.insert(DictKey::Int(1), Value::Float(2.5));
+                    expected_pairs.insert(DictKey::Int(2), Value::Object(Arc::new(Object::String(String::from("a")))));
+                    expected_pairs.insert(DictKey::String(String::from("b")), Value::Bool(false));
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Dict(expected_pairs))));
+                    assert_eq!(expected_value, *value);
+                },
+                None => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_interpolated_string()
+{
+    let s = "
+a = 0.123456789
+b = .{ \"c\": \"d\"; .}
+i = 3
+X = $\"epoch {i + 1}: {a:.3} {{e}} {b[\"c\"]} {$\"{i:>3}\"}\"
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+            crate::builtins::add_std_builtin_funs(&mut root_mod);
+            let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => assert_eq!(Value::Object(Arc::new(Object::String(String::from("epoch 4: 0.123 {e} d   3")))), *value),
+                None => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_sorts_with_key_functions_and_comparators()
+{
+    let s = "
+A = .[{ name: \"a\"; score: 0.5 }, { name: \"b\"; score: 0.9 }, { name: \"c\"; score: 0.5 }.]
+sort(A, none, function(D, X) X.score end)
+X = .[A[1].name, A[2].name, A[3].name.]
+Y = argsort(.[3, 1, 2, 1 .], none, function(D, X, Y) Y - X end, \"cmp\")
+Z = sorted(.[\"bb\", \"a\", \"ccc\", \"d\".], 0, function(D, X) D - length(X) end)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+            crate::builtins::add_std_builtin_funs(&mut root_mod);
+            let mut env = Env::new(Arc::new(RwLock::new(root_mod)));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                                    Value::Object(Arc::new(Object::String(String::from("a")))),
+                                    Value::Object(Arc::new(Object::String(String::from("c")))),
+                                    Value::Object(Arc::new(Object::String(String::from("b"))))
+                    ]))));
+                    assert_eq!(expected_value, *value);
+                },
+                None => assert!(false),
+            }
+            match root_mod_g.var(&String::from("Y")) {
+                Some(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(3), Value::Int(2), Value::Int(4)]))));
+                    assert_eq!(expected_value, *value);
+                },
+                None => assert!(false),
+            }
+            match root_mod_g.var(&String::from("Z")) {
+                Some(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![
+                                    Value::Object(Arc::new(Object::String(String::from("ccc")))),
+                                    Value::Object(Arc::new(Object::String(String::from("bb")))),
+                                    Value::Object(Arc::new(Object::String(String::from("a")))),
+                                    Value::Object(Arc::new(Object::String(String::from("d"))))
+                    ]))));
+                    assert_eq!(expected_value, *value);
+                },
+                None => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_already_defined_dictionary_key()
+{
+    let s = "
+X = .{ 1: 2; 1.0: 3; .}
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined key 1"), msg),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_interp_interpret_interprets_expression_statement()
 {
@@ -1888,12 +2212,14 @@ Z = 3
 }
 
 #[test]
-fn test_interp_interpret_interprets_definition()
+fn test_interp_interpret_interprets_try_statement_with_catch()
 {
     let s = "
-function f(X)
-    Y = 1
-    X + Y
+try
+    X = Y + 1
+    Z = 1
+catch e
+    E = e
 end
 ";
     let s2 = &s[1..];
@@ -1912,7 +2238,145 @@ end
             }
             assert_eq!(true, interp.stack_trace().is_empty());
             let root_mod_g = env.root_mod().read().unwrap();
-            match root_mod_g.var(&String::from("f")) {
+            match root_mod_g.var(&String::from("E")) {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Error(kind, msg) => {
+                            assert_eq!(String::from("interp"), *kind);
+                            assert_eq!(String::from("variable Y isn't set"), *msg);
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            assert_eq!(false, root_mod_g.has_var(&String::from("X")));
+            assert_eq!(false, root_mod_g.has_var(&String::from("Z")));
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_try_statement_with_catch_in_function()
+{
+    let s = "
+function f(Y)
+    try
+        X = g(Y)
+    catch e
+        X = e
+    end
+    X
+end
+function g(Y)
+    Y + Z
+end
+X = f(1)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            assert_eq!(true, env.stack().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Error(kind, msg) => {
+                            assert_eq!(String::from("interp"), *kind);
+                            assert_eq!(String::from("variable Z isn't set"), *msg);
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_try_statement_with_finally_and_return()
+{
+    let s = "
+function f()
+    try
+        return 1
+    finally
+        root::Y = 2
+    end
+    3
+end
+X = f()
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Int(1)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("Y")) {
+                Some(Value::Int(2)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_definition()
+{
+    let s = "
+function f(X)
+    Y = 1
+    X + Y
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("f")) {
                 Some(Value::Object(object)) => {
                     match &**object {
                         Object::Fun(idents, ident, _) => {
@@ -2271,21 +2735,13 @@ X = fib(10)
 }
 
 #[test]
-fn test_interp_interpret_interprets_functions_with_variable_in_module()
+fn test_interp_interpret_interprets_anonymous_functions()
 {
     let s = "
-module a
-    I = 1
-    function f(X)
-        ::I = I + 1
-        X + I
-    end
-end
-module b
-    I = 10
-    X = a::f(1)
-    Y = a::f(2)
+f = function(X, Y)
+    X + Y
 end
+X = f(1, 2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2303,45 +2759,9 @@ end
             }
             assert_eq!(true, interp.stack_trace().is_empty());
             let root_mod_g = env.root_mod().read().unwrap();
-            match root_mod_g.mod1(&String::from("a")) {
-                Some(a_mod) => {
-                    let a_mod_g = a_mod.read().unwrap();
-                    match a_mod_g.var(&String::from("f")) {
-                        Some(Value::Object(object)) => {
-                            match &**object {
-                                Object::Fun(idents, ident, _) => {
-                                    assert_eq!(vec![String::from("a")], *idents);
-                                    assert_eq!(String::from("f"), *ident);
-                                },
-                                _ => assert!(false),
-                            }
-                        },
-                        _ => assert!(false),
-                    }
-                    match a_mod_g.var(&String::from("I")) {
-                        Some(Value::Int(3)) => assert!(true),
-                        _ => assert!(false),
-                    }
-                },
-                None => assert!(false),
-            }
-            match root_mod_g.mod1(&String::from("b")) {
-                Some(b_mod) => {
-                    let b_mod_g = b_mod.read().unwrap();
-                    match b_mod_g.var(&String::from("I")) {
-                        Some(Value::Int(10)) => assert!(true),
-                        _ => assert!(false),
-                    }
-                    match b_mod_g.var(&String::from("X")) {
-                        Some(Value::Int(3)) => assert!(true),
-                        _ => assert!(false),
-                    }
-                    match b_mod_g.var(&String::from("Y")) {
-                        Some(Value::Int(5)) => assert!(true),
-                        _ => assert!(false),
-                    }
-                },
-                None => assert!(false),
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Int(3)) => assert!(true),
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2349,17 +2769,15 @@ end
 }
 
 #[test]
-fn test_interp_interpret_interprets_empty_statements_in_if_statement()
+fn test_interp_interpret_interprets_anonymous_function_with_captured_local_variables()
 {
     let s = "
-function f(X)
-    if X
-        1
-    else
-    end
+function adder(X)
+    function(Y) X + Y end
 end
-X = f(false)
-Y = f(true)
+f = adder(2)
+g = adder(10)
+X = f(3) + g(4)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2378,11 +2796,7 @@ Y = f(true)
             assert_eq!(true, interp.stack_trace().is_empty());
             let root_mod_g = env.root_mod().read().unwrap();
             match root_mod_g.var(&String::from("X")) {
-                Some(Value::None) => assert!(true),
-                _ => assert!(false),
-            }
-            match root_mod_g.var(&String::from("Y")) {
-                Some(Value::Int(1)) => assert!(true),
+                Some(Value::Int(19)) => assert!(true),
                 _ => assert!(false),
             }
         },
@@ -2391,14 +2805,14 @@ Y = f(true)
 }
 
 #[test]
-fn test_interp_interpret_interprets_matrix_with_floating_point_numbers()
+fn test_interp_interpret_interprets_anonymous_function_with_variable_in_module()
 {
     let s = "
-X = [
-    1.5, 2.5, 3.5
-    4.5, 5.5, 6.5
-    7.5, 8.5, 9.5
-]
+module m
+    Z = 5
+    f = function(X) X + Z end
+end
+X = m::f(2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2417,15 +2831,7 @@ X = [
             assert_eq!(true, interp.stack_trace().is_empty());
             let root_mod_g = env.root_mod().read().unwrap();
             match root_mod_g.var(&String::from("X")) {
-                Some(value) => {
-                    let a = vec![
-                        1.5, 2.5, 3.5,
-                        4.5, 5.5, 6.5,
-                        7.5, 8.5, 9.5
-                    ];
-                    let matrix_array = Arc::new(Object::MatrixArray(3, 3, TransposeFlag::NoTranspose, a.clone()));
-                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
-                },
+                Some(Value::Int(7)) => assert!(true),
                 _ => assert!(false),
             }
         },
@@ -2434,14 +2840,17 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_interprets_matrix_with_filled_rows_and_floating_point_numbers()
+fn test_interp_interpret_interprets_anonymous_function_without_changing_captured_local_variables()
 {
     let s = "
-X = [
-    1.5 fill 3
-    7.5, 8.5, 9.5
-    2.5 fill 3
-]
+function g(X)
+    f = function()
+        X = X + 1
+        X
+    end
+    f() + f() + X
+end
+X = g(1)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2460,15 +2869,7 @@ X = [
             assert_eq!(true, interp.stack_trace().is_empty());
             let root_mod_g = env.root_mod().read().unwrap();
             match root_mod_g.var(&String::from("X")) {
-                Some(value) => {
-                    let a = vec![
-                        1.5, 1.5, 1.5,
-                        7.5, 8.5, 9.5,
-                        2.5, 2.5, 2.5
-                    ];
-                    let matrix_array = Arc::new(Object::MatrixArray(3, 3, TransposeFlag::NoTranspose, a.clone()));
-                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
-                },
+                Some(Value::Int(5)) => assert!(true),
                 _ => assert!(false),
             }
         },
@@ -2477,10 +2878,16 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_variable_is_not_set()
+fn test_interp_interpret_interprets_functions_with_default_arguments()
 {
     let s = "
-X = Y
+function f(X, Y = X + 1, Z = 10)
+    X + Y + Z
+end
+A = f(1)
+B = f(1, 5)
+C = f(1, Z = 2)
+D = f(Y = 3, X = 2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2493,13 +2900,26 @@ X = Y
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("variable Y isn't set"), msg),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("A")) {
+                Some(Value::Int(13)) => assert!(true),
                 _ => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
-                (_, _) => assert!(false),
+            match root_mod_g.var(&String::from("B")) {
+                Some(Value::Int(16)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("C")) {
+                Some(Value::Int(5)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("D")) {
+                Some(Value::Int(15)) => assert!(true),
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2507,13 +2927,18 @@ X = Y
 }
 
 #[test]
-fn test_interp_interpret_complains_on_invalid_number_of_arguments()
+fn test_interp_interpret_interprets_anonymous_functions_with_rest_arguments()
 {
     let s = "
-function f(X, Y)
-    X + Y
+f = function(X, Ys...)
+    S = X
+    for Y in Ys
+        S = S + Y
+    end
+    S
 end
-X = f(1, 2, 3)
+A = f(1)
+B = f(1, 2, 3)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2526,13 +2951,18 @@ X = f(1, 2, 3)
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("invalid number of arguments"), msg),
-                _ => assert!(false),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
-                (_, _) => assert!(false),
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("A")) {
+                Some(Value::Int(1)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("B")) {
+                Some(Value::Int(6)) => assert!(true),
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2540,10 +2970,21 @@ X = f(1, 2, 3)
 }
 
 #[test]
-fn test_interp_interpret_complains_on_value_is_not_function_for_number()
+fn test_interp_interpret_interprets_functions_with_variable_in_module()
 {
     let s = "
-X = 1(1, 2, 3)
+module a
+    I = 1
+    function f(X)
+        ::I = I + 1
+        X + I
+    end
+end
+module b
+    I = 10
+    X = a::f(1)
+    Y = a::f(2)
+end
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2556,13 +2997,50 @@ X = 1(1, 2, 3)
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't function"), msg),
-                _ => assert!(false),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
-                (_, _) => assert!(false),
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.mod1(&String::from("a")) {
+                Some(a_mod) => {
+                    let a_mod_g = a_mod.read().unwrap();
+                    match a_mod_g.var(&String::from("f")) {
+                        Some(Value::Object(object)) => {
+                            match &**object {
+                                Object::Fun(idents, ident, _) => {
+                                    assert_eq!(vec![String::from("a")], *idents);
+                                    assert_eq!(String::from("f"), *ident);
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                    match a_mod_g.var(&String::from("I")) {
+                        Some(Value::Int(3)) => assert!(true),
+                        _ => assert!(false),
+                    }
+                },
+                None => assert!(false),
+            }
+            match root_mod_g.mod1(&String::from("b")) {
+                Some(b_mod) => {
+                    let b_mod_g = b_mod.read().unwrap();
+                    match b_mod_g.var(&String::from("I")) {
+                        Some(Value::Int(10)) => assert!(true),
+                        _ => assert!(false),
+                    }
+                    match b_mod_g.var(&String::from("X")) {
+                        Some(Value::Int(3)) => assert!(true),
+                        _ => assert!(false),
+                    }
+                    match b_mod_g.var(&String::from("Y")) {
+                        Some(Value::Int(5)) => assert!(true),
+                        _ => assert!(false),
+                    }
+                },
+                None => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2570,10 +3048,17 @@ X = 1(1, 2, 3)
 }
 
 #[test]
-fn test_interp_interpret_complains_on_value_is_not_function_for_string()
+fn test_interp_interpret_interprets_empty_statements_in_if_statement()
 {
     let s = "
-X = \"abc\"(1, 2, 3)
+function f(X)
+    if X
+        1
+    else
+    end
+end
+X = f(false)
+Y = f(true)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2586,13 +3071,18 @@ X = \"abc\"(1, 2, 3)
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't function"), msg),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::None) => assert!(true),
                 _ => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
-                (_, _) => assert!(false),
+            match root_mod_g.var(&String::from("Y")) {
+                Some(Value::Int(1)) => assert!(true),
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2600,10 +3090,14 @@ X = \"abc\"(1, 2, 3)
 }
 
 #[test]
-fn test_interp_interpret_complains_on_unsupported_type_for_negation_for_string()
+fn test_interp_interpret_interprets_matrix_with_floating_point_numbers()
 {
     let s = "
-X = -\"abc\"
+X = [
+    1.5, 2.5, 3.5
+    4.5, 5.5, 6.5
+    7.5, 8.5, 9.5
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2616,13 +3110,22 @@ X = -\"abc\"
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported type for negation"), msg),
-                _ => assert!(false),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
-                (_, _) => assert!(false),
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => {
+                    let a = vec![
+                        1.5, 2.5, 3.5,
+                        4.5, 5.5, 6.5,
+                        7.5, 8.5, 9.5
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(3, 3, TransposeFlag::NoTranspose, a.clone()));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2630,10 +3133,14 @@ X = -\"abc\"
 }
 
 #[test]
-fn test_interp_interpret_complains_on_unsupported_types_for_subtraction_for_strings()
+fn test_interp_interpret_interprets_matrix_with_filled_rows_and_floating_point_numbers()
 {
     let s = "
-X = \"abc\" - \"def\"
+X = [
+    1.5 fill 3
+    7.5, 8.5, 9.5
+    2.5 fill 3
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2646,13 +3153,22 @@ X = \"abc\" - \"def\"
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for subtraction"), msg),
-                _ => assert!(false),
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
             }
-            assert_eq!(1, interp.stack_trace().len());
-            match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
-                (_, _) => assert!(false),
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => {
+                    let a = vec![
+                        1.5, 1.5, 1.5,
+                        7.5, 8.5, 9.5,
+                        2.5, 2.5, 2.5
+                    ];
+                    let matrix_array = Arc::new(Object::MatrixArray(3, 3, TransposeFlag::NoTranspose, a.clone()));
+                    assert_eq!(Value::Object(matrix_array), value.to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
             }
         },
         Err(_) => assert!(false),
@@ -2660,10 +3176,10 @@ X = \"abc\" - \"def\"
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero()
+fn test_interp_interpret_complains_on_variable_is_not_set()
 {
     let s = "
-X = 1 / 0
+X = Y
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2676,7 +3192,7 @@ X = 1 / 0
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("variable Y isn't set"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
@@ -2690,11 +3206,14 @@ X = 1 / 0
 }
 
 #[test]
-fn test_interp_interpret_complains_on_structure_has_not_field()
+fn test_interp_interpret_complains_on_variable_is_not_set_for_try_statement_with_finally()
 {
     let s = "
-Y = { a: 1; b: 2.5; c: false; }
-X = Y.d
+try
+    X = Y
+finally
+    Z = 1
+end
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2707,24 +3226,32 @@ X = Y.d
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("structure hasn't field d"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("variable Y isn't set"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 9), *pos),
                 (_, _) => assert!(false),
             }
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("Z")) {
+                Some(Value::Int(1)) => assert!(true),
+                _ => assert!(false),
+            }
         },
         Err(_) => assert!(false),
     }
 }
 
 #[test]
-fn test_interp_interpret_complains_on_unsupported_types_for_range_creation()
+fn test_interp_interpret_complains_on_invalid_number_of_arguments()
 {
     let s = "
-X = true to false
+function f(X, Y)
+    X + Y
+end
+X = f(1, 2, 3)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2737,12 +3264,12 @@ X = true to false
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for range creation"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("invalid number of arguments"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2751,10 +3278,13 @@ X = true to false
 }
 
 #[test]
-fn test_interp_interpret_complains_on_unsupported_types_for_range_creation_for_second_case()
+fn test_interp_interpret_complains_on_invalid_number_of_arguments_for_anonymous_function()
 {
     let s = "
-X = 1 to 2.5 by false
+f = function(X, Y)
+    X + Y
+end
+X = f(1, 2, 3)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2767,12 +3297,12 @@ X = 1 to 2.5 by false
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for range creation"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("invalid number of arguments"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2781,13 +3311,12 @@ X = 1 to 2.5 by false
 }
 
 #[test]
-fn test_interp_interpret_complains_on_can_not_convert_value_to_floating_point_number()
+fn test_interp_interpret_complains_on_already_defined_argument_for_anonymous_function()
 {
     let s = "
-X = [
-    1, true, 2
-    3, 4, 5
-]
+f = function(X, X)
+    X
+end
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2800,12 +3329,12 @@ X = [
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to floating-point number"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined argument X"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 8), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 17), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2814,13 +3343,13 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_can_not_convert_value_to_floating_point_number_for_filled_matrix_row()
+fn test_interp_interpret_complains_on_no_argument()
 {
     let s = "
-X = [
-    true fill 3
-    3, 4, 5
-]
+function f(X, Y = 1)
+    X + Y
+end
+X = f(1, Z = 2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2833,12 +3362,12 @@ X = [
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to floating-point number"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("no argument Z"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2847,13 +3376,13 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_matrix_row()
+fn test_interp_interpret_complains_on_already_set_argument()
 {
     let s = "
-X = [
-    1 fill true
-    3, 4, 5
-]
+function f(X, Y = 1)
+    X + Y
+end
+X = f(1, X = 2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2866,12 +3395,12 @@ X = [
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already set argument X"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 12), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2880,13 +3409,13 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_matrix()
+fn test_interp_interpret_complains_on_invalid_number_of_arguments_for_named_arguments()
 {
     let s = "
-X = [
-    1, 2, 3
-    fill true
-]
+function f(X, Y, Z = 1)
+    X + Y + Z
+end
+X = f(1, Z = 2)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2899,12 +3428,12 @@ X = [
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("invalid number of arguments"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 10), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2913,13 +3442,10 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_numbers_of_columns_of_matrix_rows_are_not_equal()
+fn test_interp_interpret_complains_on_value_is_not_function_for_number()
 {
     let s = "
-X = [
-    1, 2, 3
-    4, 5, 6, 7
-]
+X = 1(1, 2, 3)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2932,7 +3458,7 @@ X = [
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("numbers of columns of matrix rows aren't equal"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't function"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
@@ -2946,15 +3472,10 @@ X = [
 }
 
 #[test]
-fn test_interp_interpret_complains_on_numbers_of_columns_of_matrix_rows_are_not_equal_for_filled_matrix()
+fn test_interp_interpret_complains_on_value_is_not_function_for_string()
 {
     let s = "
-X = 2
-function f()
-    ::X = ::X + 1
-    ::X
-end
-X = [1 fill f(); fill 4]
+X = \"abc\"(1, 2, 3)
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2967,12 +3488,12 @@ X = [1 fill f(); fill 4]
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("numbers of columns of matrix rows aren't equal"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't function"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -2981,10 +3502,10 @@ X = [1 fill f(); fill 4]
 }
 
 #[test]
-fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_array()
+fn test_interp_interpret_complains_on_unsupported_type_for_negation_for_string()
 {
     let s = "
-X = .[ 1 fill true .]
+X = -\"abc\"
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -2997,12 +3518,12 @@ X = .[ 1 fill true .]
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported type for negation"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 15), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3011,10 +3532,10 @@ X = .[ 1 fill true .]
 }
 
 #[test]
-fn test_interp_interpret_complains_on_already_defined_field()
+fn test_interp_interpret_complains_on_unsupported_types_for_subtraction_for_strings()
 {
     let s = "
-X = { a: 1; a: 2.5; c: false; }
+X = \"abc\" - \"def\"
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3027,12 +3548,12 @@ X = { a: 1; a: 2.5; c: false; }
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined field a"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for subtraction"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 13), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3041,10 +3562,10 @@ X = { a: 1; a: 2.5; c: false; }
 }
 
 #[test]
-fn test_interp_interpret_complains_on_expression_is_not_assignable()
+fn test_interp_interpret_complains_on_division_by_zero()
 {
     let s = "
-1 = 2
+X = 1 / 0
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3057,12 +3578,12 @@ fn test_interp_interpret_complains_on_expression_is_not_assignable()
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("expression isn't assignable"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3071,11 +3592,11 @@ fn test_interp_interpret_complains_on_expression_is_not_assignable()
 }
 
 #[test]
-fn test_interp_interpret_complains_on_index_out_of_bounds()
+fn test_interp_interpret_complains_on_structure_has_not_field()
 {
     let s = "
-X = .[ 1, 2.5, false .]
-X[4] = 2
+Y = { a: 1; b: 2.5; c: false; }
+X = Y.d
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3088,12 +3609,12 @@ X[4] = 2
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("index out of bounds"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("structure hasn't field d"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3102,11 +3623,10 @@ X[4] = 2
 }
 
 #[test]
-fn test_interp_interpret_complains_on_unsupprted_type_for_field()
+fn test_interp_interpret_complains_on_unsupported_types_for_range_creation()
 {
     let s = "
-X = 1
-X.a = 2
+X = true to false
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3119,12 +3639,12 @@ X.a = 2
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported type for field a"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for range creation"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3133,10 +3653,10 @@ X.a = 2
 }
 
 #[test]
-fn test_interp_interpret_complains_on_undefined_module_for_variable()
+fn test_interp_interpret_complains_on_unsupported_types_for_range_creation_for_second_case()
 {
     let s = "
-a::X = 2
+X = 1 to 2.5 by false
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3149,12 +3669,12 @@ a::X = 2
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("undefined module for variable a::X"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for range creation"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3163,13 +3683,13 @@ a::X = 2
 }
 
 #[test]
-fn test_interp_interpret_complains_on_value_is_not_iterable()
+fn test_interp_interpret_complains_on_can_not_convert_value_to_floating_point_number()
 {
     let s = "
-X = 1
-for I in true
-    X = X + 1    
-end
+X = [
+    1, true, 2
+    3, 4, 5
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3182,12 +3702,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't iterable"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to floating-point number"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 10), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 8), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3196,13 +3716,13 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_range_step_is_zero()
+fn test_interp_interpret_complains_on_can_not_convert_value_to_floating_point_number_for_filled_matrix_row()
 {
     let s = "
-X = 1
-for I in 1 to 3 by 0
-    X = X + 1    
-end
+X = [
+    true fill 3
+    3, 4, 5
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3215,12 +3735,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("range step is zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to floating-point number"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3229,10 +3749,13 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_break_is_not_in_loop()
+fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_matrix_row()
 {
     let s = "
-break
+X = [
+    1 fill true
+    3, 4, 5
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3245,12 +3768,12 @@ break
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("break isn't in loop"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 12), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3259,13 +3782,13 @@ break
 }
 
 #[test]
-fn test_interp_interpret_complains_on_break_is_not_in_loop_in_function()
+fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_matrix()
 {
     let s = "
-function f()
-    break
-end
-f()
+X = [
+    1, 2, 3
+    fill true
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3278,19 +3801,12 @@ f()
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("break isn't in loop"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
                 _ => assert!(false),
             }
-            assert_eq!(2, interp.stack_trace().len());
+            assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (Some(fun_value), pos) => {
-                    assert_eq!(String::from("f"), format!("{}", fun_value));
-                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
-                },
-                (_, _) => assert!(false),
-            }
-            match &interp.stack_trace()[1] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 10), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3299,10 +3815,13 @@ f()
 }
 
 #[test]
-fn test_interp_interpret_complains_on_continue_is_not_in_loop()
+fn test_interp_interpret_complains_on_numbers_of_columns_of_matrix_rows_are_not_equal()
 {
     let s = "
-continue
+X = [
+    1, 2, 3
+    4, 5, 6, 7
+]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3315,12 +3834,12 @@ continue
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("continue isn't in loop"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("numbers of columns of matrix rows aren't equal"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3329,13 +3848,15 @@ continue
 }
 
 #[test]
-fn test_interp_interpret_complains_on_continue_is_not_in_loop_in_function()
+fn test_interp_interpret_complains_on_numbers_of_columns_of_matrix_rows_are_not_equal_for_filled_matrix()
 {
     let s = "
+X = 2
 function f()
-    continue
+    ::X = ::X + 1
+    ::X
 end
-f()
+X = [1 fill f(); fill 4]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3348,19 +3869,12 @@ f()
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("continue isn't in loop"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("numbers of columns of matrix rows aren't equal"), msg),
                 _ => assert!(false),
             }
-            assert_eq!(2, interp.stack_trace().len());
+            assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (Some(fun_value), pos) => {
-                    assert_eq!(String::from("f"), format!("{}", fun_value));
-                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
-                },
-                (_, _) => assert!(false),
-            }
-            match &interp.stack_trace()[1] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3369,10 +3883,10 @@ f()
 }
 
 #[test]
-fn test_interp_interpret_complains_on_return_is_not_in_function()
+fn test_interp_interpret_complains_on_can_not_convert_value_to_integer_number_for_filled_array()
 {
     let s = "
-return
+X = .[ 1 fill true .]
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3385,12 +3899,12 @@ return
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("return isn't in function"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("can't convert value to integer number"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 15), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3399,15 +3913,10 @@ return
 }
 
 #[test]
-fn test_interp_interpret_complains_on_already_defined_module()
+fn test_interp_interpret_complains_on_already_defined_field()
 {
     let s = "
-module a
-    X = 1
-end
-module a
-    Y = 1
-end
+X = { a: 1; a: 2.5; c: false; }
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3420,12 +3929,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined module a"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined field a"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 13), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3434,13 +3943,10 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_already_variable_is_set()
+fn test_interp_interpret_complains_on_expression_is_not_assignable()
 {
     let s = "
-f = 1
-function f(X)
-    X + 1
-end
+1 = 2
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3453,12 +3959,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("already variable f is set"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("expression isn't assignable"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3467,12 +3973,11 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_already_defined_argument()
+fn test_interp_interpret_complains_on_index_out_of_bounds()
 {
     let s = "
-function f(X, Y, Y)
-    X + Y
-end
+X = .[ 1, 2.5, false .]
+X[4] = 2
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3485,12 +3990,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined argument Y"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("index out of bounds"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 18), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3499,16 +4004,11 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_in_nested_function()
+fn test_interp_interpret_complains_on_unsupprted_type_for_field()
 {
     let s = "
-function f(X)
-    X / 0
-end
-function g(X)
-    f(X)
-end
-g(1)
+X = 1
+X.a = 2
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3521,26 +4021,12 @@ g(1)
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported type for field a"), msg),
                 _ => assert!(false),
             }
-            assert_eq!(3, interp.stack_trace().len());
+            assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (Some(fun_value), pos) => {
-                    assert_eq!(String::from("f"), format!("{}", fun_value));
-                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
-                },
-                (_, _) => assert!(false),
-            }
-            match &interp.stack_trace()[1] {
-                (Some(fun_value), pos) => {
-                    assert_eq!(String::from("g"), format!("{}", fun_value));
-                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 5), *pos);
-                },
-                (_, _) => assert!(false),
-            }
-            match &interp.stack_trace()[2] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 7, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3549,15 +4035,10 @@ g(1)
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_after_for_statement_with_break()
+fn test_interp_interpret_complains_on_undefined_module_for_variable()
 {
     let s = "
-for I in 1 to 3
-    if true
-        break
-    end
-end
-1 / 0
+a::X = 2
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3570,12 +4051,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("undefined module for variable a::X"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3584,15 +4065,13 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_after_for_statement_with_continue()
+fn test_interp_interpret_complains_on_value_is_not_iterable()
 {
     let s = "
-for I in 1 to 3
-    if true
-        continue
-    end
+X = 1
+for I in true
+    X = X + 1    
 end
-1 / 0
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3605,12 +4084,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("value isn't iterable"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 10), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3619,17 +4098,13 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_after_while_statement_with_break()
+fn test_interp_interpret_complains_on_range_step_is_zero()
 {
     let s = "
-I = 1
-while I <= 3
-    if true
-        break
-    end
-    I = I + 1
-end
-1 / 0
+X = 1
+for I in 1 to 3 by 0
+    X = X + 1    
+end
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3642,12 +4117,12 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("range step is zero"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3656,17 +4131,43 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_after_while_statement_with_continue()
+fn test_interp_interpret_complains_on_break_is_not_in_loop()
 {
     let s = "
-I = 1
-while I <= 3
-    I = I + 1
-    if true
-        continue
-    end
+break
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("break isn't in loop"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_break_is_not_in_loop_in_function()
+{
+    let s = "
+function f()
+    break
 end
-1 / 0
+f()
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3679,12 +4180,49 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("break isn't in loop"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(2, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("f"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[1] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_continue_is_not_in_loop()
+{
+    let s = "
+continue
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("continue isn't in loop"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3693,14 +4231,13 @@ end
 }
 
 #[test]
-fn test_interp_interpret_complains_on_division_by_zero_after_application_with_return()
+fn test_interp_interpret_complains_on_continue_is_not_in_loop_in_function()
 {
     let s = "
 function f()
-    return
+    continue
 end
 f()
-1 / 0
 ";
     let s2 = &s[1..];
     let mut cursor = Cursor::new(s2.as_bytes());
@@ -3713,12 +4250,49 @@ f()
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("continue isn't in loop"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(2, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("f"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[1] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_return_is_not_in_function()
+{
+    let s = "
+return
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("return isn't in function"), msg),
                 _ => assert!(false),
             }
             assert_eq!(1, interp.stack_trace().len());
             match &interp.stack_trace()[0] {
-                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 1), *pos),
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos),
                 (_, _) => assert!(false),
             }
         },
@@ -3727,9 +4301,45 @@ f()
 }
 
 #[test]
-fn test_interp_apply_fun_applies_function()
+fn test_interp_interpret_complains_on_already_defined_module()
+{
+    let s = "
+module a
+    X = 1
+end
+module a
+    Y = 1
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined module a"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_already_variable_is_set()
 {
     let s = "
+f = 1
 function f(X)
     X + 1
 end
@@ -3745,40 +4355,668 @@ end
             let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
             let mut interp = Interp::new();
             match interp.interpret(&mut env, &tree) {
-                Ok(()) => assert!(true),
-                Err(_) => assert!(false),
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already variable f is set"), msg),
+                _ => assert!(false),
             }
-            assert_eq!(true, interp.stack_trace().is_empty());
-            let fun_value ={
-                let root_mod_g = env.root_mod().read().unwrap();
-                match root_mod_g.var(&String::from("f")) {
-                    Some(tmp_fun_value) => tmp_fun_value.clone(),
-                    None => {
-                        assert!(false);
-                        return;
-                    },
-                }
-            };
-            match interp.apply_fun(&mut env, &fun_value, &[Value::Int(2)]) {
-                Ok(Value::Int(3)) => assert_eq!(true, interp.stack_trace().is_empty()),
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_already_defined_argument()
+{
+    let s = "
+function f(X, Y, Y)
+    X + Y
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("already defined argument Y"), msg),
                 _ => assert!(false),
             }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 18), *pos),
+                (_, _) => assert!(false),
+            }
         },
         Err(_) => assert!(false),
     }
 }
 
 #[test]
-fn test_interp_apply_fun_applies_builtin_function()
+fn test_interp_interpret_complains_on_division_by_zero_in_nested_function()
 {
-    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
-    let mut interp = Interp::new();
-    let fun_value = Value::Object(Arc::new(Object::BuiltinFun(String::from("f"), f)));
-    match interp.apply_fun(&mut env, &fun_value, &[Value::Int(1), Value::Float(2.5), Value::Bool(false)]) {
-        Ok(value) => {
-            assert_eq!(true, interp.stack_trace().is_empty());
-            let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5), Value::Bool(false)]))));
-            assert_eq!(expected_value, value);
+    let s = "
+function f(X)
+    X / 0
+end
+function g(X)
+    f(X)
+end
+g(1)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(3, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("f"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[1] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("g"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[2] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 7, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_after_for_statement_with_break()
+{
+    let s = "
+for I in 1 to 3
+    if true
+        break
+    end
+end
+1 / 0
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_after_for_statement_with_continue()
+{
+    let s = "
+for I in 1 to 3
+    if true
+        continue
+    end
+end
+1 / 0
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_after_while_statement_with_break()
+{
+    let s = "
+I = 1
+while I <= 3
+    if true
+        break
+    end
+    I = I + 1
+end
+1 / 0
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_after_while_statement_with_continue()
+{
+    let s = "
+I = 1
+while I <= 3
+    I = I + 1
+    if true
+        continue
+    end
+end
+1 / 0
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_after_application_with_return()
+{
+    let s = "
+function f()
+    return
+end
+f()
+1 / 0
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(1, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 1), *pos),
+                (_, _) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_apply_fun_applies_function()
+{
+    let s = "
+function f(X)
+    X + 1
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new();
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let fun_value ={
+                let root_mod_g = env.root_mod().read().unwrap();
+                match root_mod_g.var(&String::from("f")) {
+                    Some(tmp_fun_value) => tmp_fun_value.clone(),
+                    None => {
+                        assert!(false);
+                        return;
+                    },
+                }
+            };
+            match interp.apply_fun(&mut env, &fun_value, &[Value::Int(2)]) {
+                Ok(Value::Int(3)) => assert_eq!(true, interp.stack_trace().is_empty()),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_apply_fun_applies_builtin_function()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut interp = Interp::new();
+    let fun_value = Value::Object(Arc::new(Object::BuiltinFun(String::from("f"), f)));
+    match interp.apply_fun(&mut env, &fun_value, &[Value::Int(1), Value::Float(2.5), Value::Bool(false)]) {
+        Ok(value) => {
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5), Value::Bool(false)]))));
+            assert_eq!(expected_value, value);
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_recursion_for_virtual_machine()
+{
+    let s = "
+function fib(X)
+    if X == 0
+        0
+    else if X == 1
+        1
+    else
+        fib(X - 1) + fib(X - 2)
+    end
+end
+X = fib(10)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Int(55)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_loops_with_try_statements_for_virtual_machine()
+{
+    let s = "
+function f(N)
+    S = 0
+    for I in 1 to N
+        try
+            if I == 2
+                continue
+            end
+            if I == 5
+                break
+            end
+            S = S + I
+        finally
+            root::C = root::C + 1
+        end
+    end
+    J = 0
+    while true
+        J = J + 1
+        if J > 3
+            break
+        end
+        S = S + 100
+    end
+    S
+end
+C = 0
+X = f(10)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Int(308)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("C")) {
+                Some(Value::Int(5)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_try_statement_with_catch_in_function_for_virtual_machine()
+{
+    let s = "
+function f(Y)
+    try
+        X = g(Y)
+    catch e
+        X = e
+    end
+    X
+end
+function g(Y)
+    Y + Z
+end
+X = f(1)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Object(object)) => {
+                    match &**object {
+                        Object::Error(kind, msg) => {
+                            assert_eq!(String::from("interp"), *kind);
+                            assert_eq!(String::from("variable Z isn't set"), *msg);
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_functions_with_default_arguments_for_virtual_machine()
+{
+    let s = "
+function f(X, Y = X + 1, Z = 10)
+    X + Y + Z
+end
+A = f(1)
+B = f(1, 5)
+C = f(1, Z = 2)
+D = f(Y = 3, X = 2)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("A")) {
+                Some(Value::Int(13)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("B")) {
+                Some(Value::Int(16)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("C")) {
+                Some(Value::Int(5)) => assert!(true),
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("D")) {
+                Some(Value::Int(15)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_anonymous_function_with_captured_local_variables_for_virtual_machine()
+{
+    let s = "
+function adder(X)
+    function(Y) X + Y end
+end
+f = adder(2)
+g = adder(10)
+X = f(3) + g(4)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(Value::Int(19)) => assert!(true),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_interprets_literals_for_virtual_machine()
+{
+    let s = "
+I = 0
+function f()
+    ::I = ::I + 1
+    ::I
+end
+X = .[ f() fill 3 .]
+Y = { a: 1; b: .[ 1, 2.5 .]; }
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+            assert_eq!(true, interp.stack_trace().is_empty());
+            let root_mod_g = env.root_mod().read().unwrap();
+            match root_mod_g.var(&String::from("X")) {
+                Some(value) => {
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]))));
+                    assert_eq!(expected_value, *value);
+                },
+                _ => assert!(false),
+            }
+            match root_mod_g.var(&String::from("Y")) {
+                Some(value) => {
+                    let mut expected_fields: BTreeMap<String, Value> = BTreeMap::new();
+                    expected_fields.insert(String::from("a"), Value::Int(1));
+                    expected_fields.insert(String::from("b"), Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1), Value::Float(2.5)])))));
+                    let expected_value = Value::Ref(Arc::new(RwLock::new(MutObject::Struct(expected_fields))));
+                    assert_eq!(expected_value, *value);
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_interp_interpret_complains_on_division_by_zero_in_nested_function_for_virtual_machine()
+{
+    let s = "
+function f(X)
+    X / 0
+end
+function g(X)
+    f(X)
+end
+g(1)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => {
+            let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+            let mut interp = Interp::new_with_vm_flag(true);
+            match interp.interpret(&mut env, &tree) {
+                Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+                _ => assert!(false),
+            }
+            assert_eq!(3, interp.stack_trace().len());
+            match &interp.stack_trace()[0] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("f"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[1] {
+                (Some(fun_value), pos) => {
+                    assert_eq!(String::from("g"), format!("{}", fun_value));
+                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 5), *pos);
+                },
+                (_, _) => assert!(false),
+            }
+            match &interp.stack_trace()[2] {
+                (None, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 7, 1), *pos),
+                (_, _) => assert!(false),
+            }
         },
         Err(_) => assert!(false),
     }
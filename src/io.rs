@@ -14,27 +14,43 @@ use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::ErrorKind;
 use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::io::Write;
 use std::mem::size_of;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::Weak;
+use bzip2::bufread::BzDecoder;
+use bzip2::write::BzEncoder;
+use flate2::bufread::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Crc;
+use liblzma::bufread::XzDecoder;
+use liblzma::write::XzEncoder;
+use crate::matrix::Matrix;
+use crate::regex::Regex;
 use crate::env::*;
 use crate::error::*;
 use crate::tree::*;
 use crate::utils::*;
 use crate::value::*;
 
-fn read_magic(r: &mut dyn Read) -> Result<()>
+fn read_magic(r: &mut dyn Read) -> Result<u8>
 {
     let mut buf = [0u8; 6];
     match r.read_exact(&mut buf) {
         Ok(()) => {
-            if &buf != b"unlab1" {
+            if &buf[0..5] != b"unlab" {
                 return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid data format")));
             }
-            Ok(())
+            match buf[5] {
+                b'1' => Ok(FORMAT_VERSION_1),
+                b'2' => Ok(FORMAT_VERSION_2),
+                b'3' => Ok(FORMAT_VERSION_3),
+                _ => Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "unsupported format version"))),
+            }
         },
         Err(err) => Err(Error::Io(err)),
     }
@@ -58,6 +74,15 @@ fn read_bool(r: &mut dyn Read) -> Result<bool>
     }
 }
 
+fn read_u32(r: &mut dyn Read) -> Result<u32>
+{
+    let mut buf = [0u8; 4];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(u32::from_le_bytes(buf)),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
 fn read_u64(r: &mut dyn Read) -> Result<u64>
 {
     let mut buf = [0u8; 8];
@@ -94,6 +119,24 @@ fn read_f32(r: &mut dyn Read) -> Result<f32>
     }
 }
 
+fn read_i32(r: &mut dyn Read) -> Result<i32>
+{
+    let mut buf = [0u8; 4];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(i32::from_le_bytes(buf)),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+fn read_f64(r: &mut dyn Read) -> Result<f64>
+{
+    let mut buf = [0u8; 8];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(f64::from_le_bytes(buf)),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
 fn read_string(r: &mut dyn Read) -> Result<String>
 {
     let len = read_usize(r)?;
@@ -109,9 +152,14 @@ fn read_string(r: &mut dyn Read) -> Result<String>
     }
 }
 
-fn write_magic(w: &mut dyn Write) -> Result<()>
+fn write_magic(w: &mut dyn Write, version: u8) -> Result<()>
 {
-    match w.write_all(b"unlab1") {
+    let magic: &[u8] = match version {
+        FORMAT_VERSION_1 => b"unlab1",
+        FORMAT_VERSION_2 => b"unlab2",
+        _ => b"unlab3",
+    };
+    match w.write_all(magic) {
         Ok(()) => Ok(()),
         Err(err) => Err(Error::Io(err)),
     }
@@ -129,6 +177,15 @@ fn write_u8(w: &mut dyn Write, n: u8) -> Result<()>
 fn write_bool(w: &mut dyn Write, b: bool) -> Result<()>
 { write_u8(w, if b { 1 } else { 0 }) }
 
+fn write_u32(w: &mut dyn Write, n: u32) -> Result<()>
+{
+    let buf = n.to_le_bytes();
+    match w.write_all(&buf) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
 fn write_u64(w: &mut dyn Write, n: u64) -> Result<()>
 {
     let buf = n.to_le_bytes();
@@ -159,6 +216,24 @@ fn write_f32(w: &mut dyn Write, n: f32) -> Result<()>
     }
 }
 
+fn write_i32(w: &mut dyn Write, n: i32) -> Result<()>
+{
+    let buf = n.to_le_bytes();
+    match w.write_all(&buf) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+fn write_f64(w: &mut dyn Write, n: f64) -> Result<()>
+{
+    let buf = n.to_le_bytes();
+    match w.write_all(&buf) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
 fn write_str(w: &mut dyn Write, s: &str) -> Result<()>
 {
     write_usize(w, s.as_bytes().len())?;
@@ -168,6 +243,18 @@ fn write_str(w: &mut dyn Write, s: &str) -> Result<()>
     }
 }
 
+const FORMAT_VERSION_1: u8 = 1;
+const FORMAT_VERSION_2: u8 = 2;
+const FORMAT_VERSION_3: u8 = 3;
+
+const INDEXED_HEADER_SIZE: u64 = 15;
+const INDEXED_FOOTER_SIZE: u64 = 12;
+
+const COMPRESSION_NONE: u8 = 0;
+const COMPRESSION_GZIP: u8 = 1;
+const COMPRESSION_BZIP2: u8 = 2;
+const COMPRESSION_XZ: u8 = 3;
+
 const VALUE_NONE: u8 = 0;
 const VALUE_BOOL: u8 = 1;
 const VALUE_INT: u8 = 2;
@@ -179,6 +266,7 @@ const VALUE_WEAK_NONE: u8 = 7;
 const VALUE_OBJECT_INDEX: u8 = 8;
 const VALUE_REF_INDEX: u8 = 9;
 const VALUE_WEAK_INDEX: u8 = 10;
+const VALUE_DOUBLE: u8 = 11;
 
 const OBJECT_STRING: u8 = 0;
 const OBJECT_INT_RANGE: u8 = 1;
@@ -189,13 +277,73 @@ const OBJECT_BUILTIN_FUN: u8 = 5;
 const OBJECT_MATRIX_ARRAY: u8 = 6;
 const OBJECT_MATRIX_ROW_SLICE: u8 = 7;
 const OBJECT_ERROR: u8 = 8;
+const OBJECT_TENSOR: u8 = 9;
+const OBJECT_HOST_MATRIX: u8 = 10;
+const OBJECT_DOUBLE_RANGE: u8 = 11;
+const OBJECT_REGEX: u8 = 12;
+
+const ELEM_TYPE_DOUBLE: u8 = 1;
+const ELEM_TYPE_INT: u8 = 2;
 
 const MUT_OBJECT_ARRAY: u8 = 0;
 const MUT_OBJECT_STRUCT: u8 = 1;
+const MUT_OBJECT_DICT: u8 = 2;
 
 const MATRIX_ARRAY_OBJECT: u8 = 0;
 const MATRIX_ARRAY_INDEX: u8 = 1;
 
+/// An enumeration of compression codecs of value files.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression
+{
+    /// No compression.
+    None,
+    /// The gzip compression.
+    Gzip,
+    /// The bzip2 compression.
+    Bzip2,
+    /// The xz compression.
+    Xz,
+}
+
+impl Compression
+{
+    /// Returns a compression codec for the name or `None` if the name is invalid.
+    ///
+    /// The names of compression codecs are `"none"`, `"gzip"`, `"bzip2"`, and `"xz"`.
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        match name {
+            "none" => Some(Compression::None),
+            "gzip" => Some(Compression::Gzip),
+            "bzip2" => Some(Compression::Bzip2),
+            "xz" => Some(Compression::Xz),
+            _ => None,
+        }
+    }
+}
+
+fn compression_to_u8(compression: Compression) -> u8
+{
+    match compression {
+        Compression::None => COMPRESSION_NONE,
+        Compression::Gzip => COMPRESSION_GZIP,
+        Compression::Bzip2 => COMPRESSION_BZIP2,
+        Compression::Xz => COMPRESSION_XZ,
+    }
+}
+
+fn compression_from_u8(n: u8) -> Result<Compression>
+{
+    match n {
+        COMPRESSION_NONE => Ok(Compression::None),
+        COMPRESSION_GZIP => Ok(Compression::Gzip),
+        COMPRESSION_BZIP2 => Ok(Compression::Bzip2),
+        COMPRESSION_XZ => Ok(Compression::Xz),
+        _ => Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "unsupported compression"))),
+    }
+}
+
 struct ObjectTab<T>
 {
     indices: HashMap<*const T, usize>,
@@ -228,6 +376,57 @@ impl<T> ObjectTab<T>
     }
 }
 
+struct ChecksumReader<R>
+{
+    r: R,
+    crc: Crc,
+    len: u64,
+}
+
+impl<R: Read> ChecksumReader<R>
+{
+    fn new(r: R) -> Self
+    { ChecksumReader { r, crc: Crc::new(), len: 0, } }
+}
+
+impl<R: Read> Read for ChecksumReader<R>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        let n = self.r.read(buf)?;
+        self.crc.update(&buf[..n]);
+        self.len += n as u64;
+        Ok(n)
+    }
+}
+
+struct ChecksumWriter<W>
+{
+    w: W,
+    crc: Crc,
+    len: u64,
+}
+
+impl<W: Write> ChecksumWriter<W>
+{
+    fn new(w: W) -> Self
+    { ChecksumWriter { w, crc: Crc::new(), len: 0, } }
+}
+
+impl<W: Write> Write for ChecksumWriter<W>
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        let n = self.w.write(buf)?;
+        self.crc.update(&buf[..n]);
+        self.len += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()>
+    { self.w.flush() }
+}
+
 fn checked_mul_row_count_and_col_count(row_count: usize, col_count: usize) -> Result<usize>
 {
     if row_count > (isize::MAX as usize) {
@@ -250,6 +449,23 @@ fn checked_mul_row_count_and_col_count(row_count: usize, col_count: usize) -> Re
     }
 }
 
+fn read_matrix(r: &mut dyn Read) -> Result<Matrix>
+{
+    let row_count = read_usize(r)?;
+    let col_count = read_usize(r)?;
+    let is_transposed = read_bool(r)?;
+    let len = checked_mul_row_count_and_col_count(row_count, col_count)?;
+    let mut xs = vec![0.0f32; len];
+    for i in 0..len {
+        xs[i] = read_f32(r)?;
+    }
+    if !is_transposed {
+        matrix_create_and_set_elems(row_count, col_count, xs.as_slice())
+    } else {
+        Ok(matrix_create_and_set_elems(col_count, row_count, xs.as_slice())?.transpose())
+    }
+}
+
 fn read_object(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Object>) -> Result<Arc<Object>>
 {
     let object = match read_u8(r)? {
@@ -264,23 +480,15 @@ fn read_object(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Object>)
             let from = read_f32(r)?;
             let to = read_f32(r)?;
             let step = read_f32(r)?;
-            Arc::new(Object::FloatRange(from, to, step))
+            Arc::new(Object::FloatRange(from as f64, to as f64, step as f64))
         },
-        OBJECT_MATRIX => {
-            let row_count = read_usize(r)?;
-            let col_count = read_usize(r)?;
-            let is_transposed = read_bool(r)?;
-            let len = checked_mul_row_count_and_col_count(row_count, col_count)?;
-            let mut xs = vec![0.0f32; len];
-            for i in 0..len {
-                xs[i] = read_f32(r)?;
-            }
-            if !is_transposed {
-                Arc::new(Object::Matrix(matrix_create_and_set_elems(row_count, col_count, xs.as_slice())?))
-            } else {
-                Arc::new(Object::Matrix(matrix_create_and_set_elems(col_count, row_count, xs.as_slice())?.transpose()))
-            }
+        OBJECT_DOUBLE_RANGE => {
+            let from = read_f64(r)?;
+            let to = read_f64(r)?;
+            let step = read_f64(r)?;
+            Arc::new(Object::FloatRange(from, to, step))
         },
+        OBJECT_MATRIX => Arc::new(Object::Matrix(read_matrix(r)?)),
         OBJECT_FUN => {
             let ident_count = read_usize(r)?;
             let mut idents: Vec<String> = Vec::new();
@@ -338,6 +546,53 @@ fn read_object(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Object>)
             let msg = read_string(r)?;
             Arc::new(Object::Error(kind, msg))
         },
+        OBJECT_REGEX => {
+            let pattern = read_string(r)?;
+            match Regex::new(pattern.as_str()) {
+                Ok(regex) => Arc::new(Object::Regex(regex)),
+                Err(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid regular expression"))),
+            }
+        },
+        OBJECT_TENSOR => {
+            let dim_count = read_usize(r)?;
+            if dim_count == 0 {
+                return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid tensor shape")));
+            }
+            let mut shape: Vec<usize> = Vec::new();
+            for _ in 0..dim_count {
+                shape.push(read_usize(r)?);
+            }
+            let a = read_matrix(r)?;
+            let col_count = shape[1..].iter().fold(Some(1usize), |n, m| n.and_then(|n| n.checked_mul(*m)));
+            if a.row_count() != shape[0] || col_count != Some(a.col_count()) {
+                return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid tensor shape")));
+            }
+            Arc::new(Object::Tensor(shape, a))
+        },
+        OBJECT_HOST_MATRIX => {
+            let row_count = read_usize(r)?;
+            let col_count = read_usize(r)?;
+            let elem_type = read_u8(r)?;
+            let len = checked_mul_row_count_and_col_count(row_count, col_count)?;
+            let elems = match elem_type {
+                ELEM_TYPE_DOUBLE => {
+                    let mut xs: Vec<f64> = Vec::new();
+                    for _ in 0..len {
+                        xs.push(read_f64(r)?);
+                    }
+                    HostElems::Double(xs)
+                },
+                ELEM_TYPE_INT => {
+                    let mut xs: Vec<i32> = Vec::new();
+                    for _ in 0..len {
+                        xs.push(read_i32(r)?);
+                    }
+                    HostElems::Int(xs)
+                },
+                _ => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid element type"))),
+            };
+            Arc::new(Object::HostMatrix(row_count, col_count, elems))
+        },
         _ => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid object type"))),
     };
     if !object_tab.add_object(object.clone()) {
@@ -351,6 +606,7 @@ fn read_mut_object(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Objec
     let object = match read_u8(r)? {
         MUT_OBJECT_ARRAY => Arc::new(RwLock::new(MutObject::Array(Vec::new()))),
         MUT_OBJECT_STRUCT => Arc::new(RwLock::new(MutObject::Struct(BTreeMap::new()))),
+        MUT_OBJECT_DICT => Arc::new(RwLock::new(MutObject::Dict(BTreeMap::new()))),
         _ => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid mutable object type"))),
     };
     if !mut_object_tab.add_object(object.clone()) {
@@ -372,6 +628,16 @@ fn read_mut_object(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Objec
                     fields.insert(ident, field);
                 }
             },
+            MutObject::Dict(pairs) => {
+                for _ in 0..len {
+                    let key = match DictKey::from_value(&read_value(r, env, object_tab, mut_object_tab)?) {
+                        Ok(tmp_key) => tmp_key,
+                        Err(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid dictionary key"))),
+                    };
+                    let value = read_value(r, env, object_tab, mut_object_tab)?;
+                    pairs.insert(key, value);
+                }
+            },
         }
     }
     Ok(object)
@@ -383,7 +649,8 @@ fn read_value(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Object>, m
         VALUE_NONE => Ok(Value::None),
         VALUE_BOOL => Ok(Value::Bool(read_bool(r)?)),
         VALUE_INT => Ok(Value::Int(read_i64(r)?)),
-        VALUE_FLOAT => Ok(Value::Float(read_f32(r)?)),
+        VALUE_FLOAT => Ok(Value::Float(read_f32(r)? as f64)),
+        VALUE_DOUBLE => Ok(Value::Float(read_f64(r)?)),
         VALUE_OBJECT => Ok(Value::Object(read_object(r, env, object_tab)?)),
         VALUE_REF => Ok(Value::Ref(read_mut_object(r, env, object_tab, mut_object_tab)?)),
         VALUE_WEAK => Ok(Value::Weak(Arc::downgrade(&read_mut_object(r, env, object_tab, mut_object_tab)?))),
@@ -410,12 +677,10 @@ fn read_value(r: &mut dyn Read, env: &Env, object_tab: &mut ObjectTab<Object>, m
     }
 }
 
-/// Reads values from the reader.
-pub fn read_values(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
+fn read_body(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
 { 
     let mut object_tab: ObjectTab<Object> = ObjectTab::new();
     let mut mut_object_tab: ObjectTab<RwLock<MutObject>> = ObjectTab::new();
-    read_magic(r)?;
     let count = read_usize(r)?;
     let mut values: Vec<Value> = Vec::new();
     for _ in 0..count {
@@ -424,6 +689,151 @@ pub fn read_values(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
     Ok(values)
 }
 
+fn truncated_data_error(err: Error) -> Error
+{
+    match err {
+        Error::Io(io_err) if io_err.kind() == ErrorKind::UnexpectedEof => Error::Io(io::Error::new(ErrorKind::InvalidData, "truncated data")),
+        _ => err,
+    }
+}
+
+fn read_checked_body<R: Read>(r: R, env: &Env, is_compressed: bool) -> Result<(Vec<Value>, u32, u64)>
+{
+    let mut checksum_r = ChecksumReader::new(r);
+    let values = read_body(&mut checksum_r, env)?;
+    if is_compressed {
+        let mut buf = [0u8; 1];
+        match checksum_r.read(&mut buf) {
+            Ok(0) => (),
+            Ok(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "unexpected data after values"))),
+            Err(err) => return Err(Error::Io(err)),
+        }
+    }
+    Ok((values, checksum_r.crc.sum(), checksum_r.len))
+}
+
+fn check_trailer(r: &mut dyn Read, sum: u32, len: u64) -> Result<()>
+{
+    let expected_sum = read_u32(r).map_err(truncated_data_error)?;
+    let expected_len = read_u64(r).map_err(truncated_data_error)?;
+    if len != expected_len {
+        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid data length")));
+    }
+    if sum != expected_sum {
+        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "checksum mismatch")));
+    }
+    Ok(())
+}
+
+fn read_body_with_checksum(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
+{
+    let compression = read_u8(r)?;
+    match compression {
+        COMPRESSION_NONE => {
+            let (values, sum, len) = read_checked_body(&mut *r, env, false).map_err(truncated_data_error)?;
+            check_trailer(r, sum, len)?;
+            Ok(values)
+        },
+        COMPRESSION_GZIP | COMPRESSION_BZIP2 | COMPRESSION_XZ => {
+            let mut br = BufReader::new(r);
+            let (values, sum, len) = match compression {
+                COMPRESSION_GZIP => read_checked_body(GzDecoder::new(&mut br), env, true),
+                COMPRESSION_BZIP2 => read_checked_body(BzDecoder::new(&mut br), env, true),
+                _ => read_checked_body(XzDecoder::new(&mut br), env, true),
+            }.map_err(truncated_data_error)?;
+            check_trailer(&mut br, sum, len)?;
+            Ok(values)
+        },
+        _ => Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "unsupported compression"))),
+    }
+}
+
+fn decompress(buf: &[u8], compression: Compression) -> Result<Vec<u8>>
+{
+    let mut r: Box<dyn Read + '_> = match compression {
+        Compression::None => return Ok(buf.to_vec()),
+        Compression::Gzip => Box::new(GzDecoder::new(buf)),
+        Compression::Bzip2 => Box::new(BzDecoder::new(buf)),
+        Compression::Xz => Box::new(XzDecoder::new(buf)),
+    };
+    let mut data: Vec<u8> = Vec::new();
+    match r.read_to_end(&mut data) {
+        Ok(_) => Ok(data),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+fn read_block(r: &mut dyn Read, env: &Env, compression: Compression) -> Result<Value>
+{
+    let stored_len = read_u64(r)?;
+    let len = read_u64(r)?;
+    let expected_sum = read_u32(r)?;
+    let mut stored: Vec<u8> = Vec::new();
+    match r.take(stored_len).read_to_end(&mut stored) {
+        Ok(n) => {
+            if (n as u64) != stored_len {
+                return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "truncated data")));
+            }
+        },
+        Err(err) => return Err(Error::Io(err)),
+    }
+    let data = decompress(stored.as_slice(), compression)?;
+    if (data.len() as u64) != len {
+        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid data length")));
+    }
+    let mut crc = Crc::new();
+    crc.update(data.as_slice());
+    if crc.sum() != expected_sum {
+        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "checksum mismatch")));
+    }
+    let mut object_tab: ObjectTab<Object> = ObjectTab::new();
+    let mut mut_object_tab: ObjectTab<RwLock<MutObject>> = ObjectTab::new();
+    let mut data_r = data.as_slice();
+    let value = read_value(&mut data_r, env, &mut object_tab, &mut mut_object_tab)?;
+    if !data_r.is_empty() {
+        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "unexpected data after value")));
+    }
+    Ok(value)
+}
+
+fn read_indexed_body(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
+{
+    let compression = compression_from_u8(read_u8(r)?)?;
+    let count = read_usize(r)?;
+    let mut values: Vec<Value> = Vec::new();
+    for _ in 0..count {
+        values.push(read_block(r, env, compression).map_err(truncated_data_error)?);
+    }
+    Ok(values)
+}
+
+/// Reads values from the reader.
+///
+/// This function reads values in the `unlab1` format, the `unlab2` format, and the `unlab3`
+/// format. Values in the `unlab2` format and the `unlab3` format can be compressed and are
+/// verified by checksums. Compressed values in the `unlab2` format are read through a buffer, so
+/// the reader can be read beyond these values and should be treated as consumed.
+pub fn read_values(r: &mut dyn Read, env: &Env) -> Result<Vec<Value>>
+{
+    match read_magic(r)? {
+        FORMAT_VERSION_1 => read_body(r, env),
+        FORMAT_VERSION_2 => read_body_with_checksum(r, env),
+        _ => read_indexed_body(r, env),
+    }
+}
+
+fn write_matrix(w: &mut dyn Write, a: &Matrix) -> Result<()>
+{
+    let xs = matrix_elems_and_transpose_flag(a)?.0;
+    write_usize(w, a.row_count())?;
+    write_usize(w, a.col_count())?;
+    write_bool(w, a.is_transposed())?;
+    for x in &xs {
+        write_f32(w, *x)?;
+    }
+    Ok(())
+}
+
 fn write_object(w: &mut dyn Write, object: &Arc<Object>, object_tab: &mut ObjectTab<Object>) -> Result<()>
 {
     match &**object {
@@ -438,19 +848,40 @@ fn write_object(w: &mut dyn Write, object: &Arc<Object>, object_tab: &mut Object
             write_i64(w, *step)?;
         },
         Object::FloatRange(from, to, step) => {
-            write_u8(w, OBJECT_FLOAT_RANGE)?;
-            write_f32(w, *from)?;
-            write_f32(w, *to)?;
-            write_f32(w, *step)?;
+            write_u8(w, OBJECT_DOUBLE_RANGE)?;
+            write_f64(w, *from)?;
+            write_f64(w, *to)?;
+            write_f64(w, *step)?;
         },
         Object::Matrix(a) => {
-            let xs = matrix_elems_and_transpose_flag(a)?.0;
             write_u8(w, OBJECT_MATRIX)?;
-            write_usize(w, a.row_count())?;
-            write_usize(w, a.col_count())?;
-            write_bool(w, a.is_transposed())?;
-            for x in &xs {
-                write_f32(w, *x)?;
+            write_matrix(w, a)?;
+        },
+        Object::Tensor(shape, a) => {
+            write_u8(w, OBJECT_TENSOR)?;
+            write_usize(w, shape.len())?;
+            for n in shape {
+                write_usize(w, *n)?;
+            }
+            write_matrix(w, a)?;
+        },
+        Object::HostMatrix(row_count, col_count, elems) => {
+            write_u8(w, OBJECT_HOST_MATRIX)?;
+            write_usize(w, *row_count)?;
+            write_usize(w, *col_count)?;
+            match elems {
+                HostElems::Double(xs) => {
+                    write_u8(w, ELEM_TYPE_DOUBLE)?;
+                    for x in xs {
+                        write_f64(w, *x)?;
+                    }
+                },
+                HostElems::Int(xs) => {
+                    write_u8(w, ELEM_TYPE_INT)?;
+                    for x in xs {
+                        write_i32(w, *x)?;
+                    }
+                },
             }
         },
         Object::Fun(idents, ident, _) => {
@@ -493,7 +924,15 @@ fn write_object(w: &mut dyn Write, object: &Arc<Object>, object_tab: &mut Object
             write_str(w, kind.as_str())?;
             write_str(w, msg.as_str())?;
         },
+        Object::Regex(regex) => {
+            write_u8(w, OBJECT_REGEX)?;
+            write_str(w, regex.as_str())?;
+        },
+        Object::Lambda(_, _, _) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "can't write anonymous function"))),
+        Object::ValueReader(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "can't write value reader"))),
+        Object::ValueWriter(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "can't write value writer"))),
         Object::WindowId(_) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "can't write window identifier"))),
+        Object::Tracked(_, _) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "can't write tracked value"))),
     }
     if !object_tab.add_object(object.clone()) {
         return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "too large index")));
@@ -523,6 +962,14 @@ fn write_mut_object(w: &mut dyn Write, object: &Arc<RwLock<MutObject>>, object_t
                 write_value(w, field, object_tab, mut_object_tab)?;
             }
         },
+        MutObject::Dict(pairs) => {
+            write_u8(w, MUT_OBJECT_DICT)?;
+            write_usize(w, pairs.len())?;
+            for (key, value) in pairs {
+                write_value(w, &key.to_value(), object_tab, mut_object_tab)?;
+                write_value(w, value, object_tab, mut_object_tab)?;
+            }
+        },
     }
     Ok(())
 }
@@ -540,8 +987,8 @@ fn write_value(w: &mut dyn Write, value: &Value, object_tab: &mut ObjectTab<Obje
             write_i64(w, *n)?;
         },
         Value::Float(n) => {
-            write_u8(w, VALUE_FLOAT)?;
-            write_f32(w, *n)?;
+            write_u8(w, VALUE_DOUBLE)?;
+            write_f64(w, *n)?;
         },
         Value::Object(object) => {
             match object_tab.index(object) {
@@ -588,12 +1035,10 @@ fn write_value(w: &mut dyn Write, value: &Value, object_tab: &mut ObjectTab<Obje
     Ok(())
 }
 
-/// Writes the values to the writer.
-pub fn write_values(w: &mut dyn Write, values: &[Value]) -> Result<()>
+fn write_body(w: &mut dyn Write, values: &[Value]) -> Result<()>
 { 
     let mut object_tab: ObjectTab<Object> = ObjectTab::new();
     let mut mut_object_tab: ObjectTab<RwLock<MutObject>> = ObjectTab::new();
-    write_magic(w)?;
     write_usize(w, values.len())?;
     for value in values {
         write_value(w, value, &mut object_tab, &mut mut_object_tab)?;
@@ -601,6 +1046,135 @@ pub fn write_values(w: &mut dyn Write, values: &[Value]) -> Result<()>
     Ok(())
 }
 
+fn write_checked_body<W: Write>(w: W, values: &[Value]) -> Result<(W, u32, u64)>
+{
+    let mut checksum_w = ChecksumWriter::new(w);
+    write_body(&mut checksum_w, values)?;
+    Ok((checksum_w.w, checksum_w.crc.sum(), checksum_w.len))
+}
+
+/// Writes the values to the writer in the `unlab2` format with the compression.
+///
+/// The values are followed by a CRC-32 checksum and a length of uncompressed values.
+pub fn write_values_with_compression(w: &mut dyn Write, values: &[Value], compression: Compression) -> Result<()>
+{
+    write_magic(w, FORMAT_VERSION_2)?;
+    let (sum, len) = match compression {
+        Compression::None => {
+            write_u8(w, COMPRESSION_NONE)?;
+            let (_, sum, len) = write_checked_body(&mut *w, values)?;
+            (sum, len)
+        },
+        Compression::Gzip => {
+            write_u8(w, COMPRESSION_GZIP)?;
+            let (encoder, sum, len) = write_checked_body(GzEncoder::new(&mut *w, flate2::Compression::default()), values)?;
+            match encoder.finish() {
+                Ok(_) => (),
+                Err(err) => return Err(Error::Io(err)),
+            }
+            (sum, len)
+        },
+        Compression::Bzip2 => {
+            write_u8(w, COMPRESSION_BZIP2)?;
+            let (encoder, sum, len) = write_checked_body(BzEncoder::new(&mut *w, bzip2::Compression::default()), values)?;
+            match encoder.finish() {
+                Ok(_) => (),
+                Err(err) => return Err(Error::Io(err)),
+            }
+            (sum, len)
+        },
+        Compression::Xz => {
+            write_u8(w, COMPRESSION_XZ)?;
+            let (encoder, sum, len) = write_checked_body(XzEncoder::new(&mut *w, 6), values)?;
+            match encoder.finish() {
+                Ok(_) => (),
+                Err(err) => return Err(Error::Io(err)),
+            }
+            (sum, len)
+        },
+    };
+    write_u32(w, sum)?;
+    write_u64(w, len)
+}
+
+fn compress(data: &[u8], compression: Compression) -> Result<Vec<u8>>
+{
+    let res = match compression {
+        Compression::None => return Ok(data.to_vec()),
+        Compression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        },
+        Compression::Bzip2 => {
+            let mut encoder = BzEncoder::new(Vec::new(), bzip2::Compression::default());
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        },
+        Compression::Xz => {
+            let mut encoder = XzEncoder::new(Vec::new(), 6);
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        },
+    };
+    match res {
+        Ok(stored) => Ok(stored),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+fn write_block(w: &mut dyn Write, value: &Value, compression: Compression) -> Result<u64>
+{
+    let mut object_tab: ObjectTab<Object> = ObjectTab::new();
+    let mut mut_object_tab: ObjectTab<RwLock<MutObject>> = ObjectTab::new();
+    let mut data: Vec<u8> = Vec::new();
+    write_value(&mut data, value, &mut object_tab, &mut mut_object_tab)?;
+    let mut crc = Crc::new();
+    crc.update(data.as_slice());
+    let stored = compress(data.as_slice(), compression)?;
+    write_u64(w, stored.len() as u64)?;
+    write_u64(w, data.len() as u64)?;
+    write_u32(w, crc.sum())?;
+    match w.write_all(stored.as_slice()) {
+        Ok(()) => Ok(20 + (stored.len() as u64)),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+fn write_index(w: &mut dyn Write, offsets: &[u64], index_offset: u64) -> Result<()>
+{
+    let mut crc = Crc::new();
+    for block_offset in offsets {
+        crc.update(&block_offset.to_le_bytes());
+        write_u64(w, *block_offset)?;
+    }
+    write_u64(w, index_offset)?;
+    write_u32(w, crc.sum())
+}
+
+/// Writes the values to the writer in the `unlab3` format with the compression.
+///
+/// Each value is written as a separate block that is compressed and is verified by a CRC-32
+/// checksum. The blocks are followed by an index of block offsets, so the values can be read
+/// lazily by [`ValueReader`]. Objects that are shared by different values aren't shared after
+/// reading.
+pub fn write_indexed_values(w: &mut dyn Write, values: &[Value], compression: Compression) -> Result<()>
+{
+    write_magic(w, FORMAT_VERSION_3)?;
+    write_u8(w, compression_to_u8(compression))?;
+    write_usize(w, values.len())?;
+    let mut offset = INDEXED_HEADER_SIZE;
+    let mut offsets: Vec<u64> = Vec::with_capacity(values.len());
+    for value in values {
+        offsets.push(offset);
+        offset += write_block(w, value, compression)?;
+    }
+    write_index(w, offsets.as_slice(), offset)
+}
+
+/// Writes the values to the writer.
+///
+/// The values are written in the `unlab2` format without compression.
+pub fn write_values(w: &mut dyn Write, values: &[Value]) -> Result<()>
+{ write_values_with_compression(w, values, Compression::None) }
+
 /// Loads values from the file.
 pub fn load_values<P: AsRef<Path>>(path: P, env: &Env) -> Result<Vec<Value>>
 {
@@ -615,11 +1189,209 @@ pub fn load_values<P: AsRef<Path>>(path: P, env: &Env) -> Result<Vec<Value>>
 
 /// Saves the values to a file.
 pub fn save_values<P: AsRef<Path>>(path: P, values: &[Value]) -> Result<()>
+{ save_values_with_compression(path, values, Compression::None) }
+
+/// Saves the values to a file in the `unlab3` format with the compression.
+///
+/// The values are written as for [`write_indexed_values`].
+pub fn save_indexed_values<P: AsRef<Path>>(path: P, values: &[Value], compression: Compression) -> Result<()>
+{
+    match File::create(path) {
+        Ok(file) => {
+            let mut w = BufWriter::new(file);
+            write_indexed_values(&mut w, values, compression)?;
+            match w.flush() {
+                Ok(()) => Ok(()),
+                Err(err) => Err(Error::Io(err)),
+            }
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// A structure of reader of values from a file.
+///
+/// The reader reads the values lazily by block offsets from a file in the `unlab3` format. Files
+/// in the other formats aren't supported because they don't have indices of values.
+#[derive(Debug)]
+pub struct ValueReader
+{
+    r: BufReader<File>,
+    compression: Compression,
+    offsets: Vec<u64>,
+}
+
+fn seek(r: &mut dyn Seek, pos: SeekFrom) -> Result<u64>
+{
+    match r.seek(pos) {
+        Ok(offset) => Ok(offset),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+impl ValueReader
+{
+    /// Opens a file with values in the `unlab3` format.
+    ///
+    /// This method returns an error if the file is in the other format.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self>
+    {
+        let mut r = match File::open(path) {
+            Ok(file) => BufReader::new(file),
+            Err(err) => return Err(Error::Io(err)),
+        };
+        match read_magic(&mut r)? {
+            FORMAT_VERSION_3 => {
+                let compression = compression_from_u8(read_u8(&mut r)?)?;
+                let count = read_u64(&mut r)?;
+                let file_len = seek(&mut r, SeekFrom::End(0))?;
+                if file_len < INDEXED_HEADER_SIZE + INDEXED_FOOTER_SIZE {
+                    return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "truncated data")));
+                }
+                seek(&mut r, SeekFrom::Start(file_len - INDEXED_FOOTER_SIZE))?;
+                let index_offset = read_u64(&mut r)?;
+                let expected_sum = read_u32(&mut r)?;
+                match count.checked_mul(8).and_then(|n| n.checked_add(index_offset)).and_then(|n| n.checked_add(INDEXED_FOOTER_SIZE)) {
+                    Some(n) if n == file_len => (),
+                    _ => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid index"))),
+                }
+                seek(&mut r, SeekFrom::Start(index_offset))?;
+                let mut crc = Crc::new();
+                let mut offsets: Vec<u64> = Vec::new();
+                for _ in 0..count {
+                    let offset = read_u64(&mut r)?;
+                    if offset < INDEXED_HEADER_SIZE || offset >= index_offset {
+                        return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "invalid index")));
+                    }
+                    crc.update(&offset.to_le_bytes());
+                    offsets.push(offset);
+                }
+                if crc.sum() != expected_sum {
+                    return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "checksum mismatch")));
+                }
+                Ok(ValueReader { r, compression, offsets, })
+            },
+            _ => Err(Error::Io(io::Error::new(ErrorKind::InvalidData, "values aren't indexed"))),
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize
+    { self.offsets.len() }
+
+    /// Returns `true` if the file doesn't have values, otherwise `false`.
+    pub fn is_empty(&self) -> bool
+    { self.len() == 0 }
+
+    /// Reads the value with the index or returns `None` if the index is out of bounds.
+    pub fn read_value(&mut self, i: usize, env: &Env) -> Result<Option<Value>>
+    {
+        match self.offsets.get(i) {
+            Some(offset) => {
+                seek(&mut self.r, SeekFrom::Start(*offset))?;
+                Ok(Some(read_block(&mut self.r, env, self.compression).map_err(truncated_data_error)?))
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+/// A structure of writer of values to a file.
+///
+/// The writer appends the values to a file in the `unlab3` format one by one, so all values don't
+/// have to be in the memory. The index of block offsets is written when the writer is finished or
+/// is dropped. If an error occurs while writing, the writer is closed without the index and the
+/// file can't be read.
+#[derive(Debug)]
+pub struct ValueWriter
+{
+    w: Option<BufWriter<File>>,
+    compression: Compression,
+    offsets: Vec<u64>,
+    offset: u64,
+}
+
+impl ValueWriter
+{
+    /// Creates a file for values in the `unlab3` format with the compression.
+    pub fn create<P: AsRef<Path>>(path: P, compression: Compression) -> Result<Self>
+    {
+        let mut w = match File::create(path) {
+            Ok(file) => BufWriter::new(file),
+            Err(err) => return Err(Error::Io(err)),
+        };
+        write_magic(&mut w, FORMAT_VERSION_3)?;
+        write_u8(&mut w, compression_to_u8(compression))?;
+        write_u64(&mut w, 0)?;
+        Ok(ValueWriter { w: Some(w), compression, offsets: Vec::new(), offset: INDEXED_HEADER_SIZE, })
+    }
+
+    /// Returns the number of written values.
+    pub fn len(&self) -> usize
+    { self.offsets.len() }
+
+    /// Returns `true` if values aren't written, otherwise `false`.
+    pub fn is_empty(&self) -> bool
+    { self.len() == 0 }
+
+    /// Appends the value to the file.
+    pub fn write_value(&mut self, value: &Value) -> Result<()>
+    {
+        match &mut self.w {
+            Some(w) => {
+                match write_block(w, value, self.compression) {
+                    Ok(n) => {
+                        self.offsets.push(self.offset);
+                        self.offset += n;
+                        Ok(())
+                    },
+                    Err(err) => {
+                        self.w = None;
+                        Err(err)
+                    },
+                }
+            },
+            None => Err(Error::Io(io::Error::new(ErrorKind::Other, "value writer is closed"))),
+        }
+    }
+
+    /// Writes the index of block offsets and closes the file.
+    ///
+    /// This method does nothing if the writer is already closed.
+    pub fn finish(&mut self) -> Result<()>
+    {
+        match self.w.take() {
+            Some(mut w) => {
+                write_index(&mut w, self.offsets.as_slice(), self.offset)?;
+                seek(&mut w, SeekFrom::Start(INDEXED_HEADER_SIZE - 8))?;
+                write_u64(&mut w, self.offsets.len() as u64)?;
+                match w.flush() {
+                    Ok(()) => Ok(()),
+                    Err(err) => Err(Error::Io(err)),
+                }
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for ValueWriter
+{
+    fn drop(&mut self)
+    { let _res = self.finish(); }
+}
+
+/// Saves the values to a file with the compression.
+pub fn save_values_with_compression<P: AsRef<Path>>(path: P, values: &[Value], compression: Compression) -> Result<()>
 {
     match File::create(path) {
         Ok(file) => {
             let mut w = BufWriter::new(file);
-            write_values(&mut w, values)
+            write_values_with_compression(&mut w, values, compression)?;
+            match w.flush() {
+                Ok(()) => Ok(()),
+                Err(err) => Err(Error::Io(err)),
+            }
         },
         Err(err) => Err(Error::Io(err)),
     }
@@ -6,6 +6,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
 use std::io::Cursor;
+use std::fs;
+use sealed_test::prelude::*;
 use crate::matrix::matrix;
 use crate::interp::*;
 use crate::mod_node::*;
@@ -107,6 +109,143 @@ fn test_write_values_and_read_values_writes_values_and_reads_values()
     }
 }
 
+#[test]
+fn test_write_values_and_read_values_writes_floats_and_reads_floats_with_double_precision()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let values = vec![
+        Value::Float(0.1),
+        Value::Float(16777217.125),
+        Value::Object(Arc::new(Object::FloatRange(0.1, 16777217.125, 0.3)))
+    ];
+    match write_values(&mut cursor, values.as_slice()) {
+        Ok(()) => {
+            cursor.set_position(0);
+            match read_values(&mut cursor, &mut env) {
+                Ok(values2) => {
+                    assert_eq!(3, values2.len());
+                    assert_eq!(Value::Float(0.1), values2[0]);
+                    assert_eq!(Value::Float(16777217.125), values2[1]);
+                    assert_eq!(Value::Object(Arc::new(Object::FloatRange(0.1, 16777217.125, 0.3))), values2[2]);
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_values_and_read_values_writes_dictionary_and_reads_dictionary()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let mut pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+    pairs.insert(DictKey::Bool(true), Value::Int(1));
+    pairs.insert(DictKey::Int(2), Value::Float(2.0));
+    pairs.insert(DictKey::Float(2.5), Value::Object(Arc::new(Object::String(String::from("abc")))));
+    pairs.insert(DictKey::String(String::from("d")), Value::Bool(false));
+    let values = vec![Value::Ref(Arc::new(RwLock::new(MutObject::Dict(pairs))))];
+    match write_values(&mut cursor, values.as_slice()) {
+        Ok(()) => {
+            cursor.set_position(0);
+            match read_values(&mut cursor, &mut env) {
+                Ok(values2) => {
+                    assert_eq!(1, values2.len());
+                    assert!(values[0].eq_with_types(&values2[0]).unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_values_and_read_values_writes_host_matrices_and_reads_host_matrices()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let values = vec![
+        Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![1.0, 0.1, 3.25, -4.0])))),
+        Value::Object(Arc::new(Object::HostMatrix(1, 3, HostElems::Int(vec![1, -2, 3]))))
+    ];
+    match write_values(&mut cursor, values.as_slice()) {
+        Ok(()) => {
+            cursor.set_position(0);
+            match read_values(&mut cursor, &mut env) {
+                Ok(values2) => {
+                    assert_eq!(2, values2.len());
+                    assert!(values[0].eq_with_types(&values2[0]).unwrap());
+                    assert!(values[1].eq_with_types(&values2[1]).unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_values_and_read_values_writes_regex_and_reads_regex()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let values = vec![Value::Object(Arc::new(Object::Regex(Regex::new("(?P<a>[a-z]+)[0-9]*").unwrap())))];
+    match write_values(&mut cursor, values.as_slice()) {
+        Ok(()) => {
+            cursor.set_position(0);
+            match read_values(&mut cursor, &mut env) {
+                Ok(values2) => {
+                    assert_eq!(1, values2.len());
+                    assert!(values[0].eq_with_types(&values2[0]).unwrap());
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_values_and_read_values_writes_tensor_and_reads_tensor()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let a = matrix![
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0]
+    ];
+    let values = vec![Value::Object(Arc::new(Object::Tensor(vec![2, 2, 2], a)))];
+    match write_values(&mut cursor, values.as_slice()) {
+        Ok(()) => {
+            cursor.set_position(0);
+            match read_values(&mut cursor, &mut env) {
+                Ok(values2) => {
+                    assert_eq!(1, values2.len());
+                    match (&values[0], &values2[0]) {
+                        (Value::Object(object), Value::Object(object2)) => {
+                            match (&**object, &**object2) {
+                                (Object::Tensor(shape, a), Object::Tensor(shape2, a2)) => {
+                                    assert_eq!(*shape, *shape2);
+                                    let value = Value::Object(Arc::new(Object::Matrix(a.clone())));
+                                    let value2 = Value::Object(Arc::new(Object::Matrix(a2.clone())));
+                                    assert!(value.to_matrix_array().unwrap().eq_with_types(&value2.to_matrix_array().unwrap()).unwrap());
+                                },
+                                (_, _) => assert!(false),
+                            }
+                        },
+                        (_, _) => assert!(false),
+                    }
+                },
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_write_values_and_read_values_writes_values_and_reads_values_for_object_indices()
 {
@@ -286,3 +425,318 @@ fn test_write_values_and_read_values_writes_value_and_reads_value_for_reference_
         Err(_) => assert!(false),
     }
 }
+
+#[test]
+fn test_write_values_with_compression_and_read_values_writes_values_and_reads_values()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+    fields.insert(String::from("a"), Value::Int(1));
+    fields.insert(String::from("b"), Value::Object(Arc::new(Object::String(String::from("abcdef")))));
+    let values = vec![
+        Value::Int(1234),
+        Value::Float(1.5),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(1); 1000]))))
+    ];
+    for compression in [Compression::None, Compression::Gzip, Compression::Bzip2, Compression::Xz] {
+        let mut cursor = Cursor::new(Vec::<u8>::new());
+        match write_values_with_compression(&mut cursor, values.as_slice(), compression) {
+            Ok(()) => {
+                assert_eq!(b"unlab2", &cursor.get_ref()[0..6]);
+                cursor.set_position(0);
+                match read_values(&mut cursor, &mut env) {
+                    Ok(values2) => assert_eq!(values, values2),
+                    Err(_) => assert!(false),
+                }
+            },
+            Err(_) => assert!(false),
+        }
+    }
+}
+
+#[test]
+fn test_checksum_reader_and_checksum_writer_count_lengths_beyond_u32()
+{
+    let data = b"abcdef";
+    let mut crc = Crc::new();
+    crc.update(data);
+    let mut checksum_w = ChecksumWriter { w: Vec::<u8>::new(), crc: Crc::new(), len: u32::MAX as u64, };
+    checksum_w.write_all(data).unwrap();
+    assert_eq!((u32::MAX as u64) + 6, checksum_w.len);
+    assert_eq!(crc.sum(), checksum_w.crc.sum());
+    let mut checksum_r = ChecksumReader { r: &data[..], crc: Crc::new(), len: u32::MAX as u64, };
+    let mut buf: Vec<u8> = Vec::new();
+    checksum_r.read_to_end(&mut buf).unwrap();
+    assert_eq!((u32::MAX as u64) + 6, checksum_r.len);
+    assert_eq!(crc.sum(), checksum_r.crc.sum());
+}
+
+#[test]
+fn test_read_values_reads_values_without_data_after_trailer()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![Value::Int(1), Value::Object(Arc::new(Object::String(String::from("abc"))))];
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    write_values_with_compression(&mut cursor, values.as_slice(), Compression::None).unwrap();
+    let len = cursor.get_ref().len() as u64;
+    cursor.get_mut().extend_from_slice(b"abcdef");
+    cursor.set_position(0);
+    match read_values(&mut cursor, &mut env) {
+        Ok(values2) => {
+            assert_eq!(values, values2);
+            assert_eq!(len, cursor.position());
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_values_reads_values_in_unlab1_format()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![
+        Value::None,
+        Value::Int(2),
+        Value::Object(Arc::new(Object::String(String::from("abc"))))
+    ];
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    write_magic(&mut cursor, FORMAT_VERSION_1).unwrap();
+    write_body(&mut cursor, values.as_slice()).unwrap();
+    cursor.set_position(0);
+    match read_values(&mut cursor, &mut env) {
+        Ok(values2) => assert_eq!(values, values2),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_values_complains_on_truncated_data()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![
+        Value::Int(1),
+        Value::Object(Arc::new(Object::String(String::from("abcdef"))))
+    ];
+    for compression in [Compression::None, Compression::Gzip, Compression::Bzip2, Compression::Xz] {
+        let mut cursor = Cursor::new(Vec::<u8>::new());
+        write_values_with_compression(&mut cursor, values.as_slice(), compression).unwrap();
+        let mut buf = cursor.into_inner();
+        let len = buf.len();
+        for new_len in [len - 4, len - 20] {
+            buf.truncate(new_len);
+            let mut cursor = Cursor::new(buf.clone());
+            match read_values(&mut cursor, &mut env) {
+                Err(Error::Io(err)) => assert_eq!(ErrorKind::InvalidData, err.kind()),
+                _ => assert!(false),
+            }
+        }
+    }
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    write_values_with_compression(&mut cursor, values.as_slice(), Compression::None).unwrap();
+    let mut buf = cursor.into_inner();
+    let len = buf.len();
+    buf.truncate(len - 4);
+    let mut cursor = Cursor::new(buf);
+    match read_values(&mut cursor, &mut env) {
+        Err(Error::Io(err)) => assert_eq!(String::from("truncated data"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_values_complains_on_checksum_mismatch()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![Value::Object(Arc::new(Object::String(String::from("abcdef"))))];
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    write_values_with_compression(&mut cursor, values.as_slice(), Compression::None).unwrap();
+    let mut buf = cursor.into_inner();
+    let len = buf.len();
+    buf[len - 13] = b'x';
+    let mut cursor = Cursor::new(buf);
+    match read_values(&mut cursor, &mut env) {
+        Err(Error::Io(err)) => assert_eq!(String::from("checksum mismatch"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_values_complains_on_unsupported_format_version()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut cursor = Cursor::new(b"unlab9".to_vec());
+    match read_values(&mut cursor, &mut env) {
+        Err(Error::Io(err)) => assert_eq!(String::from("unsupported format version"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_indexed_values_and_read_values_writes_values_and_reads_values()
+{
+    let mut env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+    fields.insert(String::from("a"), Value::Int(1));
+    fields.insert(String::from("b"), Value::Object(Arc::new(Object::String(String::from("abcdef")))));
+    let values = vec![
+        Value::Int(1234),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Struct(fields)))),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Float(1.5); 1000]))))
+    ];
+    for compression in [Compression::None, Compression::Gzip, Compression::Bzip2, Compression::Xz] {
+        let mut cursor = Cursor::new(Vec::<u8>::new());
+        match write_indexed_values(&mut cursor, values.as_slice(), compression) {
+            Ok(()) => {
+                assert_eq!(b"unlab3", &cursor.get_ref()[0..6]);
+                cursor.set_position(0);
+                match read_values(&mut cursor, &mut env) {
+                    Ok(values2) => assert_eq!(values, values2),
+                    Err(_) => assert!(false),
+                }
+            },
+            Err(_) => assert!(false),
+        }
+    }
+}
+
+#[sealed_test]
+fn test_value_reader_read_value_reads_values_lazily()
+{
+    let env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![
+        Value::Int(1),
+        Value::Object(Arc::new(Object::String(String::from("abc")))),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(3)]))))
+    ];
+    for compression in [Compression::None, Compression::Gzip] {
+        save_indexed_values("test.bin", values.as_slice(), compression).unwrap();
+        match ValueReader::open("test.bin") {
+            Ok(mut reader) => {
+                assert_eq!(3, reader.len());
+                for i in [2, 0, 1] {
+                    match reader.read_value(i, &env) {
+                        Ok(Some(value)) => assert_eq!(values[i], value),
+                        _ => assert!(false),
+                    }
+                }
+                match reader.read_value(3, &env) {
+                    Ok(None) => assert!(true),
+                    _ => assert!(false),
+                }
+            },
+            Err(_) => assert!(false),
+        }
+    }
+}
+
+#[sealed_test]
+fn test_value_reader_open_complains_on_values_without_index()
+{
+    let values = vec![
+        Value::Int(1),
+        Value::Object(Arc::new(Object::String(String::from("abc"))))
+    ];
+    save_values("test.bin", values.as_slice()).unwrap();
+    match ValueReader::open("test.bin") {
+        Err(Error::Io(err)) => assert_eq!(String::from("values aren't indexed"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[sealed_test]
+fn test_value_writer_write_value_appends_values()
+{
+    let env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![
+        Value::Int(1),
+        Value::Object(Arc::new(Object::String(String::from("abc")))),
+        Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![Value::Int(2), Value::Int(3)]))))
+    ];
+    for compression in [Compression::None, Compression::Xz] {
+        match ValueWriter::create("test.bin", compression) {
+            Ok(mut writer) => {
+                for value in &values {
+                    match writer.write_value(value) {
+                        Ok(()) => assert!(true),
+                        Err(_) => assert!(false),
+                    }
+                }
+                assert_eq!(3, writer.len());
+                match writer.finish() {
+                    Ok(()) => assert!(true),
+                    Err(_) => assert!(false),
+                }
+                match writer.write_value(&values[0]) {
+                    Err(Error::Io(err)) => assert_eq!(String::from("value writer is closed"), format!("{}", err)),
+                    _ => assert!(false),
+                }
+            },
+            Err(_) => assert!(false),
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        write_indexed_values(&mut buf, values.as_slice(), compression).unwrap();
+        assert_eq!(buf, fs::read("test.bin").unwrap());
+        match ValueReader::open("test.bin") {
+            Ok(mut reader) => {
+                assert_eq!(3, reader.len());
+                for i in 0..3 {
+                    match reader.read_value(i, &env) {
+                        Ok(Some(value)) => assert_eq!(values[i], value),
+                        _ => assert!(false),
+                    }
+                }
+            },
+            Err(_) => assert!(false),
+        }
+    }
+    match ValueWriter::create("test2.bin", Compression::Gzip) {
+        Ok(mut writer) => {
+            match writer.write_value(&values[1]) {
+                Ok(()) => assert!(true),
+                Err(_) => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+    match load_values("test2.bin", &env) {
+        Ok(values2) => assert_eq!(vec![values[1].clone()], values2),
+        Err(_) => assert!(false),
+    }
+}
+
+#[sealed_test]
+fn test_value_reader_read_value_complains_on_checksum_mismatch()
+{
+    let env = Env::new(Arc::new(RwLock::new(ModNode::new(()))));
+    let values = vec![
+        Value::Object(Arc::new(Object::String(String::from("abc")))),
+        Value::Object(Arc::new(Object::String(String::from("def"))))
+    ];
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    write_indexed_values(&mut cursor, values.as_slice(), Compression::None).unwrap();
+    let mut buf = cursor.into_inner();
+    let pos = buf.windows(3).position(|w| w == b"abc").unwrap();
+    buf[pos] = b'x';
+    fs::write("test.bin", buf.as_slice()).unwrap();
+    match ValueReader::open("test.bin") {
+        Ok(mut reader) => {
+            match reader.read_value(0, &env) {
+                Err(Error::Io(err)) => assert_eq!(String::from("checksum mismatch"), format!("{}", err)),
+                _ => assert!(false),
+            }
+            match reader.read_value(1, &env) {
+                Ok(Some(value)) => assert_eq!(values[1], value),
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+    let len = buf.len();
+    buf.truncate(len - 1);
+    fs::write("test.bin", buf.as_slice()).unwrap();
+    match ValueReader::open("test.bin") {
+        Err(Error::Io(err)) => assert_eq!(ErrorKind::InvalidData, err.kind()),
+        _ => assert!(false),
+    }
+}
@@ -33,6 +33,10 @@ pub enum Token
     DotLBracket,
     /// A `.]` token.
     DotRBracket,
+    /// A `.{` token.
+    DotLBrace,
+    /// A `.}` token.
+    DotRBrace,
     /// A `?` token.
     Ques,
     /// A `*` token.
@@ -69,6 +73,8 @@ pub enum Token
     Apos,
     /// A `.` token.
     Dot,
+    /// A `...` token.
+    DotDotDot,
     /// A `:` token.
     Colon,
     /// A `::` token.
@@ -83,6 +89,8 @@ pub enum Token
     Break,
     /// A `by` keyword.
     By,
+    /// A `catch` keyword.
+    Catch,
     /// A `continue` keyword.
     Continue,
     /// An `else` keyword.
@@ -93,6 +101,8 @@ pub enum Token
     False,
     /// A `fill` keyword.
     Fill,
+    /// A `finally` keyword.
+    Finally,
     /// A `for` keyword.
     For,
     /// A `function` keyword.
@@ -123,12 +133,14 @@ pub enum Token
     To,
     /// A `true` keyword.
     True,
+    /// A `try` keyword.
+    Try,
     /// A `while` keyword.
     While,
     /// An integer number token.
     Int(i64),
     /// A floating-point number token.
-    Float(f32),
+    Float(f64),
     /// A string token.
     String(String),
     /// An identifier token.
@@ -150,26 +162,30 @@ pub struct Lexer<'a>
     is_stopped: bool,
     keywords: HashMap<String, Token>,
     doc: Option<Option<String>>,
+    comments: Option<Vec<(String, Pos)>>,
 }
 
 impl<'a> Lexer<'a>
 {
-    /// Creates a lexer with the line number and the documentation flag.
+    /// Creates a lexer with the line number, the documentation flag, and the comment flag.
     ///
     /// Also, this method takes the path that refers to the script and the reader. The lexer reads
     /// a script from the reader. The documentation flag determines whether the lexer can store
-    /// the documentation comments.
-    pub fn new_with_line_and_doc_flag(path: Arc<String>, reader: &'a mut dyn BufRead, line: u64, is_doc: bool) -> Self
+    /// the documentation comments. The comment flag determines whether the lexer can store all
+    /// comments with their positions.
+    pub fn new_with_line_and_doc_flag_and_comment_flag(path: Arc<String>, reader: &'a mut dyn BufRead, line: u64, is_doc: bool, is_comment: bool) -> Self
     {
         let mut keywords: HashMap<String, Token> = HashMap::new();
         keywords.insert(String::from("and"), Token::And);
         keywords.insert(String::from("break"), Token::Break);
         keywords.insert(String::from("by"), Token::By);
+        keywords.insert(String::from("catch"), Token::Catch);
         keywords.insert(String::from("continue"), Token::Continue);
         keywords.insert(String::from("else"), Token::Else);
         keywords.insert(String::from("end"), Token::End);
         keywords.insert(String::from("false"), Token::False);
         keywords.insert(String::from("fill"), Token::Fill);
+        keywords.insert(String::from("finally"), Token::Finally);
         keywords.insert(String::from("for"), Token::For);
         keywords.insert(String::from("function"), Token::Function);
         keywords.insert(String::from("if"), Token::If);
@@ -185,12 +201,18 @@ impl<'a> Lexer<'a>
         keywords.insert(String::from("root"), Token::Root);
         keywords.insert(String::from("to"), Token::To);
         keywords.insert(String::from("true"), Token::True);
+        keywords.insert(String::from("try"), Token::Try);
         keywords.insert(String::from("while"), Token::While);
         let doc = if is_doc {
             Some(None)
         } else {
             None
         };
+        let comments = if is_comment {
+            Some(Vec::new())
+        } else {
+            None
+        };
         Lexer {
             path,
             line,
@@ -200,30 +222,54 @@ impl<'a> Lexer<'a>
             is_stopped: false,
             keywords,
             doc,
+            comments,
         }
     }
 
+    /// Creates a lexer with the line number and the documentation flag without the comment flag.
+    ///
+    /// See [`new_with_line_and_doc_flag_and_comment_flag`](Self::new_with_line_and_doc_flag_and_comment_flag).
+    pub fn new_with_line_and_doc_flag(path: Arc<String>, reader: &'a mut dyn BufRead, line: u64, is_doc: bool) -> Self
+    { Self::new_with_line_and_doc_flag_and_comment_flag(path, reader, line, is_doc, false) }
+
+    /// Creates a lexer with the comment flag.
+    ///
+    /// See [`new_with_line_and_doc_flag_and_comment_flag`](Self::new_with_line_and_doc_flag_and_comment_flag).
+    pub fn new_with_comment_flag(path: Arc<String>, reader: &'a mut dyn BufRead, is_comment: bool) -> Self
+    { Self::new_with_line_and_doc_flag_and_comment_flag(path, reader, 1, false, is_comment) }
+
     /// Creates a lexer with the line number without the documentatio flag.
     ///
-    /// See [`new_with_line_and_doc_flag`](Self::new_with_line_and_doc_flag).
+    /// See [`new_with_line_and_doc_flag_and_comment_flag`](Self::new_with_line_and_doc_flag_and_comment_flag).
     pub fn new_with_line(path: Arc<String>, reader: &'a mut dyn BufRead, line: u64) -> Self
     { Self::new_with_line_and_doc_flag(path, reader, line, false) }
     
     /// Creates a lexer with the documentatio flag.
     ///
-    /// See [`new_with_line_and_doc_flag`](Self::new_with_line_and_doc_flag).
+    /// See [`new_with_line_and_doc_flag_and_comment_flag`](Self::new_with_line_and_doc_flag_and_comment_flag).
     pub fn new_with_doc_flag(path: Arc<String>, reader: &'a mut dyn BufRead, is_doc: bool) -> Self
     { Self::new_with_line_and_doc_flag(path, reader, 1, is_doc) }
     
     /// Creates a lexer.
     ///
-    /// See [`new_with_line_and_doc_flag`](Self::new_with_line_and_doc_flag).
+    /// See [`new_with_line_and_doc_flag_and_comment_flag`](Self::new_with_line_and_doc_flag_and_comment_flag).
     pub fn new(path: Arc<String>, reader: &'a mut dyn BufRead) -> Self
     { Self::new_with_doc_flag(path, reader, false) }
 
     /// Returns the script path.
     pub fn path(&self) -> &Arc<String>
     { &self.path }
+
+    /// Takes the stored comments with their positions.
+    ///
+    /// The comments are stored with the comment characters if the lexer has the comment flag.
+    pub fn take_comments(&mut self) -> Vec<(String, Pos)>
+    {
+        match &mut self.comments {
+            Some(comments) => comments.split_off(0),
+            None => Vec::new(),
+        }
+    }
     
     fn read_line_tokens(&mut self)
     {
@@ -397,7 +443,7 @@ impl<'a> Lexer<'a>
             None => (),
         }
         if is_dot_or_exp {
-            match s.parse::<f32>() {
+            match s.parse::<f64>() {
                 Ok(n) => self.line_tokens.push(Ok((Token::Float(n), s_pos))),
                 Err(_) => {
                     self.line_tokens.push(Err(Error::Parser(s_pos, String::from("invalid number"))));
@@ -418,6 +464,92 @@ impl<'a> Lexer<'a>
         true
     }
 
+    fn read_escape(&mut self, cs: &mut PushbackIter<&mut dyn Iterator<Item = (char, Pos)>>, pos: &Pos, pos2: Pos, s: &mut String) -> bool
+    {
+        match cs.next() {
+            Some(('a', _)) => s.push('\x07'),
+            Some(('b', _)) => s.push('\x08'),
+            Some(('t', _)) => s.push('\t'),
+            Some(('n', _)) => s.push('\n'),
+            Some(('v', _)) => s.push('\x0b'),
+            Some(('f', _)) => s.push('\x0c'),
+            Some(('r', _)) => s.push('\r'),
+            Some((c3 @ ('U'| 'u'), _)) => {
+                let mut t = String::new();
+                let n = if c3 == 'U' { 6 } else { 4 };
+                for _ in 0..n {
+                    match cs.next() {
+                        Some((c4, _)) if c4.is_ascii_hexdigit() => t.push(c4),
+                        _ => {
+                            self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
+                            self.is_stopped = true;
+                            return false;
+                        }
+                    }
+                }
+                match u32::from_str_radix(t.as_str(), 16) {
+                    Ok(code) => {
+                        match char::from_u32(code) {
+                            Some(esc_c) => s.push(esc_c),
+                            None => {
+                                self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
+                                self.is_stopped = true;
+                                return false;
+                            },
+                        }
+                    },
+                    Err(_) => {
+                        self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
+                        self.is_stopped = true;
+                        return false;
+                    },
+                }
+            },
+            Some((c3 @ ('0'..='7'), _)) => {
+                let mut t = String::new();
+                t.push(c3);
+                for _ in 0..2 {
+                    match cs.next() {
+                        Some((c4 @ ('0'..='7'), _)) => t.push(c4),
+                        Some((c4, pos4)) => {
+                            cs.undo((c4, pos4));
+                            break;
+                        },
+                        None => {
+                            self.line_tokens.push(Err(Error::Parser(pos2, String::from("unclosed string"))));
+                            self.is_stopped = true;
+                            return false;
+                        }
+                    }
+                }
+                match u32::from_str_radix(t.as_str(), 8) {
+                    Ok(code) => {
+                        match char::from_u32(code) {
+                            Some(esc_c) => s.push(esc_c),
+                            None => {
+                                self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid octal escape"))));
+                                self.is_stopped = true;
+                                return false;
+                            },
+                        }
+                    },
+                    Err(_) => {
+                        self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid octal escape"))));
+                        self.is_stopped = true;
+                        return false;
+                    },
+                }
+            },
+            Some((c3, _)) => s.push(c3),
+            None => {
+                self.line_tokens.push(Err(Error::Parser(pos.clone(), String::from("unclosed string"))));
+                self.is_stopped = true;
+                return false;
+            },
+        }
+        true
+    }
+
     fn read_string_token(&mut self, cs: &mut PushbackIter<&mut dyn Iterator<Item = (char, Pos)>>) -> bool
     {
         match cs.next() {
@@ -427,83 +559,180 @@ impl<'a> Lexer<'a>
                     match cs.next() {
                         Some(('"', _)) => break,
                         Some(('\\', pos2)) => {
+                            if !self.read_escape(cs, &pos, pos2, &mut s) {
+                                return false;
+                            }
+                        },
+                        Some((c2, _)) => s.push(c2),
+                        None => {
+                            self.line_tokens.push(Err(Error::Parser(pos, String::from("unclosed string"))));
+                            self.is_stopped = true;
+                            return false;
+                        },
+                    }
+                }
+                self.line_tokens.push(Ok((Token::String(s), pos)));
+                true
+            },
+            Some((_, pos)) => {
+                self.line_tokens.push(Err(Error::Parser(pos, String::from("invalid string"))));
+                self.is_stopped = true;
+                false
+            },
+            None => {
+                self.line_tokens.push(Err(Error::Parser(Pos::new(self.path.clone(), self.line, self.eol_column), String::from("invalid string"))));
+                self.is_stopped = true;
+                false
+            },
+        }
+    }
+
+    fn read_interp_expr_chars(&mut self, cs: &mut PushbackIter<&mut dyn Iterator<Item = (char, Pos)>>, pos: &Pos, expr_cs: &mut Vec<(char, Pos)>) -> bool
+    {
+        let mut depth = 0usize;
+        loop {
+            match cs.next() {
+                Some((c @ ('(' | '[' | '{'), pos2)) => {
+                    depth += 1;
+                    expr_cs.push((c, pos2));
+                },
+                Some((c @ ('}' | ':'), pos2)) if depth == 0 => {
+                    match (c, cs.next()) {
+                        (':', Some((':', pos3))) => {
+                            expr_cs.push((c, pos2));
+                            expr_cs.push((':', pos3));
+                        },
+                        (_, Some((c3, pos3))) => {
+                            cs.undo((c3, pos3));
+                            cs.undo((c, pos2));
+                            return true;
+                        },
+                        (_, None) => {
+                            cs.undo((c, pos2));
+                            return true;
+                        },
+                    }
+                },
+                Some((c @ (')' | ']' | '}'), pos2)) => {
+                    depth = depth.saturating_sub(1);
+                    expr_cs.push((c, pos2));
+                },
+                Some((c @ '"', pos2)) => {
+                    expr_cs.push((c, pos2));
+                    loop {
+                        match cs.next() {
+                            Some((c3 @ '"', pos3)) => {
+                                expr_cs.push((c3, pos3));
+                                break;
+                            },
+                            Some((c3 @ '\\', pos3)) => {
+                                expr_cs.push((c3, pos3));
+                                match cs.next() {
+                                    Some((c4, pos4)) => expr_cs.push((c4, pos4)),
+                                    None => break,
+                                }
+                            },
+                            Some((c3, pos3)) => expr_cs.push((c3, pos3)),
+                            None => break,
+                        }
+                    }
+                },
+                Some((c, pos2)) => expr_cs.push((c, pos2)),
+                None => {
+                    self.line_tokens.push(Err(Error::Parser(pos.clone(), String::from("unclosed string"))));
+                    self.is_stopped = true;
+                    return false;
+                },
+            }
+        }
+    }
+
+    fn read_interp_string_token(&mut self, cs: &mut PushbackIter<&mut dyn Iterator<Item = (char, Pos)>>) -> bool
+    {
+        match cs.next() {
+            Some(('$', pos)) => {
+                match cs.next() {
+                    Some(('"', _)) => (),
+                    Some((_, pos2)) => {
+                        self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid interpolated string"))));
+                        self.is_stopped = true;
+                        return false;
+                    },
+                    None => {
+                        self.line_tokens.push(Err(Error::Parser(Pos::new(self.path.clone(), self.line, self.eol_column), String::from("invalid interpolated string"))));
+                        self.is_stopped = true;
+                        return false;
+                    },
+                }
+                self.line_tokens.push(Ok((Token::Root, pos.clone())));
+                self.line_tokens.push(Ok((Token::ColonColon, pos.clone())));
+                self.line_tokens.push(Ok((Token::Ident(String::from("format")), pos.clone())));
+                self.line_tokens.push(Ok((Token::LParen, pos.clone())));
+                let s_idx = self.line_tokens.len();
+                self.line_tokens.push(Ok((Token::String(String::new()), pos.clone())));
+                let mut s = String::new();
+                loop {
+                    match cs.next() {
+                        Some(('"', _)) => break,
+                        Some(('\\', pos2)) => {
+                            let mut t = String::new();
+                            if !self.read_escape(cs, &pos, pos2, &mut t) {
+                                return false;
+                            }
+                            for c3 in t.chars() {
+                                match c3 {
+                                    '{' => s.push_str("{{"),
+                                    '}' => s.push_str("}}"),
+                                    _ => s.push(c3),
+                                }
+                            }
+                        },
+                        Some(('{', pos2)) => {
                             match cs.next() {
-                                Some(('a', _)) => s.push('\x07'),
-                                Some(('b', _)) => s.push('\x08'),
-                                Some(('t', _)) => s.push('\t'),
-                                Some(('n', _)) => s.push('\n'),
-                                Some(('v', _)) => s.push('\x0b'),
-                                Some(('f', _)) => s.push('\x0c'),
-                                Some(('r', _)) => s.push('\r'),
-                                Some((c3 @ ('U'| 'u'), _)) => {
-                                    let mut t = String::new();
-                                    let n = if c3 == 'U' { 6 } else { 4 };
-                                    for _ in 0..n {
-                                        match cs.next() {
-                                            Some((c4, _)) if c4.is_ascii_hexdigit() => t.push(c4),
-                                            _ => {
-                                                self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
-                                                self.is_stopped = true;
-                                                return false;
-                                            }
-                                        }
-                                    }
-                                    match u32::from_str_radix(t.as_str(), 16) {
-                                        Ok(code) => {
-                                            match char::from_u32(code) {
-                                                Some(esc_c) => s.push(esc_c),
-                                                None => {
-                                                    self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
-                                                    self.is_stopped = true;
-                                                    return false;
-                                                },
-                                            }
-                                        },
-                                        Err(_) => {
-                                            self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid unicode escape"))));
-                                            self.is_stopped = true;
-                                            return false;
-                                        },
-                                    }
-                                },
-                                Some((c3 @ ('0'..='7'), _)) => {
-                                    let mut t = String::new();
-                                    t.push(c3);
-                                    for _ in 0..2 {
-                                        match cs.next() {
-                                            Some((c4 @ ('0'..='7'), _)) => t.push(c4),
-                                            Some((c4, pos4)) => {
-                                                cs.undo((c4, pos4));
-                                                break;
-                                            },
-                                            None => {
-                                                self.line_tokens.push(Err(Error::Parser(pos2, String::from("unclosed string"))));
-                                                self.is_stopped = true;
-                                                return false;
-                                            }
-                                        }
-                                    }
-                                    match u32::from_str_radix(t.as_str(), 8) {
-                                        Ok(code) => {
-                                            match char::from_u32(code) {
-                                                Some(esc_c) => s.push(esc_c),
-                                                None => {
-                                                    self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid octal escape"))));
-                                                    self.is_stopped = true;
-                                                    return false;
-                                                },
-                                            }
-                                        },
-                                        Err(_) => {
-                                            self.line_tokens.push(Err(Error::Parser(pos2, String::from("invalid octal escape"))));
-                                            self.is_stopped = true;
-                                            return false;
-                                        },
-                                    }
+                                Some(('{', _)) => {
+                                    s.push_str("{{");
+                                    continue;
                                 },
-                                Some((c3, _)) => s.push(c3),
-                                None => {
-                                    self.line_tokens.push(Err(Error::Parser(pos, String::from("unclosed string"))));
+                                Some((c3, pos3)) => cs.undo((c3, pos3)),
+                                None => (),
+                            }
+                            let mut expr_cs: Vec<(char, Pos)> = Vec::new();
+                            if !self.read_interp_expr_chars(cs, &pos, &mut expr_cs) {
+                                return false;
+                            }
+                            self.line_tokens.push(Ok((Token::Comma, pos2.clone())));
+                            let token_count = self.line_tokens.len();
+                            let mut expr_cs2 = expr_cs.into_iter();
+                            let expr_cs3: &mut dyn Iterator<Item = (char, Pos)> = &mut expr_cs2;
+                            let mut expr_cs4 = PushbackIter::new(expr_cs3);
+                            while self.read_token(&mut expr_cs4) {}
+                            if self.is_stopped {
+                                return false;
+                            }
+                            if self.line_tokens.len() == token_count {
+                                self.line_tokens.push(Err(Error::Parser(pos2, String::from("no expression in interpolated string"))));
+                                self.is_stopped = true;
+                                return false;
+                            }
+                            s.push('{');
+                            loop {
+                                match cs.next() {
+                                    Some(('}', _)) => break,
+                                    Some((c3, _)) => s.push(c3),
+                                    None => {
+                                        self.line_tokens.push(Err(Error::Parser(pos, String::from("unclosed string"))));
+                                        self.is_stopped = true;
+                                        return false;
+                                    },
+                                }
+                            }
+                            s.push('}');
+                        },
+                        Some(('}', pos2)) => {
+                            match cs.next() {
+                                Some(('}', _)) => s.push_str("}}"),
+                                _ => {
+                                    self.line_tokens.push(Err(Error::Parser(pos2, String::from("unexpected closing brace in interpolated string"))));
                                     self.is_stopped = true;
                                     return false;
                                 },
@@ -517,16 +746,17 @@ impl<'a> Lexer<'a>
                         },
                     }
                 }
-                self.line_tokens.push(Ok((Token::String(s), pos)));
+                self.line_tokens[s_idx] = Ok((Token::String(s), pos.clone()));
+                self.line_tokens.push(Ok((Token::RParen, pos)));
                 true
             },
             Some((_, pos)) => {
-                self.line_tokens.push(Err(Error::Parser(pos, String::from("invalid string"))));
+                self.line_tokens.push(Err(Error::Parser(pos, String::from("invalid interpolated string"))));
                 self.is_stopped = true;
                 false
             },
             None => {
-                self.line_tokens.push(Err(Error::Parser(Pos::new(self.path.clone(), self.line, self.eol_column), String::from("invalid string"))));
+                self.line_tokens.push(Err(Error::Parser(Pos::new(self.path.clone(), self.line, self.eol_column), String::from("invalid interpolated string"))));
                 self.is_stopped = true;
                 false
             },
@@ -577,17 +807,26 @@ impl<'a> Lexer<'a>
     {
         self.skip_spaces(cs);
         match cs.next() {
-            Some(('#' | '%', _)) => {
-                match cs.next() {
-                    Some(('#' | '%', _)) => {
+            Some((c @ ('#' | '%'), pos)) => {
+                let comment_line: Vec<char> = cs.map(|p| p.0).collect();
+                match &mut self.comments {
+                    Some(comments) => {
+                        let mut comment = String::new();
+                        comment.push(c);
+                        comment.extend(comment_line.iter());
+                        comments.push((String::from(comment.trim_end()), pos));
+                    },
+                    None => (),
+                }
+                match comment_line.first() {
+                    Some('#' | '%') => {
                         match &mut self.doc {
                             Some(doc) => {
-                                match cs.next() {
-                                    Some((c3, _)) if c3.is_whitespace() => (),
-                                    Some((c3, pos3)) => cs.undo((c3, pos3)),
-                                    None => (),
-                                }
-                                let mut doc_line: String = cs.map(|p| p.0).collect();
+                                let doc_line_start = match comment_line.get(1) {
+                                    Some(c3) if c3.is_whitespace() => 2,
+                                    _ => 1,
+                                };
+                                let mut doc_line: String = comment_line[doc_line_start..].iter().collect();
                                 doc_line.push('\n');
                                 match doc {
                                     Some(doc) => doc.push_str(doc_line.as_str()),
@@ -657,10 +896,26 @@ impl<'a> Lexer<'a>
                 match cs.next() {
                     Some(('[', _)) => self.line_tokens.push(Ok((Token::DotLBracket, pos))),
                     Some((']', _)) => self.line_tokens.push(Ok((Token::DotRBracket, pos))),
+                    Some(('{', _)) => self.line_tokens.push(Ok((Token::DotLBrace, pos))),
+                    Some(('}', _)) => self.line_tokens.push(Ok((Token::DotRBrace, pos))),
                     Some(('*', _)) => self.line_tokens.push(Ok((Token::DotStar, pos))),
                     Some(('/', _)) => self.line_tokens.push(Ok((Token::DotSlash, pos))),
                     Some(('+', _)) => self.line_tokens.push(Ok((Token::DotPlus, pos))),
                     Some(('-', _)) => self.line_tokens.push(Ok((Token::DotMinus, pos))),
+                    Some((c2 @ '.', pos2)) => {
+                        match cs.next() {
+                            Some(('.', _)) => self.line_tokens.push(Ok((Token::DotDotDot, pos))),
+                            Some((c3, pos3)) => {
+                                self.line_tokens.push(Ok((Token::Dot, pos)));
+                                cs.undo((c3, pos3));
+                                cs.undo((c2, pos2));
+                            },
+                            None => {
+                                self.line_tokens.push(Ok((Token::Dot, pos)));
+                                cs.undo((c2, pos2));
+                            },
+                        }
+                    },
                     Some((c2, pos2)) => {
                         self.line_tokens.push(Ok((Token::Dot, pos)));
                         cs.undo((c2, pos2));
@@ -684,6 +939,16 @@ impl<'a> Lexer<'a>
                 cs.undo((c, pos));
                 return self.read_string_token(cs);
             },
+            Some((c @ '$', pos)) => {
+                cs.undo((c, pos));
+                let token_count = self.line_tokens.len();
+                if !self.read_interp_string_token(cs) {
+                    let err = self.line_tokens.pop();
+                    self.line_tokens.truncate(token_count);
+                    self.line_tokens.extend(err);
+                    return false;
+                }
+            },
             Some((c, pos)) if c.is_ascii_digit() => {
                 cs.undo((c, pos));
                 return self.read_number_token(cs);
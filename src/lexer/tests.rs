@@ -220,6 +220,55 @@ fn test_lexer_next_returns_interpuction_tokens()
     }
 }
 
+#[test]
+fn test_lexer_next_returns_interpuction_tokens_for_dot_dot_dot()
+{
+    let s = "... a.b x...";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    match lexer.next() {
+        Some(Ok((Token::DotDotDot, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Ident(ident), pos))) => {
+            assert_eq!(String::from("a"), ident);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Dot, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 6), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Ident(ident), pos))) => {
+            assert_eq!(String::from("b"), ident);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 7), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Ident(ident), pos))) => {
+            assert_eq!(String::from("x"), ident);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 9), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::DotDotDot, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 10), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Newline, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 13), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        None => assert!(true),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_lexer_next_returns_keyboard_tokens()
 {
@@ -332,6 +381,34 @@ fn test_lexer_next_returns_keyboard_tokens()
     }
 }
 
+#[test]
+fn test_lexer_next_returns_keyboard_tokens_for_try_statement()
+{
+    let s = "catch finally try";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    match lexer.next() {
+        Some(Ok((Token::Catch, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Finally, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 7), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Try, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 15), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Newline, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 18), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        None => assert!(true),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_lexer_next_returns_integer_tokens()
 {
@@ -468,6 +545,29 @@ fn test_lexer_next_returns_float_tokens()
     }
 }
 
+#[test]
+fn test_lexer_next_returns_float_token_with_double_precision()
+{
+    let s = "16777217.125\n";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    match lexer.next() {
+        Some(Ok((Token::Float(n), pos))) => {
+            assert_eq!(16777217.125, n);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Newline, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 13), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        None => assert!(true),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_lexer_next_returns_string_token()
 {
@@ -537,6 +637,71 @@ fn test_lexer_next_returns_string_token_with_unicode_escapes()
     }
 }
 
+#[test]
+fn test_lexer_next_returns_tokens_for_interpolated_string()
+{
+    let s = "$\"a {x + 1:.2} {{b}}\"\n";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    match lexer.next() {
+        Some(Ok((Token::Root, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::ColonColon, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Ident(ident), pos))) => {
+            assert_eq!(String::from("format"), ident);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::LParen, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::String(t), pos))) => {
+            assert_eq!(String::from("a {:.2} {{b}}"), t);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Comma, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Ident(ident), pos))) => {
+            assert_eq!(String::from("x"), ident);
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 6), pos);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Plus, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 8), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Int(1), pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 10), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::RParen, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        Some(Ok((Token::Newline, pos))) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 22), pos),
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        None => assert!(true),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_lexer_next_returns_identifier_tokens()
 {
@@ -731,6 +896,29 @@ fn test_lexer_take_doc_does_not_return_second_documentation()
     }
 }
 
+#[test]
+fn test_lexer_take_comments_returns_comments()
+{
+    let s = "
+# first text
++ % second text  
+## third text
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new_with_comment_flag(Arc::new(String::from("test.un")), &mut cursor, true);
+    while lexer.next().is_some() {}
+    let comments = lexer.take_comments();
+    assert_eq!(3, comments.len());
+    assert_eq!(String::from("# first text"), comments[0].0);
+    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), comments[0].1);
+    assert_eq!(String::from("% second text"), comments[1].0);
+    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 3), comments[1].1);
+    assert_eq!(String::from("## third text"), comments[2].0);
+    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 1), comments[2].1);
+    assert!(lexer.take_comments().is_empty());
+}
+
 
 #[test]
 fn test_lexer_next_complains_on_unexpected_character()
@@ -921,3 +1109,22 @@ fn test_lexer_next_complains_on_unclosed_string()
         _ => assert!(false),
     }
 }
+
+#[test]
+fn test_lexer_next_complains_on_unexpected_closing_brace_in_interpolated_string()
+{
+    let s = "$\"abc}\"";
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    match lexer.next() {
+        Some(Err(Error::Parser(pos, msg))) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 6), pos);
+            assert_eq!(String::from("unexpected closing brace in interpolated string"), msg);
+        },
+        _ => assert!(false),
+    }
+    match lexer.next() {
+        None => assert!(true),
+        _ => assert!(false),
+    }
+}
@@ -29,6 +29,7 @@
 //! this scripting language also can be replaced by own built-in functions.
 pub use ctrlc;
 pub use curl;
+pub use regex;
 pub use serde;
 pub use serde_json;
 pub use toml;
@@ -36,13 +37,18 @@ pub use unmtx_gpu as matrix;
 #[cfg(feature = "plot")]
 pub use winit;
 
+pub mod autograd;
 pub mod backend;
 pub mod builtin_doc;
 pub mod builtins;
+pub mod bytecode;
+pub mod dap;
+pub mod debug;
 pub mod dfs;
 pub mod doc;
 pub mod env;
 pub mod error;
+pub mod formatter;
 pub mod fs;
 pub mod getopts;
 pub mod getopts_doc;
@@ -51,8 +57,11 @@ pub mod interp;
 pub mod intr;
 pub mod io;
 pub mod lexer;
+pub mod linter;
+pub mod lsp;
 pub mod main_loop;
 pub mod mod_node;
+pub mod npy;
 pub mod parser;
 pub mod pkg;
 pub mod pkg_cmds;
@@ -60,6 +69,7 @@ pub mod pkg_cmds;
 pub mod plot;
 #[cfg(feature = "plot")]
 pub mod plot_doc;
+pub mod safetensors;
 pub mod tester;
 pub mod tree;
 pub mod utils;
@@ -75,6 +85,8 @@ pub use error::Result;
 pub use home::Home;
 pub use interp::Interp;
 pub use main_loop::main_loop;
+pub use main_loop::main_loop_with_debug_flag;
+pub use main_loop::main_loop_with_debug_and_vm_flags;
 pub use mod_node::ModNode;
 pub use parser::parse;
 pub use parser::parse_with_doc_root_mod;
@@ -0,0 +1,1077 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of static linter.
+//!
+//! The linter checks scripts for common mistakes without executing them. Names are resolved
+//! against a tree of modules that is built from the definitions of the scripts, the libraries
+//! that are used by the `uselib` function, the imports of the `usemod`, `usemods`, `usevar`,
+//! and `usevars` functions, and the built-in variables.
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::fmt;
+use std::path;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::RwLock;
+use crate::error::*;
+use crate::mod_node::*;
+use crate::parser::*;
+use crate::tree::*;
+use crate::utils::*;
+use crate::value::*;
+
+#[derive(Clone, Debug)]
+enum Sym
+{
+    Var,
+    Fun(Arc<Fun>),
+    Builtin,
+}
+
+/// A structure of lint warning.
+///
+/// The lint warning contains the file position and the message.
+#[derive(Clone, Debug)]
+pub struct Warning
+{
+    pos: Pos,
+    msg: String,
+}
+
+impl Warning
+{
+    /// Creates a lint warning.
+    pub fn new(pos: Pos, msg: String) -> Self
+    { Warning { pos, msg, } }
+
+    /// Returns the file position.
+    pub fn pos(&self) -> &Pos
+    { &self.pos }
+
+    /// Returns the message.
+    pub fn msg(&self) -> &String
+    { &self.msg }
+}
+
+impl fmt::Display for Warning
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    { write!(f, "{}: {}.{}: {}", self.pos.path, self.pos.line, self.pos.column, self.msg) }
+}
+
+struct Script
+{
+    tree: Tree,
+    mod_idents: Vec<String>,
+}
+
+struct LocalVar
+{
+    pos: Pos,
+    is_arg: bool,
+    is_used: bool,
+}
+
+/// A structure of static linter.
+///
+/// The scripts are added to the linter before linting. The scripts that are run by the `run`
+/// and `runwithdoc` functions and the libraries that are used by the `uselib` and `reuselib`
+/// functions are added automatically if their names are string literals. The linter reports
+/// the following mistakes:
+///
+/// - undefined variables, functions, and modules
+/// - unused local variables
+/// - assignments to built-in names
+/// - `break` and `continue` outside loops and `return` outside functions
+/// - calls of functions with the wrong number of arguments
+pub struct Linter
+{
+    root_mod: Arc<RwLock<ModNode<Sym, ()>>>,
+    lib_path: OsString,
+    scripts: Vec<Script>,
+    script_keys: HashSet<(PathBuf, Vec<String>)>,
+    used_libs: HashSet<String>,
+    warnings: Vec<Warning>,
+}
+
+impl Linter
+{
+    /// Creates a linter with the root module of built-in variables and the library path.
+    pub fn new(builtin_root_mod: &ModNode<Value, ()>, lib_path: OsString) -> Self
+    {
+        let mut root_mod: ModNode<Sym, ()> = ModNode::new(());
+        for ident in builtin_root_mod.vars().keys() {
+            root_mod.add_var(ident.clone(), Sym::Builtin);
+        }
+        Linter {
+            root_mod: Arc::new(RwLock::new(root_mod)),
+            lib_path,
+            scripts: Vec::new(),
+            script_keys: HashSet::new(),
+            used_libs: HashSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Adds the syntax tree of the script to the linter.
+    ///
+    /// The script directory is used to find the scripts that are run by the script. The domain
+    /// is used to find the libraries that are used by the script.
+    pub fn add_tree(&mut self, tree: Tree, script_dir: &Path, domain: Option<&str>) -> Result<()>
+    {
+        let root_mod = self.root_mod.clone();
+        self.collect_script(tree, &root_mod, Vec::new(), script_dir, domain)
+    }
+
+    /// Adds the script that is referred by the path to the linter.
+    ///
+    /// A syntax error of the script is reported as a warning.
+    pub fn add_script<P: AsRef<Path>>(&mut self, path: P, domain: Option<&str>) -> Result<()>
+    {
+        let root_mod = self.root_mod.clone();
+        self.add_script_for_mod(path.as_ref(), None, &root_mod, Vec::new(), domain)
+    }
+
+    /// Adds the script of the library that is referred by the path to the linter.
+    ///
+    /// The library isn't added if the library has already been used by other script.
+    pub fn add_lib_script<P: AsRef<Path>>(&mut self, path: P, lib_name: &str) -> Result<()>
+    {
+        if self.used_libs.contains(lib_name) {
+            return Ok(());
+        }
+        self.used_libs.insert(String::from(lib_name));
+        let domain = match lib_name.split_once('/') {
+            Some((tmp_domain, _)) => Some(tmp_domain),
+            None => None,
+        };
+        self.add_script(path, domain)
+    }
+
+    /// Lints the added scripts and returns the lint warnings that are sorted by the file
+    /// positions.
+    pub fn lint(&mut self) -> Result<Vec<Warning>>
+    {
+        let root_mod = self.root_mod.clone();
+        let scripts = self.scripts.drain(..).collect::<Vec<Script>>();
+        for script in &scripts {
+            let mod1 = self.mod_for_idents(&script.mod_idents)?;
+            match &script.tree {
+                Tree(nodes) => self.import_nodes(nodes.as_slice(), &mod1, &root_mod)?,
+            }
+        }
+        for script in &scripts {
+            let mod1 = self.mod_for_idents(&script.mod_idents)?;
+            let mut frames: Vec<BTreeMap<String, LocalVar>> = Vec::new();
+            match &script.tree {
+                Tree(nodes) => self.lint_nodes(nodes.as_slice(), &mod1, &mut frames, 0)?,
+            }
+        }
+        self.scripts = scripts;
+        let mut warnings = self.warnings.drain(..).collect::<Vec<Warning>>();
+        warnings.sort_by(|warning1, warning2| compare_poses(&warning1.pos, &warning2.pos));
+        Ok(warnings)
+    }
+
+    fn warn(&mut self, pos: &Pos, msg: String)
+    { self.warnings.push(Warning::new(pos.clone(), msg)); }
+
+    fn warn_error(&mut self, pos: &Pos, err: Error)
+    {
+        match err {
+            Error::Parser(pos2, msg) => self.warnings.push(Warning::new(pos2, msg)),
+            err => self.warn(pos, format!("{}", err)),
+        }
+    }
+
+    fn mod_for_idents(&self, idents: &[String]) -> Result<Arc<RwLock<ModNode<Sym, ()>>>>
+    {
+        match ModNode::mod_from(&self.root_mod, idents, false)? {
+            Some(mod1) => Ok(mod1),
+            None => Err(Error::Interp(String::from("undefined module"))),
+        }
+    }
+
+    fn add_script_for_mod(&mut self, path: &Path, pos: Option<&Pos>, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, mod_idents: Vec<String>, domain: Option<&str>) -> Result<()>
+    {
+        let key = (path.to_path_buf(), mod_idents.clone());
+        if self.script_keys.contains(&key) {
+            return Ok(());
+        }
+        self.script_keys.insert(key);
+        match parse(path) {
+            Ok(tree) => {
+                let script_dir = match path.parent() {
+                    Some(tmp_script_dir) => tmp_script_dir.to_path_buf(),
+                    None => PathBuf::from("."),
+                };
+                self.collect_script(tree, mod1, mod_idents, script_dir.as_path(), domain)
+            },
+            Err(err) => {
+                let pos = match pos {
+                    Some(tmp_pos) => tmp_pos.clone(),
+                    None => Pos::new(Arc::new(path.to_string_lossy().into_owned()), 1, 1),
+                };
+                self.warn_error(&pos, err);
+                Ok(())
+            },
+        }
+    }
+
+    fn use_lib(&mut self, lib_name: &str, pos: &Pos, domain: Option<&str>) -> Result<()>
+    {
+        let lib_name = if lib_name.contains('/') {
+            String::from(lib_name)
+        } else {
+            match domain {
+                Some(domain) => format!("{}/{}", domain, lib_name),
+                None => {
+                    self.warn(pos, String::from("name library without domain"));
+                    return Ok(());
+                },
+            }
+        };
+        if self.used_libs.contains(&lib_name) {
+            return Ok(());
+        }
+        for dir in std::env::split_paths(self.lib_path.as_os_str()) {
+            let mut path_buf = dir.clone();
+            path_buf.push(lib_name.replace('/', path::MAIN_SEPARATOR_STR));
+            path_buf.push("lib.un");
+            if path_buf.is_file() {
+                self.used_libs.insert(lib_name.clone());
+                let lib_domain = match lib_name.split_once('/') {
+                    Some((tmp_lib_domain, _)) => String::from(tmp_lib_domain),
+                    None => String::new(),
+                };
+                let root_mod = self.root_mod.clone();
+                return self.add_script_for_mod(path_buf.as_path(), Some(pos), &root_mod, Vec::new(), Some(lib_domain.as_str()));
+            }
+        }
+        self.warn(pos, format!("not found library {}", lib_name));
+        Ok(())
+    }
+
+    fn collect_script(&mut self, tree: Tree, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, mod_idents: Vec<String>, script_dir: &Path, domain: Option<&str>) -> Result<()>
+    {
+        let mut tmp_mod_idents = mod_idents.clone();
+        match &tree {
+            Tree(nodes) => self.collect_nodes(nodes.as_slice(), mod1, &mut tmp_mod_idents, script_dir, domain)?,
+        }
+        self.scripts.push(Script { tree, mod_idents, });
+        Ok(())
+    }
+
+    fn collect_nodes(&mut self, nodes: &[Node], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, mod_idents: &mut Vec<String>, script_dir: &Path, domain: Option<&str>) -> Result<()>
+    {
+        for node in nodes {
+            match node {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Mod(ident, mod2, _) => {
+                            let child = {
+                                let mod1_g = rw_lock_read(&**mod1)?;
+                                mod1_g.mod1(ident).map(|m| m.clone())
+                            };
+                            let child = match child {
+                                Some(tmp_child) => tmp_child,
+                                None => {
+                                    let tmp_child = Arc::new(RwLock::new(ModNode::new(())));
+                                    ModNode::add_mod(mod1, ident.clone(), tmp_child.clone())?;
+                                    tmp_child
+                                },
+                            };
+                            mod_idents.push(ident.clone());
+                            match &**mod2 {
+                                Mod(nodes2) => self.collect_nodes(nodes2.as_slice(), &child, mod_idents, script_dir, domain)?,
+                            }
+                            mod_idents.pop();
+                        },
+                        Def::Fun(ident, fun, _) => {
+                            let mut mod1_g = rw_lock_write(&**mod1)?;
+                            match mod1_g.var(ident) {
+                                Some(Sym::Builtin) => (),
+                                _ => mod1_g.add_var(ident.clone(), Sym::Fun(fun.clone())),
+                            }
+                        },
+                    }
+                },
+                Node::Stat(stat) => self.collect_stat(&**stat, mod1, mod_idents, script_dir, domain)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_stats(&mut self, stats: &[Box<Stat>], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, mod_idents: &mut Vec<String>, script_dir: &Path, domain: Option<&str>) -> Result<()>
+    {
+        for stat in stats {
+            self.collect_stat(&**stat, mod1, mod_idents, script_dir, domain)?;
+        }
+        Ok(())
+    }
+
+    fn collect_stat(&mut self, stat: &Stat, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, mod_idents: &mut Vec<String>, script_dir: &Path, domain: Option<&str>) -> Result<()>
+    {
+        match stat {
+            Stat::Expr(expr, _) => {
+                match fun_call_with_string(&**expr) {
+                    Some((fun_ident, name, pos)) if fun_ident == "run" || fun_ident == "runwithdoc" => {
+                        let mut path_buf = script_dir.to_path_buf();
+                        path_buf.push(name.replace('/', path::MAIN_SEPARATOR_STR));
+                        self.add_script_for_mod(path_buf.as_path(), Some(pos), mod1, mod_idents.clone(), domain)?;
+                    },
+                    Some((fun_ident, name, pos)) if fun_ident == "uselib" || fun_ident == "reuselib" => {
+                        self.use_lib(name, pos, domain)?;
+                    },
+                    _ => (),
+                }
+            },
+            Stat::Assign(expr, _, _) => {
+                match &**expr {
+                    Expr::Var(Name::Var(ident), _) => add_var_if_undefined(mod1, ident)?,
+                    Expr::Var(Name::Rel(idents, ident), _) if idents.is_empty() => add_var_if_undefined(mod1, ident)?,
+                    Expr::Var(Name::Rel(idents, ident), _) => {
+                        match ModNode::mod_from(mod1, idents.as_slice(), true)? {
+                            Some(mod2) => add_var_if_undefined(&mod2, ident)?,
+                            None => (),
+                        }
+                    },
+                    Expr::Var(Name::Abs(idents, ident), _) => {
+                        match ModNode::mod_from(&self.root_mod, idents.as_slice(), false)? {
+                            Some(mod2) => add_var_if_undefined(&mod2, ident)?,
+                            None => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            Stat::If(_, stats, else_if_pairs, else_stats, _) => {
+                self.collect_stats(stats.as_slice(), mod1, mod_idents, script_dir, domain)?;
+                for (_, else_if_stats) in else_if_pairs {
+                    self.collect_stats(else_if_stats.as_slice(), mod1, mod_idents, script_dir, domain)?;
+                }
+                match else_stats {
+                    Some(else_stats) => self.collect_stats(else_stats.as_slice(), mod1, mod_idents, script_dir, domain)?,
+                    None => (),
+                }
+            },
+            Stat::For(ident, _, stats, _) => {
+                add_var_if_undefined(mod1, ident)?;
+                self.collect_stats(stats.as_slice(), mod1, mod_idents, script_dir, domain)?;
+            },
+            Stat::While(_, stats, _) => self.collect_stats(stats.as_slice(), mod1, mod_idents, script_dir, domain)?,
+            Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                self.collect_stats(try_stats.as_slice(), mod1, mod_idents, script_dir, domain)?;
+                match catch_pair {
+                    Some((ident, catch_stats)) => {
+                        add_var_if_undefined(mod1, ident)?;
+                        self.collect_stats(catch_stats.as_slice(), mod1, mod_idents, script_dir, domain)?;
+                    },
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => self.collect_stats(finally_stats.as_slice(), mod1, mod_idents, script_dir, domain)?,
+                    None => (),
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn import_nodes(&mut self, nodes: &[Node], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, root_mod: &Arc<RwLock<ModNode<Sym, ()>>>) -> Result<()>
+    {
+        for node in nodes {
+            match node {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Mod(ident, mod2, _) => {
+                            let child = {
+                                let mod1_g = rw_lock_read(&**mod1)?;
+                                mod1_g.mod1(ident).map(|m| m.clone())
+                            };
+                            match (child, &**mod2) {
+                                (Some(child), Mod(nodes2)) => self.import_nodes(nodes2.as_slice(), &child, root_mod)?,
+                                (None, _) => (),
+                            }
+                        },
+                        Def::Fun(_, _, _) => (),
+                    }
+                },
+                Node::Stat(stat) => self.import_stat(&**stat, mod1, root_mod)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn import_stats(&mut self, stats: &[Box<Stat>], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, root_mod: &Arc<RwLock<ModNode<Sym, ()>>>) -> Result<()>
+    {
+        for stat in stats {
+            self.import_stat(&**stat, mod1, root_mod)?;
+        }
+        Ok(())
+    }
+
+    fn import_stat(&mut self, stat: &Stat, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, root_mod: &Arc<RwLock<ModNode<Sym, ()>>>) -> Result<()>
+    {
+        match stat {
+            Stat::Expr(expr, _) => {
+                match &**expr {
+                    Expr::App(expr2, exprs, _, pos) => {
+                        let fun_ident = match &**expr2 {
+                            Expr::Var(Name::Var(tmp_fun_ident), _) => tmp_fun_ident.as_str(),
+                            _ => return Ok(()),
+                        };
+                        if fun_ident != "usemod" && fun_ident != "usemods" && fun_ident != "usevar" && fun_ident != "usevars" {
+                            return Ok(());
+                        }
+                        let mut ss: Vec<&str> = Vec::new();
+                        for expr3 in exprs {
+                            match &**expr3 {
+                                Expr::Lit(Lit::String(s), _) => ss.push(s.as_str()),
+                                _ => return Ok(()),
+                            }
+                        }
+                        self.import(fun_ident, ss.as_slice(), pos, mod1, root_mod)?;
+                    },
+                    _ => (),
+                }
+            },
+            Stat::If(_, stats, else_if_pairs, else_stats, _) => {
+                self.import_stats(stats.as_slice(), mod1, root_mod)?;
+                for (_, else_if_stats) in else_if_pairs {
+                    self.import_stats(else_if_stats.as_slice(), mod1, root_mod)?;
+                }
+                match else_stats {
+                    Some(else_stats) => self.import_stats(else_stats.as_slice(), mod1, root_mod)?,
+                    None => (),
+                }
+            },
+            Stat::For(_, _, stats, _) => self.import_stats(stats.as_slice(), mod1, root_mod)?,
+            Stat::While(_, stats, _) => self.import_stats(stats.as_slice(), mod1, root_mod)?,
+            Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                self.import_stats(try_stats.as_slice(), mod1, root_mod)?;
+                match catch_pair {
+                    Some((_, catch_stats)) => self.import_stats(catch_stats.as_slice(), mod1, root_mod)?,
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => self.import_stats(finally_stats.as_slice(), mod1, root_mod)?,
+                    None => (),
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn import(&mut self, fun_ident: &str, ss: &[&str], pos: &Pos, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, root_mod: &Arc<RwLock<ModNode<Sym, ()>>>) -> Result<()>
+    {
+        let name = match ss.first() {
+            Some(tmp_name) => *tmp_name,
+            None => return Ok(()),
+        };
+        let is_var = fun_ident == "usevar";
+        let (used_mod, idents) = match mod_pair_for_name(mod1, root_mod, name, is_var)? {
+            Some(tmp_pair) => tmp_pair,
+            None => {
+                self.warn(pos, format!("undefined module {}", name));
+                return Ok(());
+            },
+        };
+        match fun_ident {
+            "usemod" => {
+                let ident = match (ss.get(1), idents.last()) {
+                    (Some(tmp_ident), _) => String::from(*tmp_ident),
+                    (None, Some(tmp_ident)) => tmp_ident.clone(),
+                    (None, None) => return Ok(()),
+                };
+                ModNode::add_used_mod(mod1, ident, used_mod)?;
+            },
+            "usemods" => {
+                let mods: Vec<(String, Arc<RwLock<ModNode<Sym, ()>>>)> = {
+                    let used_mod_g = rw_lock_read(&*used_mod)?;
+                    used_mod_g.mods().iter().map(|p| (p.0.clone(), p.1.clone())).collect()
+                };
+                for (ident, mod2) in mods {
+                    ModNode::add_used_mod(mod1, ident, mod2)?;
+                }
+            },
+            "usevar" => {
+                let used_var_ident = match idents.last() {
+                    Some(tmp_used_var_ident) => tmp_used_var_ident.clone(),
+                    None => return Ok(()),
+                };
+                let is_defined_var = {
+                    let used_mod_g = rw_lock_read(&*used_mod)?;
+                    used_mod_g.has_var(&used_var_ident)
+                };
+                if !is_defined_var {
+                    self.warn(pos, format!("undefined variable {}", name));
+                    return Ok(());
+                }
+                let ident = match ss.get(1) {
+                    Some(tmp_ident) => String::from(*tmp_ident),
+                    None => used_var_ident.clone(),
+                };
+                ModNode::add_used_var(mod1, ident, used_mod, used_var_ident)?;
+            },
+            _ => {
+                let idents2: Vec<String> = {
+                    let used_mod_g = rw_lock_read(&*used_mod)?;
+                    used_mod_g.vars().keys().map(|ident| ident.clone()).collect()
+                };
+                for ident in idents2 {
+                    ModNode::add_used_var(mod1, ident.clone(), used_mod.clone(), ident)?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn lint_nodes(&mut self, nodes: &[Node], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>, loop_count: usize) -> Result<()>
+    {
+        for node in nodes {
+            match node {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Mod(ident, mod2, _) => {
+                            let child = {
+                                let mod1_g = rw_lock_read(&**mod1)?;
+                                mod1_g.mod1(ident).map(|m| m.clone())
+                            };
+                            match (child, &**mod2) {
+                                (Some(child), Mod(nodes2)) => self.lint_nodes(nodes2.as_slice(), &child, frames, loop_count)?,
+                                (None, _) => (),
+                            }
+                        },
+                        Def::Fun(ident, fun, pos) => {
+                            if Arc::ptr_eq(mod1, &self.root_mod) && self.is_builtin(ident)? {
+                                self.warn(pos, format!("assignment to built-in name {}", ident));
+                            }
+                            let mut fun_frames: Vec<BTreeMap<String, LocalVar>> = Vec::new();
+                            self.lint_fun(&**fun, mod1, &mut fun_frames)?;
+                        },
+                    }
+                },
+                Node::Stat(stat) => self.lint_stat(&**stat, mod1, frames, loop_count)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn lint_fun(&mut self, fun: &Fun, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        match fun {
+            Fun(args, stats) => {
+                let mut frame: BTreeMap<String, LocalVar> = BTreeMap::new();
+                for arg in args {
+                    match arg {
+                        Arg(ident, _, pos) => {
+                            frame.insert(ident.clone(), LocalVar { pos: pos.clone(), is_arg: true, is_used: false, });
+                        },
+                    }
+                }
+                add_local_vars_for_stats(&mut frame, stats.as_slice());
+                frames.push(frame);
+                for arg in args {
+                    match arg {
+                        Arg(_, ArgKind::Default(expr), _) => self.lint_expr(&**expr, mod1, frames)?,
+                        _ => (),
+                    }
+                }
+                self.lint_stats(stats.as_slice(), mod1, frames, 0)?;
+                match frames.pop() {
+                    Some(frame) => {
+                        for (ident, local_var) in &frame {
+                            if !local_var.is_arg && !local_var.is_used && !ident.starts_with('_') {
+                                self.warn(&local_var.pos, format!("unused local variable {}", ident));
+                            }
+                        }
+                    },
+                    None => (),
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn lint_stats(&mut self, stats: &[Box<Stat>], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>, loop_count: usize) -> Result<()>
+    {
+        for stat in stats {
+            self.lint_stat(&**stat, mod1, frames, loop_count)?;
+        }
+        Ok(())
+    }
+
+    fn lint_stat(&mut self, stat: &Stat, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>, loop_count: usize) -> Result<()>
+    {
+        match stat {
+            Stat::Expr(expr, _) => self.lint_expr(&**expr, mod1, frames)?,
+            Stat::Assign(expr, expr2, _) => {
+                self.lint_expr(&**expr2, mod1, frames)?;
+                match &**expr {
+                    Expr::Var(Name::Var(ident), pos) => {
+                        if self.is_builtin(ident)? {
+                            self.warn(pos, format!("assignment to built-in name {}", ident));
+                        }
+                    },
+                    Expr::Var(Name::Rel(idents, _), pos) if !idents.is_empty() => {
+                        if self.mod_for_rel_idents(idents.as_slice(), mod1)?.is_none() {
+                            self.warn(pos, format!("undefined module {}", idents.join("::")));
+                        }
+                    },
+                    Expr::Var(Name::Abs(idents, _), pos) => {
+                        if ModNode::mod_from(&self.root_mod, idents.as_slice(), false)?.is_none() {
+                            self.warn(pos, format!("undefined module root::{}", idents.join("::")));
+                        }
+                    },
+                    Expr::Var(_, _) => (),
+                    Expr::BinOp(BinOp::Index, expr3, expr4, _) => {
+                        self.lint_expr(&**expr3, mod1, frames)?;
+                        self.lint_expr(&**expr4, mod1, frames)?;
+                    },
+                    Expr::Field(expr3, _, _) => self.lint_expr(&**expr3, mod1, frames)?,
+                    Expr::Index2(_, _, _, _) => self.lint_expr(&**expr, mod1, frames)?,
+                    _ => self.warn(expr.pos(), String::from("expression isn't assignable")),
+                }
+            },
+            Stat::If(expr, stats, else_if_pairs, else_stats, _) => {
+                self.lint_expr(&**expr, mod1, frames)?;
+                self.lint_stats(stats.as_slice(), mod1, frames, loop_count)?;
+                for (else_if_expr, else_if_stats) in else_if_pairs {
+                    self.lint_expr(&**else_if_expr, mod1, frames)?;
+                    self.lint_stats(else_if_stats.as_slice(), mod1, frames, loop_count)?;
+                }
+                match else_stats {
+                    Some(else_stats) => self.lint_stats(else_stats.as_slice(), mod1, frames, loop_count)?,
+                    None => (),
+                }
+            },
+            Stat::For(ident, expr, stats, pos) => {
+                self.lint_expr(&**expr, mod1, frames)?;
+                if self.is_builtin(ident)? {
+                    self.warn(pos, format!("assignment to built-in name {}", ident));
+                }
+                self.lint_stats(stats.as_slice(), mod1, frames, loop_count + 1)?;
+            },
+            Stat::While(expr, stats, _) => {
+                self.lint_expr(&**expr, mod1, frames)?;
+                self.lint_stats(stats.as_slice(), mod1, frames, loop_count + 1)?;
+            },
+            Stat::Break(pos) => {
+                if loop_count == 0 {
+                    self.warn(pos, String::from("break isn't in loop"));
+                }
+            },
+            Stat::Continue(pos) => {
+                if loop_count == 0 {
+                    self.warn(pos, String::from("continue isn't in loop"));
+                }
+            },
+            Stat::Return(expr, pos) => {
+                match expr {
+                    Some(expr) => self.lint_expr(&**expr, mod1, frames)?,
+                    None => (),
+                }
+                if frames.is_empty() {
+                    self.warn(pos, String::from("return isn't in function"));
+                }
+            },
+            Stat::Quit(_) => (),
+            Stat::Try(try_stats, catch_pair, finally_stats, _) => {
+                self.lint_stats(try_stats.as_slice(), mod1, frames, loop_count)?;
+                match catch_pair {
+                    Some((_, catch_stats)) => self.lint_stats(catch_stats.as_slice(), mod1, frames, loop_count)?,
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => self.lint_stats(finally_stats.as_slice(), mod1, frames, loop_count)?,
+                    None => (),
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn lint_exprs(&mut self, exprs: &[Box<Expr>], mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        for expr in exprs {
+            self.lint_expr(&**expr, mod1, frames)?;
+        }
+        Ok(())
+    }
+
+    fn lint_expr(&mut self, expr: &Expr, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        match expr {
+            Expr::Lit(lit, _) => self.lint_lit(lit, mod1, frames)?,
+            Expr::Var(name, pos) => {
+                match self.sym_for_name(name, mod1, frames)? {
+                    Some(_) => (),
+                    None => self.warn(pos, format!("undefined variable {}", name)),
+                }
+            },
+            Expr::App(expr2, exprs, named_args, pos) => {
+                self.lint_expr(&**expr2, mod1, frames)?;
+                self.lint_exprs(exprs.as_slice(), mod1, frames)?;
+                for named_arg in named_args {
+                    match named_arg {
+                        NamedArg(_, expr3, _) => self.lint_expr(&**expr3, mod1, frames)?,
+                    }
+                }
+                match &**expr2 {
+                    Expr::Var(name, _) => {
+                        match self.sym_for_name(name, mod1, frames)? {
+                            Some(Some(Sym::Fun(fun))) => {
+                                match check_args(&*fun, exprs.len(), named_args.as_slice()) {
+                                    Some(msg) => self.warn(pos, format!("{} for function {}", msg, name)),
+                                    None => (),
+                                }
+                            },
+                            _ => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            Expr::UnaryOp(_, expr2, _) => self.lint_expr(&**expr2, mod1, frames)?,
+            Expr::BinOp(_, expr2, expr3, _) | Expr::And(expr2, expr3, _) | Expr::Or(expr2, expr3, _) => {
+                self.lint_expr(&**expr2, mod1, frames)?;
+                self.lint_expr(&**expr3, mod1, frames)?;
+            },
+            Expr::Field(expr2, _, _) => self.lint_expr(&**expr2, mod1, frames)?,
+            Expr::Index2(expr2, expr3, expr4, _) => {
+                self.lint_expr(&**expr2, mod1, frames)?;
+                self.lint_index(expr3, mod1, frames)?;
+                self.lint_index(expr4, mod1, frames)?;
+            },
+            Expr::Range(expr2, expr3, expr4, _) => {
+                self.lint_expr(&**expr2, mod1, frames)?;
+                self.lint_expr(&**expr3, mod1, frames)?;
+                match expr4 {
+                    Some(expr4) => self.lint_expr(&**expr4, mod1, frames)?,
+                    None => (),
+                }
+            },
+            Expr::PropagateError(expr2, _) => self.lint_expr(&**expr2, mod1, frames)?,
+            Expr::Lambda(fun, _) => self.lint_fun(&**fun, mod1, frames)?,
+        }
+        Ok(())
+    }
+
+    fn lint_index(&mut self, expr: &Option<Box<Expr>>, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        match expr {
+            Some(expr) => self.lint_expr(&**expr, mod1, frames),
+            None => Ok(()),
+        }
+    }
+
+    fn lint_lit(&mut self, lit: &Lit, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        match lit {
+            Lit::Matrix(rows) => {
+                for row in rows {
+                    self.lint_matrix_row(row, mod1, frames)?;
+                }
+            },
+            Lit::FilledMatrix(row, expr) => {
+                self.lint_matrix_row(row, mod1, frames)?;
+                self.lint_expr(&**expr, mod1, frames)?;
+            },
+            Lit::Array(exprs) => self.lint_exprs(exprs.as_slice(), mod1, frames)?,
+            Lit::FilledArray(expr, expr2) => {
+                self.lint_expr(&**expr, mod1, frames)?;
+                self.lint_expr(&**expr2, mod1, frames)?;
+            },
+            Lit::Struct(field_pairs) => {
+                for field_pair in field_pairs {
+                    match field_pair {
+                        FieldPair(_, expr, _) => self.lint_expr(&**expr, mod1, frames)?,
+                    }
+                }
+            },
+            Lit::Dict(dict_pairs) => {
+                for dict_pair in dict_pairs {
+                    match dict_pair {
+                        DictPair(expr, expr2, _) => {
+                            self.lint_expr(&**expr, mod1, frames)?;
+                            self.lint_expr(&**expr2, mod1, frames)?;
+                        },
+                    }
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn lint_matrix_row(&mut self, row: &MatrixRow, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<()>
+    {
+        match row {
+            MatrixRow::Row(exprs) => self.lint_exprs(exprs.as_slice(), mod1, frames),
+            MatrixRow::FilledRow(expr, expr2) => {
+                self.lint_expr(&**expr, mod1, frames)?;
+                self.lint_expr(&**expr2, mod1, frames)
+            },
+        }
+    }
+
+    fn is_builtin(&self, ident: &String) -> Result<bool>
+    {
+        let root_mod_g = rw_lock_read(&*self.root_mod)?;
+        match root_mod_g.var(ident) {
+            Some(Sym::Builtin) => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    fn mod_for_rel_idents(&self, idents: &[String], mod1: &Arc<RwLock<ModNode<Sym, ()>>>) -> Result<Option<Arc<RwLock<ModNode<Sym, ()>>>>>
+    {
+        match ModNode::mod_from(mod1, idents, true)? {
+            Some(mod2) => Ok(Some(mod2)),
+            None => ModNode::mod_from(&self.root_mod, idents, false),
+        }
+    }
+
+    /// Returns `Some(None)` for a local variable, `Some(Some(sym))` for a module variable, and
+    /// `None` for an undefined variable.
+    fn sym_for_name(&mut self, name: &Name, mod1: &Arc<RwLock<ModNode<Sym, ()>>>, frames: &mut Vec<BTreeMap<String, LocalVar>>) -> Result<Option<Option<Sym>>>
+    {
+        match name {
+            Name::Abs(idents, ident) => {
+                match ModNode::mod_from(&self.root_mod, idents.as_slice(), false)? {
+                    Some(mod2) => {
+                        let mod2_g = rw_lock_read(&*mod2)?;
+                        Ok(mod2_g.var(ident).map(|sym| Some(sym.clone())))
+                    },
+                    None => Ok(None),
+                }
+            },
+            Name::Rel(idents, ident) => {
+                let mod2 = if idents.is_empty() {
+                    mod1.clone()
+                } else {
+                    match self.mod_for_rel_idents(idents.as_slice(), mod1)? {
+                        Some(tmp_mod2) => tmp_mod2,
+                        None => return Ok(None),
+                    }
+                };
+                let sym = mod_var(&mod2, ident, !idents.is_empty())?;
+                Ok(sym.map(Some))
+            },
+            Name::Var(ident) => {
+                for frame in frames.iter_mut().rev() {
+                    match frame.get_mut(ident) {
+                        Some(local_var) => {
+                            local_var.is_used = true;
+                            return Ok(Some(None));
+                        },
+                        None => (),
+                    }
+                }
+                match mod_var(mod1, ident, false)? {
+                    Some(sym) => Ok(Some(Some(sym))),
+                    None => {
+                        let root_mod_g = rw_lock_read(&*self.root_mod)?;
+                        Ok(root_mod_g.var(ident).map(|sym| Some(sym.clone())))
+                    },
+                }
+            },
+        }
+    }
+}
+
+fn compare_poses(pos1: &Pos, pos2: &Pos) -> Ordering
+{ (&*pos1.path, pos1.line, pos1.column).cmp(&(&*pos2.path, pos2.line, pos2.column)) }
+
+fn add_var_if_undefined(mod1: &Arc<RwLock<ModNode<Sym, ()>>>, ident: &String) -> Result<()>
+{
+    let mut mod1_g = rw_lock_write(&**mod1)?;
+    if !mod1_g.has_var(ident) {
+        mod1_g.add_var(ident.clone(), Sym::Var);
+    }
+    Ok(())
+}
+
+fn mod_var(mod1: &Arc<RwLock<ModNode<Sym, ()>>>, ident: &String, is_only_var: bool) -> Result<Option<Sym>>
+{
+    let mod1_g = rw_lock_read(&**mod1)?;
+    match mod1_g.var(ident) {
+        Some(sym) => Ok(Some(sym.clone())),
+        None if is_only_var => Ok(None),
+        None => {
+            match mod1_g.used_var(ident) {
+                Some(used_var) => {
+                    match used_var.mod1().to_arc() {
+                        Some(used_var_mod) => {
+                            let used_var_mod_g = rw_lock_read(&*used_var_mod)?;
+                            Ok(used_var_mod_g.var(used_var.ident()).map(|sym| sym.clone()))
+                        },
+                        None => Ok(None),
+                    }
+                },
+                None => Ok(None),
+            }
+        },
+    }
+}
+
+fn mod_pair_for_name(mod1: &Arc<RwLock<ModNode<Sym, ()>>>, root_mod: &Arc<RwLock<ModNode<Sym, ()>>>, name: &str, is_var: bool) -> Result<Option<(Arc<RwLock<ModNode<Sym, ()>>>, Vec<String>)>>
+{
+    let name_without_first_colons = match name.strip_prefix("::") {
+        Some(tmp_name) => tmp_name,
+        None => name,
+    };
+    let idents: Vec<String> = name_without_first_colons.split("::").map(String::from).collect();
+    let end = if is_var {
+        idents.len().saturating_sub(1)
+    } else {
+        idents.len()
+    };
+    match idents.first() {
+        Some(ident) if ident == "root" => {
+            match ModNode::mod_from(root_mod, &idents[1..end.max(1)], false)? {
+                Some(mod2) => Ok(Some((mod2, (&idents[1..]).to_vec()))),
+                None => Ok(None),
+            }
+        },
+        _ => {
+            match ModNode::mod_from(mod1, &idents[0..end], true)? {
+                Some(mod2) => Ok(Some((mod2, idents))),
+                None => {
+                    match ModNode::mod_from(root_mod, &idents[0..end], false)? {
+                        Some(mod2) => Ok(Some((mod2, idents))),
+                        None => Ok(None),
+                    }
+                },
+            }
+        },
+    }
+}
+
+fn fun_call_with_string(expr: &Expr) -> Option<(&str, &str, &Pos)>
+{
+    match expr {
+        Expr::App(expr2, exprs, _, pos) if exprs.len() == 1 => {
+            match (&**expr2, &*exprs[0]) {
+                (Expr::Var(Name::Var(fun_ident), _), Expr::Lit(Lit::String(s), _)) => Some((fun_ident.as_str(), s.as_str(), pos)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn add_local_var(frame: &mut BTreeMap<String, LocalVar>, ident: &String, pos: &Pos, is_used: bool)
+{
+    match frame.get_mut(ident) {
+        Some(local_var) => local_var.is_used |= is_used,
+        None => {
+            frame.insert(ident.clone(), LocalVar { pos: pos.clone(), is_arg: false, is_used, });
+        },
+    }
+}
+
+fn add_local_vars_for_stats(frame: &mut BTreeMap<String, LocalVar>, stats: &[Box<Stat>])
+{
+    for stat in stats {
+        match &**stat {
+            Stat::Assign(expr, _, _) => {
+                match &**expr {
+                    Expr::Var(Name::Var(ident), pos) => add_local_var(frame, ident, pos, false),
+                    _ => (),
+                }
+            },
+            Stat::If(_, stats2, else_if_pairs, else_stats, _) => {
+                add_local_vars_for_stats(frame, stats2.as_slice());
+                for (_, else_if_stats) in else_if_pairs {
+                    add_local_vars_for_stats(frame, else_if_stats.as_slice());
+                }
+                match else_stats {
+                    Some(else_stats) => add_local_vars_for_stats(frame, else_stats.as_slice()),
+                    None => (),
+                }
+            },
+            Stat::For(ident, _, stats2, pos) => {
+                add_local_var(frame, ident, pos, true);
+                add_local_vars_for_stats(frame, stats2.as_slice());
+            },
+            Stat::While(_, stats2, _) => add_local_vars_for_stats(frame, stats2.as_slice()),
+            Stat::Try(try_stats, catch_pair, finally_stats, pos) => {
+                add_local_vars_for_stats(frame, try_stats.as_slice());
+                match catch_pair {
+                    Some((ident, catch_stats)) => {
+                        add_local_var(frame, ident, pos, true);
+                        add_local_vars_for_stats(frame, catch_stats.as_slice());
+                    },
+                    None => (),
+                }
+                match finally_stats {
+                    Some(finally_stats) => add_local_vars_for_stats(frame, finally_stats.as_slice()),
+                    None => (),
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+fn check_args(fun: &Fun, arg_count: usize, named_args: &[NamedArg]) -> Option<String>
+{
+    match fun {
+        Fun(args, _) => {
+            let is_rest = args.iter().any(|arg| match arg { Arg(_, ArgKind::Rest, _) => true, _ => false, });
+            if !is_rest && arg_count > args.len() {
+                return Some(String::from("too many arguments"));
+            }
+            let mut set_idents: HashSet<&String> = HashSet::new();
+            for arg in args.iter().take(arg_count) {
+                match arg {
+                    Arg(ident, ArgKind::Normal | ArgKind::Default(_), _) => {
+                        set_idents.insert(ident);
+                    },
+                    Arg(_, ArgKind::Rest, _) => break,
+                }
+            }
+            for named_arg in named_args {
+                match named_arg {
+                    NamedArg(ident, _, _) => {
+                        match args.iter().find(|arg| &arg.0 == ident) {
+                            Some(Arg(_, ArgKind::Normal | ArgKind::Default(_), _)) => {
+                                if set_idents.contains(ident) {
+                                    return Some(format!("already set argument {}", ident));
+                                }
+                                set_idents.insert(ident);
+                            },
+                            Some(Arg(_, ArgKind::Rest, _)) | None => return Some(format!("no argument {}", ident)),
+                        }
+                    },
+                }
+            }
+            for arg in args {
+                match arg {
+                    Arg(ident, ArgKind::Normal, _) => {
+                        if !set_idents.contains(ident) {
+                            return Some(String::from("too few arguments"));
+                        }
+                    },
+                    _ => (),
+                }
+            }
+            None
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,186 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io::Cursor;
+use crate::builtins::*;
+use crate::doc::*;
+use crate::lexer::*;
+use super::*;
+
+fn parse_str(s: &str) -> Tree
+{
+    let mut cursor = Cursor::new(s.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(tree) => tree,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn lint_str(s: &str) -> Vec<(u64, usize, String)>
+{
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    add_std_builtin_funs(&mut root_mod);
+    let mut linter = Linter::new(&root_mod, OsString::new());
+    match linter.add_tree(parse_str(s), Path::new("."), None) {
+        Ok(()) => (),
+        Err(err) => panic!("{}", err),
+    }
+    match linter.lint() {
+        Ok(warnings) => warnings.iter().map(|w| (w.pos().line, w.pos().column, w.msg().clone())).collect(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn test_linter_lint_returns_no_warnings_for_correct_script()
+{
+    let s = "
+module m
+    function f(x, y = 2, zs...)
+        z = x + y
+        for i in zs
+            z = z + i
+        end
+        return z
+    end
+end
+usemod(\"m\", \"n\")
+usevar(\"m::f\")
+X = f(1, 2, 3)
+Y = n::f(1, y = X)
+Z = root::m::f(1)
+while true
+    if X > 1
+        break
+    end
+    continue
+end
+println(filter(function(a) a > Y end, .[1, 2, Z .]))
+";
+    assert_eq!(Vec::<(u64, usize, String)>::new(), lint_str(&s[1..]));
+}
+
+#[test]
+fn test_linter_lint_complains_on_undefined_variables_and_modules()
+{
+    let s = "
+function f()
+    return a + g(b)
+end
+X = m::c
+Y = root::n::d
+usemod(\"o\")
+";
+    let expected = vec![
+        (2, 12, String::from("undefined variable a")),
+        (2, 16, String::from("undefined variable g")),
+        (2, 18, String::from("undefined variable b")),
+        (4, 5, String::from("undefined variable m::c")),
+        (5, 5, String::from("undefined variable root::n::d")),
+        (6, 1, String::from("undefined module o"))
+    ];
+    assert_eq!(expected, lint_str(&s[1..]));
+}
+
+#[test]
+fn test_linter_lint_complains_on_unused_local_variables()
+{
+    let s = "
+function f(x)
+    y = 1
+    _z = 2
+    w = 3
+    F = function(a)
+        b = a
+        w
+    end
+    F
+end
+";
+    let expected = vec![
+        (2, 5, String::from("unused local variable y")),
+        (6, 9, String::from("unused local variable b"))
+    ];
+    assert_eq!(expected, lint_str(&s[1..]));
+}
+
+#[test]
+fn test_linter_lint_complains_on_assignments_to_builtin_names()
+{
+    let s = "
+println = 1
+function f()
+    for length in 1 to 2
+        X = length
+    end
+    X
+end
+function zeros()
+end
+";
+    let expected = vec![
+        (1, 1, String::from("assignment to built-in name println")),
+        (3, 5, String::from("assignment to built-in name length")),
+        (8, 1, String::from("assignment to built-in name zeros"))
+    ];
+    assert_eq!(expected, lint_str(&s[1..]));
+}
+
+#[test]
+fn test_linter_lint_complains_on_break_and_continue_outside_loops()
+{
+    let s = "
+break
+function f()
+    while true
+        G = function()
+            continue
+        end
+        G
+    end
+    return 1
+end
+return
+";
+    let expected = vec![
+        (1, 1, String::from("break isn't in loop")),
+        (5, 13, String::from("continue isn't in loop")),
+        (11, 1, String::from("return isn't in function"))
+    ];
+    assert_eq!(expected, lint_str(&s[1..]));
+}
+
+#[test]
+fn test_linter_lint_complains_on_wrong_number_of_arguments()
+{
+    let s = "
+function f(x, y = 2)
+    x + y
+end
+function g(xs...)
+    xs
+end
+f()
+f(1, 2, 3)
+f(1, z = 2)
+f(1, x = 2)
+f(y = 1)
+g(1, 2, 3)
+";
+    let expected = vec![
+        (7, 1, String::from("too few arguments for function f")),
+        (8, 1, String::from("too many arguments for function f")),
+        (9, 1, String::from("no argument z for function f")),
+        (10, 1, String::from("already set argument x for function f")),
+        (11, 1, String::from("too few arguments for function f"))
+    ];
+    assert_eq!(expected, lint_str(&s[1..]));
+}
@@ -0,0 +1,1032 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of language server.
+//!
+//! The language server is a server of the Language Server Protocol that provides diagnostics,
+//! definitions, hovers, completions, and document symbols for scripts in editors.
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::Cursor;
+use std::io::Write;
+use std::path;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::RwLock;
+use crate::serde_json;
+use crate::serde_json::json;
+use crate::doc::*;
+use crate::error::*;
+use crate::lexer::*;
+use crate::mod_node::*;
+use crate::parser::*;
+use crate::tree::*;
+use crate::utils::*;
+
+#[derive(Clone, Debug)]
+struct Request
+{
+    id: Option<serde_json::Value>,
+    method: String,
+    params: serde_json::Value,
+}
+
+impl Request
+{
+    fn from_message(msg: &serde_json::Value) -> Option<Self>
+    {
+        match msg["method"].as_str() {
+            Some(method) => {
+                let id = match msg.get("id") {
+                    Some(id) if !id.is_null() => Some(id.clone()),
+                    _ => None,
+                };
+                Some(Request { id, method: String::from(method), params: msg["params"].clone(), })
+            },
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Index
+{
+    def_root_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>>,
+    doc_tree: Option<DocTree>,
+}
+
+#[derive(Clone, Debug)]
+struct Document
+{
+    path: Arc<String>,
+    text: String,
+    tree: Option<Tree>,
+    index: Option<Index>,
+}
+
+#[derive(Clone, Debug)]
+struct DefGen
+{
+    current_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>>,
+    script_dir: PathBuf,
+}
+
+impl DefGen
+{
+    fn new(root_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>>, script_dir: PathBuf) -> Self
+    { DefGen { current_mod: root_mod, script_dir, } }
+
+    fn generate_for_nodes(&mut self, nodes: &[Node]) -> Result<()>
+    {
+        let mut script_names: Vec<String> = Vec::new();
+        for node in nodes {
+            match node {
+                Node::Def(def) => self.generate_for_def(&**def)?,
+                Node::Stat(stat) => self.generate_for_stat(&**stat, &mut script_names)?,
+            }
+        }
+        for script_name in &script_names {
+            let mut path_buf = self.script_dir.clone();
+            path_buf.push(script_name.replace('/', path::MAIN_SEPARATOR_STR).as_str());
+            match parse(path_buf) {
+                Ok(Tree(nodes2)) => self.generate_for_nodes(nodes2.as_slice())?,
+                Err(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_for_def(&mut self, def: &Def) -> Result<()>
+    {
+        match def {
+            Def::Mod(ident, mod1, pos) => {
+                let mod2 = {
+                    let current_mod_g = rw_lock_read(&*self.current_mod)?;
+                    current_mod_g.mod1(ident).map(|m| m.clone())
+                };
+                let new_mod = match mod2 {
+                    Some(mod2) => mod2,
+                    None => {
+                        let new_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>> = Arc::new(RwLock::new(ModNode::new(Some(pos.clone()))));
+                        ModNode::add_mod(&self.current_mod, ident.clone(), new_mod.clone())?;
+                        new_mod
+                    },
+                };
+                let parent = self.current_mod.clone();
+                self.current_mod = new_mod;
+                let res = match &**mod1 {
+                    Mod(nodes) => self.generate_for_nodes(nodes.as_slice()),
+                };
+                self.current_mod = parent;
+                res
+            },
+            Def::Fun(ident, _, pos) => {
+                let mut current_mod_g = rw_lock_write(&*self.current_mod)?;
+                current_mod_g.add_var(ident.clone(), pos.clone());
+                Ok(())
+            },
+        }
+    }
+
+    fn generate_for_stat(&mut self, stat: &Stat, script_names: &mut Vec<String>) -> Result<()>
+    {
+        match stat {
+            Stat::Expr(expr, _) => {
+                match &**expr {
+                    Expr::App(expr2, exprs, _, _) => {
+                        let is_run_with_doc = match &**expr2 {
+                            Expr::Var(Name::Abs(idents, ident), _) => idents.is_empty() && ident == "runwithdoc",
+                            Expr::Var(Name::Var(ident), _) => ident == "runwithdoc",
+                            _ => false,
+                        };
+                        if is_run_with_doc {
+                            match exprs.first().map(|e| &**e) {
+                                Some(Expr::Lit(Lit::String(s), _)) => script_names.push(s.clone()),
+                                _ => (),
+                            }
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            Stat::Assign(expr, _, pos) => {
+                match &**expr {
+                    Expr::Var(Name::Var(ident), _) => {
+                        let mut current_mod_g = rw_lock_write(&*self.current_mod)?;
+                        if !current_mod_g.has_var(ident) {
+                            current_mod_g.add_var(ident.clone(), pos.clone());
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct NameAtPos
+{
+    is_abs: bool,
+    idents: Vec<String>,
+    ident: Option<String>,
+}
+
+impl NameAtPos
+{
+    fn mod_paths(&self, current_idents: &[String]) -> Vec<Vec<String>>
+    {
+        let mut paths: Vec<Vec<String>> = Vec::new();
+        if !self.is_abs {
+            let mut path = current_idents.to_vec();
+            path.extend(self.idents.iter().cloned());
+            paths.push(path);
+        }
+        if !paths.contains(&self.idents) {
+            paths.push(self.idents.clone());
+        }
+        paths
+    }
+}
+
+/// A language server structure.
+///
+/// The language server reads requests and notifications of the Language Server Protocol from a
+/// reader and writes responses and notifications to a writer. The language server uses open
+/// documents, libraries from a library path, and a documentation tree of built-in functions to
+/// find definitions, hovers, and completions.
+pub struct LspServer
+{
+    r: Box<dyn BufRead>,
+    w: Box<dyn Write>,
+    builtin_index: Index,
+    lib_path: OsString,
+    lib_indices: Vec<Index>,
+    docs: BTreeMap<String, Document>,
+    is_shutdown: bool,
+}
+
+impl LspServer
+{
+    /// Creates a language server.
+    ///
+    /// The language server reads requests and notifications from the reader and writes responses
+    /// and notifications to the writer. Also, this method takes a documentation tree of built-in
+    /// functions and a library path.
+    pub fn new(r: Box<dyn BufRead>, w: Box<dyn Write>, builtin_doc_tree: DocTree, lib_path: OsString) -> Self
+    {
+        let builtin_index = Index {
+            def_root_mod: Arc::new(RwLock::new(ModNode::new(None))),
+            doc_tree: Some(builtin_doc_tree),
+        };
+        LspServer {
+            r,
+            w,
+            builtin_index,
+            lib_path,
+            lib_indices: Vec::new(),
+            docs: BTreeMap::new(),
+            is_shutdown: false,
+        }
+    }
+
+    /// Runs the language server.
+    ///
+    /// The language server runs to the `exit` notification or an end of file.
+    pub fn run(&mut self) -> Result<()>
+    {
+        loop {
+            let msg = match read_message(&mut *self.r)? {
+                Some(tmp_msg) => tmp_msg,
+                None => break,
+            };
+            match Request::from_message(&msg) {
+                Some(req) => {
+                    if !self.execute_request(req)? {
+                        break;
+                    }
+                },
+                None => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn send(&mut self, mut msg: serde_json::Value) -> Result<()>
+    {
+        msg["jsonrpc"] = json!("2.0");
+        write_message(&mut *self.w, &msg)
+    }
+
+    fn send_response(&mut self, req: &Request, result: serde_json::Value) -> Result<()>
+    {
+        match &req.id {
+            Some(id) => self.send(json!({ "id": id, "result": result })),
+            None => Ok(()),
+        }
+    }
+
+    fn send_error_response(&mut self, req: &Request, code: i64, msg: &str) -> Result<()>
+    {
+        match &req.id {
+            Some(id) => self.send(json!({ "id": id, "error": { "code": code, "message": msg } })),
+            None => Ok(()),
+        }
+    }
+
+    fn send_notification(&mut self, method: &str, params: serde_json::Value) -> Result<()>
+    { self.send(json!({ "method": method, "params": params })) }
+
+    fn execute_request(&mut self, req: Request) -> Result<bool>
+    {
+        if self.is_shutdown && req.method.as_str() != "exit" {
+            self.send_error_response(&req, -32600, "server is shut down")?;
+            return Ok(true);
+        }
+        match req.method.as_str() {
+            "initialize" => {
+                self.load_libs()?;
+                self.send_response(&req, json!({
+                        "capabilities": {
+                            "textDocumentSync": 1,
+                            "hoverProvider": true,
+                            "definitionProvider": true,
+                            "completionProvider": { "triggerCharacters": [":"] },
+                            "documentSymbolProvider": true
+                        },
+                        "serverInfo": { "name": "unlab-gpu" }
+                }))?;
+            },
+            "initialized" => (),
+            "shutdown" => {
+                self.is_shutdown = true;
+                self.send_response(&req, serde_json::Value::Null)?;
+            },
+            "exit" => return Ok(false),
+            "textDocument/didOpen" => {
+                match (req.params["textDocument"]["uri"].as_str(), req.params["textDocument"]["text"].as_str()) {
+                    (Some(uri), Some(text)) => self.update_doc(uri, String::from(text))?,
+                    (_, _) => (),
+                }
+            },
+            "textDocument/didChange" => {
+                let text = match req.params["contentChanges"].as_array() {
+                    Some(changes) => changes.last().and_then(|change| change["text"].as_str()),
+                    None => None,
+                };
+                match (req.params["textDocument"]["uri"].as_str(), text) {
+                    (Some(uri), Some(text)) => self.update_doc(uri, String::from(text))?,
+                    (_, _) => (),
+                }
+            },
+            "textDocument/didClose" => {
+                match req.params["textDocument"]["uri"].as_str() {
+                    Some(uri) => {
+                        self.docs.remove(uri);
+                        self.send_notification("textDocument/publishDiagnostics", json!({ "uri": uri, "diagnostics": [] }))?;
+                    },
+                    None => (),
+                }
+            },
+            "textDocument/definition" => {
+                let location = match self.name_at_pos(&req.params) {
+                    Some((uri, current_idents, name)) => {
+                        let indices = self.indices(uri.as_str());
+                        match find_def(indices.as_slice(), name.mod_paths(current_idents.as_slice()).as_slice(), &name.ident)? {
+                            Some(pos) => json!({ "uri": self.uri_for_path(pos.path.as_str()), "range": range(self.text_for_path(pos.path.as_str()).as_str(), &pos, pos.line, pos.column) }),
+                            None => serde_json::Value::Null,
+                        }
+                    },
+                    None => serde_json::Value::Null,
+                };
+                self.send_response(&req, location)?;
+            },
+            "textDocument/hover" => {
+                let hover = match self.name_at_pos(&req.params) {
+                    Some((uri, current_idents, name)) => {
+                        let indices = self.indices(uri.as_str());
+                        match find_hover(indices.as_slice(), name.mod_paths(current_idents.as_slice()).as_slice(), &name.ident)? {
+                            Some(value) => json!({ "contents": { "kind": "markdown", "value": value } }),
+                            None => serde_json::Value::Null,
+                        }
+                    },
+                    None => serde_json::Value::Null,
+                };
+                self.send_response(&req, hover)?;
+            },
+            "textDocument/completion" => {
+                let items = match self.completion_name(&req.params) {
+                    Some((uri, current_idents, name, prefix)) => {
+                        let indices = self.indices(uri.as_str());
+                        let mut items = find_completion_items(indices.as_slice(), name.mod_paths(current_idents.as_slice()).as_slice(), prefix.as_str())?;
+                        if !name.is_abs && name.idents.is_empty() && "root".starts_with(prefix.as_str()) {
+                            items.insert(0, json!({ "label": "root", "kind": 9 }));
+                        }
+                        items
+                    },
+                    None => Vec::new(),
+                };
+                self.send_response(&req, json!({ "isIncomplete": false, "items": items }))?;
+            },
+            "textDocument/documentSymbol" => {
+                let symbols = match req.params["textDocument"]["uri"].as_str() {
+                    Some(uri) => {
+                        match self.docs.get(uri) {
+                            Some(Document { text, tree: Some(Tree(nodes)), .. }) => symbols_for_nodes(text.as_str(), nodes.as_slice()),
+                            _ => Vec::new(),
+                        }
+                    },
+                    None => Vec::new(),
+                };
+                self.send_response(&req, json!(symbols))?;
+            },
+            _ => self.send_error_response(&req, -32601, format!("unsupported method {}", req.method).as_str())?,
+        }
+        Ok(true)
+    }
+
+    fn load_libs(&mut self) -> Result<()>
+    {
+        self.lib_indices.clear();
+        for dir in std::env::split_paths(self.lib_path.as_os_str()) {
+            let domain_entries = match fs::read_dir(dir) {
+                Ok(tmp_domain_entries) => tmp_domain_entries,
+                Err(_) => continue,
+            };
+            for domain_entry in domain_entries {
+                let lib_entries = match domain_entry {
+                    Ok(domain_entry) => {
+                        match fs::read_dir(domain_entry.path()) {
+                            Ok(tmp_lib_entries) => tmp_lib_entries,
+                            Err(_) => continue,
+                        }
+                    },
+                    Err(_) => continue,
+                };
+                for lib_entry in lib_entries {
+                    let script_dir = match lib_entry {
+                        Ok(lib_entry) => lib_entry.path(),
+                        Err(_) => continue,
+                    };
+                    let mut lib_file = script_dir.clone();
+                    lib_file.push("lib.un");
+                    let tree = match parse(lib_file.as_path()) {
+                        Ok(tmp_tree) => tmp_tree,
+                        Err(_) => continue,
+                    };
+                    let def_root_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>> = Arc::new(RwLock::new(ModNode::new(None)));
+                    let mut def_gen = DefGen::new(def_root_mod.clone(), script_dir.clone());
+                    def_gen.generate_for_nodes(tree.0.as_slice())?;
+                    let doc_tree = generate_doc_tree(script_dir.as_path()).ok();
+                    self.lib_indices.push(Index { def_root_mod, doc_tree, });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn update_doc(&mut self, uri: &str, text: String) -> Result<()>
+    {
+        let path = Arc::new(uri_to_path(uri));
+        let script_dir = match Path::new(path.as_str()).parent() {
+            Some(parent) => PathBuf::from(parent),
+            None => PathBuf::from("."),
+        };
+        let doc_root_mod: Arc<RwLock<ModNode<String, Option<String>>>> = Arc::new(RwLock::new(ModNode::new(None)));
+        let (tree, index, diags) = match parse_text(path.clone(), text.as_str(), Some(doc_root_mod.clone())) {
+            Ok(tree) => {
+                let def_root_mod: Arc<RwLock<ModNode<Pos, Option<Pos>>>> = Arc::new(RwLock::new(ModNode::new(None)));
+                let mut def_gen = DefGen::new(def_root_mod.clone(), script_dir.clone());
+                def_gen.generate_for_nodes(tree.0.as_slice())?;
+                let mut doc_tree_gen = DocTreeGen::new_with_script_dir(doc_root_mod, script_dir);
+                let doc_tree = doc_tree_gen.generate(&tree).ok();
+                (Some(tree), Some(Index { def_root_mod, doc_tree, }), Vec::new())
+            },
+            Err(err) => {
+                let (tree, index) = match self.docs.remove(uri) {
+                    Some(doc) => (doc.tree, doc.index),
+                    None => (None, None),
+                };
+                (tree, index, vec![diag(&err, text.as_str())])
+            },
+        };
+        self.docs.insert(String::from(uri), Document { path, text, tree, index, });
+        self.send_notification("textDocument/publishDiagnostics", json!({ "uri": uri, "diagnostics": diags }))
+    }
+
+    fn indices(&self, uri: &str) -> Vec<Index>
+    {
+        let mut indices: Vec<Index> = Vec::new();
+        match self.docs.get(uri).and_then(|doc| doc.index.clone()) {
+            Some(index) => indices.push(index),
+            None => (),
+        }
+        for (uri2, doc) in &self.docs {
+            if uri2.as_str() != uri {
+                match &doc.index {
+                    Some(index) => indices.push(index.clone()),
+                    None => (),
+                }
+            }
+        }
+        indices.extend(self.lib_indices.iter().cloned());
+        indices.push(self.builtin_index.clone());
+        indices
+    }
+
+    fn text_for_path(&self, path: &str) -> String
+    {
+        for doc in self.docs.values() {
+            if doc.path.as_str() == path {
+                return doc.text.clone();
+            }
+        }
+        match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => String::new(),
+        }
+    }
+
+    fn uri_for_path(&self, path: &str) -> String
+    {
+        for (uri, doc) in &self.docs {
+            if doc.path.as_str() == path {
+                return uri.clone();
+            }
+        }
+        let path = match fs::canonicalize(path) {
+            Ok(path_buf) => path_buf.to_string_lossy().into_owned(),
+            Err(_) => String::from(path),
+        };
+        path_to_uri(path.as_str())
+    }
+
+    fn doc_line_and_current_idents(&self, params: &serde_json::Value) -> Option<(String, Vec<char>, usize, Vec<String>)>
+    {
+        let uri = params["textDocument"]["uri"].as_str()?;
+        let line = params["position"]["line"].as_u64()?;
+        let character = params["position"]["character"].as_u64()? as usize;
+        let doc = self.docs.get(uri)?;
+        let cs: Vec<char> = str_without_crlf(doc.text.split('\n').nth(line as usize)?).chars().collect();
+        let column = char_column(cs.as_slice(), character);
+        let mut current_idents: Vec<String> = Vec::new();
+        match &doc.tree {
+            Some(Tree(nodes)) => mod_idents_at(nodes.as_slice(), line + 1, column + 1, &mut current_idents),
+            None => (),
+        }
+        Some((String::from(uri), cs, column, current_idents))
+    }
+
+    fn name_at_pos(&self, params: &serde_json::Value) -> Option<(String, Vec<String>, NameAtPos)>
+    {
+        let (uri, cs, column, current_idents) = self.doc_line_and_current_idents(params)?;
+        let column = if column < cs.len() && is_ident_char(cs[column]) {
+            column
+        } else if column > 0 && is_ident_char(cs[column - 1]) {
+            column - 1
+        } else {
+            return None;
+        };
+        let mut end = column;
+        while end < cs.len() && is_ident_char(cs[end]) {
+            end += 1;
+        }
+        let start = name_start(cs.as_slice(), column);
+        let is_mod = cs[end..].starts_with(&[':', ':']);
+        let s: String = cs[start..end].iter().collect();
+        let mut idents: Vec<String> = s.split("::").map(String::from).collect();
+        let is_abs = idents.first().map(|ident| ident == "root").unwrap_or(false);
+        if is_abs {
+            idents.remove(0);
+        }
+        if idents.iter().any(|ident| ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit())) {
+            return None;
+        }
+        let ident = if is_mod || (is_abs && idents.is_empty()) {
+            None
+        } else {
+            idents.pop()
+        };
+        Some((uri, current_idents, NameAtPos { is_abs, idents, ident, }))
+    }
+
+    fn completion_name(&self, params: &serde_json::Value) -> Option<(String, Vec<String>, NameAtPos, String)>
+    {
+        let (uri, cs, column, current_idents) = self.doc_line_and_current_idents(params)?;
+        let start = name_start(cs.as_slice(), column);
+        let s: String = cs[start..column].iter().collect();
+        let mut idents: Vec<String> = s.split("::").map(String::from).collect();
+        let prefix = idents.pop().unwrap_or(String::new());
+        let is_abs = idents.first().map(|ident| ident == "root").unwrap_or(false);
+        if is_abs {
+            idents.remove(0);
+        }
+        if prefix.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        Some((uri, current_idents, NameAtPos { is_abs, idents, ident: Some(prefix.clone()), }, prefix))
+    }
+}
+
+fn parse_text(path: Arc<String>, text: &str, doc_root_mod: Option<Arc<RwLock<ModNode<String, Option<String>>>>>) -> Result<Tree>
+{
+    let mut cursor = Cursor::new(text.as_bytes());
+    let mut lexer = Lexer::new_with_doc_flag(path, &mut cursor, doc_root_mod.is_some());
+    let parser_path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new_with_doc_root_mod(parser_path, tokens, doc_root_mod);
+    parser.parse()
+}
+
+fn is_ident_char(c: char) -> bool
+{ c.is_alphanumeric() || c == '_' }
+
+fn name_start(cs: &[char], column: usize) -> usize
+{
+    let mut start = column;
+    while start > 0 && is_ident_char(cs[start - 1]) {
+        start -= 1;
+    }
+    while start > 2 && cs[start - 1] == ':' && cs[start - 2] == ':' && is_ident_char(cs[start - 3]) {
+        start -= 2;
+        while start > 0 && is_ident_char(cs[start - 1]) {
+            start -= 1;
+        }
+    }
+    start
+}
+
+fn node_pos(node: &Node) -> &Pos
+{
+    match node {
+        Node::Def(def) => def.pos(),
+        Node::Stat(stat) => stat.pos(),
+    }
+}
+
+fn mod_idents_at(nodes: &[Node], line: u64, column: usize, idents: &mut Vec<String>)
+{
+    for (i, node) in nodes.iter().enumerate() {
+        match node {
+            Node::Def(def) => {
+                match &**def {
+                    Def::Mod(ident, mod1, pos) => {
+                        let is_after_start = (pos.line, pos.column) < (line, column);
+                        let is_before_end = match nodes.get(i + 1) {
+                            Some(next_node) => (line, column) < (node_pos(next_node).line, node_pos(next_node).column),
+                            None => true,
+                        };
+                        if is_after_start && is_before_end {
+                            idents.push(ident.clone());
+                            match &**mod1 {
+                                Mod(nodes2) => mod_idents_at(nodes2.as_slice(), line, column, idents),
+                            }
+                            break;
+                        }
+                    },
+                    Def::Fun(_, _, _) => (),
+                }
+            },
+            Node::Stat(_) => (),
+        }
+    }
+}
+
+fn idents_to_mod_name(idents: &[String]) -> String
+{
+    let mut s = String::from("root");
+    for ident in idents {
+        s.push_str("::");
+        s.push_str(ident.as_str());
+    }
+    s
+}
+
+fn ident_and_sig_to_string(ident: &str, sig: &Sig) -> String
+{
+    let mut s = String::new();
+    match sig {
+        Sig::Var => (),
+        Sig::Fun(_) | Sig::BuiltinFun(_) => s.push_str("function "),
+    }
+    s.push_str(ident);
+    match sig {
+        Sig::Var => (),
+        Sig::Fun(args) => {
+            let arg_strs: Vec<String> = args.iter().map(|arg| {
+                    match arg {
+                        FunArg::Arg(ident2) => ident2.clone(),
+                        FunArg::DefaultArg(ident2, default) => format!("{} = {}", ident2, default),
+                        FunArg::RestArg(ident2) => format!("{}...", ident2),
+                    }
+            }).collect();
+            s.push_str(format!("({})", arg_strs.join(", ")).as_str());
+        },
+        Sig::BuiltinFun(args) => {
+            let arg_strs: Vec<String> = args.iter().map(|arg| {
+                    match arg {
+                        BuiltinFunArg::Arg(ident2) => ident2.clone(),
+                        BuiltinFunArg::OptArg(ident2) => format!("{}?", ident2),
+                        BuiltinFunArg::DotDotDot => String::from("..."),
+                    }
+            }).collect();
+            s.push_str(format!("({})", arg_strs.join(", ")).as_str());
+        },
+    }
+    s
+}
+
+fn hover_value(sig_str: &str, desc: Option<&String>) -> String
+{
+    let mut s = format!("```unlab\n{}\n```\n", sig_str);
+    match desc {
+        Some(desc) => {
+            s.push('\n');
+            s.push_str(desc.as_str());
+        },
+        None => (),
+    }
+    s
+}
+
+fn find_def(indices: &[Index], paths: &[Vec<String>], ident: &Option<String>) -> Result<Option<Pos>>
+{
+    for path in paths {
+        for index in indices {
+            match ModNode::mod_from(&index.def_root_mod, path.as_slice(), false)? {
+                Some(def_mod) => {
+                    let def_mod_g = rw_lock_read(&*def_mod)?;
+                    let pos = match ident {
+                        Some(ident) => def_mod_g.var(ident),
+                        None => def_mod_g.value().as_ref(),
+                    };
+                    match pos {
+                        Some(pos) => return Ok(Some(pos.clone())),
+                        None => (),
+                    }
+                },
+                None => (),
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn find_hover(indices: &[Index], paths: &[Vec<String>], ident: &Option<String>) -> Result<Option<String>>
+{
+    for path in paths {
+        for index in indices {
+            let doc_tree = match &index.doc_tree {
+                Some(tmp_doc_tree) => tmp_doc_tree,
+                None => continue,
+            };
+            let sig_mod = match ModNode::mod_from(doc_tree.sig_root_mod(), path.as_slice(), false)? {
+                Some(tmp_sig_mod) => tmp_sig_mod,
+                None => continue,
+            };
+            let doc_mod = ModNode::mod_from(doc_tree.doc_root_mod(), path.as_slice(), false)?;
+            let doc_mod_g = match &doc_mod {
+                Some(doc_mod) => Some(rw_lock_read(&**doc_mod)?),
+                None => None,
+            };
+            match ident {
+                Some(ident) => {
+                    let sig_mod_g = rw_lock_read(&*sig_mod)?;
+                    match sig_mod_g.var(ident) {
+                        Some(sig) => {
+                            let desc = doc_mod_g.as_ref().and_then(|g| g.var(ident));
+                            return Ok(Some(hover_value(ident_and_sig_to_string(ident, sig).as_str(), desc)));
+                        },
+                        None => (),
+                    }
+                },
+                None => {
+                    let desc = doc_mod_g.as_ref().and_then(|g| g.value().as_ref());
+                    return Ok(Some(hover_value(format!("module {}", idents_to_mod_name(path.as_slice())).as_str(), desc)));
+                },
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn find_completion_items(indices: &[Index], paths: &[Vec<String>], prefix: &str) -> Result<Vec<serde_json::Value>>
+{
+    let mut items: BTreeMap<String, serde_json::Value> = BTreeMap::new();
+    for path in paths {
+        for index in indices {
+            let doc_tree = match &index.doc_tree {
+                Some(tmp_doc_tree) => tmp_doc_tree,
+                None => continue,
+            };
+            let sig_mod = match ModNode::mod_from(doc_tree.sig_root_mod(), path.as_slice(), false)? {
+                Some(tmp_sig_mod) => tmp_sig_mod,
+                None => continue,
+            };
+            let doc_mod = ModNode::mod_from(doc_tree.doc_root_mod(), path.as_slice(), false)?;
+            let doc_mod_g = match &doc_mod {
+                Some(doc_mod) => Some(rw_lock_read(&**doc_mod)?),
+                None => None,
+            };
+            let sig_mod_g = rw_lock_read(&*sig_mod)?;
+            for ident in sig_mod_g.mods().keys() {
+                if ident.starts_with(prefix) && !items.contains_key(ident) {
+                    items.insert(ident.clone(), json!({ "label": ident, "kind": 9 }));
+                }
+            }
+            for (ident, sig) in sig_mod_g.vars() {
+                if ident.starts_with(prefix) && !items.contains_key(ident) {
+                    let kind = match sig {
+                        Sig::Var => 6,
+                        Sig::Fun(_) | Sig::BuiltinFun(_) => 3,
+                    };
+                    let mut item = json!({ "label": ident, "kind": kind, "detail": ident_and_sig_to_string(ident, sig) });
+                    match doc_mod_g.as_ref().and_then(|g| g.var(ident)) {
+                        Some(desc) => item["documentation"] = json!({ "kind": "markdown", "value": desc }),
+                        None => (),
+                    }
+                    items.insert(ident.clone(), item);
+                }
+            }
+        }
+    }
+    Ok(items.into_values().collect())
+}
+
+fn symbol(text: &str, ident: &str, detail: Option<String>, kind: u64, pos: &Pos, children: Vec<serde_json::Value>) -> serde_json::Value
+{
+    let range = json!({
+            "start": { "line": pos.line.saturating_sub(1), "character": utf16_column(text, pos.line, pos.column) },
+            "end": { "line": pos.line, "character": 0 }
+    });
+    let mut symbol = json!({ "name": ident, "kind": kind, "range": range, "selectionRange": range });
+    match detail {
+        Some(detail) => symbol["detail"] = json!(detail),
+        None => (),
+    }
+    if !children.is_empty() {
+        symbol["children"] = json!(children);
+    }
+    symbol
+}
+
+fn symbols_for_nodes(text: &str, nodes: &[Node]) -> Vec<serde_json::Value>
+{
+    let mut symbols: Vec<serde_json::Value> = Vec::new();
+    let mut var_idents: BTreeSet<String> = BTreeSet::new();
+    for node in nodes {
+        match node {
+            Node::Def(def) => {
+                match &**def {
+                    Def::Mod(ident, mod1, pos) => {
+                        match &**mod1 {
+                            Mod(nodes2) => symbols.push(symbol(text, ident, None, 2, pos, symbols_for_nodes(text, nodes2.as_slice()))),
+                        }
+                    },
+                    Def::Fun(ident, fun, pos) => {
+                        match &**fun {
+                            Fun(args, _) => {
+                                let arg_strs: Vec<String> = args.iter().map(|arg| format!("{}", arg)).collect();
+                                symbols.push(symbol(text, ident, Some(format!("function {}({})", ident, arg_strs.join(", "))), 12, pos, Vec::new()));
+                            },
+                        }
+                    },
+                }
+            },
+            Node::Stat(stat) => {
+                match &**stat {
+                    Stat::Assign(expr, _, pos) => {
+                        match &**expr {
+                            Expr::Var(Name::Var(ident), _) => {
+                                if var_idents.insert(ident.clone()) {
+                                    symbols.push(symbol(text, ident, None, 13, pos, Vec::new()));
+                                }
+                            },
+                            _ => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+        }
+    }
+    symbols
+}
+
+fn char_column(cs: &[char], character: usize) -> usize
+{
+    let mut utf16_len = 0;
+    for (i, c) in cs.iter().enumerate() {
+        if utf16_len >= character {
+            return i;
+        }
+        utf16_len += c.len_utf16();
+    }
+    cs.len()
+}
+
+fn utf16_column(text: &str, line: u64, column: usize) -> usize
+{
+    let char_count = column.saturating_sub(1);
+    match text.split('\n').nth(line.saturating_sub(1) as usize) {
+        Some(line_text) => {
+            let mut cs = line_text.chars();
+            let mut utf16_len = 0;
+            for _ in 0..char_count {
+                match cs.next() {
+                    Some(c) => utf16_len += c.len_utf16(),
+                    None => utf16_len += 1,
+                }
+            }
+            utf16_len
+        },
+        None => char_count,
+    }
+}
+
+fn range(text: &str, pos: &Pos, end_line: u64, end_column: usize) -> serde_json::Value
+{
+    json!({
+            "start": { "line": pos.line.saturating_sub(1), "character": utf16_column(text, pos.line, pos.column) },
+            "end": { "line": end_line.saturating_sub(1), "character": utf16_column(text, end_line, end_column) }
+    })
+}
+
+fn diag(err: &Error, text: &str) -> serde_json::Value
+{
+    let (range, msg) = match err {
+        Error::Parser(pos, msg) => (range(text, pos, pos.line, pos.column + 1), msg.clone()),
+        Error::ParserEof(path, _) => {
+            let line_count = text.split('\n').count() as u64;
+            let column = text.split('\n').last().map(|s| s.chars().count()).unwrap_or(0) + 1;
+            let pos = Pos::new(path.clone(), line_count, column);
+            (range(text, &pos, pos.line, pos.column), String::from("unexpected end of file"))
+        },
+        _ => {
+            let pos = Pos::new(Arc::new(String::new()), 1, 1);
+            (range(text, &pos, pos.line, pos.column), format!("{}", err))
+        },
+    };
+    json!({ "range": range, "severity": 1, "source": "unlab", "message": msg })
+}
+
+fn uri_to_path(uri: &str) -> String
+{
+    match uri.strip_prefix("file://") {
+        Some(s) => {
+            let bytes = s.as_bytes();
+            let mut path_bytes: Vec<u8> = Vec::new();
+            let mut i = 0;
+            while i < bytes.len() {
+                if bytes[i] == b'%' && i + 2 < bytes.len() {
+                    match std::str::from_utf8(&bytes[(i + 1)..(i + 3)]).ok().and_then(|t| u8::from_str_radix(t, 16).ok()) {
+                        Some(b) => {
+                            path_bytes.push(b);
+                            i += 3;
+                            continue;
+                        },
+                        None => (),
+                    }
+                }
+                path_bytes.push(bytes[i]);
+                i += 1;
+            }
+            let path = String::from_utf8_lossy(path_bytes.as_slice()).into_owned();
+            let cs: Vec<char> = path.chars().take(3).collect();
+            if cs.len() == 3 && cs[0] == '/' && cs[1].is_ascii_alphabetic() && cs[2] == ':' {
+                String::from(&path[1..])
+            } else {
+                path
+            }
+        },
+        None => String::from(uri),
+    }
+}
+
+fn path_to_uri(path: &str) -> String
+{
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+    for b in path.replace('\\', "/").bytes() {
+        if b.is_ascii_alphanumeric() || b"/-_.~:".contains(&b) {
+            uri.push(b as char);
+        } else {
+            uri.push_str(format!("%{:02X}", b).as_str());
+        }
+    }
+    uri
+}
+
+fn read_message(r: &mut dyn BufRead) -> Result<Option<serde_json::Value>>
+{
+    let mut content_len: Option<usize> = None;
+    let mut is_first = true;
+    loop {
+        let mut line = String::new();
+        match r.read_line(&mut line) {
+            Ok(0) if is_first => return Ok(None),
+            Ok(0) => return Err(Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "unexpected end of file"))),
+            Ok(_) => (),
+            Err(err) => return Err(Error::Io(err)),
+        }
+        is_first = false;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        match line.split_once(':') {
+            Some((name, value)) if name.trim().eq_ignore_ascii_case("Content-Length") => {
+                match value.trim().parse::<usize>() {
+                    Ok(len) => content_len = Some(len),
+                    Err(_) => return Err(Error::Io(io::Error::new(io::ErrorKind::InvalidData, "invalid content length"))),
+                }
+            },
+            _ => (),
+        }
+    }
+    let content_len = match content_len {
+        Some(tmp_content_len) => tmp_content_len,
+        None => return Err(Error::Io(io::Error::new(io::ErrorKind::InvalidData, "no content length"))),
+    };
+    let mut buf = vec![0u8; content_len];
+    match r.read_exact(buf.as_mut_slice()) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    match serde_json::from_slice(buf.as_slice()) {
+        Ok(msg) => Ok(Some(msg)),
+        Err(err) => Err(Error::SerdeJson(err)),
+    }
+}
+
+fn write_message(w: &mut dyn Write, msg: &serde_json::Value) -> Result<()>
+{
+    let s = match serde_json::to_string(msg) {
+        Ok(tmp_s) => tmp_s,
+        Err(err) => return Err(Error::SerdeJson(err)),
+    };
+    match write!(w, "Content-Length: {}\r\n\r\n{}", s.len(), s) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    match w.flush() {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests;
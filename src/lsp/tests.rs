@@ -0,0 +1,181 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io;
+use std::io::BufReader;
+use std::sync::Mutex;
+use crate::builtin_doc::add_std_builtin_fun_doc;
+use super::*;
+
+struct SharedWriter
+{
+    buf: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Write for SharedWriter
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        self.buf.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()>
+    { Ok(()) }
+}
+
+fn run_server(reqs: &[serde_json::Value]) -> Vec<serde_json::Value>
+{
+    let mut input: Vec<u8> = Vec::new();
+    for req in reqs {
+        write_message(&mut input, req).unwrap();
+    }
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let mut sig_root_mod: ModNode<Sig, ()> = ModNode::new(());
+    let mut doc_root_mod: ModNode<String, Option<String>> = ModNode::new(None);
+    add_std_builtin_fun_doc(&mut sig_root_mod, &mut doc_root_mod);
+    let doc_tree = DocTree::new(Arc::new(RwLock::new(sig_root_mod)), Arc::new(RwLock::new(doc_root_mod)));
+    let r = BufReader::new(Cursor::new(input));
+    let w = SharedWriter { buf: output.clone(), };
+    let mut server = LspServer::new(Box::new(r), Box::new(w), doc_tree, OsString::from("lib"));
+    server.run().unwrap();
+    let output_g = output.lock().unwrap();
+    let mut r = Cursor::new(output_g.as_slice());
+    let mut msgs: Vec<serde_json::Value> = Vec::new();
+    loop {
+        match read_message(&mut r).unwrap() {
+            Some(msg) => msgs.push(msg),
+            None => break,
+        }
+    }
+    msgs
+}
+
+fn response(msgs: &[serde_json::Value], id: u64) -> &serde_json::Value
+{ msgs.iter().find(|msg| msg["id"].as_u64() == Some(id)).unwrap() }
+
+fn notifications<'a>(msgs: &'a [serde_json::Value], method: &str) -> Vec<&'a serde_json::Value>
+{ msgs.iter().filter(|msg| msg["method"].as_str() == Some(method)).collect() }
+
+#[test]
+fn test_lsp_server_run_publishes_diagnostics()
+{
+    let uri = "file:///tmp/test.un";
+    let msgs = run_server(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": { "textDocument": { "uri": uri, "languageId": "unlab", "version": 1, "text": "x = 1\ny = x +\n" } } }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didChange", "params": { "textDocument": { "uri": uri, "version": 2 }, "contentChanges": [{ "text": "x = 1\ny = x + 1\n" }] } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" })
+    ]);
+    let res = response(msgs.as_slice(), 1);
+    assert_eq!(Some(1), res["result"]["capabilities"]["textDocumentSync"].as_u64());
+    let diag_msgs = notifications(msgs.as_slice(), "textDocument/publishDiagnostics");
+    assert_eq!(2, diag_msgs.len());
+    assert_eq!(Some(uri), diag_msgs[0]["params"]["uri"].as_str());
+    assert_eq!(Some(1), diag_msgs[0]["params"]["diagnostics"][0]["range"]["start"]["line"].as_u64());
+    assert_eq!(Some(7), diag_msgs[0]["params"]["diagnostics"][0]["range"]["start"]["character"].as_u64());
+    assert_eq!(Some("unexpected token"), diag_msgs[0]["params"]["diagnostics"][0]["message"].as_str());
+    assert_eq!(Some(0), diag_msgs[1]["params"]["diagnostics"].as_array().map(|a| a.len()));
+    let res = response(msgs.as_slice(), 2);
+    assert!(res["result"].is_null());
+}
+
+#[test]
+fn test_lsp_server_run_finds_definitions_and_hovers_and_completions_and_symbols()
+{
+    let uri = "file:///tmp/test.un";
+    let text = "
+## A module of networks.
+module nets
+    ## Creates a network.
+    function create(n, m = 2)
+        zeros(n, m)
+    end
+    x = create(1)
+end
+a = nets::create(2)
+b = root::nets::x
+println(a)
+";
+    let msgs = run_server(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": { "textDocument": { "uri": uri, "languageId": "unlab", "version": 1, "text": &text[1..] } } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/definition", "params": { "textDocument": { "uri": uri }, "position": { "line": 8, "character": 10 } } }),
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "textDocument/definition", "params": { "textDocument": { "uri": uri }, "position": { "line": 9, "character": 16 } } }),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "textDocument/definition", "params": { "textDocument": { "uri": uri }, "position": { "line": 6, "character": 9 } } }),
+            json!({ "jsonrpc": "2.0", "id": 5, "method": "textDocument/hover", "params": { "textDocument": { "uri": uri }, "position": { "line": 8, "character": 12 } } }),
+            json!({ "jsonrpc": "2.0", "id": 6, "method": "textDocument/hover", "params": { "textDocument": { "uri": uri }, "position": { "line": 10, "character": 3 } } }),
+            json!({ "jsonrpc": "2.0", "id": 7, "method": "textDocument/hover", "params": { "textDocument": { "uri": uri }, "position": { "line": 8, "character": 5 } } }),
+            json!({ "jsonrpc": "2.0", "id": 8, "method": "textDocument/completion", "params": { "textDocument": { "uri": uri }, "position": { "line": 9, "character": 16 } } }),
+            json!({ "jsonrpc": "2.0", "id": 9, "method": "textDocument/documentSymbol", "params": { "textDocument": { "uri": uri } } }),
+            json!({ "jsonrpc": "2.0", "method": "exit" })
+    ]);
+    let res = response(msgs.as_slice(), 2);
+    assert_eq!(Some(uri), res["result"]["uri"].as_str());
+    assert_eq!(Some(3), res["result"]["range"]["start"]["line"].as_u64());
+    assert_eq!(Some(4), res["result"]["range"]["start"]["character"].as_u64());
+    let res = response(msgs.as_slice(), 3);
+    assert_eq!(Some(6), res["result"]["range"]["start"]["line"].as_u64());
+    let res = response(msgs.as_slice(), 4);
+    assert_eq!(Some(3), res["result"]["range"]["start"]["line"].as_u64());
+    let res = response(msgs.as_slice(), 5);
+    let value = res["result"]["contents"]["value"].as_str().unwrap();
+    assert!(value.contains("function create(n, m = 2)"));
+    assert!(value.contains("Creates a network."));
+    let res = response(msgs.as_slice(), 6);
+    let value = res["result"]["contents"]["value"].as_str().unwrap();
+    assert!(value.contains("function println("));
+    let res = response(msgs.as_slice(), 7);
+    let value = res["result"]["contents"]["value"].as_str().unwrap();
+    assert!(value.contains("module root::nets"));
+    assert!(value.contains("A module of networks."));
+    let res = response(msgs.as_slice(), 8);
+    let items = res["result"]["items"].as_array().unwrap();
+    assert_eq!(2, items.len());
+    assert_eq!(Some("create"), items[0]["label"].as_str());
+    assert_eq!(Some(3), items[0]["kind"].as_u64());
+    assert_eq!(Some("x"), items[1]["label"].as_str());
+    assert_eq!(Some(6), items[1]["kind"].as_u64());
+    let res = response(msgs.as_slice(), 9);
+    let symbols = res["result"].as_array().unwrap();
+    assert_eq!(3, symbols.len());
+    assert_eq!(Some("nets"), symbols[0]["name"].as_str());
+    assert_eq!(Some(2), symbols[0]["kind"].as_u64());
+    assert_eq!(Some("create"), symbols[0]["children"][0]["name"].as_str());
+    assert_eq!(Some(12), symbols[0]["children"][0]["kind"].as_u64());
+    assert_eq!(Some("x"), symbols[0]["children"][1]["name"].as_str());
+    assert_eq!(Some("a"), symbols[1]["name"].as_str());
+    assert_eq!(Some("b"), symbols[2]["name"].as_str());
+}
+
+#[test]
+fn test_lsp_server_run_uses_utf16_characters()
+{
+    let uri = "file:///tmp/test.un";
+    let text = "
+function foo(x)
+    x
+end
+y = println(\"😀😀\", foo(1))
+";
+    let msgs = run_server(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": { "textDocument": { "uri": uri, "languageId": "unlab", "version": 1, "text": &text[1..] } } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/definition", "params": { "textDocument": { "uri": uri }, "position": { "line": 3, "character": 22 } } }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didChange", "params": { "textDocument": { "uri": uri, "version": 2 }, "contentChanges": [{ "text": "z = \"😀😀\" + )\n" }] } }),
+            json!({ "jsonrpc": "2.0", "method": "exit" })
+    ]);
+    let res = response(msgs.as_slice(), 2);
+    assert_eq!(Some(uri), res["result"]["uri"].as_str());
+    assert_eq!(Some(0), res["result"]["range"]["start"]["line"].as_u64());
+    let diag_msgs = notifications(msgs.as_slice(), "textDocument/publishDiagnostics");
+    assert_eq!(2, diag_msgs.len());
+    assert_eq!(Some(0), diag_msgs[1]["params"]["diagnostics"][0]["range"]["start"]["line"].as_u64());
+    assert_eq!(Some(13), diag_msgs[1]["params"]["diagnostics"][0]["range"]["start"]["character"].as_u64());
+    assert_eq!(Some(14), diag_msgs[1]["params"]["diagnostics"][0]["range"]["end"]["character"].as_u64());
+}
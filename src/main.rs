@@ -17,7 +17,7 @@ use unlab_gpu::Value;
 use unlab_gpu::add_std_builtin_funs;
 use unlab_gpu::finalize_backend;
 use unlab_gpu::initialize_backend;
-use unlab_gpu::main_loop;
+use unlab_gpu::main_loop_with_debug_and_vm_flags;
 
 #[derive(Parser, Debug)]
 #[command(version)]
@@ -50,6 +50,12 @@ struct Args
     /// Don't show plotter windows
     #[arg(short = 'p', long)]
     no_plotter_windows: bool,
+    /// Debug script
+    #[arg(short = 'g', long)]
+    debug: bool,
+    /// Execute script by virtual machine
+    #[arg(short = 'm', long)]
+    vm: bool,
     /// Script file
     script_file: Option<String>,
     /// Arguments
@@ -98,7 +104,7 @@ fn main()
         let mut root_mod: ModNode<Value, ()> = ModNode::new(());
         add_std_builtin_funs(&mut root_mod);
         let root_mod_arc = Arc::new(RwLock::new(root_mod));
-        main_loop(args.script_file.clone(), args.args.clone(), PathBuf::from(home.history_file()), root_mod_arc, OsString::from(home.lib_path()), OsString::from(home.doc_path()), !args.no_ctrl_c, !args.no_plotter_windows)
+        main_loop_with_debug_and_vm_flags(args.script_file.clone(), args.args.clone(), PathBuf::from(home.history_file()), root_mod_arc, OsString::from(home.lib_path()), OsString::from(home.doc_path()), !args.no_ctrl_c, !args.no_plotter_windows, args.debug, args.vm)
     };
     match finalize_backend() {
         Ok(()) => (),
@@ -12,6 +12,7 @@ use std::fs::create_dir_all;
 use std::io::Cursor;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::RwLock;
 #[cfg(feature = "plot")]
 use std::thread;
@@ -21,6 +22,7 @@ use rustyline::DefaultEditor;
 use crate::winit::event_loop::ControlFlow;
 #[cfg(feature = "plot")]
 use crate::winit::event_loop::EventLoop;
+use crate::debug::*;
 use crate::doc::*;
 use crate::env::*;
 use crate::error::*;
@@ -104,7 +106,7 @@ fn run_plotter_app<F>(_are_plotter_windows: bool, f: F) -> Option<i32>
 fn quit_from_plotter_app(_env: &Env) -> bool
 { true }
 
-fn non_interactive_main_loop(path: String, args: Vec<String>, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool) -> Option<i32>
+fn non_interactive_main_loop(path: String, args: Vec<String>, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool, is_debug: bool, is_vm: bool) -> Option<i32>
 {
     run_plotter_app(are_plotter_windows, move |event_loop_proxy| {
             let intr_checker: Arc<dyn IntrCheck + Send + Sync> = if is_ctrl_c_intr_checker {
@@ -121,7 +123,27 @@ fn non_interactive_main_loop(path: String, args: Vec<String>, root_mod: Arc<RwLo
             };
             let shared_env = SharedEnv::new_with_intr_checker_and_event_loop_proxy(lib_path, doc_path, args, intr_checker, event_loop_proxy);
             let mut env = Env::new_with_script_dir_and_domain_and_shared_env(root_mod, PathBuf::from("."), None, Arc::new(RwLock::new(shared_env)));
-            let mut interp = Interp::new();
+            let mut interp = Interp::new_with_vm_flag(is_vm);
+            if is_debug {
+                let mut editor = match DefaultEditor::new() {
+                    Ok(tmp_editor) => tmp_editor,
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        return Some(1);
+                    },
+                };
+                let debugger = Debugger::new(Box::new(move |prompt| {
+                        match editor.readline(prompt) {
+                            Ok(line) => {
+                                let _res = editor.add_history_entry(line.as_str());
+                                Some(line)
+                            },
+                            Err(ReadlineError::Interrupted) => Some(String::new()),
+                            Err(_) => None,
+                        }
+                }));
+                interp.set_debug_hook(Some(Arc::new(Mutex::new(debugger))));
+            }
             let res = match parse(path) {
                 Ok(tree) => {
                     match interp.interpret(&mut env, &tree) {
@@ -154,7 +176,7 @@ fn non_interactive_main_loop(path: String, args: Vec<String>, root_mod: Arc<RwLo
     })
 }
 
-fn interactive_main_loop(args: Vec<String>, history_file: PathBuf, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool) -> Option<i32>
+fn interactive_main_loop(args: Vec<String>, history_file: PathBuf, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool, is_vm: bool) -> Option<i32>
 {
     run_plotter_app(are_plotter_windows, move |event_loop_proxy| {
             let intr_checker: Arc<dyn IntrCheck + Send + Sync> = if is_ctrl_c_intr_checker {
@@ -171,7 +193,7 @@ fn interactive_main_loop(args: Vec<String>, history_file: PathBuf, root_mod: Arc
             };
             let shared_env = SharedEnv::new_with_intr_checker_and_event_loop_proxy(lib_path, doc_path, args, intr_checker, event_loop_proxy);
             let mut env = Env::new_with_script_dir_and_domain_and_shared_env(root_mod, PathBuf::from("."), None, Arc::new(RwLock::new(shared_env)));
-            let mut interp = Interp::new();
+            let mut interp = Interp::new_with_vm_flag(is_vm);
             let mut editor = match DefaultEditor::new() {
                 Ok(tmp_editor) => tmp_editor,
                 Err(err) => {
@@ -308,9 +330,25 @@ fn interactive_main_loop(args: Vec<String>, history_file: PathBuf, root_mod: Arc
 /// determines whether the main loop uses the `Ctrl-C` interruption checker. The flag of plotter
 /// windows determines whether the plotter windows can be shown.
 pub fn main_loop(path: Option<String>, args: Vec<String>, history_file: PathBuf, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool) -> Option<i32>
+{ main_loop_with_debug_flag(path, args, history_file, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows, false) }
+
+/// A main loop with a debug flag.
+///
+/// This function is similar to [`main_loop`] but also takes the debug flag. If the debug flag is
+/// `true`, the script is interpreted by a debugger that reads commands from a prompt. The debug
+/// flag is ignored for the interactive mode.
+pub fn main_loop_with_debug_flag(path: Option<String>, args: Vec<String>, history_file: PathBuf, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool, is_debug: bool) -> Option<i32>
+{ main_loop_with_debug_and_vm_flags(path, args, history_file, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows, is_debug, false) }
+
+/// A main loop with a debug flag and a flag of virtual machine.
+///
+/// This function is similar to [`main_loop_with_debug_flag`] but also takes the flag of virtual
+/// machine. If the flag of virtual machine is `true`, the script and lines are compiled to a
+/// bytecode that is executed by a virtual machine. The virtual machine isn't used by the debugger.
+pub fn main_loop_with_debug_and_vm_flags(path: Option<String>, args: Vec<String>, history_file: PathBuf, root_mod: Arc<RwLock<ModNode<Value, ()>>>, lib_path: OsString, doc_path: OsString, is_ctrl_c_intr_checker: bool, are_plotter_windows: bool, is_debug: bool, is_vm: bool) -> Option<i32>
 {
     match path {
-        Some(path) => non_interactive_main_loop(path, args, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows),
-        None => interactive_main_loop(args, history_file, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows),
+        Some(path) => non_interactive_main_loop(path, args, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows, is_debug, is_vm),
+        None => interactive_main_loop(args, history_file, root_mod, lib_path, doc_path, is_ctrl_c_intr_checker, are_plotter_windows, is_vm),
     }
 }
@@ -0,0 +1,502 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of the NumPy formats.
+//!
+//! This module reads and writes arrays in the [NPY](https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html)
+//! format and archives of arrays in the NPZ format. One-dimensional arrays are column vectors and
+//! two-dimensional arrays are matrices. Arrays in Fortran order are transposed matrices.
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::str::Chars;
+use std::iter::Peekable;
+use zip::read::ZipArchive;
+use zip::write::SimpleFileOptions;
+use zip::write::ZipWriter;
+use zip::CompressionMethod;
+use crate::matrix::Matrix;
+use crate::error::*;
+use crate::utils::*;
+
+/// An array in the NPY format.
+///
+/// The elements are stored in row-major order if the array isn't transposed, otherwise the
+/// elements are stored in column-major order (Fortran order).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpyArray
+{
+    row_count: usize,
+    col_count: usize,
+    is_transposed: bool,
+    elems: Vec<f32>,
+}
+
+impl NpyArray
+{
+    /// Creates an array.
+    pub fn new(row_count: usize, col_count: usize, is_transposed: bool, elems: Vec<f32>) -> Self
+    { NpyArray { row_count, col_count, is_transposed, elems, } }
+
+    /// Creates an array from the matrix.
+    pub fn from_matrix(a: &Matrix) -> Result<Self>
+    {
+        let (elems, is_transposed) = matrix_elems_and_transpose_flag(a)?;
+        Ok(NpyArray::new(a.row_count(), a.col_count(), is_transposed, elems))
+    }
+
+    /// Returns the number of rows.
+    pub fn row_count(&self) -> usize
+    { self.row_count }
+
+    /// Returns the number of columns.
+    pub fn col_count(&self) -> usize
+    { self.col_count }
+
+    /// Returns `true` if the array is in Fortran order, otherwise `false`.
+    pub fn is_transposed(&self) -> bool
+    { self.is_transposed }
+
+    /// Returns the elements.
+    pub fn elems(&self) -> &[f32]
+    { self.elems.as_slice() }
+
+    /// Converts the array to a matrix.
+    pub fn to_matrix(&self) -> Result<Matrix>
+    {
+        if !self.is_transposed {
+            matrix_create_and_set_elems(self.row_count, self.col_count, self.elems.as_slice())
+        } else {
+            Ok(matrix_create_and_set_elems(self.col_count, self.row_count, self.elems.as_slice())?.transpose())
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ByteOrder
+{
+    Little,
+    Big,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum DType
+{
+    Bool,
+    Int(usize),
+    UInt(usize),
+    Float(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum HeaderValue
+{
+    String(String),
+    Bool(bool),
+    Tuple(Vec<usize>),
+}
+
+fn invalid_data_error(msg: &str) -> Error
+{ Error::Io(io::Error::new(ErrorKind::InvalidData, String::from(msg))) }
+
+fn skip_header_spaces(cs: &mut Peekable<Chars<'_>>)
+{
+    loop {
+        match cs.peek() {
+            Some(c) if c.is_whitespace() => {
+                cs.next();
+            },
+            _ => break,
+        }
+    }
+}
+
+fn read_header_string(cs: &mut Peekable<Chars<'_>>) -> Result<String>
+{
+    let quote = match cs.next() {
+        Some(c @ ('\'' | '"')) => c,
+        _ => return Err(invalid_data_error("invalid NPY header")),
+    };
+    let mut s = String::new();
+    loop {
+        match cs.next() {
+            Some(c) if c == quote => break,
+            Some(c) => s.push(c),
+            None => return Err(invalid_data_error("invalid NPY header")),
+        }
+    }
+    Ok(s)
+}
+
+fn read_header_value(cs: &mut Peekable<Chars<'_>>) -> Result<HeaderValue>
+{
+    match cs.peek() {
+        Some('\'' | '"') => Ok(HeaderValue::String(read_header_string(cs)?)),
+        Some('(') => {
+            cs.next();
+            let mut ns: Vec<usize> = Vec::new();
+            loop {
+                skip_header_spaces(cs);
+                match cs.peek() {
+                    Some(')') => {
+                        cs.next();
+                        break;
+                    },
+                    Some(c) if c.is_ascii_digit() => {
+                        let mut s = String::new();
+                        loop {
+                            match cs.peek() {
+                                Some(c) if c.is_ascii_digit() => {
+                                    s.push(*c);
+                                    cs.next();
+                                },
+                                Some('L') => {
+                                    cs.next();
+                                    break;
+                                },
+                                _ => break,
+                            }
+                        }
+                        match s.parse::<usize>() {
+                            Ok(n) => ns.push(n),
+                            Err(_) => return Err(invalid_data_error("too large NPY dimension")),
+                        }
+                        skip_header_spaces(cs);
+                        match cs.peek() {
+                            Some(',') => {
+                                cs.next();
+                            },
+                            Some(')') => (),
+                            _ => return Err(invalid_data_error("invalid NPY header")),
+                        }
+                    },
+                    _ => return Err(invalid_data_error("invalid NPY header")),
+                }
+            }
+            Ok(HeaderValue::Tuple(ns))
+        },
+        Some(c) if c.is_alphabetic() => {
+            let mut s = String::new();
+            loop {
+                match cs.peek() {
+                    Some(c) if c.is_alphabetic() => {
+                        s.push(*c);
+                        cs.next();
+                    },
+                    _ => break,
+                }
+            }
+            match s.as_str() {
+                "False" => Ok(HeaderValue::Bool(false)),
+                "True" => Ok(HeaderValue::Bool(true)),
+                _ => Err(invalid_data_error("invalid NPY header")),
+            }
+        },
+        _ => Err(invalid_data_error("invalid NPY header")),
+    }
+}
+
+fn parse_header(s: &str) -> Result<Vec<(String, HeaderValue)>>
+{
+    let mut cs = s.chars().peekable();
+    let mut fields: Vec<(String, HeaderValue)> = Vec::new();
+    skip_header_spaces(&mut cs);
+    match cs.next() {
+        Some('{') => (),
+        _ => return Err(invalid_data_error("invalid NPY header")),
+    }
+    loop {
+        skip_header_spaces(&mut cs);
+        match cs.peek() {
+            Some('}') => {
+                cs.next();
+                break;
+            },
+            _ => (),
+        }
+        let key = read_header_string(&mut cs)?;
+        skip_header_spaces(&mut cs);
+        match cs.next() {
+            Some(':') => (),
+            _ => return Err(invalid_data_error("invalid NPY header")),
+        }
+        skip_header_spaces(&mut cs);
+        let value = read_header_value(&mut cs)?;
+        fields.push((key, value));
+        skip_header_spaces(&mut cs);
+        match cs.next() {
+            Some(',') => (),
+            Some('}') => break,
+            _ => return Err(invalid_data_error("invalid NPY header")),
+        }
+    }
+    skip_header_spaces(&mut cs);
+    match cs.next() {
+        Some(_) => Err(invalid_data_error("invalid NPY header")),
+        None => Ok(fields),
+    }
+}
+
+fn parse_descr(s: &str) -> Result<(ByteOrder, DType)>
+{
+    let mut cs = s.chars();
+    let byte_order = match cs.next() {
+        Some('<') => ByteOrder::Little,
+        Some('>') => ByteOrder::Big,
+        Some('|') | Some('=') => {
+            if cfg!(target_endian = "big") {
+                ByteOrder::Big
+            } else {
+                ByteOrder::Little
+            }
+        },
+        _ => return Err(invalid_data_error("invalid NPY data type")),
+    };
+    let kind = cs.next();
+    let size = match cs.as_str().parse::<usize>() {
+        Ok(n) => n,
+        Err(_) => return Err(invalid_data_error("invalid NPY data type")),
+    };
+    let dtype = match (kind, size) {
+        (Some('b'), 1) => DType::Bool,
+        (Some('i'), 1 | 2 | 4 | 8) => DType::Int(size),
+        (Some('u'), 1 | 2 | 4 | 8) => DType::UInt(size),
+        (Some('f'), 4 | 8) => DType::Float(size),
+        (_, _) => return Err(invalid_data_error(format!("unsupported NPY data type {}", s).as_str())),
+    };
+    Ok((byte_order, dtype))
+}
+
+fn elem_from_bytes(bytes: &[u8], byte_order: ByteOrder, dtype: DType) -> f32
+{
+    let mut buf = [0u8; 8];
+    let n = bytes.len();
+    match byte_order {
+        ByteOrder::Little => buf[0..n].copy_from_slice(bytes),
+        ByteOrder::Big => {
+            for i in 0..n {
+                buf[i] = bytes[n - 1 - i];
+            }
+        },
+    }
+    match dtype {
+        DType::Bool => if buf[0] != 0 { 1.0 } else { 0.0 },
+        DType::Int(1) => (buf[0] as i8) as f32,
+        DType::Int(2) => i16::from_le_bytes([buf[0], buf[1]]) as f32,
+        DType::Int(4) => i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f32,
+        DType::Int(_) => i64::from_le_bytes(buf) as f32,
+        DType::UInt(1) => buf[0] as f32,
+        DType::UInt(2) => u16::from_le_bytes([buf[0], buf[1]]) as f32,
+        DType::UInt(4) => u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f32,
+        DType::UInt(_) => u64::from_le_bytes(buf) as f32,
+        DType::Float(4) => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]),
+        DType::Float(_) => f64::from_le_bytes(buf) as f32,
+    }
+}
+
+fn read_bytes(r: &mut dyn Read, len: usize) -> Result<Vec<u8>>
+{
+    let mut buf = vec![0u8; len];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(buf),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Reads an array in the NPY format from the reader.
+///
+/// Elements of other data types than 32-bit floating-point numbers are cast to 32-bit
+/// floating-point numbers. This function supports booleans, integers, unsigned integers, and
+/// floating-point numbers. Arrays that have more than two dimensions aren't supported.
+pub fn read_npy(r: &mut dyn Read) -> Result<NpyArray>
+{
+    let magic = read_bytes(r, 8)?;
+    if &magic[0..6] != b"\x93NUMPY" {
+        return Err(invalid_data_error("invalid NPY magic"));
+    }
+    let header_len = match magic[6] {
+        1 => {
+            let buf = read_bytes(r, 2)?;
+            u16::from_le_bytes([buf[0], buf[1]]) as usize
+        },
+        2 | 3 => {
+            let buf = read_bytes(r, 4)?;
+            u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize
+        },
+        _ => return Err(invalid_data_error("unsupported NPY version")),
+    };
+    let header = match String::from_utf8(read_bytes(r, header_len)?) {
+        Ok(s) => s,
+        Err(_) => return Err(invalid_data_error("invalid NPY header")),
+    };
+    let mut descr: Option<String> = None;
+    let mut is_transposed: Option<bool> = None;
+    let mut shape: Option<Vec<usize>> = None;
+    for (key, value) in parse_header(header.as_str())? {
+        match (key.as_str(), value) {
+            ("descr", HeaderValue::String(s)) => descr = Some(s),
+            ("fortran_order", HeaderValue::Bool(b)) => is_transposed = Some(b),
+            ("shape", HeaderValue::Tuple(ns)) => shape = Some(ns),
+            (_, _) => return Err(invalid_data_error("invalid NPY header")),
+        }
+    }
+    let (byte_order, dtype) = match descr {
+        Some(s) => parse_descr(s.as_str())?,
+        None => return Err(invalid_data_error("no NPY data type")),
+    };
+    let is_transposed = match is_transposed {
+        Some(b) => b,
+        None => return Err(invalid_data_error("no NPY Fortran order")),
+    };
+    let (row_count, col_count) = match shape {
+        Some(ns) => {
+            match ns.as_slice() {
+                [] => (1, 1),
+                [n] => (*n, 1),
+                [n, m] => (*n, *m),
+                _ => return Err(invalid_data_error("unsupported number of NPY dimensions")),
+            }
+        },
+        None => return Err(invalid_data_error("no NPY shape")),
+    };
+    let len = match row_count.checked_mul(col_count) {
+        Some(len) if len <= (isize::MAX as usize) / 8 => len,
+        _ => return Err(invalid_data_error("too large number of NPY elements")),
+    };
+    let elem_size = match dtype {
+        DType::Bool => 1,
+        DType::Int(size) | DType::UInt(size) | DType::Float(size) => size,
+    };
+    let bytes = read_bytes(r, len * elem_size)?;
+    let elems: Vec<f32> = bytes.chunks(elem_size).map(|c| elem_from_bytes(c, byte_order, dtype)).collect();
+    Ok(NpyArray::new(row_count, col_count, is_transposed, elems))
+}
+
+/// Writes the array in the NPY format to the writer.
+///
+/// The array is written as a two-dimensional array of little-endian 32-bit floating-point
+/// numbers.
+pub fn write_npy(w: &mut dyn Write, a: &NpyArray) -> Result<()>
+{
+    let mut header = format!("{{'descr': '<f4', 'fortran_order': {}, 'shape': ({}, {}), }}", if a.is_transposed { "True" } else { "False" }, a.row_count, a.col_count);
+    while (10 + header.len() + 1) % 64 != 0 {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut buf: Vec<u8> = Vec::with_capacity(10 + header.len() + a.elems.len() * 4);
+    buf.extend_from_slice(b"\x93NUMPY\x01\x00");
+    buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    buf.extend_from_slice(header.as_bytes());
+    for x in &a.elems {
+        buf.extend_from_slice(&x.to_le_bytes());
+    }
+    match w.write_all(buf.as_slice()) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Loads an array in the NPY format from the file.
+pub fn load_npy<P: AsRef<Path>>(path: P) -> Result<NpyArray>
+{
+    match File::open(path) {
+        Ok(file) => {
+            let mut r = BufReader::new(file);
+            read_npy(&mut r)
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Saves the array in the NPY format to a file.
+pub fn save_npy<P: AsRef<Path>>(path: P, a: &NpyArray) -> Result<()>
+{
+    match File::create(path) {
+        Ok(file) => {
+            let mut w = BufWriter::new(file);
+            write_npy(&mut w, a)?;
+            match w.flush() {
+                Ok(()) => Ok(()),
+                Err(err) => Err(Error::Io(err)),
+            }
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Loads named arrays in the NPZ format from the file.
+///
+/// The names of arrays are the names of archive entries without the `.npy` extension.
+pub fn load_npz<P: AsRef<Path>>(path: P) -> Result<Vec<(String, NpyArray)>>
+{
+    match File::open(path) {
+        Ok(file) => {
+            let r = BufReader::new(file);
+            let mut archive = match ZipArchive::new(r) {
+                Ok(tmp_archive) => tmp_archive,
+                Err(err) => return Err(Error::Zip(Box::new(err))),
+            };
+            let mut arrays: Vec<(String, NpyArray)> = Vec::new();
+            for i in 0..archive.len() {
+                let mut entry = match archive.by_index(i) {
+                    Ok(tmp_entry) => tmp_entry,
+                    Err(err) => return Err(Error::Zip(Box::new(err))),
+                };
+                if entry.is_dir() {
+                    continue;
+                }
+                let name = match entry.name().strip_suffix(".npy") {
+                    Some(tmp_name) => String::from(tmp_name),
+                    None => String::from(entry.name()),
+                };
+                let a = read_npy(&mut entry)?;
+                arrays.push((name, a));
+            }
+            Ok(arrays)
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Saves the named arrays in the NPZ format to a file.
+///
+/// The arrays are stored without compression as archive entries that have the `.npy` extension.
+pub fn save_npz<P: AsRef<Path>>(path: P, arrays: &[(String, NpyArray)]) -> Result<()>
+{
+    match File::create(path) {
+        Ok(file) => {
+            let w = BufWriter::new(file);
+            let mut zip_writer = ZipWriter::new(w);
+            let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+            for (name, a) in arrays {
+                match zip_writer.start_file(format!("{}.npy", name), options) {
+                    Ok(()) => (),
+                    Err(err) => return Err(Error::Zip(Box::new(err))),
+                }
+                write_npy(&mut zip_writer, a)?;
+            }
+            match zip_writer.finish() {
+                Ok(mut w) => {
+                    match w.flush() {
+                        Ok(()) => Ok(()),
+                        Err(err) => Err(Error::Io(err)),
+                    }
+                },
+                Err(err) => Err(Error::Zip(Box::new(err))),
+            }
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io::Cursor;
+use sealed_test::prelude::*;
+use super::*;
+
+fn npy_bytes(header: &str, data: &[u8]) -> Vec<u8>
+{
+    let mut buf: Vec<u8> = Vec::new();
+    buf.extend_from_slice(b"\x93NUMPY\x01\x00");
+    buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    buf.extend_from_slice(header.as_bytes());
+    buf.extend_from_slice(data);
+    buf
+}
+
+#[test]
+fn test_write_npy_and_read_npy_writes_array_and_reads_array()
+{
+    let a = NpyArray::new(2, 3, false, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.5]);
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    match write_npy(&mut cursor, &a) {
+        Ok(()) => (),
+        Err(_) => assert!(false),
+    }
+    let buf = cursor.into_inner();
+    let header_len = u16::from_le_bytes([buf[8], buf[9]]) as usize;
+    assert_eq!(0, (10 + header_len) % 64);
+    assert_eq!(10 + header_len + 6 * 4, buf.len());
+    assert_eq!(b'\n', buf[10 + header_len - 1]);
+    let mut cursor = Cursor::new(buf);
+    match read_npy(&mut cursor) {
+        Ok(b) => assert_eq!(a, b),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_write_npy_and_read_npy_writes_array_and_reads_array_in_fortran_order()
+{
+    let a = NpyArray::new(2, 3, true, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    match write_npy(&mut cursor, &a) {
+        Ok(()) => (),
+        Err(_) => assert!(false),
+    }
+    let mut cursor = Cursor::new(cursor.into_inner());
+    match read_npy(&mut cursor) {
+        Ok(b) => assert_eq!(a, b),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_npy_reads_one_dimensional_array_and_casts_doubles()
+{
+    let mut data: Vec<u8> = Vec::new();
+    for x in [1.5f64, 2.0, 3.25] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    let buf = npy_bytes("{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }\n", data.as_slice());
+    let mut cursor = Cursor::new(buf);
+    match read_npy(&mut cursor) {
+        Ok(a) => assert_eq!(NpyArray::new(3, 1, false, vec![1.5, 2.0, 3.25]), a),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_npy_reads_big_endian_integers_in_fortran_order()
+{
+    let mut data: Vec<u8> = Vec::new();
+    for x in [1i32, -3, 2, 4] {
+        data.extend_from_slice(&x.to_be_bytes());
+    }
+    let buf = npy_bytes("{\"descr\": \">i4\", \"fortran_order\": True, \"shape\": (2, 2)}\n", data.as_slice());
+    let mut cursor = Cursor::new(buf);
+    match read_npy(&mut cursor) {
+        Ok(a) => assert_eq!(NpyArray::new(2, 2, true, vec![1.0, -3.0, 2.0, 4.0]), a),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_npy_complains_on_unsupported_data_type()
+{
+    let buf = npy_bytes("{'descr': '<c8', 'fortran_order': False, 'shape': (1,), }\n", &[0u8; 8]);
+    let mut cursor = Cursor::new(buf);
+    match read_npy(&mut cursor) {
+        Err(Error::Io(err)) => {
+            assert_eq!(ErrorKind::InvalidData, err.kind());
+            assert_eq!(String::from("unsupported NPY data type <c8"), format!("{}", err));
+        },
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_npy_complains_on_unsupported_number_of_dimensions()
+{
+    let buf = npy_bytes("{'descr': '<f4', 'fortran_order': False, 'shape': (1, 1, 1), }\n", &[0u8; 4]);
+    let mut cursor = Cursor::new(buf);
+    match read_npy(&mut cursor) {
+        Err(Error::Io(err)) => assert_eq!(String::from("unsupported number of NPY dimensions"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[sealed_test]
+fn test_save_npz_and_load_npz_saves_arrays_and_loads_arrays()
+{
+    let a = NpyArray::new(2, 2, false, vec![1.0, 2.0, 3.0, 4.0]);
+    let b = NpyArray::new(3, 1, false, vec![5.0, 6.0, 7.0]);
+    let arrays = vec![(String::from("a"), a), (String::from("b"), b)];
+    match save_npz("test.npz", arrays.as_slice()) {
+        Ok(()) => (),
+        Err(_) => assert!(false),
+    }
+    match load_npz("test.npz") {
+        Ok(arrays2) => assert_eq!(arrays, arrays2),
+        Err(_) => assert!(false),
+    }
+}
@@ -25,6 +25,13 @@ enum FillableExprs
     FilledExprs(Box<Expr>, Box<Expr>),
 }
 
+#[derive(Clone, Debug)]
+enum AppArg
+{
+    Expr(Box<Expr>),
+    Named(NamedArg),
+}
+
 #[derive(Clone, Debug)]
 struct DocEnv
 {
@@ -185,7 +192,25 @@ impl<'a> Parser<'a>
     fn parse_node(&mut self) -> Result<Node>
     {
         match self.tokens.next().transpose()? {
-            Some((token @ (Token::Function | Token::Module), pos)) => {
+            Some((token @ Token::Function, pos)) => {
+                match self.tokens.next().transpose()? {
+                    Some((token2 @ Token::LParen, pos2)) => {
+                        self.tokens.undo(Ok((token2, pos2)));
+                        self.tokens.undo(Ok((token, pos)));
+                        Ok(Node::Stat(self.parse_stat()?))
+                    },
+                    Some((token2, pos2)) => {
+                        self.tokens.undo(Ok((token2, pos2)));
+                        self.tokens.undo(Ok((token, pos)));
+                        Ok(Node::Def(self.parse_def()?))
+                    },
+                    None => {
+                        self.tokens.undo(Ok((token, pos)));
+                        Ok(Node::Def(self.parse_def()?))
+                    },
+                }
+            },
+            Some((token @ Token::Module, pos)) => {
                 self.tokens.undo(Ok((token, pos)));
                 Ok(Node::Def(self.parse_def()?))
             },
@@ -250,7 +275,7 @@ impl<'a> Parser<'a>
                 }
                 match self.tokens.next().transpose()? {
                     Some((Token::LParen, _)) => {
-                        let args = self.parse_zero_or_more_with_commas(&[Some(Token::RParen)], ParserEofFlag::NoRepetition, Self::parse_arg)?;
+                        let args = self.parse_args()?;
                         match self.tokens.next().transpose()? {
                             Some((Token::RParen, _)) => (),
                             Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unclosed parenthesis"))),
@@ -276,7 +301,81 @@ impl<'a> Parser<'a>
     fn parse_arg(&mut self) -> Result<Arg>
     {
         let (ident, pos) = self.parse_ident()?;
-        Ok(Arg(ident, pos))
+        match self.tokens.next().transpose()? {
+            Some((Token::Eq, _)) => Ok(Arg(ident, ArgKind::Default(self.parse_expr()?), pos)),
+            Some((Token::DotDotDot, _)) => Ok(Arg(ident, ArgKind::Rest, pos)),
+            Some((token, pos2)) => {
+                self.tokens.undo(Ok((token, pos2)));
+                Ok(Arg(ident, ArgKind::Normal, pos))
+            },
+            None => Ok(Arg(ident, ArgKind::Normal, pos)),
+        }
+    }
+
+    fn parse_args(&mut self) -> Result<Vec<Arg>>
+    {
+        let args = self.parse_zero_or_more_with_commas(&[Some(Token::RParen)], ParserEofFlag::NoRepetition, Self::parse_arg)?;
+        let mut is_default = false;
+        for (i, arg) in args.iter().enumerate() {
+            match arg {
+                Arg(_, ArgKind::Normal, pos) => {
+                    if is_default {
+                        return Err(Error::Parser(pos.clone(), String::from("argument without default value after argument with default value")));
+                    }
+                },
+                Arg(_, ArgKind::Default(_), _) => is_default = true,
+                Arg(_, ArgKind::Rest, pos) => {
+                    if i + 1 < args.len() {
+                        return Err(Error::Parser(pos.clone(), String::from("rest argument isn't last argument")));
+                    }
+                },
+            }
+        }
+        Ok(args)
+    }
+
+    fn parse_app_arg(&mut self) -> Result<AppArg>
+    {
+        match self.tokens.next().transpose()? {
+            Some((Token::Ident(ident), pos)) => {
+                match self.tokens.next().transpose()? {
+                    Some((Token::Eq, _)) => Ok(AppArg::Named(NamedArg(ident, self.parse_expr()?, pos))),
+                    Some((token2, pos2)) => {
+                        self.tokens.undo(Ok((token2, pos2)));
+                        self.tokens.undo(Ok((Token::Ident(ident), pos)));
+                        Ok(AppArg::Expr(self.parse_expr()?))
+                    },
+                    None => {
+                        self.tokens.undo(Ok((Token::Ident(ident), pos)));
+                        Ok(AppArg::Expr(self.parse_expr()?))
+                    },
+                }
+            },
+            Some((token, pos)) => {
+                self.tokens.undo(Ok((token, pos)));
+                Ok(AppArg::Expr(self.parse_expr()?))
+            },
+            None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
+        }
+    }
+
+    fn parse_app_args(&mut self) -> Result<(Vec<Box<Expr>>, Vec<NamedArg>)>
+    {
+        let app_args = self.parse_zero_or_more_with_commas(&[Some(Token::RParen)], ParserEofFlag::NoRepetition, Self::parse_app_arg)?;
+        let mut exprs: Vec<Box<Expr>> = Vec::new();
+        let mut named_args: Vec<NamedArg> = Vec::new();
+        for app_arg in app_args {
+            match app_arg {
+                AppArg::Expr(expr) => {
+                    if !named_args.is_empty() {
+                        return Err(Error::Parser(expr.pos().clone(), String::from("positional argument after named argument")));
+                    }
+                    exprs.push(expr);
+                },
+                AppArg::Named(named_arg) => named_args.push(named_arg),
+            }
+        }
+        Ok((exprs, named_args))
     }
     
     fn parse_stat(&mut self) -> Result<Box<Stat>>
@@ -366,6 +465,44 @@ impl<'a> Parser<'a>
                 }
             },
             Some((Token::Quit, pos)) => Ok(Box::new(Stat::Quit(pos))),
+            Some((Token::Try, pos)) => {
+                self.parse_newline()?;
+                let try_stats = self.parse_zero_or_more_with_newlines(&[Some(Token::End), Some(Token::Catch), Some(Token::Finally)], ParserEofFlag::Repetition, Self::parse_stat)?;
+                let catch_pair = match self.tokens.next().transpose()? {
+                    Some((Token::Catch, _)) => {
+                        let ident = self.parse_ident()?.0;
+                        self.parse_newline()?;
+                        let catch_stats = self.parse_zero_or_more_with_newlines(&[Some(Token::End), Some(Token::Finally)], ParserEofFlag::Repetition, Self::parse_stat)?;
+                        Some((ident, catch_stats))
+                    },
+                    Some((token2, pos2)) => {
+                        self.tokens.undo(Ok((token2, pos2)));
+                        None
+                    },
+                    None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
+                };
+                let finally_stats = match self.tokens.next().transpose()? {
+                    Some((Token::Finally, _)) => {
+                        self.parse_newline()?;
+                        Some(self.parse_zero_or_more_with_newlines(&[Some(Token::End)], ParserEofFlag::Repetition, Self::parse_stat)?)
+                    },
+                    Some((token2, pos2)) => {
+                        self.tokens.undo(Ok((token2, pos2)));
+                        None
+                    },
+                    None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
+                };
+                match self.tokens.next().transpose()? {
+                    Some((Token::End, pos2)) => {
+                        if catch_pair.is_none() && finally_stats.is_none() {
+                            return Err(Error::Parser(pos2, String::from("unexpected token")));
+                        }
+                        Ok(Box::new(Stat::Try(try_stats, catch_pair, finally_stats, pos)))
+                    },
+                    Some((_, pos2)) => Err(Error::Parser(pos2, String::from("unexpected token"))),
+                    None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
+                }
+            },
             Some((token, pos)) => {
                 self.tokens.undo(Ok((token, pos)));
                 let expr = self.parse_expr()?;
@@ -441,6 +578,25 @@ impl<'a> Parser<'a>
                 let (name, name_pos) = self.parse_name()?;
                 Ok(Box::new(Expr::Var(name, name_pos)))
             },
+            Some((Token::Function, pos)) => {
+                match self.tokens.next().transpose()? {
+                    Some((Token::LParen, _)) => (),
+                    Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unexpected token"))),
+                    None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
+                }
+                let args = self.parse_args()?;
+                match self.tokens.next().transpose()? {
+                    Some((Token::RParen, _)) => (),
+                    Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unclosed parenthesis"))),
+                    None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
+                }
+                let stats = self.parse_zero_or_more_with_newlines(&[Some(Token::End)], ParserEofFlag::Repetition, Self::parse_stat)?;
+                match self.tokens.next().transpose()? {
+                    Some((Token::End, _)) => Ok(Box::new(Expr::Lambda(Arc::new(Fun(args, stats)), pos))),
+                    Some((_, pos2)) => Err(Error::Parser(pos2, String::from("unexpected token"))),
+                    None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
+                }
+            },
             Some((token, pos)) => {
                 self.tokens.undo(Ok((token, pos)));
                 let (lit, lit_pos) = self.parse_lit()?;
@@ -450,6 +606,18 @@ impl<'a> Parser<'a>
         }
     }
 
+    fn parse_index(&mut self) -> Result<Option<Box<Expr>>>
+    {
+        match self.tokens.next().transpose()? {
+            Some((Token::Colon, _)) => Ok(None),
+            Some((token, pos)) => {
+                self.tokens.undo(Ok((token, pos)));
+                Ok(Some(self.parse_expr()?))
+            },
+            None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
+        }
+    }
+
     fn parse_expr10(&mut self) -> Result<Box<Expr>>
     {
         let mut expr = self.parse_expr11()?;
@@ -457,7 +625,8 @@ impl<'a> Parser<'a>
             let expr_pos = expr.pos().clone();
             match self.tokens.next().transpose()? {
                 Some((Token::LParen, _)) => {
-                    expr = Box::new(Expr::App(expr, self.parse_zero_or_more_with_commas(&[Some(Token::RParen)], ParserEofFlag::NoRepetition, Self::parse_expr)?, expr_pos));
+                    let (exprs, named_args) = self.parse_app_args()?;
+                    expr = Box::new(Expr::App(expr, exprs, named_args, expr_pos));
                     match self.tokens.next().transpose()? {
                         Some((Token::RParen, _)) => (),
                         Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unclosed parenthesis"))),
@@ -465,9 +634,23 @@ impl<'a> Parser<'a>
                     }
                 },
                 Some((Token::LBracket, _)) => {
-                    expr = Box::new(Expr::BinOp(BinOp::Index, expr, self.parse_expr()?, expr_pos));
+                    let idx_expr = self.parse_index()?;
                     match self.tokens.next().transpose()? {
-                        Some((Token::RBracket, _)) => (),
+                        Some((Token::RBracket, pos2)) => {
+                            match idx_expr {
+                                Some(idx_expr) => expr = Box::new(Expr::BinOp(BinOp::Index, expr, idx_expr, expr_pos)),
+                                None => return Err(Error::Parser(pos2, String::from("unexpected token"))),
+                            }
+                        },
+                        Some((Token::Comma, _)) => {
+                            let idx_expr2 = self.parse_index()?;
+                            expr = Box::new(Expr::Index2(expr, idx_expr, idx_expr2, expr_pos));
+                            match self.tokens.next().transpose()? {
+                                Some((Token::RBracket, _)) => (),
+                                Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unclosed bracket"))),
+                                None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
+                            }
+                        },
                         Some((_, pos2)) => return Err(Error::Parser(pos2, String::from("unclosed bracket"))),
                         None => return Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
                     }
@@ -669,8 +852,8 @@ impl<'a> Parser<'a>
             Some((Token::True, pos)) => Ok((Lit::Bool(true), pos)),
             Some((Token::Int(n), pos)) => Ok((Lit::Int(n), pos)),
             Some((Token::Float(n), pos)) => Ok((Lit::Float(n), pos)),
-            Some((Token::Inf, pos)) => Ok((Lit::Float(f32::INFINITY), pos)),
-            Some((Token::Nan, pos)) => Ok((Lit::Float(f32::NAN), pos)),
+            Some((Token::Inf, pos)) => Ok((Lit::Float(f64::INFINITY), pos)),
+            Some((Token::Nan, pos)) => Ok((Lit::Float(f64::NAN), pos)),
             Some((Token::String(s), pos)) => Ok((Lit::String(s), pos)),
             Some((Token::LBracket, pos)) => {
                 self.parse_newlines()?;
@@ -740,6 +923,14 @@ impl<'a> Parser<'a>
                     None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
                 }
             },
+            Some((Token::DotLBrace, pos)) => {
+                let dict_pairs = self.parse_zero_or_more_with_newlines(&[Some(Token::DotRBrace)], ParserEofFlag::Repetition, Self::parse_dict_pair)?;
+                match self.tokens.next().transpose()? {
+                    Some((Token::DotRBrace, _)) => Ok((Lit::Dict(dict_pairs), pos)),
+                    Some((_, pos2)) => Err(Error::Parser(pos2, String::from("unclosed dot brace"))),
+                    None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::Repetition)),
+                }
+            },
             Some((_, pos)) => Err(Error::Parser(pos, String::from("unexpected token"))),
             None => Err(Error::ParserEof(self.path.clone(), ParserEofFlag::NoRepetition)),
         }
@@ -785,6 +976,14 @@ impl<'a> Parser<'a>
         self.parse_colon()?;
         Ok(FieldPair(ident, self.parse_expr()?, pos))
     }
+
+    fn parse_dict_pair(&mut self) -> Result<DictPair>
+    {
+        let expr = self.parse_expr()?;
+        let pos = expr.pos().clone();
+        self.parse_colon()?;
+        Ok(DictPair(expr, self.parse_expr()?, pos))
+    }
     
     fn parse_name(&mut self) -> Result<(Name, Pos)>
     {
@@ -664,7 +664,7 @@ h(2, 3, 4)
                         Stat::Expr(expr, pos) => {
                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
                             match &**expr {
-                                Expr::App(expr2, args, pos) => {
+                                Expr::App(expr2, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
                                     match &**expr2 {
                                         Expr::Var(name, pos) => {
@@ -689,7 +689,7 @@ h(2, 3, 4)
                         Stat::Expr(expr, pos) => {
                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos);
                             match &**expr {
-                                Expr::App(expr2, args, pos) => {
+                                Expr::App(expr2, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos);
                                     match &**expr2 {
                                         Expr::Var(name, pos) => {
@@ -718,7 +718,7 @@ h(2, 3, 4)
                         Stat::Expr(expr, pos) => {
                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 1), *pos);
                             match &**expr {
-                                Expr::App(expr2, args, pos) => {
+                                Expr::App(expr2, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 1), *pos);
                                     match &**expr2 {
                                         Expr::Var(name, pos) => {
@@ -799,6 +799,95 @@ fn test_parser_parse_parses_index_expression()
     }
 }
 
+#[test]
+fn test_parser_parse_parses_index2_expressions()
+{
+    let s = "
+X[1 to 2, :]
+X[:, j]
+";
+    let mut cursor = Cursor::new(s[1..].as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(2, nodes.len());
+            match &nodes[0] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Expr(expr, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            match &**expr {
+                                Expr::Index2(expr2, Some(expr3), None, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                    match &**expr2 {
+                                        Expr::Var(name, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                            assert_eq!(Name::Var(String::from("X")), *name);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    match &**expr3 {
+                                        Expr::Range(expr4, expr5, None, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 3), *pos);
+                                            match &**expr4 {
+                                                Expr::Lit(Lit::Int(1), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 3), *pos),
+                                                _ => assert!(false),
+                                            }
+                                            match &**expr5 {
+                                                Expr::Lit(Lit::Int(2), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 8), *pos),
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            match &nodes[1] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Expr(expr, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos);
+                            match &**expr {
+                                Expr::Index2(expr2, None, Some(expr3), pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos);
+                                    match &**expr2 {
+                                        Expr::Var(name, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 1), *pos);
+                                            assert_eq!(Name::Var(String::from("X")), *name);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    match &**expr3 {
+                                        Expr::Var(name, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 6), *pos);
+                                            assert_eq!(Name::Var(String::from("j")), *name);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_parses_field_expression()
 {
@@ -841,6 +930,114 @@ fn test_parser_parse_parses_field_expression()
     }
 }
 
+#[test]
+fn test_parser_parse_parses_anonymous_function_expressions()
+{
+    let s = "
+f = function(X, Y)
+    X + Y
+end
+function() 1 end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(2, nodes.len());
+            match &nodes[0] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Assign(expr, expr2, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            match &**expr {
+                                Expr::Var(name, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                    assert_eq!(Name::Var(String::from("f")), *name);
+                                },
+                                _ => assert!(false),
+                            }
+                            match &**expr2 {
+                                Expr::Lambda(fun, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos);
+                                    match &**fun {
+                                        Fun(args, stats) => {
+                                            assert_eq!(2, args.len());
+                                            match &args[0] {
+                                                Arg(ident, _, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 14), *pos);
+                                                    assert_eq!(String::from("X"), *ident);
+                                                },
+                                            }
+                                            match &args[1] {
+                                                Arg(ident, _, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 17), *pos);
+                                                    assert_eq!(String::from("Y"), *ident);
+                                                },
+                                            }
+                                            assert_eq!(1, stats.len());
+                                            match &*stats[0] {
+                                                Stat::Expr(expr3, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                    match &**expr3 {
+                                                        Expr::BinOp(BinOp::Add, _, _, pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
+                                                        _ => assert!(false),
+                                                    }
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+            match &nodes[1] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Expr(expr, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos);
+                            match &**expr {
+                                Expr::Lambda(fun, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 1), *pos);
+                                    match &**fun {
+                                        Fun(args, stats) => {
+                                            assert_eq!(true, args.is_empty());
+                                            assert_eq!(1, stats.len());
+                                            match &*stats[0] {
+                                                Stat::Expr(expr2, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 12), *pos);
+                                                    match &**expr2 {
+                                                        Expr::Lit(Lit::Int(1), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 12), *pos),
+                                                        _ => assert!(false),
+                                                    }
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_parses_simple_literals()
 {
@@ -949,7 +1146,7 @@ nan
                             match &**expr {
                                 Expr::Lit(Lit::Float(n), pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 1), *pos);
-                                    assert_eq!(f32::INFINITY, *n);
+                                    assert_eq!(f64::INFINITY, *n);
                                 },
                                 _ => assert!(false),
                             }
@@ -1742,6 +1939,82 @@ fn test_parser_parse_parses_structure_literal()
     }
 }
 
+#[test]
+fn test_parser_parse_parses_dictionary_literal()
+{
+    let s = "
+.{
+    1: \"a\"
+    \"b\": 2
+.}
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Expr(expr, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            match &**expr {
+                                Expr::Lit(Lit::Dict(dict_pairs), pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                    assert_eq!(2, dict_pairs.len());
+                                    match &dict_pairs[0] {
+                                        DictPair(expr2, expr3, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                            match &**expr2 {
+                                                Expr::Lit(Lit::Int(1), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos),
+                                                _ => assert!(false),
+                                            }
+                                            match &**expr3 {
+                                                Expr::Lit(Lit::String(s), pos) => {
+                                                    assert_eq!(String::from("a"), *s);
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 8), *pos);
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                       },
+                                    }
+                                    match &dict_pairs[1] {
+                                        DictPair(expr2, expr3, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 5), *pos);
+                                            match &**expr2 {
+                                                Expr::Lit(Lit::String(s), pos) => {
+                                                    assert_eq!(String::from("b"), *s);
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 5), *pos);
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                            match &**expr3 {
+                                                Expr::Lit(Lit::Int(2), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 10), *pos),
+                                                _ => assert!(false),
+                                            }
+                                       },
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(err) => {
+            println!("{}", err);
+            assert!(false)
+        },
+    }
+}
+
 #[test]
 fn test_parser_parse_parses_empty_structure_literal()
 {
@@ -1938,7 +2211,7 @@ f()
                         Stat::Expr(expr, pos) => {
                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
                             match &**expr {
-                                Expr::App(expr2, args, pos) => {
+                                Expr::App(expr2, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
                                     match &**expr2 {
                                         Expr::Var(name, pos) => {
@@ -1992,7 +2265,7 @@ Z.a = h()
                                 _ => assert!(false),
                             }
                             match &**expr2 {
-                                Expr::App(expr3, args, pos) => {
+                                Expr::App(expr3, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 5), *pos);
                                     match &**expr3 {
                                         Expr::Var(name, pos) => {
@@ -2034,7 +2307,7 @@ Z.a = h()
                                 _ => assert!(false),
                             }
                             match &**expr2 {
-                                Expr::App(expr3, args, pos) => {
+                                Expr::App(expr3, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 8), *pos);
                                     match &**expr3 {
                                         Expr::Var(name, pos) => {
@@ -2073,7 +2346,7 @@ Z.a = h()
                                 _ => assert!(false),
                             }
                             match &**expr2 {
-                                Expr::App(expr3, args, pos) => {
+                                Expr::App(expr3, args, _, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 7), *pos);
                                     match &**expr3 {
                                         Expr::Var(name, pos) => {
@@ -2130,7 +2403,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2150,7 +2423,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2213,7 +2486,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2235,7 +2508,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2255,7 +2528,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2319,7 +2592,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2345,7 +2618,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2365,7 +2638,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2390,7 +2663,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2456,7 +2729,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2482,7 +2755,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2502,7 +2775,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2527,7 +2800,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 8, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2548,7 +2821,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 10, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 10, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2620,7 +2893,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2640,7 +2913,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2700,7 +2973,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2720,7 +2993,7 @@ end
                                 Stat::Expr(expr2, pos) => {
                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                     match &**expr2 {
-                                        Expr::App(expr3, args, pos) => {
+                                        Expr::App(expr3, args, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr3 {
                                                 Expr::Var(name, pos) => {
@@ -2897,13 +3170,15 @@ fn test_parser_parse_parses_quit_statement()
 }
 
 #[test]
-fn test_parser_parse_parses_definition()
+fn test_parser_parse_parses_try_statement()
 {
     let s = "
-function f(X)
+try
+    f()
+catch e
     g()
-
-    X + 1
+finally
+    h()
 end
 ";
     let s2 = &s[1..];
@@ -2916,30 +3191,44 @@ end
         Ok(Tree(nodes)) => {
             assert_eq!(1, nodes.len());
             match &nodes[0] {
-                Node::Def(def) => {
-                    match &**def {
-                        Def::Fun(ident, fun, pos) => {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Try(try_stats, catch_pair, finally_stats, pos) => {
                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
-                            assert_eq!(String::from("f"), *ident);
-                            match &**fun {
-                                Fun(args, stats) => {
-                                    assert_eq!(1, args.len());
-                                    match &args[0] {
-                                        Arg(ident2, pos) => {
-                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 12), *pos);
-                                            assert_eq!(String::from("X"), *ident2);
+                            assert_eq!(1, try_stats.len());
+                            match &*try_stats[0] {
+                                Stat::Expr(expr, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                    match &**expr {
+                                        Expr::App(expr2, args, _, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                            match &**expr2 {
+                                                Expr::Var(name, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                    assert_eq!(Name::Var(String::from("f")), *name);
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                            assert_eq!(true, args.is_empty());
                                         },
+                                        _ => assert!(false),
                                     }
-                                    assert_eq!(2, stats.len());
-                                    match &*stats[0] {
+                                },
+                                _ => assert!(false),
+                            }
+                            match catch_pair {
+                                Some((ident, catch_stats)) => {
+                                    assert_eq!(String::from("e"), *ident);
+                                    assert_eq!(1, catch_stats.len());
+                                    match &*catch_stats[0] {
                                         Stat::Expr(expr, pos) => {
-                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr {
-                                                Expr::App(expr2, args, pos) => {
-                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                Expr::App(expr2, args, _, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                                     match &**expr2 {
                                                         Expr::Var(name, pos) => {
-                                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                                             assert_eq!(Name::Var(String::from("g")), *name);
                                                         },
                                                         _ => assert!(false),
@@ -2951,7 +3240,139 @@ end
                                         },
                                         _ => assert!(false),
                                     }
-                                    match &*stats[1] {
+                                },
+                                None => assert!(false),
+                            }
+                            match finally_stats {
+                                Some(finally_stats) => {
+                                    assert_eq!(1, finally_stats.len());
+                                    match &*finally_stats[0] {
+                                        Stat::Expr(expr, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
+                                            match &**expr {
+                                                Expr::App(expr2, args, _, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
+                                                    match &**expr2 {
+                                                        Expr::Var(name, pos) => {
+                                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 6, 5), *pos);
+                                                            assert_eq!(Name::Var(String::from("h")), *name);
+                                                        },
+                                                        _ => assert!(false),
+                                                    }
+                                                    assert_eq!(true, args.is_empty());
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                },
+                                None => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_parser_parse_parses_try_statement_without_catch()
+{
+    let s = "
+try
+    f()
+finally
+    g()
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Try(try_stats, None, Some(finally_stats), pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            assert_eq!(1, try_stats.len());
+                            assert_eq!(1, finally_stats.len());
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_parser_parse_parses_definition()
+{
+    let s = "
+function f(X)
+    g()
+
+    X + 1
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Fun(ident, fun, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            assert_eq!(String::from("f"), *ident);
+                            match &**fun {
+                                Fun(args, stats) => {
+                                    assert_eq!(1, args.len());
+                                    match &args[0] {
+                                        Arg(ident2, _, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 12), *pos);
+                                            assert_eq!(String::from("X"), *ident2);
+                                        },
+                                    }
+                                    assert_eq!(2, stats.len());
+                                    match &*stats[0] {
+                                        Stat::Expr(expr, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                            match &**expr {
+                                                Expr::App(expr2, args, _, pos) => {
+                                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                    match &**expr2 {
+                                                        Expr::Var(name, pos) => {
+                                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 5), *pos);
+                                                            assert_eq!(Name::Var(String::from("g")), *name);
+                                                        },
+                                                        _ => assert!(false),
+                                                    }
+                                                    assert_eq!(true, args.is_empty());
+                                                },
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    match &*stats[1] {
                                         Stat::Expr(expr, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 4, 5), *pos);
                                             match &**expr {
@@ -3027,7 +3448,7 @@ end
                                                         Fun(args, stats) => {
                                                             assert_eq!(1, args.len());
                                                             match &args[0] {
-                                                                Arg(ident2, pos) => {
+                                                                Arg(ident2, _, pos) => {
                                                                     assert_eq!(Pos::new(Arc::new(String::from("test.un")), 2, 16), *pos);
                                                                     assert_eq!(String::from("X"), *ident2);
                                                                 },
@@ -3162,7 +3583,7 @@ end
                                 Fun(args, stats) => {
                                     assert_eq!(1, args.len());
                                     match &args[0] {
-                                        Arg(ident2, pos) => {
+                                        Arg(ident2, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 5, 12), *pos);
                                             assert_eq!(String::from("X"), *ident2);
                                         },
@@ -3209,19 +3630,19 @@ end
                                 Fun(args, stats) => {
                                     assert_eq!(3, args.len());
                                     match &args[0] {
-                                        Arg(ident2, pos) => {
+                                        Arg(ident2, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 9, 12), *pos);
                                             assert_eq!(String::from("X"), *ident2);
                                         },
                                     }
                                     match &args[1] {
-                                        Arg(ident2, pos) => {
+                                        Arg(ident2, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 9, 15), *pos);
                                             assert_eq!(String::from("Y"), *ident2);
                                         },
                                     }
                                     match &args[2] {
-                                        Arg(ident2, pos) => {
+                                        Arg(ident2, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 9, 18), *pos);
                                             assert_eq!(String::from("Z"), *ident2);
                                         },
@@ -3308,7 +3729,7 @@ X = 1
                                 Fun(args, stats) => {
                                     assert_eq!(1, args.len());
                                     match &args[0] {
-                                        Arg(ident2, pos) => {
+                                        Arg(ident2, _, pos) => {
                                             assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 12), *pos);
                                             assert_eq!(String::from("X"), *ident2);
                                         },
@@ -3483,6 +3904,141 @@ end
     }
 }
 
+#[test]
+fn test_parser_parse_parses_function_definitions_with_default_and_rest_arguments()
+{
+    let s = "
+function f(X, Y = 2, Z...)
+    X
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Def(def) => {
+                    match &**def {
+                        Def::Fun(ident, fun, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            assert_eq!(String::from("f"), *ident);
+                            match &**fun {
+                                Fun(args, stats) => {
+                                    assert_eq!(3, args.len());
+                                    match &args[0] {
+                                        Arg(ident2, ArgKind::Normal, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 12), *pos);
+                                            assert_eq!(String::from("X"), *ident2);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    match &args[1] {
+                                        Arg(ident2, ArgKind::Default(expr), pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 15), *pos);
+                                            assert_eq!(String::from("Y"), *ident2);
+                                            match &**expr {
+                                                Expr::Lit(Lit::Int(2), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 19), *pos),
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    match &args[2] {
+                                        Arg(ident2, ArgKind::Rest, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 22), *pos);
+                                            assert_eq!(String::from("Z"), *ident2);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    assert_eq!(1, stats.len());
+                                },
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_parser_parse_parses_application_expressions_with_named_arguments()
+{
+    let s = "
+f(1, Y = 2, Z = 3)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Ok(Tree(nodes)) => {
+            assert_eq!(1, nodes.len());
+            match &nodes[0] {
+                Node::Stat(stat) => {
+                    match &**stat {
+                        Stat::Expr(expr, pos) => {
+                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                            match &**expr {
+                                Expr::App(expr2, args, named_args, pos) => {
+                                    assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                    match &**expr2 {
+                                        Expr::Var(name, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 1), *pos);
+                                            assert_eq!(Name::Var(String::from("f")), *name);
+                                        },
+                                        _ => assert!(false),
+                                    }
+                                    assert_eq!(1, args.len());
+                                    match &*args[0] {
+                                        Expr::Lit(Lit::Int(1), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 3), *pos),
+                                        _ => assert!(false),
+                                    }
+                                    assert_eq!(2, named_args.len());
+                                    match &named_args[0] {
+                                        NamedArg(ident, expr3, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 6), *pos);
+                                            assert_eq!(String::from("Y"), *ident);
+                                            match &**expr3 {
+                                                Expr::Lit(Lit::Int(2), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 10), *pos),
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                    }
+                                    match &named_args[1] {
+                                        NamedArg(ident, expr3, pos) => {
+                                            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 13), *pos);
+                                            assert_eq!(String::from("Z"), *ident);
+                                            match &**expr3 {
+                                                Expr::Lit(Lit::Int(3), pos) => assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 17), *pos),
+                                                _ => assert!(false),
+                                            }
+                                        },
+                                    }
+                                },
+                                _ => assert!(false),
+                            }
+                        },
+                        _ => assert!(false),
+                    }
+                },
+                _ => assert!(false),
+            }
+        },
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_complains_on_unexpected_token()
 {
@@ -3661,6 +4217,29 @@ while true
     }
 }
 
+#[test]
+fn test_parser_parse_complains_on_unexpected_token_for_try()
+{
+    let s = "
+try
+    f()
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::Parser(pos, msg)) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 3, 1), pos);
+            assert_eq!(String::from("unexpected token"), msg);
+        },
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_complains_on_unclosed_parenthesis_for_function()
 {
@@ -3808,6 +4387,71 @@ fn test_parser_parse_complains_on_unclosed_brace()
     }
 }
 
+#[test]
+fn test_parser_parse_complains_on_argument_without_default_value_after_argument_with_default_value()
+{
+    let s = "
+function f(X = 1, Y)
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::Parser(pos, msg)) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 19), pos);
+            assert_eq!(String::from("argument without default value after argument with default value"), msg);
+        },
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_parser_parse_complains_on_rest_argument_which_is_not_last_argument()
+{
+    let s = "
+function f(X..., Y)
+end
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::Parser(pos, msg)) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 12), pos);
+            assert_eq!(String::from("rest argument isn't last argument"), msg);
+        },
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_parser_parse_complains_on_positional_argument_after_named_argument()
+{
+    let s = "
+f(X = 1, 2)
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::Parser(pos, msg)) => {
+            assert_eq!(Pos::new(Arc::new(String::from("test.un")), 1, 10), pos);
+            assert_eq!(String::from("positional argument after named argument"), msg);
+        },
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_complains_on_eof_with_repetition_for_module()
 {
@@ -3846,6 +4490,25 @@ function f(X)
     }
 }
 
+#[test]
+fn test_parser_parse_complains_on_eof_with_repetition_for_anonymous_function()
+{
+    let s = "
+f = function(X)
+    X + 1
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::ParserEof(path, ParserEofFlag::Repetition)) => assert_eq!(Arc::new(String::from("test.un")), path),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_complains_on_eof_with_repetition_for_if()
 {
@@ -3943,6 +4606,27 @@ while true
     }
 }
 
+#[test]
+fn test_parser_parse_complains_on_eof_with_repetition_for_try()
+{
+    let s = "
+try
+    f()
+catch e
+    g()
+";
+    let s2 = &s[1..];
+    let mut cursor = Cursor::new(s2.as_bytes());
+    let mut lexer = Lexer::new(Arc::new(String::from("test.un")), &mut cursor);
+    let path = lexer.path().clone();
+    let tokens: &mut dyn DocIterator<Item = Result<(Token, Pos)>> = &mut lexer;
+    let mut parser = Parser::new(path, tokens);
+    match parser.parse() {
+        Err(Error::ParserEof(path, ParserEofFlag::Repetition)) => assert_eq!(Arc::new(String::from("test.un")), path),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_parser_parse_complains_on_eof_with_repetition_for_matrix_literal()
 {
@@ -9,6 +9,7 @@
 use std::env::current_dir;
 use std::env::set_current_dir;
 use std::env::split_paths;
+use std::ffi::OsStr;
 use std::ffi::OsString;
 #[cfg(target_family = "unix")]
 use std::fs;
@@ -19,6 +20,7 @@ use std::fs::remove_dir;
 use std::fs::set_permissions;
 use std::io;
 use std::io::stdout;
+use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::Write;
 #[cfg(target_family = "unix")]
@@ -30,9 +32,15 @@ use std::sync::Arc;
 use std::sync::RwLock;
 use crate::toml;
 use crate::backend::*;
+use crate::builtin_doc::*;
+use crate::dap::*;
+use crate::doc::*;
 use crate::error::*;
+use crate::formatter::*;
 use crate::fs::*;
 use crate::home::*;
+use crate::linter::*;
+use crate::lsp::*;
 use crate::main_loop::*;
 use crate::mod_node::*;
 use crate::pkg;
@@ -1004,6 +1012,248 @@ pub fn console<F, G>(is_ctrl_c_intr_checker: bool, are_plotter_windows: bool, ho
         G: FnOnce(&mut ModNode<Value, ()>)
 { run_with_opt_name(None, Vec::new(), is_ctrl_c_intr_checker, are_plotter_windows, home_dir, bin_path, lib_path, doc_path, f, g) }
 
+/// A `dap` command.
+pub fn dap<F, G>(home_dir: &Option<String>, bin_path: &Option<String>, lib_path: &Option<String>, doc_path: &Option<String>, f: F, g: G) -> Option<i32>
+    where F: FnOnce(&mut Home) -> bool,
+        G: FnOnce(&mut ModNode<Value, ()>)
+{
+    let mut home = match create_home(home_dir, bin_path, lib_path, doc_path, true, f) {
+        Some(tmp_home) => tmp_home,
+        None => return Some(1),
+    };
+    match home.add_dirs_to_bin_path(&[String::from("bin")]) {
+        Ok(()) => (),
+        Err(err) => {
+            eprintln!("{}", err);
+            return Some(1);
+        },
+    }
+    match home.add_dirs_to_lib_path(&[String::from("lib")]) {
+        Ok(()) => (),
+        Err(err) => {
+            eprintln!("{}", err);
+            return Some(1);
+        },
+    }
+    match PkgManager::manifest() {
+        Ok(_) => (),
+        Err(err) => {
+            eprint_error(&err);
+            return None;
+        },
+    }
+    match initialize_backend(home.backend_config_file()) {
+        Ok(()) => (),
+        Err(err) => {
+            eprint_error(&err);
+            return Some(1);
+        },
+    }
+    let exit_code = {
+        let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+        g(&mut root_mod);
+        let root_mod_arc = Arc::new(RwLock::new(root_mod));
+        let mut server = DapServer::new(Box::new(BufReader::new(io::stdin())), Box::new(stdout()), root_mod_arc, OsString::from(home.lib_path()), OsString::from(home.doc_path()));
+        match server.run() {
+            Ok(()) => None,
+            Err(err) => {
+                eprint_error(&err);
+                Some(1)
+            },
+        }
+    };
+    match finalize_backend() {
+        Ok(()) => (),
+        Err(err) => {
+            eprint_error(&err);
+            return Some(1);
+        },
+    }
+    exit_code
+}
+
+/// A `lsp` command.
+pub fn lsp<F>(home_dir: &Option<String>, bin_path: &Option<String>, lib_path: &Option<String>, doc_path: &Option<String>, f: F) -> Option<i32>
+    where F: FnOnce(&mut Home) -> bool
+{
+    let mut home = match create_home(home_dir, bin_path, lib_path, doc_path, true, f) {
+        Some(tmp_home) => tmp_home,
+        None => return Some(1),
+    };
+    match home.add_dirs_to_lib_path(&[String::from("lib")]) {
+        Ok(()) => (),
+        Err(err) => {
+            eprintln!("{}", err);
+            return Some(1);
+        },
+    }
+    match PkgManager::manifest() {
+        Ok(_) => (),
+        Err(err) => {
+            eprint_error(&err);
+            return None;
+        },
+    }
+    let mut sig_root_mod: ModNode<Sig, ()> = ModNode::new(());
+    let mut doc_root_mod: ModNode<String, Option<String>> = ModNode::new(None);
+    add_std_builtin_fun_doc(&mut sig_root_mod, &mut doc_root_mod);
+    let doc_tree = DocTree::new(Arc::new(RwLock::new(sig_root_mod)), Arc::new(RwLock::new(doc_root_mod)));
+    let mut server = LspServer::new(Box::new(BufReader::new(io::stdin())), Box::new(stdout()), doc_tree, OsString::from(home.lib_path()));
+    match server.run() {
+        Ok(()) => None,
+        Err(err) => {
+            eprint_error(&err);
+            Some(1)
+        },
+    }
+}
+
+fn res_fmt(is_check: bool) -> Result<Vec<PathBuf>>
+{
+    PkgManager::manifest()?;
+    let mut unformatted_paths: Vec<PathBuf> = Vec::new();
+    for (dir, depth, is_un_ext) in [("bin", Some(1), false), ("lib", None, true), ("tests", None, true)] {
+        let mut suffix_paths = match paths_in_dir(dir, depth) {
+            Ok(tmp_suffix_paths) => tmp_suffix_paths,
+            Err(err) => return Err(Error::Io(err)),
+        };
+        suffix_paths.sort();
+        for suffix_path in &suffix_paths {
+            let mut path_buf = PathBuf::from(dir);
+            path_buf.push(suffix_path);
+            if !path_buf.is_file() || (is_un_ext && path_buf.extension() != Some(OsStr::new("un"))) {
+                continue;
+            }
+            let s = match fs::read_to_string(path_buf.as_path()) {
+                Ok(tmp_s) => tmp_s,
+                Err(err) => return Err(Error::Io(err)),
+            };
+            let formatted_s = format_str(Arc::new(path_buf.to_string_lossy().into_owned()), s.as_str())?;
+            if formatted_s != s {
+                if !is_check {
+                    match fs::write(path_buf.as_path(), formatted_s) {
+                        Ok(()) => (),
+                        Err(err) => return Err(Error::Io(err)),
+                    }
+                }
+                unformatted_paths.push(path_buf);
+            }
+        }
+    }
+    Ok(unformatted_paths)
+}
+
+/// A `fmt` command.
+///
+/// This command formats the scripts of the current package. If the check flag is set, this
+/// command only prints the scripts that aren't formatted and returns `Some(1)` for them.
+pub fn fmt<F>(is_check: bool, home_dir: &Option<String>, bin_path: &Option<String>, lib_path: &Option<String>, doc_path: &Option<String>, f: F) -> Option<i32>
+    where F: FnOnce(&mut Home) -> bool
+{
+    match create_home(home_dir, bin_path, lib_path, doc_path, true, f) {
+        Some(_) => (),
+        None => return Some(1),
+    }
+    match res_fmt(is_check) {
+        Ok(unformatted_paths) => {
+            for path in &unformatted_paths {
+                if is_check {
+                    println!("{} isn't formatted", path.to_string_lossy());
+                } else {
+                    println!("Formatted {}", path.to_string_lossy());
+                }
+            }
+            if is_check && !unformatted_paths.is_empty() {
+                Some(1)
+            } else {
+                None
+            }
+        },
+        Err(err) => {
+            eprint_error(&err);
+            Some(1)
+        },
+    }
+}
+
+fn res_lint(linter: &mut Linter) -> Result<Vec<Warning>>
+{
+    PkgManager::manifest()?;
+    let mut bin_suffix_paths = match paths_in_dir("bin", Some(1)) {
+        Ok(tmp_bin_suffix_paths) => tmp_bin_suffix_paths,
+        Err(err) => return Err(Error::Io(err)),
+    };
+    bin_suffix_paths.sort();
+    for bin_suffix_path in &bin_suffix_paths {
+        let mut path_buf = PathBuf::from("bin");
+        path_buf.push(bin_suffix_path);
+        if path_buf.is_file() {
+            linter.add_script(path_buf.as_path(), None)?;
+        }
+    }
+    let mut lib_suffix_paths = match paths_in_dir("lib", Some(2)) {
+        Ok(tmp_lib_suffix_paths) => tmp_lib_suffix_paths,
+        Err(err) => return Err(Error::Io(err)),
+    };
+    lib_suffix_paths.sort();
+    for lib_suffix_path in &lib_suffix_paths {
+        if lib_suffix_path.components().count() != 2 {
+            continue;
+        }
+        let lib_name = match lib_suffix_path.to_str() {
+            Some(tmp_lib_name) => tmp_lib_name.replace(path::MAIN_SEPARATOR, "/"),
+            None => return Err(Error::Pkg(String::from("library path contains invalid UTF-8 character"))),
+        };
+        let mut path_buf = PathBuf::from("lib");
+        path_buf.push(lib_suffix_path);
+        path_buf.push("lib.un");
+        if path_buf.is_file() {
+            linter.add_lib_script(path_buf.as_path(), lib_name.as_str())?;
+        }
+    }
+    linter.lint()
+}
+
+/// A `lint` command.
+///
+/// This command lints the scripts of the binaries and the libraries of the current package and
+/// prints the lint warnings. This command returns `Some(1)` if there are the lint warnings.
+pub fn lint<F, G>(home_dir: &Option<String>, bin_path: &Option<String>, lib_path: &Option<String>, doc_path: &Option<String>, f: F, g: G) -> Option<i32>
+    where F: FnOnce(&mut Home) -> bool,
+        G: FnOnce(&mut ModNode<Value, ()>)
+{
+    let mut home = match create_home(home_dir, bin_path, lib_path, doc_path, true, f) {
+        Some(tmp_home) => tmp_home,
+        None => return Some(1),
+    };
+    match home.add_dirs_to_lib_path(&[String::from("lib")]) {
+        Ok(()) => (),
+        Err(err) => {
+            eprintln!("{}", err);
+            return Some(1);
+        },
+    }
+    let mut root_mod: ModNode<Value, ()> = ModNode::new(());
+    g(&mut root_mod);
+    let mut linter = Linter::new(&root_mod, OsString::from(home.lib_path()));
+    match res_lint(&mut linter) {
+        Ok(warnings) => {
+            for warning in &warnings {
+                println!("{}", warning);
+            }
+            if !warnings.is_empty() {
+                Some(1)
+            } else {
+                None
+            }
+        },
+        Err(err) => {
+            eprint_error(&err);
+            Some(1)
+        },
+    }
+}
+
 fn res_doc(pkg_manager: &PkgManager) -> Result<()>
 {
     pkg_manager.check_last_op(true)?;
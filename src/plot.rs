@@ -1038,7 +1038,7 @@ fn create_histogram_values(value: &Value) -> Result<Vec<HistogramValue>>
                         match elem {
                             Value::Bool(b) => values.push(HistogramValue::Bool(b)),
                             Value::Int(n) => values.push(HistogramValue::Int(n)),
-                            Value::Float(n) => values.push(HistogramValue::Float(n)),
+                            Value::Float(n) => values.push(HistogramValue::Float(n as f32)),
                             _ => values.push(HistogramValue::String(Box::new(format!("{}", elem)))),
                         }
                     },
@@ -1135,7 +1135,7 @@ fn create_f32s_for_fun_value(interp: &mut Interp, env: &mut Env, fun_value: &Val
 {
     let mut ys = vec![0.0f32; xs.len()];
     for (i, x) in xs.iter().enumerate() {
-        ys[i] = fun_value.apply(interp, env, &[Value::Float(*x)])?.to_f32();
+        ys[i] = fun_value.apply(interp, env, &[Value::Float(*x as f64)])?.to_f32();
     }
     Ok(ys)
 }
@@ -1315,7 +1315,7 @@ fn create_surface_f32s_for_fun_value(interp: &mut Interp, env: &mut Env, fun_val
     let mut zs = vec![0.0f32; len];
     for (yi, y) in ys.iter().enumerate() {
         for (xi, x) in xs.iter().enumerate() {
-            zs[yi * xs.len() + xi] = fun_value.apply(interp, env, &[Value::Float(*x), Value::Float(*y)])?.to_f32();
+            zs[yi * xs.len() + xi] = fun_value.apply(interp, env, &[Value::Float(*x as f64), Value::Float(*y as f64)])?.to_f32();
         }
     }
     Ok(zs)
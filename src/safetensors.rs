@@ -0,0 +1,356 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! A module of the safetensors format.
+//!
+//! This module reads and writes named tensors in the [safetensors](https://github.com/huggingface/safetensors)
+//! format. A file in this format has an 8-byte header size, a JSON header, and a byte buffer of
+//! tensor elements in row-major order.
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use crate::matrix::Matrix;
+use crate::serde_json;
+use crate::error::*;
+use crate::utils::*;
+
+const MAX_HEADER_SIZE: u64 = 100 * 1024 * 1024;
+
+/// A tensor in the safetensors format.
+///
+/// The elements are stored in row-major order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeTensor
+{
+    shape: Vec<usize>,
+    elems: Vec<f32>,
+}
+
+impl SafeTensor
+{
+    /// Creates a tensor.
+    pub fn new(shape: Vec<usize>, elems: Vec<f32>) -> Self
+    { SafeTensor { shape, elems, } }
+
+    /// Creates a two-dimensional tensor from the matrix.
+    pub fn from_matrix(a: &Matrix) -> Result<Self>
+    {
+        let (xs, is_transposed) = matrix_elems_and_transpose_flag(a)?;
+        let elems = if !is_transposed {
+            xs
+        } else {
+            let mut ys: Vec<f32> = Vec::with_capacity(xs.len());
+            for i in 0..a.row_count() {
+                for j in 0..a.col_count() {
+                    ys.push(xs[j * a.row_count() + i]);
+                }
+            }
+            ys
+        };
+        Ok(SafeTensor::new(vec![a.row_count(), a.col_count()], elems))
+    }
+
+    /// Returns the shape.
+    pub fn shape(&self) -> &[usize]
+    { self.shape.as_slice() }
+
+    /// Returns the elements.
+    pub fn elems(&self) -> &[f32]
+    { self.elems.as_slice() }
+
+    /// Returns the number of matrix rows and the number of matrix columns for this tensor.
+    ///
+    /// A scalar is a 1x1 matrix and a one-dimensional tensor is a column vector. The number of
+    /// matrix rows of a tensor that has more dimensions is the first dimension and the number of
+    /// matrix columns of this tensor is the product of the other dimensions.
+    pub fn matrix_size(&self) -> (usize, usize)
+    {
+        match self.shape.split_first() {
+            Some((n, ns)) => (*n, ns.iter().product::<usize>()),
+            None => (1, 1),
+        }
+    }
+
+    /// Converts this tensor to a matrix that has the size of this tensor.
+    pub fn to_matrix(&self) -> Result<Matrix>
+    {
+        let (row_count, col_count) = self.matrix_size();
+        matrix_create_and_set_elems(row_count, col_count, self.elems.as_slice())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum DType
+{
+    Bool,
+    Int(usize),
+    UInt(usize),
+    Float16,
+    BFloat16,
+    Float(usize),
+}
+
+impl DType
+{
+    fn from_str(s: &str) -> Option<Self>
+    {
+        match s {
+            "BOOL" => Some(DType::Bool),
+            "I8" => Some(DType::Int(1)),
+            "I16" => Some(DType::Int(2)),
+            "I32" => Some(DType::Int(4)),
+            "I64" => Some(DType::Int(8)),
+            "U8" => Some(DType::UInt(1)),
+            "U16" => Some(DType::UInt(2)),
+            "U32" => Some(DType::UInt(4)),
+            "U64" => Some(DType::UInt(8)),
+            "F16" => Some(DType::Float16),
+            "BF16" => Some(DType::BFloat16),
+            "F32" => Some(DType::Float(4)),
+            "F64" => Some(DType::Float(8)),
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> usize
+    {
+        match self {
+            DType::Bool => 1,
+            DType::Int(size) | DType::UInt(size) | DType::Float(size) => *size,
+            DType::Float16 | DType::BFloat16 => 2,
+        }
+    }
+}
+
+fn invalid_data_error(msg: &str) -> Error
+{ Error::Io(io::Error::new(ErrorKind::InvalidData, String::from(msg))) }
+
+fn f16_to_f32(h: u16) -> f32
+{
+    let sign = ((h as u32) >> 15) << 31;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let mant = (h & 0x3ff) as u32;
+    if exp == 0 {
+        let x = (mant as f32) * 2.0f32.powi(-24);
+        if sign != 0 { -x } else { x }
+    } else if exp == 31 {
+        f32::from_bits(sign | (0xff << 23) | (mant << 13))
+    } else {
+        f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13))
+    }
+}
+
+fn elem_from_bytes(bytes: &[u8], dtype: DType) -> f32
+{
+    let mut buf = [0u8; 8];
+    buf[0..bytes.len()].copy_from_slice(bytes);
+    match dtype {
+        DType::Bool => if buf[0] != 0 { 1.0 } else { 0.0 },
+        DType::Int(1) => (buf[0] as i8) as f32,
+        DType::Int(2) => i16::from_le_bytes([buf[0], buf[1]]) as f32,
+        DType::Int(4) => i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f32,
+        DType::Int(_) => i64::from_le_bytes(buf) as f32,
+        DType::UInt(1) => buf[0] as f32,
+        DType::UInt(2) => u16::from_le_bytes([buf[0], buf[1]]) as f32,
+        DType::UInt(4) => u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f32,
+        DType::UInt(_) => u64::from_le_bytes(buf) as f32,
+        DType::Float16 => f16_to_f32(u16::from_le_bytes([buf[0], buf[1]])),
+        DType::BFloat16 => f32::from_bits((u16::from_le_bytes([buf[0], buf[1]]) as u32) << 16),
+        DType::Float(4) => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]),
+        DType::Float(_) => f64::from_le_bytes(buf) as f32,
+    }
+}
+
+fn json_usize(value: &serde_json::Value) -> Result<usize>
+{
+    match value.as_u64() {
+        Some(n) if n <= (usize::MAX as u64) => Ok(n as usize),
+        _ => Err(invalid_data_error("invalid safetensors header")),
+    }
+}
+
+fn json_usizes(value: Option<&serde_json::Value>) -> Result<Vec<usize>>
+{
+    match value {
+        Some(serde_json::Value::Array(elems)) => {
+            let mut ns: Vec<usize> = Vec::new();
+            for elem in elems {
+                ns.push(json_usize(elem)?);
+            }
+            Ok(ns)
+        },
+        _ => Err(invalid_data_error("invalid safetensors header")),
+    }
+}
+
+/// Reads named tensors and metadata in the safetensors format from the reader.
+///
+/// Elements of other data types than 32-bit floating-point numbers are cast to 32-bit
+/// floating-point numbers. This function supports booleans, integers, unsigned integers, and
+/// floating-point numbers of 16, 32, and 64 bits. The tensors are sorted by names.
+pub fn read_safetensors(r: &mut dyn Read) -> Result<(Vec<(String, SafeTensor)>, BTreeMap<String, String>)>
+{
+    let mut size_buf = [0u8; 8];
+    match r.read_exact(&mut size_buf) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    let header_size = u64::from_le_bytes(size_buf);
+    if header_size > MAX_HEADER_SIZE {
+        return Err(invalid_data_error("too large safetensors header"));
+    }
+    let mut header_buf = vec![0u8; header_size as usize];
+    match r.read_exact(&mut header_buf) {
+        Ok(()) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    let header: serde_json::Value = match serde_json::from_slice(header_buf.as_slice()) {
+        Ok(tmp_header) => tmp_header,
+        Err(err) => return Err(Error::Io(io::Error::new(ErrorKind::InvalidData, format!("invalid safetensors header: {}", err)))),
+    };
+    let fields = match header {
+        serde_json::Value::Object(tmp_fields) => tmp_fields,
+        _ => return Err(invalid_data_error("invalid safetensors header")),
+    };
+    let mut metadata: BTreeMap<String, String> = BTreeMap::new();
+    let mut infos: Vec<(String, DType, Vec<usize>, usize, usize)> = Vec::new();
+    for (name, field) in &fields {
+        if name == "__metadata__" {
+            match field {
+                serde_json::Value::Object(metadata_fields) => {
+                    for (key, value) in metadata_fields {
+                        match value {
+                            serde_json::Value::String(s) => {
+                                metadata.insert(key.clone(), s.clone());
+                            },
+                            _ => return Err(invalid_data_error("invalid safetensors metadata")),
+                        }
+                    }
+                },
+                _ => return Err(invalid_data_error("invalid safetensors metadata")),
+            }
+            continue;
+        }
+        let dtype = match field.get("dtype") {
+            Some(serde_json::Value::String(s)) => {
+                match DType::from_str(s.as_str()) {
+                    Some(tmp_dtype) => tmp_dtype,
+                    None => return Err(invalid_data_error(format!("unsupported safetensors data type {}", s).as_str())),
+                }
+            },
+            _ => return Err(invalid_data_error("invalid safetensors header")),
+        };
+        let shape = json_usizes(field.get("shape"))?;
+        let offsets = json_usizes(field.get("data_offsets"))?;
+        let (begin, end) = match offsets.as_slice() {
+            [begin, end] if begin <= end => (*begin, *end),
+            _ => return Err(invalid_data_error("invalid safetensors data offsets")),
+        };
+        let len = shape.iter().fold(Some(1usize), |n, m| n.and_then(|n| n.checked_mul(*m)));
+        match len.and_then(|n| n.checked_mul(dtype.size())) {
+            Some(size) if size == end - begin => (),
+            _ => return Err(invalid_data_error("invalid safetensors data offsets")),
+        }
+        infos.push((name.clone(), dtype, shape, begin, end));
+    }
+    let mut data: Vec<u8> = Vec::new();
+    match r.read_to_end(&mut data) {
+        Ok(_) => (),
+        Err(err) => return Err(Error::Io(err)),
+    }
+    let mut tensors: Vec<(String, SafeTensor)> = Vec::new();
+    for (name, dtype, shape, begin, end) in infos {
+        if end > data.len() {
+            return Err(invalid_data_error("invalid safetensors data offsets"));
+        }
+        let elems: Vec<f32> = data[begin..end].chunks(dtype.size()).map(|c| elem_from_bytes(c, dtype)).collect();
+        tensors.push((name, SafeTensor::new(shape, elems)));
+    }
+    Ok((tensors, metadata))
+}
+
+/// Writes the named tensors and the metadata in the safetensors format to the writer.
+///
+/// The tensors are written as 32-bit floating-point numbers. The metadata isn't written if it is
+/// empty.
+pub fn write_safetensors(w: &mut dyn Write, tensors: &[(String, SafeTensor)], metadata: &BTreeMap<String, String>) -> Result<()>
+{
+    let mut fields: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+    if !metadata.is_empty() {
+        let metadata_fields: serde_json::Map<String, serde_json::Value> = metadata.iter().map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone()))).collect();
+        fields.insert(String::from("__metadata__"), serde_json::Value::Object(metadata_fields));
+    }
+    let mut offset = 0usize;
+    for (name, tensor) in tensors {
+        if fields.contains_key(name) {
+            return Err(Error::Io(io::Error::new(ErrorKind::InvalidInput, format!("repeated tensor name {}", name))));
+        }
+        let size = tensor.elems.len() * 4;
+        let mut field: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+        field.insert(String::from("dtype"), serde_json::Value::String(String::from("F32")));
+        field.insert(String::from("shape"), serde_json::Value::Array(tensor.shape.iter().map(|n| serde_json::Value::from(*n)).collect()));
+        field.insert(String::from("data_offsets"), serde_json::Value::Array(vec![serde_json::Value::from(offset), serde_json::Value::from(offset + size)]));
+        fields.insert(name.clone(), serde_json::Value::Object(field));
+        offset += size;
+    }
+    let mut header = match serde_json::to_string(&serde_json::Value::Object(fields)) {
+        Ok(s) => s,
+        Err(err) => return Err(Error::SerdeJson(err)),
+    };
+    while header.len() % 8 != 0 {
+        header.push(' ');
+    }
+    let mut buf: Vec<u8> = Vec::with_capacity(8 + header.len() + offset);
+    buf.extend_from_slice(&(header.len() as u64).to_le_bytes());
+    buf.extend_from_slice(header.as_bytes());
+    for (_, tensor) in tensors {
+        for x in &tensor.elems {
+            buf.extend_from_slice(&x.to_le_bytes());
+        }
+    }
+    match w.write_all(buf.as_slice()) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Loads named tensors and metadata in the safetensors format from the file.
+pub fn load_safetensors<P: AsRef<Path>>(path: P) -> Result<(Vec<(String, SafeTensor)>, BTreeMap<String, String>)>
+{
+    match File::open(path) {
+        Ok(file) => {
+            let mut r = BufReader::new(file);
+            read_safetensors(&mut r)
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Saves the named tensors and the metadata in the safetensors format to a file.
+pub fn save_safetensors<P: AsRef<Path>>(path: P, tensors: &[(String, SafeTensor)], metadata: &BTreeMap<String, String>) -> Result<()>
+{
+    match File::create(path) {
+        Ok(file) => {
+            let mut w = BufWriter::new(file);
+            write_safetensors(&mut w, tensors, metadata)?;
+            match w.flush() {
+                Ok(()) => Ok(()),
+                Err(err) => Err(Error::Io(err)),
+            }
+        },
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,110 @@
+//
+// Copyright (c) 2026 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::io::Cursor;
+use super::*;
+
+fn safetensors_bytes(header: &str, data: &[u8]) -> Vec<u8>
+{
+    let mut buf: Vec<u8> = Vec::new();
+    buf.extend_from_slice(&(header.len() as u64).to_le_bytes());
+    buf.extend_from_slice(header.as_bytes());
+    buf.extend_from_slice(data);
+    buf
+}
+
+#[test]
+fn test_write_safetensors_and_read_safetensors_writes_tensors_and_reads_tensors()
+{
+    let tensors = vec![
+        (String::from("a.bias"), SafeTensor::new(vec![2, 1], vec![1.0, 2.0])),
+        (String::from("a.weight"), SafeTensor::new(vec![2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.5])),
+        (String::from("b"), SafeTensor::new(vec![2, 1, 2], vec![7.0, 8.0, 9.0, 10.0]))
+    ];
+    let mut metadata: BTreeMap<String, String> = BTreeMap::new();
+    metadata.insert(String::from("format"), String::from("pt"));
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    match write_safetensors(&mut cursor, tensors.as_slice(), &metadata) {
+        Ok(()) => (),
+        Err(_) => assert!(false),
+    }
+    let buf = cursor.into_inner();
+    let header_size = u64::from_le_bytes([buf[0], buf[1], buf[2], buf[3], buf[4], buf[5], buf[6], buf[7]]) as usize;
+    assert_eq!(0, header_size % 8);
+    assert_eq!(8 + header_size + 12 * 4, buf.len());
+    let mut cursor = Cursor::new(buf);
+    match read_safetensors(&mut cursor) {
+        Ok((tensors2, metadata2)) => {
+            assert_eq!(tensors, tensors2);
+            assert_eq!(metadata, metadata2);
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_safetensors_reads_tensors_and_casts_elements()
+{
+    let mut data: Vec<u8> = Vec::new();
+    for x in [0x3c00u16, 0xc000, 0x3800] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    for x in [0x3f80u16] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    for x in [-5i64, 6] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    let header = "{\"h\":{\"dtype\":\"F16\",\"shape\":[3],\"data_offsets\":[0,6]},\"b\":{\"dtype\":\"BF16\",\"shape\":[],\"data_offsets\":[6,8]},\"__metadata__\":{\"x\":\"y\"},\"i\":{\"dtype\":\"I64\",\"shape\":[1,2],\"data_offsets\":[8,24]}}";
+    let buf = safetensors_bytes(header, data.as_slice());
+    let mut cursor = Cursor::new(buf);
+    match read_safetensors(&mut cursor) {
+        Ok((tensors, metadata)) => {
+            let expected_tensors = vec![
+                (String::from("b"), SafeTensor::new(vec![], vec![1.0])),
+                (String::from("h"), SafeTensor::new(vec![3], vec![1.0, -2.0, 0.5])),
+                (String::from("i"), SafeTensor::new(vec![1, 2], vec![-5.0, 6.0]))
+            ];
+            assert_eq!(expected_tensors, tensors);
+            assert_eq!(Some(&String::from("y")), metadata.get("x"));
+            assert_eq!((1, 1), tensors[0].1.matrix_size());
+            assert_eq!((3, 1), tensors[1].1.matrix_size());
+        },
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_safetensors_complains_on_invalid_data_offsets()
+{
+    let header = "{\"a\":{\"dtype\":\"F32\",\"shape\":[2],\"data_offsets\":[0,4]}}";
+    let buf = safetensors_bytes(header, &[0u8; 8]);
+    let mut cursor = Cursor::new(buf);
+    match read_safetensors(&mut cursor) {
+        Err(Error::Io(err)) => assert_eq!(String::from("invalid safetensors data offsets"), format!("{}", err)),
+        _ => assert!(false),
+    }
+    let header = "{\"a\":{\"dtype\":\"F32\",\"shape\":[2],\"data_offsets\":[0,8]}}";
+    let buf = safetensors_bytes(header, &[0u8; 4]);
+    let mut cursor = Cursor::new(buf);
+    match read_safetensors(&mut cursor) {
+        Err(Error::Io(err)) => assert_eq!(String::from("invalid safetensors data offsets"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_read_safetensors_complains_on_unsupported_data_type()
+{
+    let header = "{\"a\":{\"dtype\":\"C64\",\"shape\":[1],\"data_offsets\":[0,8]}}";
+    let buf = safetensors_bytes(header, &[0u8; 8]);
+    let mut cursor = Cursor::new(buf);
+    match read_safetensors(&mut cursor) {
+        Err(Error::Io(err)) => assert_eq!(String::from("unsupported safetensors data type C64"), format!("{}", err)),
+        _ => assert!(false),
+    }
+}
@@ -68,17 +68,41 @@ pub struct Fun(pub Vec<Arg>, pub Vec<Box<Stat>>);
 
 /// An argument structure.
 #[derive(Clone, Debug)]
-pub struct Arg(pub String, pub Pos);
+pub struct Arg(pub String, pub ArgKind, pub Pos);
 
 impl Arg
 {
     /// Returns the file position.
     pub fn pos(&self) -> &Pos
-    { &self.1 }
+    { &self.2 }
     
     /// Sets the file position.
     pub fn set_pos(&mut self, pos: Pos)
-    { self.1 = pos; }
+    { self.2 = pos; }
+}
+
+impl fmt::Display for Arg
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Arg(ident, ArgKind::Normal, _) => write!(f, "{}", ident),
+            Arg(ident, ArgKind::Default(expr), _) => write!(f, "{} = {}", ident, expr),
+            Arg(ident, ArgKind::Rest, _) => write!(f, "{}...", ident),
+        }
+    }
+}
+
+/// An enumeration of argument kind.
+#[derive(Clone, Debug)]
+pub enum ArgKind
+{
+    /// A normal argument.
+    Normal,
+    /// An argument with a default value expression.
+    Default(Box<Expr>),
+    /// A rest argument that collects the remaining arguments into an array.
+    Rest,
 }
 
 /// A statement enumeration.
@@ -103,6 +127,8 @@ pub enum Stat
     Return(Option<Box<Expr>>, Pos),
     /// A `quit` statement.
     Quit(Pos),
+    /// A `try` statement.
+    Try(Vec<Box<Stat>>, Option<(String, Vec<Box<Stat>>)>, Option<Vec<Box<Stat>>>, Pos),
 }
 
 impl Stat
@@ -120,6 +146,7 @@ impl Stat
             Stat::Continue(pos) => pos,
             Stat::Return(_, pos) => pos,
             Stat::Quit(pos) => pos,
+            Stat::Try(_, _, _, pos) => pos,
         }
     }
 
@@ -136,6 +163,7 @@ impl Stat
             Stat::Continue(pos2) => *pos2 = pos,
             Stat::Return(_, pos2) => *pos2 = pos,
             Stat::Quit(pos2) => *pos2 = pos,
+            Stat::Try(_, _, _, pos2) => *pos2 = pos,
         }
     }
 }
@@ -148,8 +176,8 @@ pub enum Expr
     Lit(Lit, Pos),
     /// A variable.
     Var(Name, Pos),
-    /// A function application.
-    App(Box<Expr>, Vec<Box<Expr>>, Pos),
+    /// A function application with the positional arguments and the named arguments.
+    App(Box<Expr>, Vec<Box<Expr>>, Vec<NamedArg>, Pos),
     /// An expression of unary operator.
     UnaryOp(UnaryOp, Box<Expr>, Pos),
     /// An expression of binary operator.
@@ -160,10 +188,16 @@ pub enum Expr
     Or(Box<Expr>, Box<Expr>, Pos),
     /// A field access.
     Field(Box<Expr>, String, Pos),
+    /// A two-dimensional indexing with the optional row index and the optional column index.
+    ///
+    /// A `None` index refers to all rows or all columns and is written as a colon.
+    Index2(Box<Expr>, Option<Box<Expr>>, Option<Box<Expr>>, Pos),
     /// A range.
     Range(Box<Expr>, Box<Expr>, Option<Box<Expr>>, Pos),
     /// An error propagation.
     PropagateError(Box<Expr>, Pos),
+    /// An anonymous function.
+    Lambda(Arc<Fun>, Pos),
 }
 
 impl Expr
@@ -174,14 +208,16 @@ impl Expr
         match self {
             Expr::Lit(_, pos) => pos,
             Expr::Var(_, pos) => pos,
-            Expr::App(_, _, pos) => pos,
+            Expr::App(_, _, _, pos) => pos,
             Expr::UnaryOp(_, _, pos) => pos,
             Expr::BinOp(_, _, _, pos) => pos,
             Expr::And(_, _, pos) => pos,
             Expr::Or(_, _, pos) => pos,
             Expr::Field(_, _, pos) => pos,
+            Expr::Index2(_, _, _, pos) => pos,
             Expr::Range(_, _, _, pos) => pos,
             Expr::PropagateError(_, pos) => pos,
+            Expr::Lambda(_, pos) => pos,
         }
     }
 
@@ -191,18 +227,178 @@ impl Expr
         match self {
             Expr::Lit(_, pos2) => *pos2 = pos,
             Expr::Var(_, pos2) => *pos2 = pos,
-            Expr::App(_, _, pos2) => *pos2 = pos,
+            Expr::App(_, _, _, pos2) => *pos2 = pos,
             Expr::UnaryOp(_, _, pos2) => *pos2 = pos,
             Expr::BinOp(_, _, _, pos2) => *pos2 = pos,
             Expr::And(_, _, pos2) => *pos2 = pos,
             Expr::Or(_, _, pos2) => *pos2 = pos,
             Expr::Field(_, _, pos2) => *pos2 = pos,
+            Expr::Index2(_, _, _, pos2) => *pos2 = pos,
             Expr::Range(_, _, _, pos2) => *pos2 = pos,
             Expr::PropagateError(_, pos2) => *pos2 = pos,
+            Expr::Lambda(_, pos2) => *pos2 = pos,
+        }
+    }
+
+    /// Returns the operator precedence of the expression.
+    ///
+    /// A greater number means that the expression binds tighter.
+    pub fn prec(&self) -> u32
+    {
+        match self {
+            Expr::Lit(_, _) | Expr::Var(_, _) | Expr::Lambda(_, _) => 11,
+            Expr::App(_, _, _, _) | Expr::Field(_, _, _) | Expr::BinOp(BinOp::Index, _, _, _) | Expr::Index2(_, _, _, _) => 10,
+            Expr::PropagateError(_, _) => 9,
+            Expr::UnaryOp(_, _, _) => 8,
+            Expr::BinOp(BinOp::Mul | BinOp::DotMul | BinOp::Div | BinOp::DotDiv, _, _, _) => 7,
+            Expr::BinOp(BinOp::Add | BinOp::DotAdd | BinOp::Sub | BinOp::DotSub, _, _, _) => 6,
+            Expr::Range(_, _, _, _) => 5,
+            Expr::BinOp(BinOp::Lt | BinOp::Ge | BinOp::Gt | BinOp::Le, _, _, _) => 4,
+            Expr::BinOp(BinOp::Eq | BinOp::Ne, _, _, _) => 3,
+            Expr::And(_, _, _) => 2,
+            Expr::Or(_, _, _) => 1,
+        }
+    }
+
+    fn fmt_with_prec(&self, f: &mut fmt::Formatter<'_>, prec: u32) -> fmt::Result
+    {
+        if self.prec() < prec {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
         }
     }
 }
 
+impl fmt::Display for Expr
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Expr::Lit(lit, _) => write!(f, "{}", lit),
+            Expr::Var(name, _) => write!(f, "{}", name),
+            Expr::App(expr, exprs, named_args, _) => {
+                expr.fmt_with_prec(f, 10)?;
+                write!(f, "(")?;
+                let mut is_first = true;
+                for expr2 in exprs {
+                    if !is_first {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", expr2)?;
+                    is_first = false;
+                }
+                for named_arg in named_args {
+                    if !is_first {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", named_arg)?;
+                    is_first = false;
+                }
+                write!(f, ")")
+            },
+            Expr::UnaryOp(UnaryOp::Transpose, expr, _) => {
+                expr.fmt_with_prec(f, 9)?;
+                write!(f, "'")
+            },
+            Expr::UnaryOp(op, expr, _) => {
+                write!(f, "{}", op)?;
+                expr.fmt_with_prec(f, 8)
+            },
+            Expr::BinOp(BinOp::Index, expr, expr2, _) => {
+                expr.fmt_with_prec(f, 10)?;
+                write!(f, "[{}]", expr2)
+            },
+            Expr::BinOp(op, expr, expr2, _) => {
+                expr.fmt_with_prec(f, self.prec())?;
+                write!(f, " {} ", op)?;
+                expr2.fmt_with_prec(f, self.prec() + 1)
+            },
+            Expr::And(expr, expr2, _) => {
+                expr.fmt_with_prec(f, 2)?;
+                write!(f, " and ")?;
+                expr2.fmt_with_prec(f, 3)
+            },
+            Expr::Or(expr, expr2, _) => {
+                expr.fmt_with_prec(f, 1)?;
+                write!(f, " or ")?;
+                expr2.fmt_with_prec(f, 2)
+            },
+            Expr::Field(expr, ident, _) => {
+                expr.fmt_with_prec(f, 10)?;
+                write!(f, ".{}", ident)
+            },
+            Expr::Index2(expr, expr2, expr3, _) => {
+                expr.fmt_with_prec(f, 10)?;
+                write!(f, "[")?;
+                match expr2 {
+                    Some(expr2) => write!(f, "{}", expr2)?,
+                    None => write!(f, ":")?,
+                }
+                write!(f, ", ")?;
+                match expr3 {
+                    Some(expr3) => write!(f, "{}", expr3)?,
+                    None => write!(f, ":")?,
+                }
+                write!(f, "]")
+            },
+            Expr::Range(expr, expr2, expr3, _) => {
+                expr.fmt_with_prec(f, 6)?;
+                write!(f, " to ")?;
+                expr2.fmt_with_prec(f, 6)?;
+                match expr3 {
+                    Some(expr3) => {
+                        write!(f, " by ")?;
+                        expr3.fmt_with_prec(f, 6)
+                    },
+                    None => Ok(()),
+                }
+            },
+            Expr::PropagateError(expr, _) => {
+                expr.fmt_with_prec(f, 9)?;
+                write!(f, "?")
+            },
+            Expr::Lambda(fun, _) => {
+                match &**fun {
+                    Fun(args, _) => {
+                        write!(f, "function(")?;
+                        let mut is_first = true;
+                        for arg in args {
+                            if !is_first {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{}", arg)?;
+                            is_first = false;
+                        }
+                        write!(f, ") ... end")
+                    },
+                }
+            },
+        }
+    }
+}
+
+/// A structure of named argument.
+#[derive(Clone, Debug)]
+pub struct NamedArg(pub String, pub Box<Expr>, pub Pos);
+
+impl NamedArg
+{
+    /// Returns the file position.
+    pub fn pos(&self) -> &Pos
+    { &self.2 }
+    
+    /// Sets the file position.
+    pub fn set_pos(&mut self, pos: Pos)
+    { self.2 = pos; }
+}
+
+impl fmt::Display for NamedArg
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    { write!(f, "{} = {}", self.0, self.1) }
+}
+
 /// A literal.
 #[derive(Clone, Debug)]
 pub enum Lit
@@ -214,7 +410,7 @@ pub enum Lit
     /// An integer number literal.
     Int(i64),
     /// A floating-point number literal.
-    Float(f32),
+    Float(f64),
     /// A stribg literal.
     String(String),
     /// A matrix literal.
@@ -227,6 +423,117 @@ pub enum Lit
     FilledArray(Box<Expr>, Box<Expr>),
     /// A structure literal.
     Struct(Vec<FieldPair>),
+    /// A dictionary literal.
+    Dict(Vec<DictPair>),
+}
+
+fn fmt_exprs(f: &mut fmt::Formatter<'_>, exprs: &[Box<Expr>]) -> fmt::Result
+{
+    let mut is_first = true;
+    for expr in exprs {
+        if !is_first {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", expr)?;
+        is_first = false;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Lit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Lit::None => write!(f, "none"),
+            Lit::Bool(false) => write!(f, "false"),
+            Lit::Bool(true) => write!(f, "true"),
+            Lit::Int(n) => write!(f, "{}", n),
+            Lit::Float(n) => {
+                if n.is_nan() {
+                    write!(f, "nan")
+                } else if n.is_infinite() {
+                    write!(f, "inf")
+                } else {
+                    write!(f, "{:?}", n)
+                }
+            },
+            Lit::String(s) => {
+                write!(f, "\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\x07' => write!(f, "\\a")?,
+                        '\x08' => write!(f, "\\b")?,
+                        '\t' => write!(f, "\\t")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\x0b' => write!(f, "\\v")?,
+                        '\x0c' => write!(f, "\\f")?,
+                        '\r' => write!(f, "\\r")?,
+                        _ if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                        _ => write!(f, "{}", c)?,
+                    }
+                }
+                write!(f, "\"")
+            },
+            Lit::Matrix(matrix_rows) => {
+                write!(f, "[")?;
+                let mut is_first = true;
+                for matrix_row in matrix_rows {
+                    if !is_first {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", matrix_row)?;
+                    is_first = false;
+                }
+                write!(f, "]")
+            },
+            Lit::FilledMatrix(matrix_row, expr) => write!(f, "[{}; fill {}]", matrix_row, expr),
+            Lit::Array(exprs) => {
+                if !exprs.is_empty() {
+                    write!(f, ".[ ")?;
+                    fmt_exprs(f, exprs.as_slice())?;
+                    write!(f, " .]")
+                } else {
+                    write!(f, ".[.]")
+                }
+            },
+            Lit::FilledArray(expr, expr2) => write!(f, ".[ {} fill {} .]", expr, expr2),
+            Lit::Struct(field_pairs) => {
+                if !field_pairs.is_empty() {
+                    write!(f, "{{ ")?;
+                    let mut is_first = true;
+                    for field_pair in field_pairs {
+                        if !is_first {
+                            write!(f, "; ")?;
+                        }
+                        write!(f, "{}: {}", field_pair.0, field_pair.1)?;
+                        is_first = false;
+                    }
+                    write!(f, " }}")
+                } else {
+                    write!(f, "{{}}")
+                }
+            },
+            Lit::Dict(dict_pairs) => {
+                if !dict_pairs.is_empty() {
+                    write!(f, ".{{ ")?;
+                    let mut is_first = true;
+                    for dict_pair in dict_pairs {
+                        if !is_first {
+                            write!(f, "; ")?;
+                        }
+                        write!(f, "{}: {}", dict_pair.0, dict_pair.1)?;
+                        is_first = false;
+                    }
+                    write!(f, " .}}")
+                } else {
+                    write!(f, ".{{.}}")
+                }
+            },
+        }
+    }
 }
 
 /// An enumeration of matrix row.
@@ -239,6 +546,17 @@ pub enum MatrixRow
     FilledRow(Box<Expr>, Box<Expr>),
 }
 
+impl fmt::Display for MatrixRow
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            MatrixRow::Row(exprs) => fmt_exprs(f, exprs.as_slice()),
+            MatrixRow::FilledRow(expr, expr2) => write!(f, "{} fill {}", expr, expr2),
+        }
+    }
+}
+
 /// A structure of field pair. 
 #[derive(Clone, Debug)]
 pub struct FieldPair(pub String, pub Box<Expr>, pub Pos);
@@ -254,6 +572,23 @@ impl FieldPair
     { self.2 = pos; }
 }
 
+/// A structure of dictionary pair.
+///
+/// The file position is the file position of the key.
+#[derive(Clone, Debug)]
+pub struct DictPair(pub Box<Expr>, pub Box<Expr>, pub Pos);
+
+impl DictPair
+{
+    /// Returns the file position.
+    pub fn pos(&self) -> &Pos
+    { &self.2 }
+    
+    /// Sets the file position.
+    pub fn set_pos(&mut self, pos: Pos)
+    { self.2 = pos; }
+}
+
 /// An enumeration of unary operator.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum UnaryOp
@@ -268,6 +603,19 @@ pub enum UnaryOp
     Transpose,
 }
 
+impl fmt::Display for UnaryOp
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            UnaryOp::Neg => write!(f, "-"),
+            UnaryOp::DotNeg => write!(f, ".-"),
+            UnaryOp::Not => write!(f, "not "),
+            UnaryOp::Transpose => write!(f, "'"),
+        }
+    }
+}
+
 /// An enumeration of binary operator.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum BinOp
@@ -304,6 +652,30 @@ pub enum BinOp
     Ne,
 }
 
+impl fmt::Display for BinOp
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            BinOp::Index => write!(f, "[]"),
+            BinOp::Mul => write!(f, "*"),
+            BinOp::DotMul => write!(f, ".*"),
+            BinOp::Div => write!(f, "/"),
+            BinOp::DotDiv => write!(f, "./"),
+            BinOp::Add => write!(f, "+"),
+            BinOp::DotAdd => write!(f, ".+"),
+            BinOp::Sub => write!(f, "-"),
+            BinOp::DotSub => write!(f, ".-"),
+            BinOp::Lt => write!(f, "<"),
+            BinOp::Ge => write!(f, ">="),
+            BinOp::Gt => write!(f, ">"),
+            BinOp::Le => write!(f, "<="),
+            BinOp::Eq => write!(f, "=="),
+            BinOp::Ne => write!(f, "!="),
+        }
+    }
+}
+
 /// An enumeration of variable name.
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum Name
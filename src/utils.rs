@@ -1037,6 +1037,686 @@ pub fn matrix_min_for_scalar(a: &Matrix, b: f32) -> Result<Matrix>
     }
 }
 
+fn matrix_res_mul_with_frontend(frontend: &Frontend, a: &Matrix, b: &Matrix) -> matrix::Result<Matrix>
+{
+    let c = if frontend.backend().has_cublas() {
+        frontend.create_matrix_and_set_zeros(a.row_count(), b.col_count())?
+    } else {
+        unsafe { frontend.create_matrix(a.row_count(), b.col_count())? }
+    };
+    frontend.mul(a, b, &c)?;
+    Ok(c)
+}
+
+fn matrix_idxs_or_all_idxs(idxs: Option<&[usize]>, n: usize) -> Vec<usize>
+{
+    match idxs {
+        Some(idxs) => idxs.to_vec(),
+        None => (0..n).collect(),
+    }
+}
+
+fn matrix_res_select(a: &Matrix, row_idxs: Option<&[usize]>, col_idxs: Option<&[usize]>) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    let row_idxs = matrix_idxs_or_all_idxs(row_idxs, a.row_count());
+    let col_idxs = matrix_idxs_or_all_idxs(col_idxs, a.col_count());
+    let mut ys: Vec<f32> = Vec::with_capacity(row_idxs.len() * col_idxs.len());
+    for i in &row_idxs {
+        for j in &col_idxs {
+            ys.push(xs[i * a.col_count() + j]);
+        }
+    }
+    frontend.create_matrix_and_set_elems(row_idxs.len(), col_idxs.len(), ys.as_slice())
+}
+
+/// Selects the rows and the columns of the `a` matrix for the indices.
+///
+/// `None` indices select all rows or all columns. This function copies the selected elements
+/// through the host memory.
+pub fn matrix_select(a: &Matrix, row_idxs: Option<&[usize]>, col_idxs: Option<&[usize]>) -> Result<Matrix>
+{
+    match matrix_res_select(a, row_idxs, col_idxs) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_set_selected<F>(a: &Matrix, row_idxs: Option<&[usize]>, col_idxs: Option<&[usize]>, f: F) -> matrix::Result<Matrix>
+    where F: FnOnce(&Frontend, usize) -> matrix::Result<Vec<f32>>
+{
+    let frontend = Frontend::new()?;
+    let mut xs = matrix_res_row_major_elems(&frontend, a)?;
+    let row_idxs = matrix_idxs_or_all_idxs(row_idxs, a.row_count());
+    let col_idxs = matrix_idxs_or_all_idxs(col_idxs, a.col_count());
+    let ys = f(&frontend, row_idxs.len() * col_idxs.len())?;
+    for (i2, i) in row_idxs.iter().enumerate() {
+        for (j2, j) in col_idxs.iter().enumerate() {
+            xs[i * a.col_count() + j] = ys[i2 * col_idxs.len() + j2];
+        }
+    }
+    frontend.create_matrix_and_set_elems(a.row_count(), a.col_count(), xs.as_slice())
+}
+
+/// Sets the selected rows and the selected columns of the `a` matrix on the `b` matrix and
+/// returns a new matrix.
+///
+/// The `b` matrix must have the size of the sub-matrix. The indices mustn't be repeated. See
+/// [`matrix_select`].
+pub fn matrix_set_selected(a: &Matrix, row_idxs: Option<&[usize]>, col_idxs: Option<&[usize]>, b: &Matrix) -> Result<Matrix>
+{
+    match matrix_res_set_selected(a, row_idxs, col_idxs, |frontend, _| matrix_res_row_major_elems(frontend, b)) {
+        Ok(c) => Ok(c),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+/// Sets the selected rows and the selected columns of the `a` matrix on the `b` scalar and
+/// returns a new matrix.
+///
+/// The indices mustn't be repeated. See [`matrix_select`].
+pub fn matrix_set_selected_for_scalar(a: &Matrix, row_idxs: Option<&[usize]>, col_idxs: Option<&[usize]>, b: f32) -> Result<Matrix>
+{
+    match matrix_res_set_selected(a, row_idxs, col_idxs, |_, n| Ok(vec![b; n])) {
+        Ok(c) => Ok(c),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_ones(frontend: &Frontend, row_count: usize, col_count: usize) -> matrix::Result<Matrix>
+{ frontend.create_matrix_and_set_elems(row_count, col_count, vec![1.0f32; row_count * col_count].as_slice()) }
+
+fn matrix_res_hcat(as1: &[Matrix]) -> matrix::Result<Option<Matrix>>
+{
+    if as1.is_empty() {
+        return Ok(None);
+    }
+    let frontend = Frontend::new()?;
+    let row_count = as1[0].row_count();
+    let col_count = as1.iter().fold(0, |n, a| n + a.col_count());
+    let mut ys = vec![0.0f32; row_count * col_count];
+    let mut j = 0usize;
+    for a in as1 {
+        let xs = matrix_res_row_major_elems(&frontend, a)?;
+        for i in 0..a.row_count() {
+            ys[(i * col_count + j)..(i * col_count + j + a.col_count())].copy_from_slice(&xs[(i * a.col_count())..((i + 1) * a.col_count())]);
+        }
+        j += a.col_count();
+    }
+    Ok(Some(frontend.create_matrix_and_set_elems(row_count, col_count, ys.as_slice())?))
+}
+
+/// Concatenates the matrices horizontally.
+///
+/// The matrices must have the same number of rows. This function returns `None` if the slice of
+/// matrices is empty. This function copies the elements through the host memory.
+pub fn matrix_hcat(as1: &[Matrix]) -> Result<Option<Matrix>>
+{
+    match matrix_res_hcat(as1) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_vcat(as1: &[Matrix]) -> matrix::Result<Option<Matrix>>
+{
+    if as1.is_empty() {
+        return Ok(None);
+    }
+    let frontend = Frontend::new()?;
+    let row_count = as1.iter().fold(0, |n, a| n + a.row_count());
+    let col_count = as1[0].col_count();
+    let mut ys: Vec<f32> = Vec::with_capacity(row_count * col_count);
+    for a in as1 {
+        ys.extend(matrix_res_row_major_elems(&frontend, a)?);
+    }
+    Ok(Some(frontend.create_matrix_and_set_elems(row_count, col_count, ys.as_slice())?))
+}
+
+/// Concatenates the matrices vertically.
+///
+/// The matrices must have the same number of columns. See [`matrix_hcat`].
+pub fn matrix_vcat(as1: &[Matrix]) -> Result<Option<Matrix>>
+{
+    match matrix_res_vcat(as1) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_row_major_elems(frontend: &Frontend, a: &Matrix) -> matrix::Result<Vec<f32>>
+{
+    let (xs, is_transposed) = frontend.elems_and_transpose_flag(a)?;
+    if is_transposed {
+        let mut ys = vec![0.0f32; xs.len()];
+        for i in 0..a.row_count() {
+            for j in 0..a.col_count() {
+                ys[i * a.col_count() + j] = xs[j * a.row_count() + i];
+            }
+        }
+        Ok(ys)
+    } else {
+        Ok(xs)
+    }
+}
+
+fn matrix_res_reshape(a: &Matrix, row_count: usize, col_count: usize) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    frontend.create_matrix_and_set_elems(row_count, col_count, xs.as_slice())
+}
+
+/// Reshapes the `a` matrix to a matrix with the number of rows and the number of columns.
+///
+/// The elements are taken in the row-major order. This function copies the elements through
+/// the host memory.
+pub fn matrix_reshape(a: &Matrix, row_count: usize, col_count: usize) -> Result<Matrix>
+{
+    match matrix_res_reshape(a, row_count, col_count) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_row_sum(a: &Matrix) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let u = matrix_res_ones(&frontend, a.col_count(), 1)?;
+    matrix_res_mul_with_frontend(&frontend, a, &u)
+}
+
+/// Sums the rows of the `a` matrix and returns a column vector.
+pub fn matrix_row_sum(a: &Matrix) -> Result<Matrix>
+{
+    match matrix_res_row_sum(a) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_col_sum(a: &Matrix) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let u = matrix_res_ones(&frontend, 1, a.row_count())?;
+    matrix_res_mul_with_frontend(&frontend, &u, a)
+}
+
+/// Sums the columns of the `a` matrix and returns a row vector.
+pub fn matrix_col_sum(a: &Matrix) -> Result<Matrix>
+{
+    match matrix_res_col_sum(a) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_sum_with_frontend(frontend: &Frontend, a: &Matrix) -> matrix::Result<f32>
+{
+    let u = matrix_res_ones(frontend, 1, a.row_count())?;
+    let v = matrix_res_ones(frontend, a.col_count(), 1)?;
+    let b = matrix_res_mul_with_frontend(frontend, &u, a)?;
+    let c = matrix_res_mul_with_frontend(frontend, &b, &v)?;
+    Ok(frontend.elems_and_transpose_flag(&c)?.0[0])
+}
+
+fn matrix_res_sum(a: &Matrix) -> matrix::Result<f32>
+{
+    let frontend = Frontend::new()?;
+    matrix_res_sum_with_frontend(&frontend, a)
+}
+
+/// Sums the elements of the `a` matrix.
+///
+/// The sum is computed on the backend and only the sum is copied to the host memory.
+pub fn matrix_sum(a: &Matrix) -> Result<f32>
+{
+    match matrix_res_sum(a) {
+        Ok(x) => Ok(x),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_norm(a: &Matrix) -> matrix::Result<f32>
+{
+    let frontend = Frontend::new()?;
+    let b = unsafe { frontend.create_matrix(a.row_count(), a.col_count())? };
+    frontend.mul_elems(a, a, &b)?;
+    Ok(matrix_res_sum_with_frontend(&frontend, &b)?.sqrt())
+}
+
+/// Calculates the Frobenius norm of the `a` matrix.
+///
+/// See [`matrix_sum`].
+pub fn matrix_norm(a: &Matrix) -> Result<f32>
+{
+    match matrix_res_norm(a) {
+        Ok(x) => Ok(x),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_row_argmax(a: &Matrix) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    let mut idxs = vec![0.0f32; a.row_count()];
+    for i in 0..a.row_count() {
+        let row = &xs[(i * a.col_count())..((i + 1) * a.col_count())];
+        let mut k = 0usize;
+        for (j, x) in row.iter().enumerate() {
+            if *x > row[k] {
+                k = j;
+            }
+        }
+        idxs[i] = (k + 1) as f32;
+    }
+    frontend.create_matrix_and_set_elems(a.row_count(), 1, idxs.as_slice())
+}
+
+/// Finds the indices of the maximum elements in the rows of the `a` matrix and returns a column
+/// vector of these indices.
+///
+/// The indices start from one. This function copies the elements through the host memory.
+pub fn matrix_row_argmax(a: &Matrix) -> Result<Matrix>
+{
+    match matrix_res_row_argmax(a) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_permute(a: &Matrix, shape: &[usize], perm: &[usize]) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    let new_shape = perm.iter().map(|i| shape[*i]).collect::<Vec<usize>>();
+    let mut strides = vec![1usize; shape.len()];
+    for i in (1..shape.len()).rev() {
+        strides[i - 1] = strides[i] * shape[i];
+    }
+    let mut ys = vec![0.0f32; xs.len()];
+    let mut idxs = vec![0usize; new_shape.len()];
+    for y in ys.iter_mut() {
+        let k = idxs.iter().zip(perm.iter()).fold(0, |k, (i, j)| k + i * strides[*j]);
+        *y = xs[k];
+        for i in (0..idxs.len()).rev() {
+            idxs[i] += 1;
+            if idxs[i] < new_shape[i] {
+                break;
+            }
+            idxs[i] = 0;
+        }
+    }
+    frontend.create_matrix_and_set_elems(new_shape[0], ys.len() / new_shape[0], ys.as_slice())
+}
+
+/// Permutes the dimensions of the tensor with the shape and the elements of the `a` matrix.
+///
+/// The permutation contains the indices of the dimensions that start from zero. This function
+/// returns a matrix of the permuted tensor elements. This function copies the elements through
+/// the host memory.
+pub fn matrix_permute(a: &Matrix, shape: &[usize], perm: &[usize]) -> Result<Matrix>
+{
+    match matrix_res_permute(a, shape, perm) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+/// A structure of parameters of two-dimensional convolution or pooling.
+///
+/// The parameters describe a window that slides over images with the number of channels, the
+/// height, and the width. The elements of each image are in the row-major order of channels, rows,
+/// and columns. The padding is added to each side of the images.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Conv2dParams
+{
+    /// The number of channels.
+    pub channel_count: usize,
+    /// The image height.
+    pub height: usize,
+    /// The image width.
+    pub width: usize,
+    /// The kernel height.
+    pub kernel_height: usize,
+    /// The kernel width.
+    pub kernel_width: usize,
+    /// The stride.
+    pub stride: usize,
+    /// The padding.
+    pub padding: usize,
+}
+
+impl Conv2dParams
+{
+    /// Returns the output height.
+    pub fn out_height(&self) -> usize
+    { (self.height + 2 * self.padding - self.kernel_height) / self.stride + 1 }
+
+    /// Returns the output width.
+    pub fn out_width(&self) -> usize
+    { (self.width + 2 * self.padding - self.kernel_width) / self.stride + 1 }
+
+    fn image_len(&self) -> usize
+    { self.channel_count * self.height * self.width }
+
+    fn out_len(&self) -> usize
+    { self.out_height() * self.out_width() }
+
+    fn kernel_len(&self) -> usize
+    { self.kernel_height * self.kernel_width }
+
+    fn image_idx(&self, c: usize, out_y: usize, out_x: usize, kernel_y: usize, kernel_x: usize) -> Option<usize>
+    {
+        let y = out_y * self.stride + kernel_y;
+        let x = out_x * self.stride + kernel_x;
+        if y < self.padding || y >= self.height + self.padding || x < self.padding || x >= self.width + self.padding {
+            return None;
+        }
+        Some((c * self.height + (y - self.padding)) * self.width + (x - self.padding))
+    }
+}
+
+fn matrix_res_swap_dims(frontend: &Frontend, a: &Matrix, dims: (usize, usize, usize), row_count: usize) -> matrix::Result<Matrix>
+{
+    let (n, m, l) = dims;
+    let xs = matrix_res_row_major_elems(frontend, a)?;
+    let mut ys = vec![0.0f32; xs.len()];
+    for i in 0..n {
+        for j in 0..m {
+            for k in 0..l {
+                ys[(i * l + k) * m + j] = xs[(i * m + j) * l + k];
+            }
+        }
+    }
+    frontend.create_matrix_and_set_elems(row_count, ys.len() / row_count, ys.as_slice())
+}
+
+fn matrix_res_im2col_with_frontend(frontend: &Frontend, a: &Matrix, params: &Conv2dParams) -> matrix::Result<Matrix>
+{
+    let xs = matrix_res_row_major_elems(frontend, a)?;
+    let image_len = params.image_len();
+    let out_width = params.out_width();
+    let out_len = params.out_len();
+    let col_count = params.channel_count * params.kernel_len();
+    let mut ys = vec![0.0f32; a.row_count() * out_len * col_count];
+    for i in 0..a.row_count() {
+        for p in 0..out_len {
+            let row = &mut ys[((i * out_len + p) * col_count)..((i * out_len + p + 1) * col_count)];
+            for c in 0..params.channel_count {
+                for ky in 0..params.kernel_height {
+                    for kx in 0..params.kernel_width {
+                        match params.image_idx(c, p / out_width, p % out_width, ky, kx) {
+                            Some(k) => row[(c * params.kernel_height + ky) * params.kernel_width + kx] = xs[i * image_len + k],
+                            None => (),
+                        }
+                    }
+                }
+            }
+        }
+    }
+    frontend.create_matrix_and_set_elems(a.row_count() * out_len, col_count, ys.as_slice())
+}
+
+fn matrix_res_im2col(a: &Matrix, params: &Conv2dParams) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    matrix_res_im2col_with_frontend(&frontend, a, params)
+}
+
+/// Rearranges the windows of the images to the rows of a matrix.
+///
+/// Each row of the `a` matrix contains the elements of one image. Each row of the result matrix
+/// contains the elements of one window in the row-major order of channels, rows, and columns.
+/// The rows of the result matrix are ordered by images and then by window positions. Elements
+/// of the padding are zeros. This function copies the elements through the host memory.
+pub fn matrix_im2col(a: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    match matrix_res_im2col(a, params) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_col2im_with_frontend(frontend: &Frontend, a: &Matrix, params: &Conv2dParams) -> matrix::Result<Matrix>
+{
+    let xs = matrix_res_row_major_elems(frontend, a)?;
+    let image_len = params.image_len();
+    let out_width = params.out_width();
+    let out_len = params.out_len();
+    let col_count = params.channel_count * params.kernel_len();
+    let image_count = a.row_count() / out_len;
+    let mut ys = vec![0.0f32; image_count * image_len];
+    for i in 0..image_count {
+        for p in 0..out_len {
+            let row = &xs[((i * out_len + p) * col_count)..((i * out_len + p + 1) * col_count)];
+            for c in 0..params.channel_count {
+                for ky in 0..params.kernel_height {
+                    for kx in 0..params.kernel_width {
+                        match params.image_idx(c, p / out_width, p % out_width, ky, kx) {
+                            Some(k) => ys[i * image_len + k] += row[(c * params.kernel_height + ky) * params.kernel_width + kx],
+                            None => (),
+                        }
+                    }
+                }
+            }
+        }
+    }
+    frontend.create_matrix_and_set_elems(image_count, image_len, ys.as_slice())
+}
+
+fn matrix_res_col2im(a: &Matrix, params: &Conv2dParams) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    matrix_res_col2im_with_frontend(&frontend, a, params)
+}
+
+/// Sums the windows from the rows of the `a` matrix into images.
+///
+/// This function is the adjoint operation of [`matrix_im2col`] and returns a matrix that has one
+/// image in each row. This function copies the elements through the host memory.
+pub fn matrix_col2im(a: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    match matrix_res_col2im(a, params) {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_conv2d(a: &Matrix, b: &Matrix, params: &Conv2dParams) -> matrix::Result<Matrix>
+{
+    let frontend = Frontend::new()?;
+    let c = matrix_res_im2col_with_frontend(&frontend, a, params)?;
+    let d = matrix_res_mul_with_frontend(&frontend, &c, &b.transpose())?;
+    matrix_res_swap_dims(&frontend, &d, (a.row_count(), params.out_len(), b.row_count()), a.row_count())
+}
+
+/// Calculates the two-dimensional convolution of the images of the `a` matrix with the kernels
+/// of the `b` matrix.
+///
+/// Each row of the `b` matrix contains the elements of one kernel in the row-major order of
+/// channels, rows, and columns. Each row of the result matrix contains the output channels of
+/// one image. The windows are rearranged by [`matrix_im2col`] and multiplied by the kernels on
+/// the backend.
+pub fn matrix_conv2d(a: &Matrix, b: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    match matrix_res_conv2d(a, b, params) {
+        Ok(c) => Ok(c),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_conv2d_grads(a: &Matrix, b: &Matrix, c: &Matrix, params: &Conv2dParams) -> matrix::Result<(Matrix, Matrix)>
+{
+    let frontend = Frontend::new()?;
+    let d = matrix_res_im2col_with_frontend(&frontend, a, params)?;
+    let e = matrix_res_swap_dims(&frontend, c, (a.row_count(), b.row_count(), params.out_len()), a.row_count() * params.out_len())?;
+    let f = matrix_res_mul_with_frontend(&frontend, &e, b)?;
+    let g = matrix_res_col2im_with_frontend(&frontend, &f, params)?;
+    let h = matrix_res_mul_with_frontend(&frontend, &e.transpose(), &d)?;
+    Ok((g, h))
+}
+
+/// Calculates the gradients of the two-dimensional convolution for the images of the `a` matrix
+/// and the kernels of the `b` matrix from the gradient of the `c` matrix.
+///
+/// This function returns the gradient for the images and the gradient for the kernels. See
+/// [`matrix_conv2d`].
+pub fn matrix_conv2d_grads(a: &Matrix, b: &Matrix, c: &Matrix, params: &Conv2dParams) -> Result<(Matrix, Matrix)>
+{
+    match matrix_res_conv2d_grads(a, b, c, params) {
+        Ok(pair) => Ok(pair),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+fn matrix_res_pool2d<F>(a: &Matrix, params: &Conv2dParams, mut f: F) -> matrix::Result<Matrix>
+    where F: FnMut(&[f32], &[Option<usize>]) -> f32
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    let image_len = params.image_len();
+    let out_width = params.out_width();
+    let out_len = params.out_len();
+    let mut ys = vec![0.0f32; a.row_count() * params.channel_count * out_len];
+    let mut idxs: Vec<Option<usize>> = vec![None; params.kernel_len()];
+    for i in 0..a.row_count() {
+        for c in 0..params.channel_count {
+            for p in 0..out_len {
+                for ky in 0..params.kernel_height {
+                    for kx in 0..params.kernel_width {
+                        idxs[ky * params.kernel_width + kx] = params.image_idx(c, p / out_width, p % out_width, ky, kx);
+                    }
+                }
+                ys[(i * params.channel_count + c) * out_len + p] = f(&xs[(i * image_len)..((i + 1) * image_len)], idxs.as_slice());
+            }
+        }
+    }
+    frontend.create_matrix_and_set_elems(a.row_count(), params.channel_count * out_len, ys.as_slice())
+}
+
+fn matrix_res_pool2d_grad<F>(a: &Matrix, b: &Matrix, params: &Conv2dParams, mut f: F) -> matrix::Result<Matrix>
+    where F: FnMut(&[f32], &[Option<usize>], f32, &mut [f32])
+{
+    let frontend = Frontend::new()?;
+    let xs = matrix_res_row_major_elems(&frontend, a)?;
+    let zs = matrix_res_row_major_elems(&frontend, b)?;
+    let image_len = params.image_len();
+    let out_width = params.out_width();
+    let out_len = params.out_len();
+    let mut ys = vec![0.0f32; a.row_count() * image_len];
+    let mut idxs: Vec<Option<usize>> = vec![None; params.kernel_len()];
+    for i in 0..a.row_count() {
+        for c in 0..params.channel_count {
+            for p in 0..out_len {
+                for ky in 0..params.kernel_height {
+                    for kx in 0..params.kernel_width {
+                        idxs[ky * params.kernel_width + kx] = params.image_idx(c, p / out_width, p % out_width, ky, kx);
+                    }
+                }
+                let z = zs[(i * params.channel_count + c) * out_len + p];
+                f(&xs[(i * image_len)..((i + 1) * image_len)], idxs.as_slice(), z, &mut ys[(i * image_len)..((i + 1) * image_len)]);
+            }
+        }
+    }
+    frontend.create_matrix_and_set_elems(a.row_count(), image_len, ys.as_slice())
+}
+
+fn max_pool2d_idx(xs: &[f32], idxs: &[Option<usize>]) -> Option<usize>
+{
+    let mut k: Option<usize> = None;
+    for idx in idxs {
+        match (idx, k) {
+            (Some(i), Some(j)) if xs[*i] > xs[j] => k = Some(*i),
+            (Some(i), None) => k = Some(*i),
+            (_, _) => (),
+        }
+    }
+    k
+}
+
+/// Calculates the two-dimensional max pooling of the images of the `a` matrix.
+///
+/// The window is applied to each channel separately and elements of the padding are skipped.
+/// Each row of the result matrix contains the output channels of one image. This function copies
+/// the elements through the host memory.
+pub fn matrix_max_pool2d(a: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    let res = matrix_res_pool2d(a, params, |xs, idxs| {
+            match max_pool2d_idx(xs, idxs) {
+                Some(k) => xs[k],
+                None => 0.0,
+            }
+    });
+    match res {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+/// Calculates the gradient of the two-dimensional max pooling for the images of the `a` matrix
+/// from the gradient of the `b` matrix.
+///
+/// The gradient of each window is passed to the first maximum element of the window. See
+/// [`matrix_max_pool2d`].
+pub fn matrix_max_pool2d_grad(a: &Matrix, b: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    let res = matrix_res_pool2d_grad(a, b, params, |xs, idxs, z, ys| {
+            match max_pool2d_idx(xs, idxs) {
+                Some(k) => ys[k] += z,
+                None => (),
+            }
+    });
+    match res {
+        Ok(c) => Ok(c),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+/// Calculates the two-dimensional average pooling of the images of the `a` matrix.
+///
+/// The window is applied to each channel separately and elements of the padding are zeros. The
+/// sum of the window elements is always divided by the kernel size. See [`matrix_max_pool2d`].
+pub fn matrix_avg_pool2d(a: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    let kernel_len = params.kernel_len() as f32;
+    let res = matrix_res_pool2d(a, params, |xs, idxs| {
+            idxs.iter().fold(0.0f32, |x, idx| {
+                    match idx {
+                        Some(k) => x + xs[*k],
+                        None => x,
+                    }
+            }) / kernel_len
+    });
+    match res {
+        Ok(b) => Ok(b),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
+/// Calculates the gradient of the two-dimensional average pooling for the images of the `a`
+/// matrix from the gradient of the `b` matrix.
+///
+/// See [`matrix_avg_pool2d`].
+pub fn matrix_avg_pool2d_grad(a: &Matrix, b: &Matrix, params: &Conv2dParams) -> Result<Matrix>
+{
+    let kernel_len = params.kernel_len() as f32;
+    let res = matrix_res_pool2d_grad(a, b, params, |_, idxs, z, ys| {
+            for idx in idxs {
+                match idx {
+                    Some(k) => ys[*k] += z / kernel_len,
+                    None => (),
+                }
+            }
+    });
+    match res {
+        Ok(c) => Ok(c),
+        Err(err) => Err(Error::Matrix(err)),
+    }
+}
+
 /// Converts the string slice to an URL name.
 ///
 /// The character of string slice is escaped if the character of string slice isn't an URL
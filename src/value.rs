@@ -37,11 +37,16 @@ use crate::serde::Deserializer;
 use crate::serde::Serialize;
 use crate::serde::Serializer;
 use crate::matrix::Matrix;
+use crate::regex::Regex;
 #[cfg(feature = "plot")]
 use crate::winit;
+use crate::autograd::*;
 use crate::env::*;
 use crate::error::*;
 use crate::interp::*;
+use crate::io::ValueReader;
+use crate::io::ValueWriter;
+use crate::mod_node::*;
 use crate::tree::*;
 use crate::utils::*;
 
@@ -54,7 +59,97 @@ pub type WindowId = winit::window::WindowId;
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct WindowId(());
 
-fn nearly_eq(a: f32, b: f32, eps: f32) -> bool
+fn matrix_idx(value: &Value, n: usize) -> Result<usize>
+{
+    match value {
+        Value::Int(_) | Value::Float(_) => {
+            let i = value.to_i64();
+            if i < 1 || i > (n as i64) {
+                return Err(Error::Interp(String::from("index out of bounds")));
+            }
+            Ok((i - 1) as usize)
+        },
+        _ => Err(Error::Interp(String::from("unsupported index type for indexing"))),
+    }
+}
+
+fn matrix_idxs(idx_value: Option<&Value>, n: usize, is_unique: bool) -> Result<(Option<Vec<usize>>, bool)>
+{
+    let idxs = match idx_value {
+        Some(idx_value @ (Value::Int(_) | Value::Float(_))) => return Ok((Some(vec![matrix_idx(idx_value, n)?]), true)),
+        Some(idx_value @ Value::Object(object)) => {
+            match &**object {
+                Object::IntRange(_, _, _) => {
+                    let mut idxs: Vec<usize> = Vec::new();
+                    match idx_value.iter()? {
+                        Some(iter) => {
+                            for elem in iter {
+                                idxs.push(matrix_idx(&elem?, n)?);
+                            }
+                        },
+                        None => return Err(Error::Interp(String::from("unsupported index type for indexing"))),
+                    }
+                    idxs
+                },
+                _ => return Err(Error::Interp(String::from("unsupported index type for indexing"))),
+            }
+        },
+        Some(Value::Ref(object)) => {
+            let object_g = rw_lock_read(&**object)?;
+            match &*object_g {
+                MutObject::Array(elems) => {
+                    let mut idxs: Vec<usize> = Vec::new();
+                    for elem in elems {
+                        idxs.push(matrix_idx(elem, n)?);
+                    }
+                    idxs
+                },
+                _ => return Err(Error::Interp(String::from("unsupported index type for indexing"))),
+            }
+        },
+        Some(_) => return Err(Error::Interp(String::from("unsupported index type for indexing"))),
+        None => return Ok((None, false)),
+    };
+    if idxs.is_empty() {
+        return Err(Error::Interp(String::from("no indices")));
+    }
+    if is_unique {
+        let mut sorted_idxs = idxs.clone();
+        sorted_idxs.sort();
+        sorted_idxs.dedup();
+        if sorted_idxs.len() != idxs.len() {
+            return Err(Error::Interp(String::from("repeated index")));
+        }
+    }
+    if idxs.len() == n && idxs.iter().enumerate().all(|(i, j)| i == *j) {
+        Ok((None, false))
+    } else {
+        Ok((Some(idxs), false))
+    }
+}
+
+fn matrix_value_for_tensor(a: &Matrix) -> Value
+{ Value::Object(Arc::new(Object::Matrix(a.clone()))) }
+
+fn tensor_value_for_matrix_value(shape: &[usize], value: Value) -> Result<Value>
+{
+    match &value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(a) => {
+                    if a.row_count() != shape[0] || a.col_count() != shape[1..].iter().product::<usize>() {
+                        return Err(Error::Interp(String::from("size of matrix isn't equal to size of tensor")));
+                    }
+                    Ok(Value::Object(Arc::new(Object::Tensor(shape.to_vec(), a.clone()))))
+                },
+                _ => Err(Error::Interp(String::from("unsupported type for tensor"))),
+            }
+        },
+        _ => Err(Error::Interp(String::from("unsupported type for tensor"))),
+    }
+}
+
+fn nearly_eq(a: f64, b: f64, eps: f64) -> bool
 {
     if a == b {
         true
@@ -63,6 +158,224 @@ fn nearly_eq(a: f32, b: f32, eps: f32) -> bool
     }
 }
 
+enum HostOperand<'a>
+{
+    Int(i64),
+    Float(f64),
+    Matrix(usize, usize, &'a HostElems),
+}
+
+impl<'a> HostOperand<'a>
+{
+    fn size(&self) -> Option<(usize, usize)>
+    {
+        match self {
+            HostOperand::Matrix(row_count, col_count, _) => Some((*row_count, *col_count)),
+            _ => None,
+        }
+    }
+
+    fn elem_type(&self) -> ElemType
+    {
+        match self {
+            HostOperand::Int(_) => ElemType::Int,
+            HostOperand::Float(_) => ElemType::Double,
+            HostOperand::Matrix(_, _, elems) => elems.elem_type(),
+        }
+    }
+
+    fn to_f64s(&self) -> Vec<f64>
+    {
+        match self {
+            HostOperand::Int(a) => vec![*a as f64],
+            HostOperand::Float(a) => vec![*a],
+            HostOperand::Matrix(_, _, elems) => elems.to_f64s(),
+        }
+    }
+
+    fn to_i32s(&self) -> Result<Vec<i32>>
+    {
+        match self {
+            HostOperand::Int(a) => Ok(vec![int_elem(*a)?]),
+            HostOperand::Float(a) => Ok(vec![*a as i32]),
+            HostOperand::Matrix(_, _, elems) => {
+                match elems {
+                    HostElems::Int(xs) => Ok(xs.clone()),
+                    HostElems::Double(xs) => Ok(xs.iter().map(|x| *x as i32).collect()),
+                }
+            },
+        }
+    }
+}
+
+fn host_operand(value: &Value) -> Option<HostOperand<'_>>
+{
+    match value {
+        Value::Int(a) => Some(HostOperand::Int(*a)),
+        Value::Float(a) => Some(HostOperand::Float(*a)),
+        Value::Object(object) => {
+            match &**object {
+                Object::HostMatrix(row_count, col_count, elems) => Some(HostOperand::Matrix(*row_count, *col_count, elems)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn is_float_matrix(value: &Value) -> bool
+{
+    match value {
+        Value::Object(object) => {
+            match &**object {
+                Object::Matrix(_) | Object::Tensor(_, _) | Object::MatrixArray(_, _, _, _) | Object::MatrixRowSlice(_, _) => true,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn bin_op_name(op: BinOp) -> &'static str
+{
+    match op {
+        BinOp::Mul => "multiplication",
+        BinOp::DotMul => "dot multiplication",
+        BinOp::Div => "division",
+        BinOp::DotDiv => "dot division",
+        BinOp::Add => "addition",
+        BinOp::DotAdd => "dot addition",
+        BinOp::Sub => "subtraction",
+        BinOp::DotSub => "dot subtraction",
+        _ => "operation",
+    }
+}
+
+fn checked_int_elem(z: Option<i32>, op_name: &str) -> Result<i32>
+{
+    match z {
+        Some(z) => Ok(z),
+        None => Err(Error::Interp(format!("overflow in {}", op_name))),
+    }
+}
+
+fn host_elems_op<T: Copy, F>(a: &HostOperand<'_>, xs: &[T], b: &HostOperand<'_>, ys: &[T], mut f: F) -> Result<(usize, usize, Vec<T>)>
+    where F: FnMut(T, T) -> Result<T>
+{
+    let (row_count, col_count) = match (a.size(), b.size()) {
+        (Some((row_count, col_count)), Some((row_count2, col_count2))) => {
+            if row_count != row_count2 || col_count != col_count2 {
+                return Err(Error::Interp(String::from("sizes of two matrices aren't equal")));
+            }
+            (row_count, col_count)
+        },
+        (Some(size), None) | (None, Some(size)) => size,
+        (None, None) => return Err(Error::Interp(String::from("no matrix"))),
+    };
+    let is_a_scalar = a.size().is_none();
+    let is_b_scalar = b.size().is_none();
+    let mut zs: Vec<T> = Vec::with_capacity(row_count * col_count);
+    for k in 0..(row_count * col_count) {
+        let x = if is_a_scalar { xs[0] } else { xs[k] };
+        let y = if is_b_scalar { ys[0] } else { ys[k] };
+        zs.push(f(x, y)?);
+    }
+    Ok((row_count, col_count, zs))
+}
+
+fn host_mul<T: Copy, F>(a: &HostOperand<'_>, xs: &[T], b: &HostOperand<'_>, ys: &[T], zero: T, mut f: F) -> Result<(usize, usize, Vec<T>)>
+    where F: FnMut(T, T, T) -> Result<T>
+{
+    match (a.size(), b.size()) {
+        (Some((row_count, n)), Some((n2, col_count))) => {
+            if n != n2 {
+                return Err(Error::Interp(String::from("number of columns of first matrix isn't equal to number of rows of second matrix")));
+            }
+            let mut zs = vec![zero; row_count * col_count];
+            for i in 0..row_count {
+                for j in 0..col_count {
+                    let mut z = zero;
+                    for k in 0..n {
+                        z = f(z, xs[i * n + k], ys[k * col_count + j])?;
+                    }
+                    zs[i * col_count + j] = z;
+                }
+            }
+            Ok((row_count, col_count, zs))
+        },
+        (_, _) => Err(Error::Interp(String::from("no matrices"))),
+    }
+}
+
+fn host_matrix_bin_op(op: BinOp, value: &Value, value2: &Value) -> Result<Value>
+{
+    let op_name = bin_op_name(op);
+    let (a, b) = match (host_operand(value), host_operand(value2)) {
+        (Some(a), Some(b)) => (a, b),
+        (_, _) => {
+            if is_float_matrix(value) || is_float_matrix(value2) {
+                return Err(Error::Interp(format!("mismatched element types for {}", op_name)));
+            }
+            return Err(Error::Interp(format!("unsupported types for {}", op_name)));
+        },
+    };
+    let are_matrices = a.size().is_some() && b.size().is_some();
+    if a.elem_type() == ElemType::Double || b.elem_type() == ElemType::Double {
+        let xs = a.to_f64s();
+        let ys = b.to_f64s();
+        let (row_count, col_count, zs) = match op {
+            BinOp::Mul if are_matrices => host_mul(&a, xs.as_slice(), &b, ys.as_slice(), 0.0, |z, x, y| Ok(z + x * y))?,
+            BinOp::Mul | BinOp::DotMul => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| Ok(x * y))?,
+            BinOp::Div if are_matrices => return Err(Error::Interp(String::from("unsupported types for division"))),
+            BinOp::Div | BinOp::DotDiv => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| Ok(x / y))?,
+            BinOp::Add | BinOp::DotAdd => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| Ok(x + y))?,
+            BinOp::Sub | BinOp::DotSub => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| Ok(x - y))?,
+            _ => return Err(Error::Interp(format!("unsupported types for {}", op_name))),
+        };
+        Ok(Value::Object(Arc::new(Object::HostMatrix(row_count, col_count, HostElems::Double(zs)))))
+    } else {
+        let xs = a.to_i32s()?;
+        let ys = b.to_i32s()?;
+        let (row_count, col_count, zs) = match op {
+            BinOp::Mul if are_matrices => {
+                host_mul(&a, xs.as_slice(), &b, ys.as_slice(), 0, |z, x, y| {
+                        let t = checked_int_elem(x.checked_mul(y), op_name)?;
+                        checked_int_elem(z.checked_add(t), op_name)
+                })?
+            },
+            BinOp::Mul | BinOp::DotMul => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| checked_int_elem(x.checked_mul(y), op_name))?,
+            BinOp::Div if are_matrices => return Err(Error::Interp(String::from("unsupported types for division"))),
+            BinOp::Div | BinOp::DotDiv => {
+                host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| {
+                        if y == 0 {
+                            return Err(Error::Interp(String::from("division by zero")));
+                        }
+                        checked_int_elem(x.checked_div(y), op_name)
+                })?
+            },
+            BinOp::Add | BinOp::DotAdd => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| checked_int_elem(x.checked_add(y), op_name))?,
+            BinOp::Sub | BinOp::DotSub => host_elems_op(&a, xs.as_slice(), &b, ys.as_slice(), |x, y| checked_int_elem(x.checked_sub(y), op_name))?,
+            _ => return Err(Error::Interp(format!("unsupported types for {}", op_name))),
+        };
+        Ok(Value::Object(Arc::new(Object::HostMatrix(row_count, col_count, HostElems::Int(zs)))))
+    }
+}
+
+fn host_matrix_neg(row_count: usize, col_count: usize, elems: &HostElems) -> Result<Value>
+{
+    let new_elems = match elems {
+        HostElems::Double(xs) => HostElems::Double(xs.iter().map(|x| -x).collect()),
+        HostElems::Int(xs) => {
+            let mut ys: Vec<i32> = Vec::with_capacity(xs.len());
+            for x in xs {
+                ys.push(checked_int_elem(x.checked_neg(), "negation")?);
+            }
+            HostElems::Int(ys)
+        },
+    };
+    Ok(Value::Object(Arc::new(Object::HostMatrix(row_count, col_count, new_elems))))
+}
+
 /// A value enumeration.
 ///
 /// The value enumeration represents a value of the Unlab scripting language.
@@ -75,8 +388,8 @@ pub enum Value
     Bool(bool),
     /// An integer number.
     Int(i64),
-    /// Floating-point number.
-    Float(f32),
+    /// A double-precision floating-point number.
+    Float(f64),
     /// An immutablke object.
     Object(Arc<Object>),
     /// A strong reference to a mutable object.
@@ -98,6 +411,12 @@ impl Value
             Value::Object(object) => {
                 match &**object {
                     Object::Error(_, _) => false,
+                    Object::Tracked(_, _) => {
+                        match untracked_value(self) {
+                            Ok(value) => value.to_bool(),
+                            Err(_) => true,
+                        }
+                    },
                     _ => true,
                 }
             },
@@ -116,6 +435,12 @@ impl Value
             Value::Object(object) => {
                 match &**object {
                     Object::Error(_, _) => 0,
+                    Object::Tracked(_, _) => {
+                        match untracked_value(self) {
+                            Ok(value) => value.to_i64(),
+                            Err(_) => 1,
+                        }
+                    },
                     _ => 1,
                 }
             },
@@ -123,17 +448,27 @@ impl Value
         }
     }
 
-    /// Converts any value to a floating-point number.
+    /// Converts any value to a single-precision floating-point number.
     pub fn to_f32(&self) -> f32
+    { self.to_f64() as f32 }
+
+    /// Converts any value to a double-precision floating-point number.
+    pub fn to_f64(&self) -> f64
     {
         match self {
             Value::None => 0.0,
             Value::Bool(a) => if *a { 1.0 } else { 0.0 },
-            Value::Int(a) => *a as f32,
+            Value::Int(a) => *a as f64,
             Value::Float(a) => *a,
             Value::Object(object) => {
                 match &**object {
                     Object::Error(_, _) => 0.0,
+                    Object::Tracked(_, _) => {
+                        match untracked_value(self) {
+                            Ok(value) => value.to_f64(),
+                            Err(_) => 1.0,
+                        }
+                    },
                     _ => 1.0,
                 }
             },
@@ -162,12 +497,17 @@ impl Value
         }
     }
 
-    /// Converts the value to a floating-point number if the value is an integer type or
-    /// floating-point type, otherwise this method returns `None`.
+    /// Converts the value to a single-precision floating-point number if the value is an integer
+    /// type or floating-point type, otherwise this method returns `None`.
     pub fn to_opt_f32(&self) -> Option<f32>
+    { self.to_opt_f64().map(|a| a as f32) }
+
+    /// Converts the value to a double-precision floating-point number if the value is an integer
+    /// type or floating-point type, otherwise this method returns `None`.
+    pub fn to_opt_f64(&self) -> Option<f64>
     {
         match self {
-            Value::Int(a) => Some(*a as f32),
+            Value::Int(a) => Some(*a as f64),
             Value::Float(a) => Some(*a),
             _ => None,
         }
@@ -194,7 +534,47 @@ impl Value
         match self {
             Value::Object(object) => {
                 match &**object {
-                    Object::Fun(_, _, _) | Object::BuiltinFun(_, _) => true,
+                    Object::Fun(_, _, _) | Object::BuiltinFun(_, _) | Object::Lambda(_, _, _) => true,
+                    _ => false,
+                }
+            },
+            _ => false,
+        }
+    }
+    
+    /// Returns `true` if the value is a tracked value, otherwise `false`.
+    pub fn is_tracked(&self) -> bool
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::Tracked(_, _) => true,
+                    _ => false,
+                }
+            },
+            _ => false,
+        }
+    }
+
+    fn is_tensor(&self) -> bool
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::Tensor(_, _) => true,
+                    _ => false,
+                }
+            },
+            _ => false,
+        }
+    }
+
+    fn is_host_matrix(&self) -> bool
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::HostMatrix(_, _, _) => true,
                     _ => false,
                 }
             },
@@ -252,7 +632,7 @@ impl Value
             (Value::None, Value::None) => Ok(true),
             (Value::Bool(a), Value::Bool(b)) => Ok(a == b),
             (Value::Int(a), Value::Int(b)) => Ok(a == b),
-            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(self.to_f32() == value.to_f32()),
+            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(self.to_f64() == value.to_f64()),
             (Value::Object(object), Value::Object(object2)) => {
                 if Arc::ptr_eq(object, object2) {
                     return Ok(true);
@@ -286,7 +666,7 @@ impl Value
     pub fn nearly_eq_with_types(&self, value: &Value, eps: f32) -> Result<bool>
     {
         match (self, value) {
-            (Value::Float(a), Value::Float(b)) => Ok(nearly_eq(*a, *b, eps)),
+            (Value::Float(a), Value::Float(b)) => Ok(nearly_eq(*a, *b, eps as f64)),
             (Value::Object(object), Value::Object(object2)) => {
                 if Arc::ptr_eq(object, object2) {
                     return Ok(true);
@@ -320,8 +700,8 @@ impl Value
     pub fn nearly_eq_without_types(&self, value: &Value, eps: f32) -> Result<bool>
     {
         match (self, value) {
-            (Value::Int(a), Value::Int(b)) => Ok(nearly_eq(*a as f32, *b as f32, eps)),
-            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(nearly_eq(self.to_f32(), value.to_f32(), eps)),
+            (Value::Int(a), Value::Int(b)) => Ok(nearly_eq(*a as f64, *b as f64, eps as f64)),
+            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(nearly_eq(self.to_f64(), value.to_f64(), eps as f64)),
             (Value::Object(object), Value::Object(object2)) => {
                 if Arc::ptr_eq(object, object2) {
                     return Ok(true);
@@ -355,6 +735,7 @@ impl Value
             Value::Object(object) => {
                 match &**object {
                     Object::Matrix(_) => f(self),
+                    Object::Tensor(_, _) => self.dot1_with_fun_ref(err_msg, f),
                     _ => Ok(self.clone()),
                 }
             },
@@ -384,6 +765,19 @@ impl Value
                         }
                         Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(new_fields)))))
                     },
+                    MutObject::Dict(pairs) => {
+                        let mut new_pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+                        for (key, value) in pairs {
+                            new_pairs.insert(key.clone(), value.dot1_for_elem_with_fun_ref(err_msg, f)?);
+                        }
+                        Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Dict(new_pairs)))))
+                    },
+                }
+            },
+            Value::Object(object) => {
+                match &**object {
+                    Object::Tensor(shape, a) => tensor_value_for_matrix_value(shape.as_slice(), f(&matrix_value_for_tensor(a))?),
+                    _ => Err(Error::Interp(String::from(err_msg))),
                 }
             },
             _ => Err(Error::Interp(String::from(err_msg))),
@@ -394,10 +788,12 @@ impl Value
     ///
     /// If one element of one value or one field of one value is a floating-point number or a
     /// matrix, this method applies the function to one argument for this element or this field.
-    /// If this element or this field is an array or a structure, this method recursively invokes
-    /// itself for this element or this field. This method ignores this element or this field
-    /// otherwise. This method returns an error with the error message if one value isn't an
-    /// array or an structure.
+    /// If this element or this field is an array, a structure, or a dictionary, this method
+    /// recursively invokes itself for this element or this field. This method ignores this
+    /// element or this field otherwise. This method returns an error with the error message if
+    /// one value isn't an array, an structure, or a dictionary. If one value is a tensor, this
+    /// method applies the function to the matrix of tensor elements and returns a tensor with the
+    /// same shape.
     pub fn dot1<F>(&self, err_msg: &str, mut f: F) -> Result<Value>
         where F: FnMut(&Value) -> Result<Value>
     { self.dot1_with_fun_ref(err_msg, &mut f) }
@@ -410,6 +806,7 @@ impl Value
             (Value::Object(object), Value::Object(object2)) => {
                 match (&**object, &**object2) {
                     (Object::Matrix(_), Object::Matrix(_)) => f(self, value),
+                    (Object::Tensor(_, _), Object::Tensor(_, _)) => self.dot2_with_fun_ref(value, err_msg, f),
                     (_, _) => {
                         if !self.eq_with_types(value)? {
                             return Err(Error::Interp(String::from("two values aren't equal")))
@@ -464,9 +861,42 @@ impl Value
                         }
                         Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(new_fields)))))
                     },
+                    (MutObject::Dict(pairs), MutObject::Dict(pairs2)) => {
+                        if pairs.len() != pairs2.len() || pairs.keys().zip(pairs2.keys()).any(|(k, k2)| k != k2) {
+                            return Err(Error::Interp(String::from("keys of two dictionaries aren't equal")));
+                        }
+                        let mut new_pairs: BTreeMap<DictKey, Value> = BTreeMap::new();
+                        for ((key, value), value2) in pairs.iter().zip(pairs2.values()) {
+                            new_pairs.insert(key.clone(), value.dot2_for_elem_with_fun_ref(value2, err_msg, f)?);
+                        }
+                        Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Dict(new_pairs)))))
+                    },
                     (_, _) => Err(Error::Interp(String::from("two types aren't equal"))),
                 }
             },
+            (Value::Object(object), Value::Object(object2)) => {
+                match (&**object, &**object2) {
+                    (Object::Tensor(shape, a), Object::Tensor(shape2, b)) => {
+                        if shape != shape2 {
+                            return Err(Error::Interp(String::from("shapes of two tensors aren't equal")));
+                        }
+                        tensor_value_for_matrix_value(shape.as_slice(), f(&matrix_value_for_tensor(a), &matrix_value_for_tensor(b))?)
+                    },
+                    (_, _) => Err(Error::Interp(String::from(err_msg))),
+                }
+            },
+            (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
+                match &**object {
+                    Object::Tensor(shape, a) => tensor_value_for_matrix_value(shape.as_slice(), f(&matrix_value_for_tensor(a), value)?),
+                    _ => Err(Error::Interp(String::from(err_msg))),
+                }
+            },
+            (Value::Int(_) | Value::Float(_), Value::Object(object2)) => {
+                match &**object2 {
+                    Object::Tensor(shape, b) => tensor_value_for_matrix_value(shape.as_slice(), f(self, &matrix_value_for_tensor(b))?),
+                    _ => Err(Error::Interp(String::from(err_msg))),
+                }
+            },
             (_, _) => Err(Error::Interp(String::from(err_msg))),
         }
     }
@@ -475,11 +905,14 @@ impl Value
     ///
     /// If two elements of two values or two fields of two values are floating-point numbers or
     /// matrices, this method applies the function to two arguments for these elements or these
-    /// fields. If these elements or these fields are arrays or structures, this method
-    /// recursively invokes itself for these elements or these fields. This method compares these
+    /// fields. If these elements or these fields are arrays, structures, or dictionaries, this
+    /// method recursively invokes itself for these elements or these fields. Two dictionaries
+    /// must have the same keys. This method compares these
     /// elements or these fields otherwise. If these elements or these fields aren't equal, this
-    /// method returns an error. This method returns an error with the error message if two
-    /// values aren't arrays or structures.
+    /// method returns an error. If one value is a tensor, this method applies the function to the
+    /// matrix of tensor elements and the other value that is a number or the matrix of other
+    /// tensor elements; shapes of two tensors must be equal. This method returns an error with the
+    /// error message if two values aren't arrays, structures, dictionaries, or tensors.
     pub fn dot2<F>(&self, value: &Value, err_msg: &str, mut f: F) -> Result<Value>
         where F: FnMut(&Value, &Value) -> Result<Value>
     { self.dot2_with_fun_ref(value, err_msg, &mut f) }
@@ -490,8 +923,8 @@ impl Value
     pub fn apply(&self, interp: &mut Interp, env: &mut Env, arg_values: &[Value]) -> Result<Value>
     { interp.apply_fun(env, self, arg_values) }
     
-    /// Returns the element or the field if the value has the element or the field, otherwise
-    /// `None` or an error.
+    /// Returns the element, the field, or the dictionary value if the value has the element, the
+    /// field, or the key, otherwise `None` or an error.
     ///
     /// If the value isn't a string, a matrix array, a matrix row slice, or a mutable object,
     /// this method returns an error.
@@ -545,7 +978,7 @@ impl Value
                                             TransposeFlag::Transpose => ((j - 1) as usize) * (*row_count) + i,
                                         };
                                         match xs.get(k) {
-                                            Some(x) => Ok(Value::Float(*x)),
+                                            Some(x) => Ok(Value::Float(*x as f64)),
                                             None => Err(Error::Interp(String::from("no element"))),
                                         }
                                     },
@@ -592,13 +1025,19 @@ impl Value
                             _ => Err(Error::Interp(String::from("unsupported index type for indexing"))),
                         }
                     },
+                    MutObject::Dict(pairs) => {
+                        match pairs.get(&DictKey::from_value(idx_value)?) {
+                            Some(value) => Ok(value.clone()),
+                            None => Err(Error::Interp(String::from("not found key")))
+                        }
+                    },
                 }
             },
             _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
         }
     }
 
-    /// Sets the element or the field for the value.
+    /// Sets the element, the field, or the dictionary value for the value.
     ///
     /// If the value isn't a mutable object, this method returns an error.
     pub fn set_elem(&self, idx_value: &Value, value: Value) -> Result<()>
@@ -639,6 +1078,126 @@ impl Value
                             _ => Err(Error::Interp(String::from("unsupported index type for indexing"))),
                         }
                     },
+                    MutObject::Dict(pairs) => {
+                        pairs.insert(DictKey::from_value(idx_value)?, value);
+                        Ok(())
+                    },
+                }
+            },
+            _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
+        }
+    }
+
+    /// Returns the element or the sub-matrix for the row index and the column index.
+    ///
+    /// An index can be a number, a range, or an array of numbers. A `None` index refers to all
+    /// rows or all columns. If both indices are numbers, this method returns the element,
+    /// otherwise this method returns the sub-matrix. If the value isn't a matrix, this method
+    /// returns an error.
+    pub fn elem2(&self, row_idx_value: Option<&Value>, col_idx_value: Option<&Value>) -> Result<Value>
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::Matrix(a) => {
+                        let (row_idxs, is_row_elem) = matrix_idxs(row_idx_value, a.row_count(), false)?;
+                        let (col_idxs, is_col_elem) = matrix_idxs(col_idx_value, a.col_count(), false)?;
+                        let b = matrix_select(a, row_idxs.as_ref().map(|idxs| idxs.as_slice()), col_idxs.as_ref().map(|idxs| idxs.as_slice()))?;
+                        if is_row_elem && is_col_elem {
+                            match matrix_elems_and_transpose_flag(&b)?.0.first() {
+                                Some(x) => Ok(Value::Float(*x as f64)),
+                                None => Err(Error::Interp(String::from("no element"))),
+                            }
+                        } else {
+                            Ok(Value::Object(Arc::new(Object::Matrix(b))))
+                        }
+                    },
+                    Object::HostMatrix(row_count, col_count, elems) => {
+                        let (row_idxs, is_row_elem) = matrix_idxs(row_idx_value, *row_count, false)?;
+                        let (col_idxs, is_col_elem) = matrix_idxs(col_idx_value, *col_count, false)?;
+                        let row_idxs = row_idxs.unwrap_or((0..(*row_count)).collect());
+                        let col_idxs = col_idxs.unwrap_or((0..(*col_count)).collect());
+                        let new_elems = elems.select(*col_count, row_idxs.as_slice(), col_idxs.as_slice());
+                        if is_row_elem && is_col_elem {
+                            match new_elems.elem(0) {
+                                Some(value) => Ok(value),
+                                None => Err(Error::Interp(String::from("no element"))),
+                            }
+                        } else {
+                            Ok(Value::Object(Arc::new(Object::HostMatrix(row_idxs.len(), col_idxs.len(), new_elems))))
+                        }
+                    },
+                    _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
+                }
+            },
+            _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
+        }
+    }
+
+    /// Sets the element or the sub-matrix for the row index and the column index and returns
+    /// a new matrix.
+    ///
+    /// The indices are the same as for [`elem2`](Self::elem2), but they mustn't be repeated.
+    /// The element value can be a number or a matrix that has the size of the sub-matrix. The
+    /// matrix isn't modified because matrices are immutable, so the new matrix should be
+    /// assigned instead of the matrix. If the value isn't a matrix, this method returns an
+    /// error.
+    pub fn set_elem2(&self, row_idx_value: Option<&Value>, col_idx_value: Option<&Value>, value: Value) -> Result<Value>
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::Matrix(a) => {
+                        let (row_idxs, _) = matrix_idxs(row_idx_value, a.row_count(), true)?;
+                        let (col_idxs, _) = matrix_idxs(col_idx_value, a.col_count(), true)?;
+                        let row_idxs = row_idxs.as_ref().map(|idxs| idxs.as_slice());
+                        let col_idxs = col_idxs.as_ref().map(|idxs| idxs.as_slice());
+                        match &value {
+                            Value::Int(_) | Value::Float(_) => {
+                                let b = matrix_set_selected_for_scalar(a, row_idxs, col_idxs, value.to_f32())?;
+                                Ok(Value::Object(Arc::new(Object::Matrix(b))))
+                            },
+                            Value::Object(object2) => {
+                                match &**object2 {
+                                    Object::Matrix(b) => {
+                                        let row_count = row_idxs.map(|idxs| idxs.len()).unwrap_or(a.row_count());
+                                        let col_count = col_idxs.map(|idxs| idxs.len()).unwrap_or(a.col_count());
+                                        if b.row_count() != row_count || b.col_count() != col_count {
+                                            return Err(Error::Interp(String::from("size of matrix isn't equal to size of sub-matrix")));
+                                        }
+                                        let c = matrix_set_selected(a, row_idxs, col_idxs, b)?;
+                                        Ok(Value::Object(Arc::new(Object::Matrix(c))))
+                                    },
+                                    _ => Err(Error::Interp(String::from("unsupported element type for indexing"))),
+                                }
+                            },
+                            _ => Err(Error::Interp(String::from("unsupported element type for indexing"))),
+                        }
+                    },
+                    Object::HostMatrix(row_count, col_count, elems) => {
+                        let (row_idxs, _) = matrix_idxs(row_idx_value, *row_count, true)?;
+                        let (col_idxs, _) = matrix_idxs(col_idx_value, *col_count, true)?;
+                        let row_idxs = row_idxs.unwrap_or((0..(*row_count)).collect());
+                        let col_idxs = col_idxs.unwrap_or((0..(*col_count)).collect());
+                        let elems2 = match &value {
+                            Value::Int(_) | Value::Float(_) => HostElems::from_values(vec![value.clone(); row_idxs.len() * col_idxs.len()].as_slice(), elems.elem_type())?,
+                            Value::Object(object2) => {
+                                match &**object2 {
+                                    Object::HostMatrix(row_count2, col_count2, elems2) => {
+                                        if *row_count2 != row_idxs.len() || *col_count2 != col_idxs.len() {
+                                            return Err(Error::Interp(String::from("size of matrix isn't equal to size of sub-matrix")));
+                                        }
+                                        elems2.to_elem_type(elems.elem_type())?
+                                    },
+                                    _ => return Err(Error::Interp(String::from("unsupported element type for indexing"))),
+                                }
+                            },
+                            _ => return Err(Error::Interp(String::from("unsupported element type for indexing"))),
+                        };
+                        let new_elems = elems.set_selected(*col_count, row_idxs.as_slice(), col_idxs.as_slice(), &elems2);
+                        Ok(Value::Object(Arc::new(Object::HostMatrix(*row_count, *col_count, new_elems))))
+                    },
+                    _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
                 }
             },
             _ => Err(Error::Interp(String::from("unsupported type for indexing"))),
@@ -690,6 +1249,9 @@ impl Value
     /// Performs an operation on one value for the unary operator.
     pub fn unary_op(&self, op: UnaryOp) -> Result<Value>
     {
+        if self.is_tracked() {
+            return tracked_unary_op(op, self);
+        }
         match op {
             UnaryOp::Neg => {
                 match self {
@@ -703,6 +1265,8 @@ impl Value
                     Value::Object(object) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_rsub_for_scalar(a, 0.0)?)))),
+                            Object::Tensor(_, _) => self.dot1("unsupported type for negation", |v| v.unary_op(op)),
+                            Object::HostMatrix(row_count, col_count, elems) => host_matrix_neg(*row_count, *col_count, elems),
                             _ => Err(Error::Interp(String::from("unsupported type for negation"))),
                         }
                     },
@@ -711,10 +1275,12 @@ impl Value
             },
             UnaryOp::DotNeg => {
                 match self {
-                    Value::Int(_) | Value::Float(_) => Ok(Value::Float(-self.to_f32())),
+                    Value::Int(_) | Value::Float(_) => Ok(Value::Float(-self.to_f64())),
                     Value::Object(object) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_rsub_for_scalar(a, 0.0)?)))),
+                            Object::Tensor(_, _) => self.dot1("unsupported type for dot negation", |v| v.unary_op(op)),
+                            Object::HostMatrix(row_count, col_count, elems) => host_matrix_neg(*row_count, *col_count, elems),
                             _ => Err(Error::Interp(String::from("unsupported type for dot negation"))),
                         }
                     },
@@ -728,6 +1294,7 @@ impl Value
                     Value::Object(object) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(a.transpose())))),
+                            Object::HostMatrix(row_count, col_count, elems) => Ok(Value::Object(Arc::new(Object::HostMatrix(*col_count, *row_count, elems.transpose(*row_count, *col_count))))),
                             _ => Err(Error::Interp(String::from("unsupported type for transpose"))),
                         }
                     },
@@ -740,6 +1307,27 @@ impl Value
     /// Performs an operation on two values for the binary operator.
     pub fn bin_op(&self, op: BinOp, value: &Value) -> Result<Value>
     {
+        if self.is_tracked() || value.is_tracked() {
+            return tracked_bin_op(op, self, value);
+        }
+        if self.is_tensor() || value.is_tensor() {
+            match (op, self, value) {
+                (BinOp::Mul, Value::Object(_), Value::Object(_)) => return Err(Error::Interp(String::from("unsupported types for multiplication"))),
+                (BinOp::Div, Value::Object(_), Value::Object(_)) => return Err(Error::Interp(String::from("unsupported types for division"))),
+                (BinOp::Mul | BinOp::Div | BinOp::DotMul | BinOp::DotDiv | BinOp::Add | BinOp::DotAdd | BinOp::Sub | BinOp::DotSub, _, _) => {
+                    return self.dot2(value, "unsupported types for tensor operation", |v, w| v.bin_op(op, w));
+                },
+                (_, _, _) => (),
+            }
+        }
+        if self.is_host_matrix() || value.is_host_matrix() {
+            match op {
+                BinOp::Mul | BinOp::Div | BinOp::DotMul | BinOp::DotDiv | BinOp::Add | BinOp::DotAdd | BinOp::Sub | BinOp::DotSub => {
+                    return host_matrix_bin_op(op, self, value);
+                },
+                _ => (),
+            }
+        }
         match op {
             BinOp::Index => self.elem(value),
             BinOp::Mul => {
@@ -750,7 +1338,7 @@ impl Value
                             None => Err(Error::Interp(String::from("overflow in multiplication"))),
                         }
                     },
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() * value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() * value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_mul_for_scalar(a, value.to_f32())?)))),
@@ -774,7 +1362,7 @@ impl Value
             },
             BinOp::DotMul => {
                 match (self, value) {
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() * value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() * value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_mul_for_scalar(a, value.to_f32())?)))),
@@ -812,7 +1400,7 @@ impl Value
                             },
                         }
                     },
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() / value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() / value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_div_for_scalar(a, value.to_f32())?)))),
@@ -830,7 +1418,7 @@ impl Value
             },
             BinOp::DotDiv => {
                 match (self, value) {
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() / value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() / value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_div_for_scalar(a, value.to_f32())?)))),
@@ -862,7 +1450,7 @@ impl Value
                             None => Err(Error::Interp(String::from("overflow in addition"))),
                         }
                     },
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() + value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() + value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_add_for_scalar(a, value.to_f32())?)))),
@@ -913,6 +1501,13 @@ impl Value
                                 }
                                 Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Struct(new_fields)))))
                             },
+                            (MutObject::Dict(pairs), MutObject::Dict(pairs2)) => {
+                                let mut new_pairs = pairs2.clone();
+                                for (key, value) in pairs {
+                                    new_pairs.insert(key.clone(), value.clone());
+                                }
+                                Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Dict(new_pairs)))))
+                            },
                             _ => Err(Error::Interp(String::from("unsupported types for addition"))),
                         }
                     },
@@ -921,7 +1516,7 @@ impl Value
             },
             BinOp::DotAdd => {
                 match (self, value) {
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() + value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() + value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_add_for_scalar(a, value.to_f32())?)))),
@@ -953,7 +1548,7 @@ impl Value
                             None => Err(Error::Interp(String::from("overflow in subtraction"))),
                         }
                     },
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() - value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() - value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_sub_for_scalar(a, value.to_f32())?)))),
@@ -977,7 +1572,7 @@ impl Value
             },
             BinOp::DotSub => {
                 match (self, value) {
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f32() - value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Float(self.to_f64() - value.to_f64())),
                     (Value::Object(object), Value::Int(_) | Value::Float(_)) => {
                         match &**object {
                             Object::Matrix(a) => Ok(Value::Object(Arc::new(Object::Matrix(matrix_sub_for_scalar(a, value.to_f32())?)))),
@@ -1005,7 +1600,7 @@ impl Value
                 match (self, value) {
                     (Value::Bool(a), Value::Bool(b)) => Ok(Value::Bool(a < b)),
                     (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a < b)),
-                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Bool(self.to_f32() < value.to_f32())),
+                    (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(Value::Bool(self.to_f64() < value.to_f64())),
                     (Value::Object(object), Value::Object(object2)) => {
                         match (&**object, &**object2) {
                             (Object::String(s), Object::String(t)) => Ok(Value::Bool(s < t)),
@@ -1024,6 +1619,9 @@ impl Value
     }
     
     /// Returns an interator if the value is iterable, otherwise `None`.
+    ///
+    /// An iterator of a dictionary returns arrays of keys and values for the dictionary at the
+    /// creation of the iterator.
     pub fn iter(&self) -> Result<Option<Iter<'_>>>
     {
         match self {
@@ -1041,6 +1639,34 @@ impl Value
                 let object_g = rw_lock_read(&**object)?;
                 match &*object_g {
                     MutObject::Array(_) => Ok(Some(Iter::new(IterEnum::Array(object.clone(), 0, false)))),
+                    MutObject::Dict(pairs) => Ok(Some(Iter::new(IterEnum::Dict(pairs.iter().map(|(k, v)| (k.clone(), v.clone())).collect(), 0)))),
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns an interator that doesn't borrow the value if the value is iterable, otherwise
+    /// `None`.
+    pub fn owned_iter(&self) -> Result<Option<Iter<'static>>>
+    {
+        match self {
+            Value::Object(object) => {
+                match &**object {
+                    Object::String(_) => Ok(Some(Iter::new(IterEnum::OwnedString(object.clone(), 0)))),
+                    Object::IntRange(a, b, c) => Ok(Some(Iter::new(IterEnum::IntRange(*a, *b, *c, false)))),
+                    Object::FloatRange(a, b, c) => Ok(Some(Iter::new(IterEnum::FloatRange(*a, *b, *c, false)))),
+                    Object::MatrixArray(_, _, _, _) => Ok(Some(Iter::new(IterEnum::MatrixArray(object.clone(), 0, false)))), 
+                    Object::MatrixRowSlice(matrix_array, i) => Ok(Some(Iter::new(IterEnum::MatrixRowSlice(matrix_array.clone(), *i, 0, false)))),
+                    _ => Ok(None),
+                }
+            },
+            Value::Ref(object) => {
+                let object_g = rw_lock_read(&**object)?;
+                match &*object_g {
+                    MutObject::Array(_) => Ok(Some(Iter::new(IterEnum::Array(object.clone(), 0, false)))),
+                    MutObject::Dict(pairs) => Ok(Some(Iter::new(IterEnum::Dict(pairs.iter().map(|(k, v)| (k.clone(), v.clone())).collect(), 0)))),
                     _ => Ok(None),
                 }
             }
@@ -1106,6 +1732,111 @@ impl Value
                         Value::Float(*c).fmt_with_indent(f, indent, is_width)?;
                     },
                     Object::Matrix(_) => self.to_matrix_array().unwrap().fmt_with_indent(f, indent, is_width)?,
+                    Object::Tensor(shape, a) => {
+                        match matrix_to_matrix_array(a).unwrap() {
+                            Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
+                                let elem = |l: usize| {
+                                    let (i, j) = (l / col_count, l % col_count);
+                                    match transpose_flag {
+                                        TransposeFlag::NoTranspose => xs[i * col_count + j],
+                                        TransposeFlag::Transpose => xs[j * row_count + i],
+                                    }
+                                };
+                                write!(f, "tensor(")?;
+                                let mut is_first = true;
+                                for n in shape {
+                                    if !is_first {
+                                        write!(f, ", ")?;
+                                    }
+                                    write!(f, "{}", n)?;
+                                    is_first = false;
+                                }
+                                write!(f, ") ")?;
+                                if shape.len() == 1 {
+                                    let new_indent = indent + 4;
+                                    write!(f, "[")?;
+                                    for l in 0..shape[0] {
+                                        write!(f, " ")?;
+                                        Value::Float(elem(l) as f64).fmt_with_indent(f, new_indent, is_width)?;
+                                    }
+                                    write!(f, " ]")?;
+                                } else {
+                                    let n = shape.len();
+                                    let (slice_row_count, slice_col_count) = (shape[n - 2], shape[n - 1]);
+                                    let slice_count: usize = shape[..(n - 2)].iter().product();
+                                    let new_indent = if n > 2 { indent + 4 } else { indent };
+                                    if n > 2 {
+                                        writeln!(f, "{{")?;
+                                    }
+                                    let mut idxs = vec![0usize; n - 2];
+                                    for s in 0..slice_count {
+                                        if n > 2 {
+                                            write!(f, "{:new_indent$}(", "")?;
+                                            for idx in &idxs {
+                                                write!(f, "{}, ", idx + 1)?;
+                                            }
+                                            write!(f, ":, :) = ")?;
+                                        }
+                                        let new_indent2 = new_indent + 4;
+                                        writeln!(f, "[")?;
+                                        for i in 0..slice_row_count {
+                                            write!(f, "{:new_indent2$}", "")?;
+                                            for j in 0..slice_col_count {
+                                                let l = (s * slice_row_count + i) * slice_col_count + j;
+                                                Value::Float(elem(l) as f64).fmt_with_indent(f, new_indent2, true)?;
+                                                if j + 1 < slice_col_count {
+                                                    write!(f, " ")?;
+                                                }
+                                            }
+                                            writeln!(f, "")?;
+                                        }
+                                        write!(f, "{:new_indent$}]", "")?;
+                                        if n > 2 {
+                                            writeln!(f, "")?;
+                                            for k in (0..(n - 2)).rev() {
+                                                idxs[k] += 1;
+                                                if idxs[k] < shape[k] {
+                                                    break;
+                                                }
+                                                idxs[k] = 0;
+                                            }
+                                        }
+                                    }
+                                    if n > 2 {
+                                        write!(f, "{:indent$}}}", "")?;
+                                    }
+                                }
+                            },
+                            _ => write!(f, "tensor(...)")?,
+                        }
+                    },
+                    Object::HostMatrix(row_count, col_count, elems) => {
+                        match elems {
+                            HostElems::Double(_) => write!(f, "doublematrix ")?,
+                            HostElems::Int(_) => write!(f, "intmatrix ")?,
+                        }
+                        if *row_count > 0 && *col_count > 0 { 
+                            let new_indent = indent + 4;
+                            writeln!(f, "[")?;
+                            for i in 0..*row_count {
+                                write!(f, "{:new_indent$}", "")?;
+                                for j in 0..*col_count {
+                                    let k = i * (*col_count) + j;
+                                    match elems {
+                                        HostElems::Double(xs) => Value::Float(xs[k]).fmt_with_indent(f, new_indent, true)?,
+                                        HostElems::Int(xs) => Value::Int(xs[k] as i64).fmt_with_indent(f, new_indent, true)?,
+                                    }
+                                    if j + 1 < *col_count {
+                                        write!(f, " ")?;
+                                    }
+                                }
+                                writeln!(f, "")?;          
+                            }
+                            write!(f, "{:indent$}]", "")?;
+                        } else {
+                            write!(f, "[]")?;
+                        }
+                    },
                     Object::Fun(idents, ident, _) => {
                         for ident2 in idents {
                             write!(f, "{}::", ident2)?;
@@ -1113,6 +1844,7 @@ impl Value
                         write!(f, "{}", ident)?;
                     },
                     Object::BuiltinFun(ident, _) => write!(f, "{}", ident)?,
+                    Object::Lambda(_, _, _) => write!(f, "function(...)")?,
                     Object::MatrixArray(row_count, col_count, transpose_flag, xs) => {
                         if *row_count > 0 && *col_count > 0 { 
                             let new_indent = indent + 4;
@@ -1124,7 +1856,7 @@ impl Value
                                         TransposeFlag::NoTranspose => i * (*col_count) + j,
                                         TransposeFlag::Transpose => j * (*row_count) + i,
                                     };
-                                    Value::Float(xs[k]).fmt_with_indent(f, new_indent, true)?;
+                                    Value::Float(xs[k] as f64).fmt_with_indent(f, new_indent, true)?;
                                     if j + 1 < *col_count {
                                         write!(f, " ")?;
                                     }
@@ -1148,7 +1880,7 @@ impl Value
                                             TransposeFlag::Transpose => j * (*row_count) + (*i),
                                         };
                                         write!(f, " ")?;
-                                        Value::Float(xs[k]).fmt_with_indent(f, new_indent, is_width)?;
+                                        Value::Float(xs[k] as f64).fmt_with_indent(f, new_indent, is_width)?;
                                     }
                                     write!(f, " ]")?;
                                 } else {
@@ -1159,7 +1891,16 @@ impl Value
                         }
                     },
                     Object::Error(_, msg) => write!(f, "{}", msg)?,
+                    Object::Regex(_) => write!(f, "regex(...)")?,
+                    Object::ValueReader(_) => write!(f, "valuereader(...)")?,
+                    Object::ValueWriter(_) => write!(f, "valuewriter(...)")?,
                     Object::WindowId(_) => write!(f, "windowid(...)")?,
+                    Object::Tracked(_, _) => {
+                        match untracked_value(self) {
+                            Ok(value) => value.fmt_with_indent(f, indent, is_width)?,
+                            Err(_) => write!(f, "tracked(...)")?,
+                        }
+                    },
                 }
             },
             Value::Ref(object) => {
@@ -1192,6 +1933,20 @@ impl Value
                             write!(f, "{{}}")?;
                         }
                     },
+                    MutObject::Dict(pairs) => {
+                        if !pairs.is_empty() {
+                            let new_indent = indent + 4;
+                            writeln!(f, ".{{")?;
+                            for (key, value) in pairs {
+                                write!(f, "{:new_indent$}{}: ", "", key)?;
+                                value.fmt_with_indent(f, new_indent, is_width)?;
+                                writeln!(f, "")?;
+                            }
+                            write!(f, "{:indent$}.}}", "")?;
+                        } else {
+                            write!(f, ".{{.}}")?;
+                        }
+                    },
                 }
             },
             Value::Weak(object) => {
@@ -1426,7 +2181,7 @@ impl PartialOrd for Value
         match (self, other) {
             (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
             (Value::Int(a), Value::Int(b)) => a.partial_cmp(b),
-            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => self.to_f32().partial_cmp(&other.to_f32()),
+            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => self.to_f64().partial_cmp(&other.to_f64()),
             (Value::Object(object), Value::Object(object2)) => {
                 match (&**object, &**object2) {
                     (Object::String(s), Object::String(t)) => s.partial_cmp(t),
@@ -1453,7 +2208,7 @@ impl Serialize for Value
             Value::None => serializer.serialize_unit(),
             Value::Bool(a) => serializer.serialize_bool(*a),
             Value::Int(a) => serializer.serialize_i64(*a),
-            Value::Float(a) => serializer.serialize_f32(*a),
+            Value::Float(a) => serializer.serialize_f64(*a),
             Value::Object(object) => {
                 match &**object {
                     Object::String(s) => serializer.serialize_str(s.as_str()),
@@ -1480,6 +2235,13 @@ impl Serialize for Value
                         }
                         map.end()
                     },
+                    MutObject::Dict(pairs) => {
+                        let mut map = serializer.serialize_map(Some(pairs.len()))?;
+                        for (key, value) in pairs {
+                            map.serialize_entry(&key.to_key_string(), value)?;
+                        }
+                        map.end()
+                    },
                 }
             },
             _ => Err(ser::Error::custom("unsupported type for serialization")),
@@ -1520,11 +2282,11 @@ impl<'de> Visitor<'de> for ValueVisitor
     
     fn visit_f32<E>(self, v: f32) -> result::Result<Self::Value, E>
         where E: de::Error
-    { Ok(Value::Float(v)) }
+    { Ok(Value::Float(v as f64)) }
 
     fn visit_f64<E>(self, v: f64) -> result::Result<Self::Value, E>
         where E: de::Error
-    { Ok(Value::Float(v as f32)) }
+    { Ok(Value::Float(v)) }
     
     fn visit_str<E>(self, v: &str) -> result::Result<Self::Value, E>
         where E: de::Error
@@ -1575,21 +2337,43 @@ pub enum Object
     /// An integer number range.
     IntRange(i64, i64, i64),
     /// A floating-point number range.
-    FloatRange(f32, f32, f32),
+    FloatRange(f64, f64, f64),
     /// A matrix.
     Matrix(Matrix),
+    /// A tensor with the shape and the matrix of tensor elements.
+    ///
+    /// The tensor elements are stored in the matrix in the row-major order. The number of matrix
+    /// rows is the first dimension and the number of matrix columns is the product of the other
+    /// dimensions.
+    Tensor(Vec<usize>, Matrix),
+    /// A host matrix with the number of rows, the number of columns, and the host matrix
+    /// elements.
+    ///
+    /// The host matrix has double-precision floating-point elements or integer elements and is
+    /// stored in the host memory.
+    HostMatrix(usize, usize, HostElems),
     /// A function.
     Fun(Vec<String>, String, Arc<Fun>),
     /// A built-in function.
     BuiltinFun(String, fn(&mut Interp, &mut Env, &[Value]) -> Result<Value>),
+    /// An anonymous function with the function module and the captured local variables.
+    Lambda(Weak<RwLock<ModNode<Value, ()>>>, BTreeMap<String, Value>, Arc<Fun>),
     /// A matrix array.
     MatrixArray(usize, usize, TransposeFlag, Vec<f32>),
     /// A matrix row slice.
     MatrixRowSlice(Arc<Object>, usize),
     /// An error.
     Error(String, String),
+    /// A compiled regular expression.
+    Regex(Regex),
+    /// A reader of values from a file.
+    ValueReader(Arc<RwLock<ValueReader>>),
+    /// A writer of values to a file.
+    ValueWriter(Arc<RwLock<ValueWriter>>),
     /// A window identifier.
     WindowId(WindowId),
+    /// A tracked value with the tape and the index of the tape node.
+    Tracked(Arc<RwLock<Tape>>, usize),
 }
 
 impl Object
@@ -1602,6 +2386,20 @@ impl Object
             (Object::FloatRange(a, b, c), Object::FloatRange(d, e, f)) => Ok(a == d && b == e && c == f),
             (Object::Fun(idents, ident, fun), Object::Fun(idents2, ident2, fun2)) => Ok(idents == idents2 && ident == ident2 && Arc::ptr_eq(fun, fun2)),
             (Object::BuiltinFun(ident, f), Object::BuiltinFun(ident2, g)) => Ok(ident == ident2 && f == g),
+            (Object::Lambda(fun_mod, local_vars, fun), Object::Lambda(fun_mod2, local_vars2, fun2)) => {
+                if !Weak::ptr_eq(fun_mod, fun_mod2) || !Arc::ptr_eq(fun, fun2) {
+                    return Ok(false);
+                }
+                if local_vars.len() != local_vars2.len() {
+                    return Ok(false);
+                }
+                for ((ident, value), (ident2, value2)) in local_vars.iter().zip(local_vars2.iter()) {
+                    if ident != ident2 || !value.eq_with_types(value2)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            },
             (Object::MatrixArray(a_row_count, a_col_count, a_transpose_flag, xs), Object::MatrixArray(b_row_count, b_col_count, b_transpose_flag, ys)) => {
                 if a_row_count != b_row_count || a_col_count != b_col_count {
                     return Ok(false);
@@ -1657,8 +2455,13 @@ impl Object
                     (_, _) => return Err(Error::Interp(String::from("invalid matrix array type")))
                 }
             },
+            (Object::HostMatrix(a_row_count, a_col_count, xs), Object::HostMatrix(b_row_count, b_col_count, ys)) => Ok(a_row_count == b_row_count && a_col_count == b_col_count && xs.priv_eq(ys)),
             (Object::Error(kind, msg), Object::Error(kind2, msg2)) => Ok(kind == kind2 && msg == msg2),
+            (Object::Regex(regex), Object::Regex(regex2)) => Ok(regex.as_str() == regex2.as_str()),
+            (Object::ValueReader(reader), Object::ValueReader(reader2)) => Ok(Arc::ptr_eq(reader, reader2)),
+            (Object::ValueWriter(writer), Object::ValueWriter(writer2)) => Ok(Arc::ptr_eq(writer, writer2)),
             (Object::WindowId(window_id), Object::WindowId(window_id2)) => Ok(window_id == window_id2),
+            (Object::Tracked(tape, idx), Object::Tracked(tape2, idx2)) => Ok(Arc::ptr_eq(tape, tape2) && idx == idx2),
             (_, _) => Ok(false),
         }
     }
@@ -1682,7 +2485,7 @@ impl Object
                         };
                         match (xs.get(ak), ys.get(bk)) {
                             (Some(x), Some(y)) => {
-                                if !nearly_eq(*x, *y, eps) {
+                                if !nearly_eq(*x as f64, *y as f64, eps as f64) {
                                     return Ok(false);
                                 }
                             },
@@ -1709,7 +2512,7 @@ impl Object
                             };
                             match (xs.get(ak), ys.get(bk)) {
                                 (Some(x), Some(y)) => {
-                                    if !nearly_eq(*x, *y, eps) {
+                                    if !nearly_eq(*x as f64, *y as f64, eps as f64) {
                                         return Ok(false);
                                     }
                                 },
@@ -1721,6 +2524,7 @@ impl Object
                     (_, _) => return Err(Error::Interp(String::from("invalid matrix array type")))
                 }
             },
+            (Object::HostMatrix(a_row_count, a_col_count, xs), Object::HostMatrix(b_row_count, b_col_count, ys)) => Ok(a_row_count == b_row_count && a_col_count == b_col_count && xs.priv_nearly_eq(ys, eps)),
             (_, _) => self.priv_eq(object),
         }
     }
@@ -1738,6 +2542,244 @@ pub enum TransposeFlag
     Transpose,
 }
 
+/// An enumeration of matrix element type.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ElemType
+{
+    /// A single-precision floating-point number.
+    Float,
+    /// A double-precision floating-point number.
+    Double,
+    /// A 32-bit integer number.
+    Int,
+}
+
+impl ElemType
+{
+    /// Returns the name of the element type.
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            ElemType::Float => "float",
+            ElemType::Double => "double",
+            ElemType::Int => "int",
+        }
+    }
+}
+
+impl fmt::Display for ElemType
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    { write!(f, "{}", self.name()) }
+}
+
+fn int_elem(a: i64) -> Result<i32>
+{
+    if a < (i32::MIN as i64) || a > (i32::MAX as i64) {
+        return Err(Error::Interp(String::from("integer number is out of range of matrix element")));
+    }
+    Ok(a as i32)
+}
+
+/// An enumeration of host matrix elements.
+///
+/// The host matrix elements are stored in the host memory instead of the GPU memory in the
+/// row-major order. Operations on these elements are performed by the CPU.
+#[derive(Clone, Debug)]
+pub enum HostElems
+{
+    /// Double-precision floating-point numbers.
+    Double(Vec<f64>),
+    /// 32-bit integer numbers.
+    Int(Vec<i32>),
+}
+
+impl HostElems
+{
+    /// Converts the values to the host matrix elements with the element type.
+    ///
+    /// The values must be numbers. Floating-point numbers are truncated towards zero and
+    /// saturated for integer elements. The element type mustn't be a single-precision
+    /// floating-point number.
+    pub fn from_values(values: &[Value], elem_type: ElemType) -> Result<HostElems>
+    {
+        match elem_type {
+            ElemType::Float => Err(Error::Interp(String::from("unsupported element type for host matrix"))),
+            ElemType::Double => {
+                let mut xs: Vec<f64> = Vec::new();
+                for value in values {
+                    match value {
+                        Value::Int(a) => xs.push(*a as f64),
+                        Value::Float(a) => xs.push(*a),
+                        _ => return Err(Error::Interp(String::from("unsupported type for matrix element"))),
+                    }
+                }
+                Ok(HostElems::Double(xs))
+            },
+            ElemType::Int => {
+                let mut xs: Vec<i32> = Vec::new();
+                for value in values {
+                    match value {
+                        Value::Int(a) => xs.push(int_elem(*a)?),
+                        Value::Float(a) => xs.push(*a as i32),
+                        _ => return Err(Error::Interp(String::from("unsupported type for matrix element"))),
+                    }
+                }
+                Ok(HostElems::Int(xs))
+            },
+        }
+    }
+
+    /// Converts the single-precision floating-point numbers to the host matrix elements with the
+    /// element type.
+    ///
+    /// The numbers are converted as for [`from_values`](Self::from_values).
+    pub fn from_f32s(xs: &[f32], elem_type: ElemType) -> Result<HostElems>
+    {
+        match elem_type {
+            ElemType::Float => Err(Error::Interp(String::from("unsupported element type for host matrix"))),
+            ElemType::Double => Ok(HostElems::Double(xs.iter().map(|x| *x as f64).collect())),
+            ElemType::Int => Ok(HostElems::Int(xs.iter().map(|x| *x as i32).collect())),
+        }
+    }
+
+    /// Returns the element type.
+    pub fn elem_type(&self) -> ElemType
+    {
+        match self {
+            HostElems::Double(_) => ElemType::Double,
+            HostElems::Int(_) => ElemType::Int,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize
+    {
+        match self {
+            HostElems::Double(xs) => xs.len(),
+            HostElems::Int(xs) => xs.len(),
+        }
+    }
+
+    /// Returns the element with the index as a number value.
+    pub fn elem(&self, k: usize) -> Option<Value>
+    {
+        match self {
+            HostElems::Double(xs) => xs.get(k).map(|x| Value::Float(*x)),
+            HostElems::Int(xs) => xs.get(k).map(|x| Value::Int(*x as i64)),
+        }
+    }
+
+    /// Converts the host matrix elements to single-precision floating-point numbers.
+    pub fn to_f32s(&self) -> Vec<f32>
+    {
+        match self {
+            HostElems::Double(xs) => xs.iter().map(|x| *x as f32).collect(),
+            HostElems::Int(xs) => xs.iter().map(|x| *x as f32).collect(),
+        }
+    }
+
+    /// Converts the host matrix elements to the host matrix elements with the element type.
+    ///
+    /// The elements are converted as for [`from_values`](Self::from_values).
+    pub fn to_elem_type(&self, elem_type: ElemType) -> Result<HostElems>
+    {
+        match (self, elem_type) {
+            (_, ElemType::Float) => Err(Error::Interp(String::from("unsupported element type for host matrix"))),
+            (HostElems::Double(_), ElemType::Double) | (HostElems::Int(_), ElemType::Int) => Ok(self.clone()),
+            (HostElems::Double(xs), ElemType::Int) => Ok(HostElems::Int(xs.iter().map(|x| *x as i32).collect())),
+            (HostElems::Int(xs), ElemType::Double) => Ok(HostElems::Double(xs.iter().map(|x| *x as f64).collect())),
+        }
+    }
+
+    fn to_f64s(&self) -> Vec<f64>
+    {
+        match self {
+            HostElems::Double(xs) => xs.clone(),
+            HostElems::Int(xs) => xs.iter().map(|x| *x as f64).collect(),
+        }
+    }
+
+    fn select(&self, col_count: usize, row_idxs: &[usize], col_idxs: &[usize]) -> HostElems
+    {
+        match self {
+            HostElems::Double(xs) => HostElems::Double(host_select(xs.as_slice(), col_count, row_idxs, col_idxs)),
+            HostElems::Int(xs) => HostElems::Int(host_select(xs.as_slice(), col_count, row_idxs, col_idxs)),
+        }
+    }
+
+    fn set_selected(&self, col_count: usize, row_idxs: &[usize], col_idxs: &[usize], elems: &HostElems) -> HostElems
+    {
+        match (self, elems) {
+            (HostElems::Double(xs), HostElems::Double(ys)) => HostElems::Double(host_set_selected(xs.as_slice(), col_count, row_idxs, col_idxs, ys.as_slice())),
+            (HostElems::Int(xs), HostElems::Int(ys)) => HostElems::Int(host_set_selected(xs.as_slice(), col_count, row_idxs, col_idxs, ys.as_slice())),
+            (_, _) => self.clone(),
+        }
+    }
+
+    fn transpose(&self, row_count: usize, col_count: usize) -> HostElems
+    {
+        let row_idxs: Vec<usize> = (0..row_count).collect();
+        let col_idxs: Vec<usize> = (0..col_count).collect();
+        match self {
+            HostElems::Double(xs) => HostElems::Double(host_select_transposed(xs.as_slice(), col_count, row_idxs.as_slice(), col_idxs.as_slice())),
+            HostElems::Int(xs) => HostElems::Int(host_select_transposed(xs.as_slice(), col_count, row_idxs.as_slice(), col_idxs.as_slice())),
+        }
+    }
+
+    fn priv_eq(&self, elems: &HostElems) -> bool
+    {
+        match (self, elems) {
+            (HostElems::Double(xs), HostElems::Double(ys)) => xs == ys,
+            (HostElems::Int(xs), HostElems::Int(ys)) => xs == ys,
+            (_, _) => false,
+        }
+    }
+
+    fn priv_nearly_eq(&self, elems: &HostElems, eps: f32) -> bool
+    {
+        match (self, elems) {
+            (HostElems::Double(xs), HostElems::Double(ys)) => xs.iter().zip(ys.iter()).all(|(x, y)| nearly_eq(*x, *y, eps as f64)),
+            (_, _) => self.priv_eq(elems),
+        }
+    }
+}
+
+fn host_select<T: Copy>(xs: &[T], col_count: usize, row_idxs: &[usize], col_idxs: &[usize]) -> Vec<T>
+{
+    let mut ys: Vec<T> = Vec::with_capacity(row_idxs.len() * col_idxs.len());
+    for i in row_idxs {
+        for j in col_idxs {
+            ys.push(xs[i * col_count + j]);
+        }
+    }
+    ys
+}
+
+fn host_set_selected<T: Copy>(xs: &[T], col_count: usize, row_idxs: &[usize], col_idxs: &[usize], ys: &[T]) -> Vec<T>
+{
+    let mut zs = xs.to_vec();
+    let mut k = 0usize;
+    for i in row_idxs {
+        for j in col_idxs {
+            zs[i * col_count + j] = ys[k];
+            k += 1;
+        }
+    }
+    zs
+}
+
+fn host_select_transposed<T: Copy>(xs: &[T], col_count: usize, row_idxs: &[usize], col_idxs: &[usize]) -> Vec<T>
+{
+    let mut ys: Vec<T> = Vec::with_capacity(row_idxs.len() * col_idxs.len());
+    for j in col_idxs {
+        for i in row_idxs {
+            ys.push(xs[i * col_count + j]);
+        }
+    }
+    ys
+}
+
 /// An enumeration of mutable object.
 #[derive(Clone, Debug)]
 pub enum MutObject
@@ -1746,6 +2788,8 @@ pub enum MutObject
     Array(Vec<Value>),
     /// A structure.
     Struct(BTreeMap<String, Value>),
+    /// A dictionary.
+    Dict(BTreeMap<DictKey, Value>),
 }
 
 impl MutObject
@@ -1783,6 +2827,17 @@ impl MutObject
                 }
                 Ok(true)
             },
+            (MutObject::Dict(pairs), MutObject::Dict(pairs2)) => {
+                if pairs.len() != pairs2.len() {
+                    return Ok(false);
+                }
+                for ((key, value), (key2, value2)) in pairs.iter().zip(pairs2.iter()) {
+                    if key != key2 || !f(value, value2)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            },
             (_, _) => Ok(false),
         }
     }
@@ -1820,11 +2875,140 @@ impl MutObject
                 }
                 Ok(true)
             },
+            (MutObject::Dict(pairs), MutObject::Dict(pairs2)) => {
+                if pairs.len() != pairs2.len() {
+                    return Ok(false);
+                }
+                for ((key, value), (key2, value2)) in pairs.iter().zip(pairs2.iter()) {
+                    if key != key2 || !f(value, value2, eps)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            },
             (_, _) => Ok(false),
         }
     }
 }
 
+/// An enumeration of dictionary key.
+///
+/// A floating-point number that is equal to an integer number is converted to the integer
+/// number, so equal numbers are the same key. Numbers are ordered by their values, boolean values
+/// are before numbers, and strings are after numbers.
+#[derive(Clone, Debug)]
+pub enum DictKey
+{
+    /// A boolean value.
+    Bool(bool),
+    /// An integer number.
+    Int(i64),
+    /// A floating-point number that isn't an integer number.
+    Float(f64),
+    /// A string.
+    String(String),
+}
+
+impl DictKey
+{
+    /// Creates a dictionary key from the value.
+    ///
+    /// If the value isn't a boolean value, a number, or a string, or the value is NaN, this
+    /// method returns an error.
+    pub fn from_value(value: &Value) -> Result<DictKey>
+    {
+        match value {
+            Value::Bool(a) => Ok(DictKey::Bool(*a)),
+            Value::Int(a) => Ok(DictKey::Int(*a)),
+            Value::Float(a) => {
+                if a.is_nan() {
+                    Err(Error::Interp(String::from("nan isn't dictionary key")))
+                } else if (*a as i64) as f64 == *a {
+                    Ok(DictKey::Int(*a as i64))
+                } else {
+                    Ok(DictKey::Float(*a))
+                }
+            },
+            Value::Object(object) => {
+                match &**object {
+                    Object::String(s) => Ok(DictKey::String(s.clone())),
+                    _ => Err(Error::Interp(String::from("unsupported type for dictionary key"))),
+                }
+            },
+            _ => Err(Error::Interp(String::from("unsupported type for dictionary key"))),
+        }
+    }
+
+    /// Converts the dictionary key to a value.
+    pub fn to_value(&self) -> Value
+    {
+        match self {
+            DictKey::Bool(a) => Value::Bool(*a),
+            DictKey::Int(a) => Value::Int(*a),
+            DictKey::Float(a) => Value::Float(*a),
+            DictKey::String(s) => Value::Object(Arc::new(Object::String(s.clone()))),
+        }
+    }
+
+    /// Converts the dictionary key to a string of a key of a serialized map.
+    ///
+    /// A string key is returned without quotation marks.
+    pub fn to_key_string(&self) -> String
+    {
+        match self {
+            DictKey::String(s) => s.clone(),
+            _ => format!("{}", self),
+        }
+    }
+}
+
+impl PartialEq for DictKey
+{
+    fn eq(&self, key: &DictKey) -> bool
+    { self.cmp(key) == Ordering::Equal }
+}
+
+impl Eq for DictKey
+{}
+
+impl PartialOrd for DictKey
+{
+    fn partial_cmp(&self, key: &DictKey) -> Option<Ordering>
+    { Some(self.cmp(key)) }
+}
+
+impl Ord for DictKey
+{
+    fn cmp(&self, key: &DictKey) -> Ordering
+    {
+        match (self, key) {
+            (DictKey::Bool(a), DictKey::Bool(b)) => a.cmp(b),
+            (DictKey::Bool(_), _) => Ordering::Less,
+            (_, DictKey::Bool(_)) => Ordering::Greater,
+            (DictKey::Int(a), DictKey::Int(b)) => a.cmp(b),
+            (DictKey::Int(a), DictKey::Float(b)) => (*a as f64).partial_cmp(&(*b as f64)).unwrap_or(Ordering::Equal).then(Ordering::Less),
+            (DictKey::Float(a), DictKey::Int(b)) => (*a as f64).partial_cmp(&(*b as f64)).unwrap_or(Ordering::Equal).then(Ordering::Greater),
+            (DictKey::Float(a), DictKey::Float(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (DictKey::String(s), DictKey::String(t)) => s.cmp(t),
+            (DictKey::String(_), _) => Ordering::Greater,
+            (_, DictKey::String(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl fmt::Display for DictKey
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            DictKey::Bool(a) => write!(f, "{}", Lit::Bool(*a)),
+            DictKey::Int(a) => write!(f, "{}", Lit::Int(*a)),
+            DictKey::Float(a) => write!(f, "{}", Lit::Float(*a)),
+            DictKey::String(s) => write!(f, "{}", Lit::String(s.clone())),
+        }
+    }
+}
+
 /// A structure of iterator of values.
 #[derive(Clone, Debug)]
 pub struct Iter<'a>
@@ -1855,6 +3039,22 @@ impl<'a> Iterator for Iter<'a>
                     None => None,
                 }
             },
+            IterEnum::OwnedString(object, i) => {
+                match &**object {
+                    Object::String(s) => {
+                        match s[*i..].chars().next() {
+                            Some(c) => {
+                                *i += c.len_utf8();
+                                let mut t = String::new();
+                                t.push(c);
+                                Some(Ok(Value::Object(Arc::new(Object::String(t)))))
+                            },
+                            None => None,
+                        }
+                    },
+                    _ => None,
+                }
+            },
             IterEnum::IntRange(from, to, step, is_stopped) => {
                 if !*is_stopped {
                     let current = if *step > 0 {
@@ -1975,7 +3175,7 @@ impl<'a> Iterator for Iter<'a>
                                 };
                                 *j += 1;
                                 match xs.get(k) {
-                                    Some(x) => Some(Ok(Value::Float(*x))),
+                                    Some(x) => Some(Ok(Value::Float(*x as f64))),
                                     None => {
                                         *is_stopped = true;
                                         Some(Err(Error::Interp(String::from("invalid index"))))
@@ -2029,6 +3229,15 @@ impl<'a> Iterator for Iter<'a>
                     None
                 }
             },
+            IterEnum::Dict(pairs, i) => {
+                match pairs.get(*i) {
+                    Some((key, value)) => {
+                        *i += 1;
+                        Some(Ok(Value::Ref(Arc::new(RwLock::new(MutObject::Array(vec![key.to_value(), value.clone()]))))))
+                    },
+                    None => None,
+                }
+            },
         }
     }
 }
@@ -2037,11 +3246,13 @@ impl<'a> Iterator for Iter<'a>
 enum IterEnum<'a>
 {
     String(Chars<'a>),
+    OwnedString(Arc<Object>, usize),
     IntRange(i64, i64, i64, bool),
-    FloatRange(f32, f32, f32, bool),
+    FloatRange(f64, f64, f64, bool),
     MatrixArray(Arc<Object>, usize, bool),
     MatrixRowSlice(Arc<Object>, usize, usize, bool),
     Array(Arc<RwLock<MutObject>>, usize, bool),
+    Dict(Vec<(DictKey, Value)>, usize),
 }
 
 #[cfg(test)]
@@ -4482,9 +4482,9 @@ fn test_value_fmt_formats_values()
     assert_eq!(String::from("-1.5000e20"), format!("{}", Value::Float(-1.5e20)));
     assert_eq!(String::from("-12.3400"), format!("{}", Value::Float(-12.34)));
     assert_eq!(String::from("-1.5000e-20"), format!("{}", Value::Float(-1.5e-20)));
-    assert_eq!(String::from("inf"), format!("{}", Value::Float(f32::INFINITY)));
-    assert_eq!(String::from("-inf"), format!("{}", Value::Float(-f32::INFINITY)));
-    assert_eq!(String::from("NaN"), format!("{}", Value::Float(f32::NAN)));
+    assert_eq!(String::from("inf"), format!("{}", Value::Float(f64::INFINITY)));
+    assert_eq!(String::from("-inf"), format!("{}", Value::Float(-f64::INFINITY)));
+    assert_eq!(String::from("NaN"), format!("{}", Value::Float(f64::NAN)));
     let value = Value::Object(Arc::new(Object::String(String::from("abc"))));
     assert_eq!(String::from("abc"), format!("{}", value));
     let value = Value::Object(Arc::new(Object::IntRange(2, 4, 1)));
@@ -4587,6 +4587,198 @@ fn test_value_fmt_formats_values()
     assert_eq!(String::from("weak()"), format!("{}", value));
 }
 
+#[test]
+fn test_value_fmt_formats_tensors()
+{
+    let a = vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ];
+    let value = Value::Object(Arc::new(Object::Tensor(vec![4], Matrix::new_with_elems(4, 1, a.as_slice()))));
+    assert_eq!(String::from("tensor(4) [ 1 2 3 4 ]"), format!("{}", value));
+    let s = "
+tensor(2, 2) [
+              1           2
+              3           4
+]";
+    let s2 = &s[1..];
+    let value = Value::Object(Arc::new(Object::Tensor(vec![2, 2], Matrix::new_with_elems(2, 2, a.as_slice()))));
+    assert_eq!(String::from(s2), format!("{}", value));
+    let s = "
+tensor(2, 1, 2) {
+    (1, :, :) = [
+                  1           2
+    ]
+    (2, :, :) = [
+                  3           4
+    ]
+}";
+    let s2 = &s[1..];
+    let value = Value::Object(Arc::new(Object::Tensor(vec![2, 1, 2], Matrix::new_with_elems(2, 2, a.as_slice()))));
+    assert_eq!(String::from(s2), format!("{}", value));
+}
+
+#[test]
+fn test_value_bin_op_calculates_result_for_tensors()
+{
+    let a = vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ];
+    let b = vec![
+        5.0, 6.0,
+        7.0, 8.0
+    ];
+    let value = Value::Object(Arc::new(Object::Tensor(vec![2, 1, 2], Matrix::new_with_elems(2, 2, a.as_slice()))));
+    let value2 = Value::Object(Arc::new(Object::Tensor(vec![2, 1, 2], Matrix::new_with_elems(2, 2, b.as_slice()))));
+    match value.bin_op(BinOp::DotMul, &value2) {
+        Ok(Value::Object(object)) => {
+            match &*object {
+                Object::Tensor(shape, c) => {
+                    assert_eq!(vec![2, 1, 2], *shape);
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, vec![5.0, 12.0, 21.0, 32.0]));
+                    assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(c.clone()))).to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
+            }
+        },
+        _ => assert!(false),
+    }
+    match value.bin_op(BinOp::Add, &Value::Float(1.0)) {
+        Ok(Value::Object(object)) => {
+            match &*object {
+                Object::Tensor(shape, c) => {
+                    assert_eq!(vec![2, 1, 2], *shape);
+                    let matrix_array = Arc::new(Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, vec![2.0, 3.0, 4.0, 5.0]));
+                    assert_eq!(Value::Object(matrix_array), Value::Object(Arc::new(Object::Matrix(c.clone()))).to_matrix_array().unwrap());
+                },
+                _ => assert!(false),
+            }
+        },
+        _ => assert!(false),
+    }
+    let value3 = Value::Object(Arc::new(Object::Tensor(vec![2, 2], Matrix::new_with_elems(2, 2, b.as_slice()))));
+    match value.bin_op(BinOp::DotAdd, &value3) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("shapes of two tensors aren't equal"), msg),
+        _ => assert!(false),
+    }
+    match value.bin_op(BinOp::Mul, &value2) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("unsupported types for multiplication"), msg),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_value_fmt_formats_host_matrices()
+{
+    let s = "
+intmatrix [
+              1           2
+              3           4
+]";
+    let s2 = &s[1..];
+    let value = Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 2, 3, 4]))));
+    assert_eq!(String::from(s2), format!("{}", value));
+    let s = "
+doublematrix [
+         1.5000          -2
+]";
+    let s2 = &s[1..];
+    let value = Value::Object(Arc::new(Object::HostMatrix(1, 2, HostElems::Double(vec![1.5, -2.0]))));
+    assert_eq!(String::from(s2), format!("{}", value));
+    let value = Value::Object(Arc::new(Object::HostMatrix(0, 0, HostElems::Int(Vec::new()))));
+    assert_eq!(String::from("intmatrix []"), format!("{}", value));
+}
+
+#[test]
+fn test_value_bin_op_calculates_result_for_floats_with_double_precision()
+{
+    match Value::Float(16777216.0).bin_op(BinOp::Add, &Value::Float(1.0)) {
+        Ok(value) => assert_eq!(Value::Float(16777217.0), value),
+        Err(_) => assert!(false),
+    }
+    match Value::Int(16777217).bin_op(BinOp::Sub, &Value::Float(0.125)) {
+        Ok(value) => assert_eq!(Value::Float(16777216.875), value),
+        Err(_) => assert!(false),
+    }
+    match Value::Float(16777216.0).bin_op(BinOp::Lt, &Value::Float(16777217.0)) {
+        Ok(value) => assert_eq!(Value::Bool(true), value),
+        Err(_) => assert!(false),
+    }
+    match Value::Float(16777217.0).bin_op(BinOp::Eq, &Value::Int(16777217)) {
+        Ok(value) => assert_eq!(Value::Bool(true), value),
+        Err(_) => assert!(false),
+    }
+}
+
+#[test]
+fn test_value_bin_op_calculates_result_for_host_matrices()
+{
+    let value = Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 2, 3, 4]))));
+    let value2 = Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![5, 6, 7, 8]))));
+    match value.bin_op(BinOp::Mul, &value2) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![19, 22, 43, 50])))), value3),
+        Err(_) => assert!(false),
+    }
+    match value.bin_op(BinOp::DotMul, &value2) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![5, 12, 21, 32])))), value3),
+        Err(_) => assert!(false),
+    }
+    match value.bin_op(BinOp::Add, &Value::Int(1)) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![2, 3, 4, 5])))), value3),
+        Err(_) => assert!(false),
+    }
+    match Value::Int(10).bin_op(BinOp::DotDiv, &value) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![10, 5, 3, 2])))), value3),
+        Err(_) => assert!(false),
+    }
+    match value.bin_op(BinOp::Mul, &Value::Float(0.5)) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![0.5, 1.0, 1.5, 2.0])))), value3),
+        Err(_) => assert!(false),
+    }
+    let value4 = Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![0.25, 0.5, 0.75, 1.0]))));
+    match value.bin_op(BinOp::Sub, &value4) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Double(vec![0.75, 1.5, 2.25, 3.0])))), value3),
+        Err(_) => assert!(false),
+    }
+    match value.unary_op(UnaryOp::Transpose) {
+        Ok(value3) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 2, HostElems::Int(vec![1, 3, 2, 4])))), value3),
+        Err(_) => assert!(false),
+    }
+    let value5 = Value::Object(Arc::new(Object::HostMatrix(1, 1, HostElems::Int(vec![i32::MAX]))));
+    match value5.bin_op(BinOp::Add, &Value::Int(1)) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("overflow in addition"), msg),
+        _ => assert!(false),
+    }
+    match value.bin_op(BinOp::DotDiv, &Value::Int(0)) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("division by zero"), msg),
+        _ => assert!(false),
+    }
+    let value6 = Value::Object(Arc::new(Object::MatrixArray(2, 2, TransposeFlag::NoTranspose, vec![1.0, 2.0, 3.0, 4.0])));
+    match value.bin_op(BinOp::Add, &value6) {
+        Err(Error::Interp(msg)) => assert_eq!(String::from("mismatched element types for addition"), msg),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_value_elem2_and_set_elem2_index_host_matrices()
+{
+    let value = Value::Object(Arc::new(Object::HostMatrix(2, 3, HostElems::Int(vec![1, 2, 3, 4, 5, 6]))));
+    match value.elem2(Some(&Value::Int(2)), Some(&Value::Int(3))) {
+        Ok(value2) => assert_eq!(Value::Int(6), value2),
+        Err(_) => assert!(false),
+    }
+    match value.elem2(None, Some(&Value::Int(2))) {
+        Ok(value2) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 1, HostElems::Int(vec![2, 5])))), value2),
+        Err(_) => assert!(false),
+    }
+    match value.set_elem2(Some(&Value::Int(1)), None, Value::Float(7.5)) {
+        Ok(value2) => assert_eq!(Value::Object(Arc::new(Object::HostMatrix(2, 3, HostElems::Int(vec![7, 7, 7, 4, 5, 6])))), value2),
+        Err(_) => assert!(false),
+    }
+}
+
 #[test]
 fn test_value_fmt_formats_values_for_indent()
 {